@@ -3,11 +3,15 @@
 //! Static analysis checks for Ifá-Lang programs.
 //! Ported from legacy/src/linter.py and legacy/src/validator.py
 
+use crate::Severity;
 use crate::diagnose::Babalawo;
+use crate::infer::call_capabilities;
 use crate::iwa::IwaEngine;
 use crate::taboo::TabooEnforcer;
+use ifa_core::OduDomain;
 use ifa_core::ast::{Expression, Program, Statement, TypeHint, Visibility};
-use crate::Severity;
+use ifa_core::interpreter::handlers::{closest, levenshtein};
+use ifa_sandbox::Ofun;
 use std::collections::{HashMap, HashSet};
 
 /// Context for linting - tracks state as we walk the AST
@@ -76,15 +80,23 @@ impl LintContext {
     pub fn define_var(&mut self, name: &str, span: ifa_core::ast::Span, visibility: Visibility) {
         self.defined_vars.insert(name.to_string(), span);
         self.var_visibility.insert(name.to_string(), visibility);
-        self.var_domain.insert(name.to_string(), self.current_domain.clone());
+        self.var_domain
+            .insert(name.to_string(), self.current_domain.clone());
     }
 
     /// Define a variable with a type hint
-    pub fn define_var_typed(&mut self, name: &str, type_hint: TypeHint, span: ifa_core::ast::Span, visibility: Visibility) {
+    pub fn define_var_typed(
+        &mut self,
+        name: &str,
+        type_hint: TypeHint,
+        span: ifa_core::ast::Span,
+        visibility: Visibility,
+    ) {
         self.defined_vars.insert(name.to_string(), span);
         self.var_types.insert(name.to_string(), type_hint);
         self.var_visibility.insert(name.to_string(), visibility);
-        self.var_domain.insert(name.to_string(), self.current_domain.clone());
+        self.var_domain
+            .insert(name.to_string(), self.current_domain.clone());
     }
 
     pub fn use_var(&mut self, name: &str) {
@@ -316,7 +328,11 @@ fn check_taboo_violations(ctx: &LintContext, baba: &mut Babalawo, file: &str) {
 fn collect_definitions(stmt: &Statement, ctx: &mut LintContext) {
     match stmt {
         Statement::VarDecl {
-            name, type_hint, span, visibility, ..
+            name,
+            type_hint,
+            span,
+            visibility,
+            ..
         } => {
             if let Some(th) = type_hint {
                 ctx.define_var_typed(name, th.clone(), span.clone(), *visibility);
@@ -325,7 +341,10 @@ fn collect_definitions(stmt: &Statement, ctx: &mut LintContext) {
             }
         }
         Statement::Const {
-            name, value: _, visibility, span,
+            name,
+            value: _,
+            visibility,
+            span,
         } => {
             ctx.define_var(name, span.clone(), *visibility);
         }
@@ -336,12 +355,18 @@ fn collect_definitions(stmt: &Statement, ctx: &mut LintContext) {
             span,
             visibility,
             is_async: _,
+            doc: _,
         } => {
             ctx.define_var(name, span.clone(), *visibility);
             // Parameters are also definitions within the function (private by default)
             for param in params {
                 if let Some(th) = &param.type_hint {
-                    ctx.define_var_typed(&param.name, th.clone(), span.clone(), Visibility::Private); // Simplification: param uses Ese span
+                    ctx.define_var_typed(
+                        &param.name,
+                        th.clone(),
+                        span.clone(),
+                        Visibility::Private,
+                    ); // Simplification: param uses Ese span
                 } else {
                     ctx.define_var(&param.name, span.clone(), Visibility::Private);
                 }
@@ -350,13 +375,21 @@ fn collect_definitions(stmt: &Statement, ctx: &mut LintContext) {
                 collect_definitions(s, ctx);
             }
         }
-        Statement::OduDef { name, body, span, visibility } => {
+        Statement::OduDef {
+            name,
+            body,
+            span,
+            visibility,
+            ..
+        } => {
             ctx.define_var(name, span.clone(), *visibility);
             for s in body {
                 collect_definitions(s, ctx);
             }
         }
-        Statement::For { var, iterable: _, body, span } => {
+        Statement::For {
+            var, body, span, ..
+        } => {
             ctx.define_var(var, span.clone(), Visibility::Private);
             for s in body {
                 collect_definitions(s, ctx);
@@ -381,6 +414,7 @@ fn collect_definitions(stmt: &Statement, ctx: &mut LintContext) {
                 collect_definitions(s, ctx);
             }
         }
+        Statement::Import { path, names, span } => define_imports(path, names, span, ctx),
         // Register taboo declarations for enforcement
         Statement::Taboo { source, target, .. } => {
             ctx.taboo_enforcer.add_taboo(source, "", target, "", false);
@@ -393,6 +427,24 @@ fn collect_definitions(stmt: &Statement, ctx: &mut LintContext) {
     }
 }
 
+/// Define the names an import binds: `iba a.b;` binds `b`, and
+/// `iba { x, y } from a.b;` binds `x` and `y`.
+fn define_imports(
+    path: &[String],
+    names: &Option<Vec<String>>,
+    span: &Span,
+    ctx: &mut LintContext,
+) {
+    let bound = match names {
+        Some(names) => names.clone(),
+        None => path.last().cloned().into_iter().collect(),
+    };
+    for name in bound {
+        ctx.imports.insert(name.clone());
+        ctx.define_var(&name, span.clone(), Visibility::Private);
+    }
+}
+
 /// Check a statement for issues
 fn check_statement(stmt: &Statement, ctx: &mut LintContext, baba: &mut Babalawo, file: &str) {
     match stmt {
@@ -490,7 +542,10 @@ fn check_statement(stmt: &Statement, ctx: &mut LintContext, baba: &mut Babalawo,
                     if !ctx.is_accessible(visibility, target_domain) {
                         baba.error(
                             "VISIBILITY_VIOLATION",
-                            &format!("Èèwọ̀: Cannot access private variable '{}' from outside its domain", name),
+                            &format!(
+                                "Èèwọ̀: Cannot access private variable '{}' from outside its domain",
+                                name
+                            ),
                             file,
                             span.line,
                             span.column,
@@ -556,7 +611,7 @@ fn check_statement(stmt: &Statement, ctx: &mut LintContext, baba: &mut Babalawo,
                 .check_call(&caller, &callee, span.line, span.column);
 
             // Check arguments
-            for arg in &call.args {
+            for arg in call.all_args() {
                 check_expression(arg, ctx, baba, file, span);
             }
         }
@@ -568,6 +623,7 @@ fn check_statement(stmt: &Statement, ctx: &mut LintContext, baba: &mut Babalawo,
             span,
             visibility: _,
             is_async,
+            doc: _,
         } => {
             // Register params as used (they are implicitly used by the caller)
             for param in params {
@@ -628,6 +684,7 @@ fn check_statement(stmt: &Statement, ctx: &mut LintContext, baba: &mut Babalawo,
             condition,
             body,
             span,
+            ..
         } => {
             check_expression(condition, ctx, baba, file, span);
 
@@ -641,6 +698,7 @@ fn check_statement(stmt: &Statement, ctx: &mut LintContext, baba: &mut Babalawo,
             iterable,
             body,
             span,
+            ..
         } => {
             check_expression(iterable, ctx, baba, file, span);
             ctx.use_var(var);
@@ -701,10 +759,79 @@ fn check_statement(stmt: &Statement, ctx: &mut LintContext, baba: &mut Babalawo,
             ctx.in_ailewu = was_in_ailewu;
         }
 
+        Statement::Ewo {
+            condition, span, ..
+        } => {
+            check_expression(condition, ctx, baba, file, span);
+
+            // An assertion should only observe state; a condition that writes,
+            // prints, or reaches out changes what it claims to check.
+            if let Some((call, effect)) = find_side_effect(condition) {
+                baba.warning(
+                    "IMPURE_ASSERTION",
+                    &format!(
+                        "ewo condition calls {} ({}). The one who keeps watch over the market does not trade in it: an assertion observes, it does not act.",
+                        call, effect
+                    ),
+                    file,
+                    span.line,
+                    span.column,
+                );
+            }
+        }
+
         _ => {}
     }
 }
 
+/// Find the first side-effecting Odù call in an expression, returning the
+/// call site and the kind of effect, judged by the call's domain and the
+/// capabilities it needs.
+fn find_side_effect(expr: &Expression) -> Option<(String, &'static str)> {
+    match expr {
+        Expression::OduCall(call) => {
+            let site = format!("{:?}.{}", call.domain, call.method);
+            if call.domain == OduDomain::Irosu {
+                return Some((site, "console output"));
+            }
+            let effect = call_capabilities(call)
+                .all()
+                .iter()
+                .find_map(|cap| match cap {
+                    Ofun::WriteFiles { .. } => Some("file write"),
+                    Ofun::Network { .. } => Some("network access"),
+                    Ofun::Execute { .. } => Some("process execution"),
+                    Ofun::Bridge { .. } => Some("foreign call"),
+                    _ => None,
+                });
+            match effect {
+                Some(effect) => Some((site, effect)),
+                None => call.all_args().find_map(find_side_effect),
+            }
+        }
+        Expression::BinaryOp { left, right, .. } => {
+            find_side_effect(left).or_else(|| find_side_effect(right))
+        }
+        Expression::UnaryOp { expr, .. } | Expression::Await(expr) | Expression::Try(expr) => {
+            find_side_effect(expr)
+        }
+        Expression::MethodCall { object, args, .. } => {
+            find_side_effect(object).or_else(|| args.iter().find_map(find_side_effect))
+        }
+        Expression::Get { object, .. } => find_side_effect(object),
+        Expression::Index { object, index, .. } => {
+            find_side_effect(object).or_else(|| find_side_effect(index))
+        }
+        Expression::Call { args, .. } | Expression::List(args) => {
+            args.iter().find_map(find_side_effect)
+        }
+        Expression::Map(entries) => entries
+            .iter()
+            .find_map(|(k, v)| find_side_effect(k).or_else(|| find_side_effect(v))),
+        _ => None,
+    }
+}
+
 use ifa_core::ast::Span;
 
 /// Check an expression for issues
@@ -723,7 +850,11 @@ fn check_expression(
             if !ctx.defined_vars.contains_key(name) && !is_builtin(name) {
                 baba.error(
                     "UNDEFINED_VARIABLE",
-                    &format!("Variable '{}' used before declaration", name),
+                    &format!(
+                        "Variable '{}' used before declaration{}",
+                        name,
+                        did_you_mean(name, ctx)
+                    ),
                     file,
                     span.line,
                     span.column,
@@ -735,7 +866,10 @@ fn check_expression(
                     if !ctx.is_accessible(visibility, target_domain) {
                         baba.error(
                             "VISIBILITY_VIOLATION",
-                            &format!("Èèwọ̀: Cannot access private symbol '{}' from outside its domain", name),
+                            &format!(
+                                "Èèwọ̀: Cannot access private symbol '{}' from outside its domain",
+                                name
+                            ),
                             file,
                             span.line,
                             span.column,
@@ -794,7 +928,7 @@ fn check_expression(
 
         Expression::OduCall(call) => {
             check_unsafe_ffi_call(call, baba, file, span);
-            for arg in &call.args {
+            for arg in call.all_args() {
                 check_expression(arg, ctx, baba, file, span);
             }
         }
@@ -819,14 +953,17 @@ fn check_expression(
 
         Expression::Call { name, args } => {
             ctx.use_var(name);
-            
+
             // Check visibility
             if let Some(visibility) = ctx.get_var_visibility(name) {
                 let target_domain = ctx.get_var_domain(name).as_ref().and_then(|d| d.as_ref());
                 if !ctx.is_accessible(visibility, target_domain) {
                     baba.error(
                         "VISIBILITY_VIOLATION",
-                        &format!("Èèwọ̀: Cannot call private function '{}' from outside its domain", name),
+                        &format!(
+                            "Èèwọ̀: Cannot call private function '{}' from outside its domain",
+                            name
+                        ),
                         file,
                         span.line,
                         span.column,
@@ -855,10 +992,14 @@ fn check_expression(
     }
 }
 
-fn check_unsafe_ffi_call(call: &ifa_core::ast::OduCall, baba: &mut Babalawo, file: &str, span: &Span) {
+fn check_unsafe_ffi_call(
+    call: &ifa_core::ast::OduCall,
+    baba: &mut Babalawo,
+    file: &str,
+    span: &Span,
+) {
     if call.domain == ifa_core::OduDomain::Coop
-        && (call.method.eq_ignore_ascii_case("itumo")
-            || call.method.eq_ignore_ascii_case("summon"))
+        && (call.method.eq_ignore_ascii_case("itumo") || call.method.eq_ignore_ascii_case("summon"))
     {
         baba.error(
             "TABOO_UNSAFE_FFI",
@@ -873,15 +1014,18 @@ fn check_unsafe_ffi_call(call: &ifa_core::ast::OduCall, baba: &mut Babalawo, fil
 /// Check for unused variables
 fn check_unused_vars(ctx: &LintContext, baba: &mut Babalawo, file: &str) {
     for (var, span) in &ctx.defined_vars {
-        if !ctx.used_vars.contains(var) && !var.starts_with('_') {
-            baba.add_full(
-                Severity::Warning,
-                "UNUSED_VARIABLE",
-                &format!("Variable '{}' is defined but never used", var),
-                file,
-                span.clone(),
-            );
+        if ctx.used_vars.contains(var) || var.starts_with('_') {
+            continue;
         }
+        let (code, message) = if ctx.imports.contains(var) {
+            ("UNUSED_IMPORT", format!("Import '{}' is never used", var))
+        } else {
+            (
+                "UNUSED_VARIABLE",
+                format!("Variable '{}' is defined but never used", var),
+            )
+        };
+        baba.add_full(Severity::Warning, code, &message, file, span.clone());
     }
 }
 
@@ -1010,6 +1154,20 @@ fn types_compatible(declared: &TypeHint, inferred: &TypeHint) -> bool {
     }
 }
 
+/// A " (did you mean 'x'?)" hint naming the closest defined variable, or an
+/// empty string when nothing is within a third of the name's length.
+fn did_you_mean(name: &str, ctx: &LintContext) -> String {
+    let mut known: Vec<&str> = ctx.defined_vars.keys().map(String::as_str).collect();
+    known.sort_unstable();
+    let limit = (name.chars().count() / 3).max(1);
+    match closest(name, known) {
+        Some(candidate) if levenshtein(name, candidate) <= limit => {
+            format!(" (did you mean '{}'?)", candidate)
+        }
+        _ => String::new(),
+    }
+}
+
 /// Check if a name is a builtin
 fn is_builtin(name: &str) -> bool {
     matches!(
@@ -1041,6 +1199,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_imported_names_are_defined() {
+        let src = "iba lib;\niba { ilopo } from util;\nIrosu.fo(lib.x, ilopo(2));";
+        let program = parse(src).unwrap();
+        let baba = check_program(&program, "test.ifa");
+        assert!(!baba.has_errors(), "{:?}", baba.diagnostics);
+
+        // Only the last path segment is bound
+        let program = parse("iba utils.math;\nIrosu.fo(utils.x);").unwrap();
+        let baba = check_program(&program, "test.ifa");
+        assert!(baba.has_errors(), "{:?}", baba.diagnostics);
+    }
+
+    #[test]
+    fn test_unused_imports_are_reported_as_imports() {
+        let program = parse("iba lib;\nayanmo x = 1;").unwrap();
+        let baba = check_program(&program, "test.ifa");
+        let codes: Vec<&str> = baba
+            .diagnostics
+            .iter()
+            .map(|d| d.error.code.as_str())
+            .collect();
+        assert!(codes.contains(&"UNUSED_IMPORT"), "{:?}", codes);
+        assert!(codes.contains(&"UNUSED_VARIABLE"), "{:?}", codes);
+    }
+
     // §AWAIT_OUTSIDE_ASYNC: reti in a non-async function must trigger AWAIT_OUTSIDE_ASYNC
     #[test]
     fn test_await_outside_async_errors() {
@@ -1052,8 +1236,15 @@ mod tests {
         "#;
         if let Ok(program) = parse(src) {
             let baba = check_program(&program, "test.ifa");
-            let has_await_error = baba.diagnostics.iter().any(|d| d.error.code == "AWAIT_OUTSIDE_ASYNC");
-            assert!(has_await_error, "Expected AWAIT_OUTSIDE_ASYNC error but got: {:?}", baba.diagnostics);
+            let has_await_error = baba
+                .diagnostics
+                .iter()
+                .any(|d| d.error.code == "AWAIT_OUTSIDE_ASYNC");
+            assert!(
+                has_await_error,
+                "Expected AWAIT_OUTSIDE_ASYNC error but got: {:?}",
+                baba.diagnostics
+            );
         }
     }
 
@@ -1068,8 +1259,14 @@ mod tests {
         "#;
         if let Ok(program) = parse(src) {
             let baba = check_program(&program, "test.ifa");
-            let has_await_error = baba.diagnostics.iter().any(|d| d.error.code == "AWAIT_OUTSIDE_ASYNC");
-            assert!(!has_await_error, "Unexpected AWAIT_OUTSIDE_ASYNC in async function");
+            let has_await_error = baba
+                .diagnostics
+                .iter()
+                .any(|d| d.error.code == "AWAIT_OUTSIDE_ASYNC");
+            assert!(
+                !has_await_error,
+                "Unexpected AWAIT_OUTSIDE_ASYNC in async function"
+            );
         }
     }
 
@@ -1086,8 +1283,15 @@ mod tests {
         "#;
         if let Ok(program) = parse(src) {
             let baba = check_program(&program, "test.ifa");
-            let has_warn = baba.diagnostics.iter().any(|d| d.error.code == "NON_ITERABLE");
-            assert!(has_warn, "Expected NON_ITERABLE warning but got: {:?}", baba.diagnostics);
+            let has_warn = baba
+                .diagnostics
+                .iter()
+                .any(|d| d.error.code == "NON_ITERABLE");
+            assert!(
+                has_warn,
+                "Expected NON_ITERABLE warning but got: {:?}",
+                baba.diagnostics
+            );
         }
     }
 
@@ -1104,7 +1308,10 @@ mod tests {
         "#;
         if let Ok(program) = parse(src) {
             let baba = check_program(&program, "test.ifa");
-            let has_warn = baba.diagnostics.iter().any(|d| d.error.code == "NON_ITERABLE");
+            let has_warn = baba
+                .diagnostics
+                .iter()
+                .any(|d| d.error.code == "NON_ITERABLE");
             assert!(!has_warn, "Unexpected NON_ITERABLE on a List variable");
         }
     }
@@ -1126,8 +1333,15 @@ mod tests {
         "#;
         if let Ok(program) = parse(src) {
             let baba = check_program(&program, "test.ifa");
-            let has_error = baba.diagnostics.iter().any(|d| d.error.code == "VISIBILITY_VIOLATION");
-            assert!(has_error, "Expected VISIBILITY_VIOLATION error but got: {:?}", baba.diagnostics);
+            let has_error = baba
+                .diagnostics
+                .iter()
+                .any(|d| d.error.code == "VISIBILITY_VIOLATION");
+            assert!(
+                has_error,
+                "Expected VISIBILITY_VIOLATION error but got: {:?}",
+                baba.diagnostics
+            );
         }
     }
 
@@ -1144,8 +1358,14 @@ mod tests {
         "#;
         if let Ok(program) = parse(src) {
             let baba = check_program(&program, "test.ifa");
-            let has_error = baba.diagnostics.iter().any(|d| d.error.code == "VISIBILITY_VIOLATION");
-            assert!(!has_error, "Unexpected VISIBILITY_VIOLATION on public member");
+            let has_error = baba
+                .diagnostics
+                .iter()
+                .any(|d| d.error.code == "VISIBILITY_VIOLATION");
+            assert!(
+                !has_error,
+                "Unexpected VISIBILITY_VIOLATION on public member"
+            );
         }
     }
 
@@ -1162,8 +1382,83 @@ mod tests {
         "#;
         if let Ok(program) = parse(src) {
             let baba = check_program(&program, "test.ifa");
-            let has_error = baba.diagnostics.iter().any(|d| d.error.code == "VISIBILITY_VIOLATION");
-            assert!(!has_error, "Unexpected VISIBILITY_VIOLATION on internal member access");
+            let has_error = baba
+                .diagnostics
+                .iter()
+                .any(|d| d.error.code == "VISIBILITY_VIOLATION");
+            assert!(
+                !has_error,
+                "Unexpected VISIBILITY_VIOLATION on internal member access"
+            );
         }
     }
+
+    // §IMPURE_ASSERTION: an ewo over a pure comparison must be clean
+    #[test]
+    fn test_pure_assertion_is_clean() {
+        let src = r#"
+            ayanmo x = 5;
+            ewo x > 0, "x must be positive";
+        "#;
+        let program = parse(src).expect("ewo should parse");
+        let baba = check_program(&program, "test.ifa");
+        let has_warn = baba
+            .diagnostics
+            .iter()
+            .any(|d| d.error.code == "IMPURE_ASSERTION");
+        assert!(
+            !has_warn,
+            "Unexpected IMPURE_ASSERTION: {:?}",
+            baba.diagnostics
+        );
+    }
+
+    // §IMPURE_ASSERTION: an ewo whose condition writes a file must warn
+    #[test]
+    fn test_assertion_with_file_write_warns() {
+        let src = r#"
+            ewo Odi.write("out.txt", "data") == otito;
+        "#;
+        let program = parse(src).expect("ewo should parse");
+        let baba = check_program(&program, "test.ifa");
+        let warning = baba
+            .diagnostics
+            .iter()
+            .find(|d| d.error.code == "IMPURE_ASSERTION")
+            .expect("Expected IMPURE_ASSERTION warning");
+        assert_eq!(warning.severity, Severity::Warning);
+        assert!(
+            warning.error.message.contains("Odi.write"),
+            "{}",
+            warning.error.message
+        );
+    }
+
+    // §UNDEFINED_VARIABLE: a near-miss name gets a "did you mean" hint
+    #[test]
+    fn test_undefined_variable_suggests_closest_name() {
+        let src = r#"
+            ayanmo count = 1;
+            ayanmo total = coun + 1;
+            ayanmo other = zzzzzz;
+        "#;
+        let program = parse(src).expect("program should parse");
+        let baba = check_program(&program, "test.ifa");
+        let messages: Vec<&str> = baba
+            .diagnostics
+            .iter()
+            .filter(|d| d.error.code == "UNDEFINED_VARIABLE")
+            .map(|d| d.error.message.as_str())
+            .collect();
+        assert!(
+            messages.contains(&"Variable 'coun' used before declaration (did you mean 'count'?)"),
+            "{:?}",
+            messages
+        );
+        assert!(
+            messages.contains(&"Variable 'zzzzzz' used before declaration"),
+            "{:?}",
+            messages
+        );
+    }
 }
@@ -1,6 +1,7 @@
-use ifa_core::ast::{Expression, Program, Statement};
+use ifa_core::ast::{Expression, OduCall, Program, Statement};
+use ifa_core::interpreter::handlers::{bind_named_args, required_capabilities};
+use ifa_core::{IfaValue, OduDomain};
 use ifa_sandbox::{CapabilitySet, Ofun};
-use std::path::PathBuf;
 
 /// Infer required capabilities from the program AST
 pub fn infer_capabilities(program: &Program) -> CapabilitySet {
@@ -19,73 +20,7 @@ pub fn infer_capabilities(program: &Program) -> CapabilitySet {
 
 fn scan_statement(stmt: &Statement, caps: &mut CapabilitySet) {
     match stmt {
-        Statement::Instruction { call, .. } => {
-            let domain = format!("{:?}", call.domain).to_lowercase();
-            match domain.as_str() {
-                "odi" => {
-                    // File I/O
-                    // Odi.ka("file") -> Read
-                    // Odi.ko("file") -> Write
-                    if call.method == "ka" || call.method == "read" {
-                        if let Some(path) = extract_string_arg(&call.args, 0) {
-                            caps.grant(Ofun::ReadFiles {
-                                root: PathBuf::from(path),
-                            });
-                        } else {
-                            // If dynamic path, grant generous permission or warn?
-                            // zero-config philosophy -> broad permission if unknown
-                            caps.grant(Ofun::ReadFiles {
-                                root: PathBuf::from("/"),
-                            });
-                        }
-                    } else if call.method == "ko" || call.method == "write" {
-                        if let Some(path) = extract_string_arg(&call.args, 0) {
-                            caps.grant(Ofun::WriteFiles {
-                                root: PathBuf::from(path),
-                            });
-                        } else {
-                            caps.grant(Ofun::WriteFiles {
-                                root: PathBuf::from("/"),
-                            });
-                        }
-                    }
-                }
-                "otura" => {
-                    // Networking
-                    caps.grant(Ofun::Network {
-                        domains: vec!["*".to_string()],
-                    });
-                }
-                "ogunda" => {
-                    // Processes
-                    if call.method == "bẹrẹ" || call.method == "run" {
-                        // Spawning processes requires capabilities
-                        // How is this represented in Ofun? Need to check Capability definitions.
-                        // Assuming Ofun::Command or similar checking Ofun enum.
-                    }
-                }
-                "iwori" => {
-                    if call.method == "akoko" || call.method == "isisinyi" {
-                        caps.grant(Ofun::Time);
-                    }
-                }
-                "owonrin" => {
-                    caps.grant(Ofun::Random);
-                }
-                "ogbe" => {
-                    if call.method == "ayika" || call.method == "env" {
-                        caps.grant(Ofun::Environment {
-                            keys: vec!["*".to_string()],
-                        });
-                    }
-                }
-                _ => {}
-            }
-
-            for arg in &call.args {
-                scan_expression(arg, caps);
-            }
-        }
+        Statement::Instruction { call, .. } => scan_call(call, caps),
         Statement::VarDecl { value, .. } => scan_expression(value, caps),
         Statement::Assignment { value, .. } => scan_expression(value, caps),
         Statement::If {
@@ -137,34 +72,7 @@ fn scan_statement(stmt: &Statement, caps: &mut CapabilitySet) {
 
 fn scan_expression(expr: &Expression, caps: &mut CapabilitySet) {
     match expr {
-        Expression::OduCall(call) => {
-            // Re-use logic for instructions but for expr calls (e.g. nested calls)
-            // Copy-paste logic from scan_instruction for now or refactor
-            // For OduCall in Expression, it's the exact same structure as Instruction's OduCall
-            // We can synthesise a Statement::Instruction check, or just extract logic.
-            // Simpler: Just recursively check args.
-
-            let domain = format!("{:?}", call.domain).to_lowercase();
-            if domain == "odi" {
-                // If reading file in expression
-                if call.method == "ka" || call.method == "read" {
-                    if let Some(path) = extract_string_arg(&call.args, 0) {
-                        caps.grant(Ofun::ReadFiles {
-                            root: PathBuf::from(path),
-                        });
-                    } else {
-                        caps.grant(Ofun::ReadFiles {
-                            root: PathBuf::from("/"),
-                        });
-                    }
-                }
-            }
-            // ... other domains
-
-            for arg in &call.args {
-                scan_expression(arg, caps);
-            }
-        }
+        Expression::OduCall(call) => scan_call(call, caps),
         Expression::BinaryOp { left, right, .. } => {
             scan_expression(left, caps);
             scan_expression(right, caps);
@@ -190,10 +98,57 @@ fn scan_expression(expr: &Expression, caps: &mut CapabilitySet) {
     }
 }
 
-fn extract_string_arg(args: &[Expression], index: usize) -> Option<String> {
-    if let Some(Expression::String(s)) = args.get(index) {
-        Some(s.clone())
-    } else {
-        None
+/// Capabilities for one Odù call, using the same table the interpreter
+/// enforces at dispatch. Arguments that aren't string literals can't be
+/// resolved statically, so they widen to the broadest grant (`/` or `*`).
+/// Calls nested in the arguments are not included.
+pub(crate) fn call_capabilities(call: &OduCall) -> CapabilitySet {
+    let mut caps = CapabilitySet::new();
+    let wildcard = match call.domain {
+        OduDomain::Otura => "*",
+        _ => "/",
+    };
+    let literal = |arg: &Expression| match arg {
+        Expression::String(s) => IfaValue::str(s.as_str()),
+        _ => IfaValue::str(wildcard),
+    };
+    let named = call
+        .named_args
+        .iter()
+        .map(|(name, arg)| (name.clone(), literal(arg)))
+        .collect();
+    let args = bind_named_args(
+        call.domain,
+        &call.method,
+        call.args.iter().map(literal).collect(),
+        named,
+    )
+    .unwrap_or_default();
+    // A path with `..` can never be granted, so it infers nothing
+    for cap in required_capabilities(call.domain, &call.method, &args).unwrap_or_default() {
+        caps.grant(cap);
+    }
+
+    match call.domain {
+        OduDomain::Iwori if call.method == "akoko" || call.method == "isisinyi" => {
+            caps.grant(Ofun::Time);
+        }
+        OduDomain::Owonrin => caps.grant(Ofun::Random),
+        OduDomain::Ogbe if call.method == "ayika" || call.method == "env" => {
+            caps.grant(Ofun::Environment {
+                keys: vec!["*".to_string()],
+            });
+        }
+        _ => {}
+    }
+
+    caps
+}
+
+fn scan_call(call: &OduCall, caps: &mut CapabilitySet) {
+    caps.inherit_from(&call_capabilities(call));
+
+    for arg in call.all_args() {
+        scan_expression(arg, caps);
     }
 }
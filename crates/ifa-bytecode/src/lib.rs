@@ -63,6 +63,8 @@ pub enum OpCode {
     PushFloat = 0x09,
     /// Push String (followed by generic index)
     PushStr = 0x0A,
+    /// Push Bytes (followed by u16 length and the raw bytes)
+    PushBytes = 0x0B,
 
     // === Memory Operations (0x10-0x1F) ===
     // Standardized from ifa-embedded's Deref8/Store8 → Load8/Store8
@@ -118,6 +120,10 @@ pub enum OpCode {
     Pow = 0x26,
     /// Concatenate two strings
     Concat = 0x27,
+    /// Stringify and join the top N values in one allocation (followed by 1-byte count)
+    StrBuilder = 0x28,
+    /// Append the top string to a local string in place (followed by 2-byte slot index)
+    AppendLocal = 0x29,
 
     // === Bitwise Operations (0x30-0x3F) ===
     /// Bitwise AND
@@ -200,6 +206,11 @@ pub enum OpCode {
     GetField = 0x78,
     /// Set object field (followed by 2-byte name index)
     SetField = 0x79,
+    /// Test for a list of a pattern's shape (followed by 2-byte element
+    /// count and 1-byte rest flag)
+    MatchList = 0x7A,
+    /// Elements of a list from an index on (followed by 2-byte start index)
+    ListTail = 0x7B,
 
     // === IO & System (0x80-0x8F) ===
     /// Print to stdout
@@ -257,6 +268,7 @@ impl OpCode {
             0x08 => Some(OpCode::PushInt),
             0x09 => Some(OpCode::PushFloat),
             0x0A => Some(OpCode::PushStr),
+            0x0B => Some(OpCode::PushBytes),
 
             0x10 => Some(OpCode::Load8),
             0x11 => Some(OpCode::Load16),
@@ -282,6 +294,8 @@ impl OpCode {
             0x25 => Some(OpCode::Neg),
             0x26 => Some(OpCode::Pow),
             0x27 => Some(OpCode::Concat),
+            0x28 => Some(OpCode::StrBuilder),
+            0x29 => Some(OpCode::AppendLocal),
 
             0x30 => Some(OpCode::And),
             0x31 => Some(OpCode::Or),
@@ -323,6 +337,8 @@ impl OpCode {
             0x77 => Some(OpCode::PushMap),
             0x78 => Some(OpCode::GetField),
             0x79 => Some(OpCode::SetField),
+            0x7A => Some(OpCode::MatchList),
+            0x7B => Some(OpCode::ListTail),
 
             0x80 => Some(OpCode::Print),
             0x81 => Some(OpCode::PrintRaw),
@@ -365,6 +381,7 @@ impl OpCode {
             OpCode::PushInt => "push_int",
             OpCode::PushFloat => "push_float",
             OpCode::PushStr => "push_str",
+            OpCode::PushBytes => "push_bytes",
 
             OpCode::Load8 => "load8",
             OpCode::Load16 => "load16",
@@ -390,6 +407,8 @@ impl OpCode {
             OpCode::Neg => "neg",
             OpCode::Pow => "pow",
             OpCode::Concat => "concat",
+            OpCode::StrBuilder => "str_builder",
+            OpCode::AppendLocal => "append_local",
 
             OpCode::And => "and",
             OpCode::Or => "or",
@@ -431,6 +450,8 @@ impl OpCode {
             OpCode::PushMap => "push_map",
             OpCode::GetField => "get_field",
             OpCode::SetField => "set_field",
+            OpCode::MatchList => "match_list",
+            OpCode::ListTail => "list_tail",
 
             OpCode::Print => "print",
             OpCode::PrintRaw => "print_raw",
@@ -521,9 +542,14 @@ impl OpCode {
             | OpCode::FinallyBegin
             | OpCode::Ref => Some(4),
             OpCode::PushInt | OpCode::PushFloat => Some(8),
-            OpCode::BuildList | OpCode::BuildMap | OpCode::Call | OpCode::TailCall => Some(1),
+            OpCode::BuildList
+            | OpCode::BuildMap
+            | OpCode::StrBuilder
+            | OpCode::Call
+            | OpCode::TailCall => Some(1),
             OpCode::LoadLocal
             | OpCode::StoreLocal
+            | OpCode::AppendLocal
             | OpCode::LoadGlobal
             | OpCode::StoreGlobal
             | OpCode::LoadUpvalue
@@ -531,13 +557,14 @@ impl OpCode {
             | OpCode::PushStr
             | OpCode::Import
             | OpCode::GetField
-            | OpCode::SetField => Some(2),
-            OpCode::CallMethod => Some(3),
+            | OpCode::SetField
+            | OpCode::ListTail => Some(2),
+            OpCode::CallMethod | OpCode::MatchList => Some(3),
             OpCode::CallOdu => Some(4),
             OpCode::PushFn => Some(8),
 
             // Variable length operands
-            OpCode::DefineClass | OpCode::MakeClosure => None,
+            OpCode::DefineClass | OpCode::MakeClosure | OpCode::PushBytes => None,
         }
     }
 
@@ -555,6 +582,7 @@ impl OpCode {
             | OpCode::PushInt
             | OpCode::PushFloat
             | OpCode::PushStr
+            | OpCode::PushBytes
             | OpCode::PushList
             | OpCode::PushMap
             | OpCode::PushFn => Some((0, 1)),
@@ -588,6 +616,8 @@ impl OpCode {
             | OpCode::Gt
             | OpCode::Ge => Some((2, 1)),
             OpCode::Concat => Some((2, 1)),
+            OpCode::StrBuilder => None,          // Variable input
+            OpCode::AppendLocal => Some((1, 0)), // [rhs] -> [] (slot in operand)
 
             OpCode::Neg | OpCode::Not => Some((1, 1)),
             OpCode::Pow => Some((2, 1)),
@@ -610,6 +640,8 @@ impl OpCode {
             OpCode::SetField => Some((2, 1)), // [obj, val] -> [obj] (for chaining)
             OpCode::Len => Some((1, 1)),      // [val] -> [int]
             OpCode::Append => Some((2, 1)),   // [list, val] -> [list]
+            OpCode::MatchList => Some((1, 1)), // [val] -> [bool]
+            OpCode::ListTail => Some((1, 1)), // [list] -> [list]
 
             OpCode::BuildList | OpCode::BuildMap => None, // Variable input
 
@@ -637,6 +669,8 @@ impl OpCode {
             }
             OpCode::Add => "[a, b] → [a+b]",
             OpCode::Concat => "[lhs, rhs] â†’ [lhs++rhs]",
+            OpCode::StrBuilder => "[v1, ..., vn] → [str(v1)++...++str(vn)]",
+            OpCode::AppendLocal => "[rhs] → [] (local ++= rhs)",
             _ => "Refer to stack_effect() values",
         }
     }
@@ -680,6 +714,8 @@ mod tests {
         assert_eq!(OpCode::Store8 as u8, 0x14);
         assert_eq!(OpCode::Add as u8, 0x20);
         assert_eq!(OpCode::Concat as u8, 0x27);
+        assert_eq!(OpCode::StrBuilder as u8, 0x28);
+        assert_eq!(OpCode::AppendLocal as u8, 0x29);
         assert_eq!(OpCode::Jump as u8, 0x50);
         assert_eq!(OpCode::Call as u8, 0x53);
         assert_eq!(OpCode::Return as u8, 0x54);
@@ -690,7 +726,7 @@ mod tests {
     fn invalid_bytes_return_none() {
         assert_eq!(OpCode::from_u8(0x00), None);
         assert_eq!(OpCode::from_u8(0xFF), None);
-        assert_eq!(OpCode::from_u8(0x0B), None);
+        assert_eq!(OpCode::from_u8(0x0C), None);
     }
 
     #[test]
@@ -320,6 +320,8 @@ impl Debugger for DapAdapter {
             | Statement::While { span, .. }
             | Statement::For { span, .. }
             | Statement::Return { span, .. }
+            | Statement::Break { span, .. }
+            | Statement::Continue { span, .. }
             | Statement::Expr { span, .. }
             | Statement::Import { span, .. }
             | Statement::Ebo { span, .. }
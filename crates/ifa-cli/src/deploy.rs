@@ -3,24 +3,28 @@
 //! "Zero-Config Deployment": Scans code to detect required capabilities.
 //! Generates secure manifests (Iwe.toml / AppArmor profiles).
 
+use crate::icon;
 use eyre::{Result, WrapErr};
 use ifa_babalawo::infer_capabilities;
-use ifa_core::parse;
+use ifa_core::ast::{ProgramMetadata, Statement};
+use ifa_core::{ModuleResolver, parse};
 use ifa_sandbox::{CapabilitySet, Ofun};
+use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 
 /// Scan directory and generate capability manifest
 pub fn scan_and_generate(path: &Path) -> Result<()> {
-    println!("🔍 Scanning project at: {}", path.display());
+    println!("{}Scanning project at: {}", icon("🔍 "), path.display());
 
     let mut total_caps = CapabilitySet::new();
+    let mut oriki: Option<ProgramMetadata> = None;
     // Default grants
     total_caps.grant(Ofun::Stdio);
 
     // Find sources
     let sources = traverse_dir(path)?;
     if sources.is_empty() {
-        println!("⚠️ No .ifa source files found.");
+        println!("{}No .ifa source files found.", icon("⚠️ "));
         return Ok(());
     }
 
@@ -33,6 +37,9 @@ pub fn scan_and_generate(path: &Path) -> Result<()> {
 
         match parse(&content) {
             Ok(program) => {
+                if oriki.is_none() {
+                    oriki = program.metadata.clone();
+                }
                 let caps = infer_capabilities(&program);
                 // Manual merge since .merge() isn't on CapabilitySet
                 for cap in caps.all() {
@@ -40,18 +47,37 @@ pub fn scan_and_generate(path: &Path) -> Result<()> {
                 }
             }
             Err(e) => {
-                println!("   ⚠️ Parse error in {}: {}", src_path.display(), e);
+                println!(
+                    "   {}Parse error in {}: {}",
+                    icon("⚠️ "),
+                    src_path.display(),
+                    e
+                );
             }
         }
     }
 
     println!();
-    println!("🛡️  Inferred Capabilities (Odu Ofun):");
+    println!("{}Inferred Capabilities (Odu Ofun):", icon("🛡️  "));
     print_capabilities(&total_caps);
 
     println!();
-    println!("📜 Generated Manifest Snippet (Iwe.toml):");
+    println!("{}Generated Manifest Snippet (Iwe.toml):", icon("📜 "));
     println!("```toml");
+    if let Some(meta) = &oriki {
+        println!("[package]");
+        if let Some(name) = &meta.name {
+            println!("name = {:?}", name);
+        }
+        if let Some(version) = &meta.version {
+            println!("version = {:?}", version);
+        }
+        if !meta.domains.is_empty() {
+            let domains: Vec<String> = meta.domains.iter().map(|d| format!("{:?}", d)).collect();
+            println!("domains = {:?} # Declared via oriki", domains);
+        }
+        println!();
+    }
     println!("[package.capabilities]");
 
     // Check for network - iterate manually
@@ -81,6 +107,118 @@ pub fn scan_and_generate(path: &Path) -> Result<()> {
     Ok(())
 }
 
+/// `ifa caps <file>`: capabilities `file` and its local imports will request,
+/// plus the matching `ifa run` flags.
+pub fn dump_capabilities(file: &Path, format: &str) -> Result<()> {
+    let resolver = ModuleResolver::from_entry_file(file);
+    let mut caps = CapabilitySet::new();
+    let mut seen = HashSet::new();
+    infer_with_imports(file, &resolver, &mut caps, &mut seen)?;
+
+    let mut grants: Vec<&Ofun> = Vec::new();
+    for cap in caps.all() {
+        if !grants.contains(&cap) {
+            grants.push(cap);
+        }
+    }
+    let flags = allow_flags(&grants);
+
+    if format == "json" {
+        let summary = serde_json::json!({
+            "file": file.display().to_string(),
+            "capabilities": grants.iter().map(|c| format!("{:?}", c)).collect::<Vec<_>>(),
+            "flags": flags,
+        });
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&summary).unwrap_or_default()
+        );
+    } else {
+        println!(
+            "{}Inferred Capabilities for {}:",
+            icon("🛡️  "),
+            file.display()
+        );
+        for cap in &grants {
+            println!("   - {:?}", cap);
+        }
+        println!();
+        if flags.is_empty() {
+            println!("No --allow-* flags needed.");
+        } else {
+            println!("ifa run {} {}", file.display(), flags.join(" "));
+        }
+    }
+    Ok(())
+}
+
+/// Merge the inferred capabilities of `path` and every non-std module it
+/// imports (transitively) into `caps`.
+fn infer_with_imports(
+    path: &Path,
+    resolver: &ModuleResolver,
+    caps: &mut CapabilitySet,
+    seen: &mut HashSet<PathBuf>,
+) -> Result<()> {
+    if !seen.insert(path.canonicalize().unwrap_or_else(|_| path.to_path_buf())) {
+        return Ok(());
+    }
+    let content = std::fs::read_to_string(path)
+        .wrap_err_with(|| format!("Failed to read {}", path.display()))?;
+    let program =
+        parse(&content).map_err(|e| eyre::eyre!("Parse error in {}: {}", path.display(), e))?;
+
+    for cap in infer_capabilities(&program).all() {
+        caps.grant(cap.clone());
+    }
+    for stmt in &program.statements {
+        if let Statement::Import { path: parts, .. } = stmt {
+            let raw = parts.join(".");
+            if ModuleResolver::is_std(&raw) {
+                continue;
+            }
+            match resolver.resolve(&raw) {
+                Ok(module) if !module.is_binary => {
+                    infer_with_imports(&module.path, resolver, caps, seen)?
+                }
+                _ => eprintln!("Warning: cannot scan import '{}'", raw),
+            }
+        }
+    }
+    Ok(())
+}
+
+/// `--allow-*` flags for `ifa run` that grant exactly `grants`.
+pub fn allow_flags(grants: &[&Ofun]) -> Vec<String> {
+    let mut flags = Vec::new();
+    for grant in grants {
+        match grant {
+            Ofun::ReadFiles { root } => flags.push(format!("--allow-read {}", root.display())),
+            Ofun::WriteFiles { root } => flags.push(format!("--allow-write {}", root.display())),
+            Ofun::Network { domains } => {
+                flags.extend(domains.iter().map(|d| format!("--allow-net {}", d)))
+            }
+            Ofun::Environment { keys } => {
+                flags.extend(keys.iter().map(|k| format!("--allow-env {}", k)))
+            }
+            Ofun::Time => flags.push("--allow-time".to_string()),
+            Ofun::Bridge { language } if language == "python" => {
+                flags.push("--allow-python".to_string())
+            }
+            Ofun::Bridge { language } if language == "js" => flags.push("--allow-js".to_string()),
+            Ofun::Bridge { language } if language == "c" => flags.push("--allow-c".to_string()),
+            Ofun::Execute { programs } => {
+                flags.extend(programs.iter().map(|p| format!("--allow-exec {}", p)))
+            }
+            // Stdio and Random are granted by default; the rest have no
+            // `ifa run` flag and are only listed above
+            _ => {}
+        }
+    }
+    flags.dedup();
+    flags
+}
+
 fn traverse_dir(dir: &Path) -> Result<Vec<PathBuf>> {
     let mut files = Vec::new();
     if dir.is_file() {
@@ -3,7 +3,8 @@
 //! Generates HTML documentation for Ifá-Lang projects in the style of the Ifá Corpus.
 
 use chrono::Local;
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{Result, eyre};
+use ifa_core::ast::Statement;
 use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -382,8 +383,8 @@ pub fn generate_index_html(user_doc: &UserDoc) -> String {
             </a>
         "#,
                 slug = odu.slug,
-                name = odu.name,
-                desc = odu.description
+                name = escape_html(&odu.name),
+                desc = escape_html(&odu.description)
             ));
         }
     }
@@ -408,9 +409,9 @@ pub fn generate_index_html(user_doc: &UserDoc) -> String {
                 </div>
             "#,
                 icon = icon,
-                name = item.name,
-                sig = item.signature,
-                desc = item.description
+                name = escape_html(&item.name),
+                sig = escape_html(&item.signature),
+                desc = escape_html(&item.description)
             ));
         }
         orphan_html.push_str("</div>");
@@ -850,6 +851,10 @@ pub fn get_stdlib_methods() -> HashMap<&'static str, Vec<(&'static str, &'static
             ("env", "Get environment variable"),
             ("args", "Get CLI arguments"),
             ("platform", "Get current platform (windows/linux/macos)"),
+            ("arch", "Get the CPU architecture (x86_64/aarch64/...)"),
+            ("cpu_count", "Get the number of logical CPUs"),
+            ("pid", "Get the current process id"),
+            ("hostname", "Get the machine name (needs env access)"),
         ],
     );
 
@@ -1039,9 +1044,9 @@ pub fn generate_user_odu_page(odu: &UserOdu) -> String {
             </div>
         "#,
             icon = icon,
-            name = item.name,
-            sig = item.signature,
-            desc = item.description
+            name = escape_html(&item.name),
+            sig = escape_html(&item.signature),
+            desc = escape_html(&item.description)
         ));
     }
 
@@ -1080,8 +1085,8 @@ pub fn generate_user_odu_page(odu: &UserOdu) -> String {
 </body>
 </html>"#,
         css = CSS,
-        name = odu.name,
-        desc = odu.description,
+        name = escape_html(&odu.name),
+        desc = escape_html(&odu.description),
         methods_html = if methods_html.is_empty() {
             "<p class=\"meaning\">No documentation found.</p>".to_string()
         } else {
@@ -1090,6 +1095,14 @@ pub fn generate_user_odu_page(odu: &UserOdu) -> String {
     )
 }
 
+/// Escape text taken from user source for use in HTML
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
 /// Recursively scan directory for .ifa files
 fn walk_dir(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
     if dir.is_dir() {
@@ -1108,142 +1121,54 @@ fn walk_dir(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
     Ok(())
 }
 
-/// Parse a single file for Oríkì
+/// Parse a single file and collect its `ese`/`odu` definitions with their
+/// `///` doc comments. Top-level `ese` go to the global verses, those inside
+/// an `odu` to that Odù's page.
 fn parse_file(path: &Path, doc: &mut UserDoc) -> Result<()> {
     let content = fs::read_to_string(path)?;
-    let mut current_docs = String::new();
-
-    // Simple state machine
-    for line in content.lines() {
-        let trimmed = line.trim();
-
-        if trimmed.starts_with("///") {
-            let doc_line = trimmed[3..].trim();
-            if !current_docs.is_empty() {
-                current_docs.push(' '); // join lines with space
-            }
-            current_docs.push_str(doc_line);
-            continue;
-        }
-
-        // Ignore empty lines between docs and definition?
-        // For now, strict adjacency or single empty line.
-        if trimmed.is_empty() {
-            // Keep docs for one empty line? No, let's reset to avoid wrong attribution
-            // Actually, usually doc comments must immediately precede.
-            if !current_docs.is_empty() {
-                // allow one blank line? Let's strictly require adjacency for V1
-                // current_docs.clear();
-            }
-            continue;
-        }
-
-        // Check for definitions
-        if !current_docs.is_empty() {
-            // ODÙ Definition
-            // odu Calculator {
-            if trimmed.starts_with("odu ")
-                || trimmed.starts_with("odù ")
-                || trimmed.starts_with("class ")
-            {
-                let parts: Vec<&str> = trimmed.split_whitespace().collect();
-                if parts.len() >= 2 {
-                    let name = parts[1].trim_matches('{');
-                    doc.add_odu(name.to_string(), current_docs.clone());
-                }
-                current_docs.clear();
-                continue;
-            }
-
-            // ESE Definition
-            // ese add(a, b) {
-            if trimmed.starts_with("ese ")
-                || trimmed.starts_with("ẹsẹ ")
-                || trimmed.starts_with("fn ")
-            {
-                // Extract name and signature
-                let open_paren = trimmed.find('(');
-                let name_end = open_paren.unwrap_or(trimmed.len());
-                // "ese add"
-                let decl_part = &trimmed[0..name_end];
-                let parts: Vec<&str> = decl_part.split_whitespace().collect();
-                let name = if parts.len() >= 2 {
-                    parts[1]
-                } else {
-                    "unknown"
-                };
-
-                let signature = if let Some(idx) = open_paren {
-                    let close_paren = trimmed.find(')').unwrap_or(trimmed.len());
-                    if close_paren > idx {
-                        let params = &trimmed[idx..=close_paren];
-                        format!("{}{}", name, params)
-                    } else {
-                        name.to_string()
-                    }
-                } else {
-                    name.to_string()
-                };
-
-                let item = DocItem {
-                    name: name.to_string(),
-                    kind: "ese".to_string(),
-                    signature,
-                    description: current_docs.clone(),
-                };
-
-                // If we have a current Odù (from context), we should add to it.
-                // But we are parsing line-by-line without full context stack here (simple parser).
-                // For V1, let's just add to last added Odù if exists, or orphans.
-                if let Some(last_odu) = doc.odus.last_mut() {
-                    last_odu.items.push(item);
-                } else {
-                    doc.orphans.push(item);
-                }
-
-                current_docs.clear();
-                continue;
-            }
+    let program = ifa_core::parse(&content).map_err(|e| eyre!("{}", e))?;
 
-            // CONST/AYANMO Definition
-            // const PI = 3.14; or loruko PI = 3.14; or ka PI = ...
-            let is_const = trimmed.starts_with("const ")
-                || trimmed.starts_with("loruko ")
-                || trimmed.starts_with("ka ");
-            if is_const || trimmed.starts_with("ayanmo ") || trimmed.starts_with("let ") {
-                let kind = if is_const { "const" } else { "ayanmo" };
-                let parts: Vec<&str> = trimmed.split_whitespace().collect();
-                // const Name = ...
-                if parts.len() >= 2 {
-                    let name = parts[1];
-                    // signature is the whole line up to semicolon
-                    let signature = trimmed.trim_matches(';').to_string();
-
-                    let item = DocItem {
-                        name: name.to_string(),
-                        kind: kind.to_string(),
-                        signature,
-                        description: current_docs.clone(),
-                    };
-
-                    if let Some(last_odu) = doc.odus.last_mut() {
-                        last_odu.items.push(item);
-                    } else {
-                        doc.orphans.push(item);
-                    }
-                }
-                current_docs.clear();
-                continue;
-            }
-
-            // If unrelated line, clear docs
-            current_docs.clear();
+    for stmt in &program.statements {
+        if let Statement::OduDef {
+            name,
+            body,
+            doc: description,
+            ..
+        } = stmt
+        {
+            let items = body
+                .iter()
+                .filter_map(|stmt| doc_item(stmt, &content))
+                .collect();
+            doc.add_odu(name.clone(), description.clone().unwrap_or_default())
+                .items = items;
+        } else if let Some(item) = doc_item(stmt, &content) {
+            doc.orphans.push(item);
         }
     }
 
     Ok(())
 }
 
+/// A page entry for an `ese` definition, its signature taken from the source
+/// up to the opening brace.
+fn doc_item(stmt: &Statement, source: &str) -> Option<DocItem> {
+    let Statement::EseDef {
+        name, doc, span, ..
+    } = stmt
+    else {
+        return None;
+    };
+    let header = source.get(span.start..span.end)?;
+    let signature = header[..header.find('{').unwrap_or(header.len())].trim();
+    Some(DocItem {
+        name: name.clone(),
+        kind: "ese".to_string(),
+        signature: signature.to_string(),
+        description: doc.clone().unwrap_or_default(),
+    })
+}
+
 /// Generate all documentation files to the output directory
 pub fn generate_docs(input_path: &Path, output_dir: &Path) -> Result<()> {
     fs::create_dir_all(output_dir)?;
@@ -1,11 +1,14 @@
 use ifa_babalawo::{BabalawoConfig, LintContext, Severity as IfaSeverity, analyze_program};
-use ifa_core::parse;
+use ifa_core::IfaError;
+use ifa_core::incremental::ParsedSource;
 use lsp_server::{Connection, Message, Notification, RequestId, Response};
 use lsp_types::{
     CompletionItem, CompletionItemKind, CompletionOptions, Diagnostic, DiagnosticSeverity,
     InitializeParams, Position, PublishDiagnosticsParams, Range, ServerCapabilities,
-    TextDocumentSyncCapability, TextDocumentSyncKind, TextDocumentSyncOptions, Url,
+    TextDocumentContentChangeEvent, TextDocumentSyncCapability, TextDocumentSyncKind,
+    TextDocumentSyncOptions, Url,
 };
+use std::collections::HashMap;
 use std::error::Error;
 
 /// Run the LSP server
@@ -18,7 +21,7 @@ pub fn run() -> Result<(), Box<dyn Error + Send + Sync>> {
         text_document_sync: Some(TextDocumentSyncCapability::Options(
             TextDocumentSyncOptions {
                 open_close: Some(true),
-                change: Some(TextDocumentSyncKind::FULL),
+                change: Some(TextDocumentSyncKind::INCREMENTAL),
                 ..Default::default()
             },
         )),
@@ -49,6 +52,7 @@ fn main_loop(
 
     // Track the latest valid analysis context
     let mut context: Option<LintContext> = None;
+    let mut documents: HashMap<Url, Document> = HashMap::new();
 
     for msg in &connection.receiver {
         match msg {
@@ -57,7 +61,9 @@ fn main_loop(
                     return Ok(());
                 }
                 let req_msg = Message::Request(req);
-                if let Ok((id, params)) = cast_req::<lsp_types::request::Completion>(req_msg.clone()) {
+                if let Ok((id, params)) =
+                    cast_req::<lsp_types::request::Completion>(req_msg.clone())
+                {
                     eprintln!(
                         "Got completion request for: {}",
                         params.text_document_position.text_document.uri
@@ -67,41 +73,63 @@ fn main_loop(
                     )));
                     let result = serde_json::to_value(&result)
                         .map_err(|e| format!("Failed to serialize completion: {}", e))?;
-                    let resp = Response { id, result: Some(result), error: None };
+                    let resp = Response {
+                        id,
+                        result: Some(result),
+                        error: None,
+                    };
                     connection.sender.send(Message::Response(resp))?;
-                } else if let Ok((id, params)) = cast_req::<lsp_types::request::CodeActionRequest>(req_msg.clone()) {
+                } else if let Ok((id, params)) =
+                    cast_req::<lsp_types::request::CodeActionRequest>(req_msg.clone())
+                {
                     let mut actions = Vec::new();
                     for diagnostic in params.context.diagnostics {
-                        if diagnostic.code == Some(lsp_types::NumberOrString::String("UNUSED_VARIABLE".to_string())) {
+                        if diagnostic.code
+                            == Some(lsp_types::NumberOrString::String(
+                                "UNUSED_VARIABLE".to_string(),
+                            ))
+                        {
                             let msg = &diagnostic.message;
                             if let Some(start) = msg.find('\'') {
-                                if let Some(end) = msg[start+1..].find('\'') {
-                                    let name = &msg[start+1..start+1+end];
+                                if let Some(end) = msg[start + 1..].find('\'') {
+                                    let name = &msg[start + 1..start + 1 + end];
                                     let new_name = format!("_{}", name);
-                                    
+
                                     let mut changes = std::collections::HashMap::new();
-                                    changes.insert(params.text_document.uri.clone(), vec![lsp_types::TextEdit {
-                                        range: diagnostic.range,
-                                        new_text: new_name.clone(),
-                                    }]);
+                                    changes.insert(
+                                        params.text_document.uri.clone(),
+                                        vec![lsp_types::TextEdit {
+                                            range: diagnostic.range,
+                                            new_text: new_name.clone(),
+                                        }],
+                                    );
 
-                                    actions.push(lsp_types::CodeActionOrCommand::CodeAction(lsp_types::CodeAction {
-                                        title: format!("Sanctify (prefix with _): {}", new_name),
-                                        kind: Some(lsp_types::CodeActionKind::QUICKFIX),
-                                        diagnostics: Some(vec![diagnostic]),
-                                        edit: Some(lsp_types::WorkspaceEdit {
-                                            changes: Some(changes),
+                                    actions.push(lsp_types::CodeActionOrCommand::CodeAction(
+                                        lsp_types::CodeAction {
+                                            title: format!(
+                                                "Sanctify (prefix with _): {}",
+                                                new_name
+                                            ),
+                                            kind: Some(lsp_types::CodeActionKind::QUICKFIX),
+                                            diagnostics: Some(vec![diagnostic]),
+                                            edit: Some(lsp_types::WorkspaceEdit {
+                                                changes: Some(changes),
+                                                ..Default::default()
+                                            }),
+                                            is_preferred: Some(true),
                                             ..Default::default()
-                                        }),
-                                        is_preferred: Some(true),
-                                        ..Default::default()
-                                    }));
+                                        },
+                                    ));
                                 }
                             }
                         }
                     }
                     let result = serde_json::to_value(&actions).unwrap_or(serde_json::Value::Null);
-                    let resp = Response { id, result: Some(result), error: None };
+                    let resp = Response {
+                        id,
+                        result: Some(result),
+                        error: None,
+                    };
                     connection.sender.send(Message::Response(resp))?;
                 } else {
                     // Unknown or unhandled request
@@ -116,13 +144,15 @@ fn main_loop(
                 ) {
                     Ok(params) => {
                         eprintln!("DidOpen: {}", params.text_document.uri);
+                        let doc = Document::new(params.text_document.text);
                         if let Ok(Some(new_ctx)) = publish_diagnostics(
                             &connection,
-                            params.text_document.uri,
-                            &params.text_document.text,
+                            params.text_document.uri.clone(),
+                            &doc.parsed,
                         ) {
                             context = Some(new_ctx);
                         }
+                        documents.insert(params.text_document.uri, doc);
                     }
                     Err(Message::Notification(not)) => {
                         match cast_not::<lsp_types::notification::DidChangeTextDocument>(
@@ -130,14 +160,18 @@ fn main_loop(
                         ) {
                             Ok(params) => {
                                 eprintln!("DidChange: {}", params.text_document.uri);
-                                if let Some(change) = params.content_changes.into_iter().next() {
-                                    if let Ok(Some(new_ctx)) = publish_diagnostics(
-                                        &connection,
-                                        params.text_document.uri,
-                                        &change.text,
-                                    ) {
-                                        context = Some(new_ctx);
-                                    }
+                                let doc = documents
+                                    .entry(params.text_document.uri.clone())
+                                    .or_insert_with(|| Document::new(String::new()));
+                                for change in params.content_changes {
+                                    doc.apply(change);
+                                }
+                                if let Ok(Some(new_ctx)) = publish_diagnostics(
+                                    &connection,
+                                    params.text_document.uri,
+                                    &doc.parsed,
+                                ) {
+                                    context = Some(new_ctx);
                                 }
                             }
                             Err(Message::Notification(not)) => {
@@ -154,19 +188,72 @@ fn main_loop(
     Ok(())
 }
 
+/// An open document and its parse, kept current across edits.
+struct Document {
+    text: String,
+    parsed: Result<ParsedSource, IfaError>,
+}
+
+impl Document {
+    fn new(text: String) -> Self {
+        let parsed = ParsedSource::parse(&text);
+        Self { text, parsed }
+    }
+
+    /// Apply one change, re-parsing only the edited statement when possible.
+    fn apply(&mut self, change: TextDocumentContentChangeEvent) {
+        let Some(range) = change.range else {
+            *self = Document::new(change.text);
+            return;
+        };
+        let start = byte_offset(&self.text, range.start);
+        let end = byte_offset(&self.text, range.end).max(start);
+        self.text.replace_range(start..end, &change.text);
+
+        let previous = std::mem::replace(
+            &mut self.parsed,
+            Err(IfaError::Parse("document is being edited".to_string())),
+        );
+        self.parsed = match previous {
+            Ok(parsed) => parsed
+                .reparse(start..end, &change.text, &self.text)
+                .map(|(parsed, _)| parsed),
+            Err(_) => ParsedSource::parse(&self.text),
+        };
+    }
+}
+
+/// Byte offset of an LSP position (UTF-16 column) in `text`, clamped to its line.
+fn byte_offset(text: &str, position: Position) -> usize {
+    let line_start: usize = text
+        .split_inclusive('\n')
+        .take(position.line as usize)
+        .map(str::len)
+        .sum();
+    let mut units = 0;
+    for (i, c) in text[line_start..].char_indices() {
+        if units >= position.character as usize || c == '\n' {
+            return line_start + i;
+        }
+        units += c.len_utf16();
+    }
+    text.len()
+}
+
 fn publish_diagnostics(
     connection: &Connection,
     uri: Url,
-    text: &str,
+    parsed: &Result<ParsedSource, IfaError>,
 ) -> Result<Option<LintContext>, Box<dyn Error + Send + Sync>> {
     let mut diagnostics = Vec::new();
     let mut context = None;
 
-    // 1. Parse Syntax
-    match parse(text) {
-        Ok(program) => {
+    // 1. Parse Syntax (kept up to date by `Document::apply`)
+    match parsed {
+        Ok(parsed) => {
             // 2. Run Babalawo Analyzer (get diagnostics + symbols)
-            let (baba, ctx) = analyze_program(&program, uri.path(), BabalawoConfig::default());
+            let (baba, ctx) =
+                analyze_program(&parsed.program, uri.path(), BabalawoConfig::default());
             context = Some(ctx);
 
             for diag in baba.diagnostics {
@@ -185,7 +272,8 @@ fn publish_diagnostics(
                         },
                         end: Position {
                             line: span.line.saturating_sub(1) as u32, // Simplified: assume single line for now if not available
-                            character: (span.column as u32 + (span.end as u32).saturating_sub(span.start as u32)),
+                            character: (span.column as u32
+                                + (span.end as u32).saturating_sub(span.start as u32)),
                         },
                     }
                 } else {
@@ -7,11 +7,13 @@ mod deploy;
 mod docgen;
 mod lsp;
 mod oja;
+mod repl;
 mod sandbox;
 
 use clap::{Parser, Subcommand};
 use eyre::{Result, WrapErr};
 use ifa_core::IfaValue;
+use sha2::{Digest, Sha256};
 use std::path::PathBuf;
 
 #[derive(Parser)]
@@ -23,6 +25,10 @@ use std::path::PathBuf;
 struct Cli {
     #[command(subcommand)]
     command: Commands,
+
+    /// Print CLI messages without ANSI colors or emoji (also set by NO_COLOR)
+    #[arg(long, global = true)]
+    no_color: bool,
 }
 
 #[derive(Subcommand)]
@@ -71,9 +77,57 @@ enum Commands {
         #[arg(long)]
         allow_python: bool,
 
+        /// Allow compiling and running C snippets with Coop.c
+        #[arg(long)]
+        allow_c: bool,
+
+        /// Allow starting specific programs (e.g. a Coop.c compiler)
+        #[arg(long, value_name = "PROGRAM")]
+        allow_exec: Vec<String>,
+
         /// Sandbox mode: wasm (OmniBox WASM sandbox), native (Igbale OS sandbox), none (no sandbox)
         #[arg(long, default_value = "none")]
         sandbox: String,
+
+        /// Print every capability check (granted or denied) after the run
+        #[arg(long)]
+        audit: bool,
+
+        /// After a capability is denied, name it and the `--allow-*` flag
+        /// that would have granted it
+        #[arg(long)]
+        strict_capabilities: bool,
+
+        /// With --strict-capabilities, offer to grant a denied capability
+        /// and run the program again from the top (anything it did before
+        /// the denial, such as printing or writing files, happens again)
+        #[arg(long, requires = "strict_capabilities")]
+        interactive: bool,
+
+        /// Output format: text, json (machine-readable run summary on stdout)
+        #[arg(long, default_value = "text")]
+        format: String,
+
+        /// Drop program output after this many lines
+        #[arg(long)]
+        max_output: Option<usize>,
+
+        /// Drop program output after this many bytes
+        #[arg(long)]
+        max_output_bytes: Option<usize>,
+
+        /// Fail the run instead of dropping output once a limit is hit
+        #[arg(long)]
+        abort_on_max_output: bool,
+
+        /// Print parse, execution and total wall time to stderr after the run
+        #[arg(long)]
+        time: bool,
+
+        /// Append runtime events (capability checks, served requests,
+        /// handler errors) to this file as newline-delimited JSON
+        #[arg(long, value_name = "FILE")]
+        events: Option<PathBuf>,
     },
 
     /// Compile to bytecode (.ifab)
@@ -93,6 +147,12 @@ enum Commands {
         #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
         args: Vec<String>,
 
+        /// Compile every imported module instead of using the project's
+        /// module cache (`target/ifa-cache`, written by ifa itself whatever
+        /// the --allow-write grants)
+        #[arg(long)]
+        no_cache: bool,
+
         /// Allow all permissions (insecure)
         #[arg(long)]
         allow_all: bool,
@@ -166,6 +226,27 @@ enum Commands {
         /// Output as reusable Cargo project (instead of building executable)
         #[arg(long)]
         project: bool,
+
+        /// Let the binary read from specific paths
+        #[arg(long)]
+        allow_read: Vec<PathBuf>,
+
+        /// Let the binary write to specific paths
+        #[arg(long)]
+        allow_write: Vec<PathBuf>,
+
+        /// Let the binary contact specific domains
+        #[arg(long)]
+        allow_net: Vec<String>,
+
+        /// Print the reproducible build hash and exit without compiling
+        #[arg(long)]
+        print_hash: bool,
+
+        /// Generate the Rust crate (`Cargo.toml` + `src/main.rs`) in this
+        /// directory instead of a temp one, and keep it after the build
+        #[arg(long, value_name = "DIR")]
+        emit_rust: Option<PathBuf>,
     },
 
     /// Flash to embedded device
@@ -196,16 +277,27 @@ enum Commands {
     Check {
         /// Path to .ifa source file
         file: PathBuf,
+        /// Print the full Babalawo lint report
+        #[arg(long)]
+        lint: bool,
+        /// With --lint, fail on warnings as well as errors
+        #[arg(long, requires = "lint")]
+        strict: bool,
     },
 
     /// Format source code
     Fmt {
-        /// Path to .ifa source file
-        file: PathBuf,
+        /// Path to .ifa source file (`-` reads stdin and writes stdout)
+        #[arg(required_unless_present = "stdin")]
+        file: Option<PathBuf>,
+        /// Read source from stdin and write the formatted source to stdout
+        #[arg(long, conflicts_with = "file")]
+        stdin: bool,
         /// Check only, don't modify
         #[arg(long)]
         check: bool,
         /// Acknowledge that the formatter is not yet release-stable
+        /// (needed to rewrite files; stdin mode never touches them)
         #[arg(long)]
         unstable: bool,
     },
@@ -252,6 +344,20 @@ enum Commands {
         verbose: bool,
     },
 
+    /// Time a program over several runs and report how much work the
+    /// interpreter did (statements, recursion depth, lists/maps created and
+    /// the most alive at once)
+    Bench {
+        /// Path to .ifa source file
+        file: PathBuf,
+        /// Number of timed runs
+        #[arg(short = 'n', long, default_value = "10")]
+        iterations: u32,
+        /// Output format: text, json
+        #[arg(long, default_value = "text")]
+        format: String,
+    },
+
     /// Zero-Config Deployment scanner
     Deploy {
         /// Project directory
@@ -259,6 +365,15 @@ enum Commands {
         path: PathBuf,
     },
 
+    /// Show the capabilities a program (and its imports) will request
+    Caps {
+        /// Path to .ifa source file
+        file: PathBuf,
+        /// Output format: text, json
+        #[arg(long, default_value = "text")]
+        format: String,
+    },
+
     /// Start Debug Adapter (DAP)
     Debug {
         /// File to debug
@@ -311,6 +426,18 @@ enum OjaCommands {
         /// Build in release mode
         #[arg(long)]
         release: bool,
+
+        /// Let the binary read from specific paths
+        #[arg(long)]
+        allow_read: Vec<PathBuf>,
+
+        /// Let the binary write to specific paths
+        #[arg(long)]
+        allow_write: Vec<PathBuf>,
+
+        /// Let the binary contact specific domains
+        #[arg(long)]
+        allow_net: Vec<String>,
     },
     /// Run project
     Run {
@@ -341,6 +468,24 @@ enum OjaCommands {
     Publish,
 }
 
+/// Content hash of a native build: the transpiled Rust plus the feature set
+/// and target it is compiled with. Identical inputs always give the same hash.
+fn build_hash(rust_code: &str, features: &[&str], target: Option<&str>) -> String {
+    let mut features = features.to_vec();
+    features.sort_unstable();
+    features.dedup();
+    let input = format!(
+        "{}\0features={}\0target={}",
+        rust_code,
+        features.join(","),
+        target.unwrap_or("")
+    );
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
 fn run_babalawo(program: &ifa_core::ast::Program, filepath: &std::path::Path) -> bool {
     let filename = filepath.display().to_string();
     let config = ifa_babalawo::BabalawoConfig {
@@ -352,7 +497,8 @@ fn run_babalawo(program: &ifa_core::ast::Program, filepath: &std::path::Path) ->
     }
     if baba.error_count() > 0 {
         eprintln!(
-            "❌ Babalawo rejected {}: {} error(s) found. Ìdájọ́ (Judgment) invoked. Àṣẹ!",
+            "{}Babalawo rejected {}: {} error(s) found. Ìdájọ́ (Judgment) invoked. Àṣẹ!",
+            icon("❌ "),
             filename,
             baba.error_count()
         );
@@ -361,27 +507,197 @@ fn run_babalawo(program: &ifa_core::ast::Program, filepath: &std::path::Path) ->
     true
 }
 
+/// `ifa check --lint`: always print the Babalawo report; under `strict`,
+/// warnings fail the check too.
+fn run_babalawo_lint(
+    program: &ifa_core::ast::Program,
+    filepath: &std::path::Path,
+    strict: bool,
+) -> bool {
+    let filename = filepath.display().to_string();
+    let config = ifa_babalawo::BabalawoConfig {
+        include_wisdom: true,
+    };
+    let baba = ifa_babalawo::check_program_with_config(program, &filename, config);
+    eprintln!("{}", baba.format());
+
+    let errors = baba.error_count();
+    let warnings = baba.warning_count();
+    if errors > 0 || (strict && warnings > 0) {
+        eprintln!(
+            "{}Lint failed for {}: {} error(s), {} warning(s){}",
+            icon("❌ "),
+            filename,
+            errors,
+            warnings,
+            if strict { " (strict)" } else { "" }
+        );
+        return false;
+    }
+    true
+}
+
+/// Route Ctrl-C to a flag the interpreter polls between statements, so a
+/// long run stops with a catchable "interrupted" error instead of killing the
+/// process. A second Ctrl-C before the flag is consumed exits as usual.
+fn install_interrupt_flag() -> std::sync::Arc<std::sync::atomic::AtomicBool> {
+    use signal_hook::consts::SIGINT;
+
+    let flag = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+    // Registration order matters: the shutdown check must see the old value
+    if let Err(e) = signal_hook::flag::register_conditional_shutdown(SIGINT, 130, flag.clone())
+        .and_then(|_| signal_hook::flag::register(SIGINT, flag.clone()))
+    {
+        eprintln!("Warning: Ctrl-C handling unavailable: {}", e);
+    }
+    flag
+}
+
+/// Milliseconds, as printed by `ifa run --time`
+fn millis(duration: std::time::Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+/// `ifa run --format json`: one summary object on stdout.
+fn print_run_summary_json(
+    statements: usize,
+    error: Option<&str>,
+    output: &[String],
+    audit: Option<&[ifa_core::interpreter::CapabilityAuditEntry]>,
+) {
+    let mut summary = serde_json::json!({
+        "status": if error.is_some() { "error" } else { "ok" },
+        "statements": statements,
+        "error": error,
+        "output": output,
+    });
+    if let Some(entries) = audit {
+        summary["audit"] = entries
+            .iter()
+            .map(|entry| {
+                serde_json::json!({
+                    "capability": format!("{:?}", entry.capability),
+                    "granted": entry.granted,
+                    "call_site": entry.call_site,
+                    "line": entry.line,
+                })
+            })
+            .collect();
+    }
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&summary).unwrap_or_default()
+    );
+}
+
+/// Set once in `main` by `--no-color` or a non-empty `NO_COLOR`.
+static PLAIN_OUTPUT: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+
+/// Whether CLI messages must avoid ANSI colors and emoji.
+fn plain_output() -> bool {
+    PLAIN_OUTPUT.load(std::sync::atomic::Ordering::Relaxed)
+}
+
+/// An emoji prefix for a CLI message, or nothing under `--no-color`.
+fn icon(emoji: &'static str) -> &'static str {
+    if plain_output() { "" } else { emoji }
+}
+
+/// The first capability check the last run denied, if any.
+fn first_denied_capability(
+    interpreter: &ifa_core::Interpreter,
+) -> Option<ifa_core::interpreter::CapabilityAuditEntry> {
+    interpreter
+        .capability_audit()
+        .iter()
+        .find(|entry| !entry.granted)
+        .cloned()
+}
+
+/// `ifa run --strict-capabilities`: say which capability was denied where,
+/// and how to grant it. Goes to stderr, like the other run diagnostics.
+fn report_denied_capability(
+    file: &std::path::Path,
+    entry: &ifa_core::interpreter::CapabilityAuditEntry,
+) {
+    eprintln!();
+    eprintln!(
+        "Capability denied: {:?} at {} (line {})",
+        entry.capability, entry.call_site, entry.line
+    );
+    let flags = deploy::allow_flags(&[&entry.capability]);
+    if flags.is_empty() {
+        eprintln!("  No `ifa run` flag grants this capability.");
+    } else {
+        eprintln!(
+            "  To allow it, run: ifa run {} {}",
+            file.display(),
+            flags.join(" ")
+        );
+    }
+}
+
+/// Grants every `ifa run` and `ifa bench` starts with: stdio, and reading
+/// the script and its directory. Anything else, the project's `src`
+/// included, needs an `--allow-*` flag; imports resolve without a grant.
+fn grant_default_capabilities(caps: &mut ifa_sandbox::CapabilitySet, file: &std::path::Path) {
+    use ifa_sandbox::Ofun;
+
+    caps.grant(Ofun::Stdio);
+    if let Ok(abs_path) = file.canonicalize() {
+        caps.grant(Ofun::ReadFiles { root: abs_path });
+        if let Some(parent) = file.parent()
+            && let Ok(abs_parent) = parent.canonicalize()
+        {
+            caps.grant(Ofun::ReadFiles { root: abs_parent });
+        }
+    }
+}
+
+/// Ask a yes/no question on stderr; anything but "y"/"yes" is no.
+fn confirm(question: &str) -> bool {
+    use std::io::Write;
+
+    eprint!("{} [y/N] ", question);
+    std::io::stderr().flush().ok();
+    let mut answer = String::new();
+    if std::io::stdin().read_line(&mut answer).is_err() {
+        return false;
+    }
+    matches!(answer.trim().to_lowercase().as_str(), "y" | "yes")
+}
+
 fn cli_args_value(args: Vec<String>) -> IfaValue {
     IfaValue::list(args.into_iter().map(IfaValue::str).collect())
 }
 
 fn main() -> Result<()> {
-    color_eyre::install()?;
-
     // Intercept arguments to support implicit 'run' subcommand for `ifa <file>`
+    // Leading global flags (`ifa --no-color prog.ifa`) are skipped first
     let mut args: Vec<String> = std::env::args().collect();
-    if args.len() >= 2 && !args[1].starts_with('-') {
-        let first_arg = args[1].as_str();
+    if let Some(pos) = args.iter().skip(1).position(|arg| !arg.starts_with('-')) {
+        let first_arg = args[pos + 1].as_str();
         let subcommands = [
-            "run", "runb", "bytecode", "check", "doc", "fmt", "test", "lsp", "oja", "deploy", "help"
+            "run", "runb", "bytecode", "build", "flash", "check", "doc", "fmt", "test", "bench",
+            "lsp", "repl", "oja", "deploy", "caps", "help",
         ];
         if !subcommands.contains(&first_arg) {
-            args.insert(1, "run".to_string());
+            args.insert(pos + 1, "run".to_string());
         }
     }
 
     let cli = Cli::parse_from(args);
 
+    let plain = cli.no_color || std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+    PLAIN_OUTPUT.store(plain, std::sync::atomic::Ordering::Relaxed);
+    if plain {
+        color_eyre::config::HookBuilder::default()
+            .theme(color_eyre::config::Theme::new())
+            .install()?;
+    } else {
+        color_eyre::install()?;
+    }
+
     match cli.command {
         Commands::Run {
             file,
@@ -395,14 +711,41 @@ fn main() -> Result<()> {
             allow_random,
             allow_js,
             allow_python,
+            allow_c,
+            allow_exec,
             sandbox,
+            audit,
+            strict_capabilities,
+            interactive,
+            format,
+            max_output,
+            max_output_bytes,
+            abort_on_max_output,
+            time,
+            events,
         } => {
+            use ifa_core::interpreter::handlers::bridge_tool;
+            use ifa_core::interpreter::{OutputLimit, OutputSink};
             use ifa_core::{Interpreter, parse};
             use ifa_sandbox::{CapabilitySet, Ofun};
 
-            println!("Ifa-Lang Interpreter v1.2.2");
-            println!();
-            println!("Running: {}", file.display());
+            let json = match format.as_str() {
+                "text" => false,
+                "json" => true,
+                other => {
+                    return Err(color_eyre::eyre::eyre!(
+                        "Unknown output format '{}' (expected text or json)",
+                        other
+                    ));
+                }
+            };
+
+            let started = std::time::Instant::now();
+            if !json {
+                println!("Ifa-Lang Interpreter v1.2.2");
+                println!();
+                println!("Running: {}", file.display());
+            }
 
             // Configure Capabilities
             let mut caps = CapabilitySet::new();
@@ -410,7 +753,7 @@ fn main() -> Result<()> {
             if allow_all {
                 // In a real implementation this would check a wildcard,
                 // but for now we'll just add common roots/domains
-                println!("Warning: Running with all permissions allowed!");
+                eprintln!("Warning: Running with all permissions allowed!");
                 caps.grant(Ofun::ReadFiles {
                     root: PathBuf::from("/"),
                 });
@@ -434,12 +777,12 @@ fn main() -> Result<()> {
                 caps.grant(Ofun::Stdio);
             } else {
                 // Configurable permissions
-                caps.grant(Ofun::Stdio); // Default allow stdio for now
+                grant_default_capabilities(&mut caps, &file);
                 for path in allow_read {
-                    caps.grant(Ofun::ReadFiles { root: path });
+                    caps.grant(Ofun::read_files(path));
                 }
                 for path in allow_write {
-                    caps.grant(Ofun::WriteFiles { root: path });
+                    caps.grant(Ofun::write_files(path));
                 }
                 if !allow_net.is_empty() {
                     caps.grant(Ofun::Network { domains: allow_net });
@@ -458,75 +801,138 @@ fn main() -> Result<()> {
                         language: "js".into(),
                     });
                 }
-                if allow_python {
+                // A bridge flag also lets the bridge start its own tool
+                for (allowed, language) in [(allow_python, "python"), (allow_c, "c")] {
+                    if !allowed {
+                        continue;
+                    }
                     caps.grant(Ofun::Bridge {
-                        language: "python".into(),
+                        language: language.into(),
                     });
-                }
-
-                // Always allow reading the script itself and its directory (for imports)
-                if let Ok(abs_path) = file.canonicalize() {
-                    caps.grant(Ofun::ReadFiles { root: abs_path });
-                    if let Some(parent) = file.parent()
-                        && let Ok(abs_parent) = parent.canonicalize()
-                    {
-                        caps.grant(Ofun::ReadFiles { root: abs_parent });
+                    if let Some(tool) = bridge_tool(language) {
+                        caps.grant(Ofun::Execute {
+                            programs: vec![tool.into()],
+                        });
                     }
                 }
+                if !allow_exec.is_empty() {
+                    caps.grant(Ofun::Execute {
+                        programs: allow_exec,
+                    });
+                }
             }
 
-            println!();
-
             // Read source file
             let source = std::fs::read_to_string(&file)
                 .map_err(|e| color_eyre::eyre::eyre!("Failed to read file: {}", e))?;
 
             // Parse
-            let program =
-                parse(&source).map_err(|e| color_eyre::eyre::eyre!("Parse error: {}", e))?;
+            let parse_started = std::time::Instant::now();
+            let parsed = parse(&source);
+            let parse_time = parse_started.elapsed();
+            let program = match parsed {
+                Ok(program) => program,
+                Err(e) if json => {
+                    print_run_summary_json(0, Some(&format!("Parse error: {}", e)), &[], None);
+                    std::process::exit(1);
+                }
+                Err(e) => return Err(color_eyre::eyre::eyre!("Parse error: {}", e)),
+            };
 
             // 5-Layer Integrity Defence (Babalawo Static Analysis)
             if !run_babalawo(&program, &file) {
+                if json {
+                    print_run_summary_json(
+                        program.statements.len(),
+                        Some("Babalawo rejected the program"),
+                        &[],
+                        None,
+                    );
+                }
                 std::process::exit(1);
             }
 
-            println!("Parsed {} statements", program.statements.len());
-            println!("---");
-            println!();
+            if !json {
+                println!();
+                println!("Parsed {} statements", program.statements.len());
+                println!("---");
+                println!();
+            }
 
-            // Interpret (with_file enables imports relative to script location)
-            let mut interpreter = Interpreter::with_file(&file);
+            // Interpret (with_file enables imports relative to script location).
+            // Every run gets a fresh interpreter, so a retry after granting a
+            // capability starts the program over with nothing left from the
+            // denied attempt
+            let interrupt = install_interrupt_flag();
+            let args = cli_args_value(args);
+            let new_interpreter = |caps: &CapabilitySet| -> Result<Interpreter> {
+                let mut interpreter = Interpreter::with_file(&file);
+                // Inside an ọjà project, imports also resolve from its `src`
+                if let Some(root) = oja::find_project_root(&file) {
+                    interpreter.add_module_path(root.join("src"));
+                }
 
-            // Register Standard Library Handlers (Breaking the Cycle)
-            interpreter.register_handler(Box::new(ifa_std::handlers::sys::SysHandler::new()));
+                // Register Standard Library Handlers (Breaking the Cycle)
+                interpreter.register_handler(Box::new(ifa_std::handlers::sys::SysHandler::new()));
 
-            interpreter.set_capabilities(caps.clone());
-            ifa_core::interpreter::Environment::define(
-                &interpreter.env,
-                "sys.args",
-                cli_args_value(args),
-            );
+                interpreter.set_capabilities(caps.clone());
+                interpreter.set_interrupt_flag(interrupt.clone());
+                if audit || strict_capabilities {
+                    interpreter.enable_capability_audit();
+                }
+                if let Some(path) = &events {
+                    let mut log = std::fs::OpenOptions::new()
+                        .create(true)
+                        .append(true)
+                        .open(path)
+                        .map_err(|e| {
+                            color_eyre::eyre::eyre!("Cannot open {}: {}", path.display(), e)
+                        })?;
+                    interpreter.set_event_sink(move |event| {
+                        use std::io::Write;
+                        if let Ok(json) = ifa_core::interpreter::handlers::ifa_to_json(event) {
+                            let _ = writeln!(log, "{}", json);
+                        }
+                    });
+                }
+                if json {
+                    // Keep stdout for the summary; program output is reported inside it
+                    interpreter.set_output_sink(OutputSink::Captured);
+                }
+                interpreter.set_output_limit(OutputLimit {
+                    max_lines: max_output,
+                    max_bytes: max_output_bytes,
+                    abort: abort_on_max_output,
+                });
+                ifa_core::interpreter::Environment::define(
+                    &interpreter.env,
+                    "sys.args",
+                    args.clone(),
+                );
+                Ok(interpreter)
+            };
+            let mut interpreter = new_interpreter(&caps)?;
 
             // Handle sandbox modes
             match sandbox.as_str() {
                 "wasm" => {
                     use ifa_sandbox::{SandboxConfig, SecurityProfile};
 
-                    println!("Running in OmniBox (WASM) sandbox...");
+                    eprintln!("Running in OmniBox (WASM) sandbox...");
 
                     // Create sandbox config from capabilities
                     let mut config = SandboxConfig::new(SecurityProfile::Standard);
-                    config.capabilities = caps;
+                    config.capabilities = caps.clone();
                     config.force_wasm = true;
 
                     // Note: Full WASM execution would compile .ifa to .wasm first
                     // For now, we run interpreted but with the capability restrictions
-                    println!(
+                    eprintln!(
                         "   (WASM compilation not yet implemented - using capability enforcement)"
                     );
                 }
                 "native" => {
-                    println!("Running in Igbale (native OS) sandbox...");
+                    eprintln!("Running in Igbale (native OS) sandbox...");
                     // Native sandbox uses OS-level isolation (Linux namespaces, etc.)
                     // Capabilities are already set on the interpreter
                 }
@@ -534,11 +940,72 @@ fn main() -> Result<()> {
                     // No sandbox - just use capability checks
                 }
                 _ => {
-                    println!("Warning: Unknown sandbox mode '{}', using 'none'", sandbox);
+                    eprintln!("Warning: Unknown sandbox mode '{}', using 'none'", sandbox);
                 }
             }
 
-            match interpreter.execute(&program) {
+            let execution_started = std::time::Instant::now();
+            let mut result = interpreter.execute(&program);
+            if strict_capabilities {
+                while let Some(denied) = first_denied_capability(&interpreter) {
+                    report_denied_capability(&file, &denied);
+                    if !(interactive && confirm("Grant it and run the program again?")) {
+                        break;
+                    }
+                    caps.grant(denied.capability);
+                    // Run the previous attempt's Ẹbọ cleanup before starting over
+                    drop(interpreter);
+                    interpreter = new_interpreter(&caps)?;
+                    result = interpreter.execute(&program);
+                }
+            }
+            if time {
+                // stderr, so program output and the JSON summary stay clean
+                let stats = interpreter.stats();
+                eprintln!();
+                eprintln!("Timing:");
+                eprintln!("  parse:       {:.3} ms", millis(parse_time));
+                eprintln!(
+                    "  execution:   {:.3} ms",
+                    millis(execution_started.elapsed())
+                );
+                eprintln!("  total:       {:.3} ms", millis(started.elapsed()));
+                eprintln!("  statements:  {}", stats.statements);
+                eprintln!("  max depth:   {}", stats.max_call_depth);
+            }
+            let spanned = result.as_ref().err().and_then(|e| {
+                let spanned = interpreter.spanned_error(e)?;
+                // Quote the offending line when it is in this file, not an import
+                let in_script = spanned.file.as_deref() == Some(&*file.display().to_string());
+                Some(match source.lines().nth(spanned.line.saturating_sub(1)) {
+                    Some(line) if in_script => spanned.with_source(line),
+                    _ => spanned,
+                })
+            });
+
+            if json {
+                let error = result.as_ref().err().map(|e| match &spanned {
+                    Some(s) => {
+                        let at = s.file.clone().unwrap_or_else(|| file.display().to_string());
+                        format!("{}:{}:{}: {}", at, s.line, s.column, e)
+                    }
+                    None => e.to_string(),
+                });
+                print_run_summary_json(
+                    program.statements.len(),
+                    error.as_deref(),
+                    interpreter.get_output(),
+                    audit.then(|| interpreter.capability_audit()),
+                );
+                // Run Ẹbọ cleanup (e.g. the scratch directory) before exiting
+                drop(interpreter);
+                if result.is_err() {
+                    std::process::exit(1);
+                }
+                return Ok(());
+            }
+
+            match &result {
                 Ok(_) => {
                     println!();
                     println!("---");
@@ -547,17 +1014,39 @@ fn main() -> Result<()> {
                 Err(e) => {
                     println!();
                     println!("---");
-                    println!("Runtime error: {}", e);
+                    match &spanned {
+                        Some(spanned) => print!("{}", spanned),
+                        None => println!("Runtime error: {}", e),
+                    }
                 }
             }
 
+            if audit {
+                println!();
+                println!("Capability audit:");
+                for entry in interpreter.capability_audit() {
+                    println!(
+                        "  [{}] {:?} at {} (line {})",
+                        if entry.granted { "granted" } else { "DENIED" },
+                        entry.capability,
+                        entry.call_site,
+                        entry.line
+                    );
+                }
+            }
+
+            drop(interpreter);
+            if result.is_err() {
+                std::process::exit(1);
+            }
             Ok(())
         }
 
         Commands::Bytecode { file, output } => {
             let out = output.unwrap_or_else(|| file.with_extension("ifab"));
             println!(
-                "📦 Compiling to bytecode: {} -> {}",
+                "{}Compiling to bytecode: {} -> {}",
+                icon("📦 "),
                 file.display(),
                 out.display()
             );
@@ -596,6 +1085,7 @@ fn main() -> Result<()> {
         Commands::Runb {
             file,
             args,
+            no_cache,
             allow_all,
             allow_read,
             allow_write,
@@ -607,7 +1097,7 @@ fn main() -> Result<()> {
             allow_python,
         } => {
             use ifa_sandbox::{CapabilitySet, Ofun};
-            println!("⚡ Running bytecode: {}", file.display());
+            println!("{}Running bytecode: {}", icon("⚡ "), file.display());
 
             // Security gate: bytecode must remain coupled to a verifiable source file.
             let source_candidate = file.with_extension("ifa");
@@ -637,25 +1127,57 @@ fn main() -> Result<()> {
 
             if allow_all {
                 println!("Warning: Running bytecode with all permissions allowed!");
-                caps.grant(Ofun::ReadFiles { root: PathBuf::from("/") });
-                caps.grant(Ofun::ReadFiles { root: PathBuf::from("C:\\") });
-                caps.grant(Ofun::WriteFiles { root: PathBuf::from("/") });
-                caps.grant(Ofun::WriteFiles { root: PathBuf::from("C:\\") });
-                caps.grant(Ofun::Network { domains: vec!["*".to_string()] });
-                caps.grant(Ofun::Environment { keys: vec!["*".to_string()] });
+                caps.grant(Ofun::ReadFiles {
+                    root: PathBuf::from("/"),
+                });
+                caps.grant(Ofun::ReadFiles {
+                    root: PathBuf::from("C:\\"),
+                });
+                caps.grant(Ofun::WriteFiles {
+                    root: PathBuf::from("/"),
+                });
+                caps.grant(Ofun::WriteFiles {
+                    root: PathBuf::from("C:\\"),
+                });
+                caps.grant(Ofun::Network {
+                    domains: vec!["*".to_string()],
+                });
+                caps.grant(Ofun::Environment {
+                    keys: vec!["*".to_string()],
+                });
                 caps.grant(Ofun::Time);
                 caps.grant(Ofun::Random);
                 caps.grant(Ofun::Stdio);
             } else {
                 caps.grant(Ofun::Stdio); // Default allow stdio
-                for path in allow_read { caps.grant(Ofun::ReadFiles { root: path }); }
-                for path in allow_write { caps.grant(Ofun::WriteFiles { root: path }); }
-                if !allow_net.is_empty() { caps.grant(Ofun::Network { domains: allow_net }); }
-                if !allow_env.is_empty() { caps.grant(Ofun::Environment { keys: allow_env }); }
-                if allow_time { caps.grant(Ofun::Time); }
-                if allow_random { caps.grant(Ofun::Random); }
-                if allow_js { caps.grant(Ofun::Bridge { language: "js".into() }); }
-                if allow_python { caps.grant(Ofun::Bridge { language: "python".into() }); }
+                for path in allow_read {
+                    caps.grant(Ofun::read_files(path));
+                }
+                for path in allow_write {
+                    caps.grant(Ofun::write_files(path));
+                }
+                if !allow_net.is_empty() {
+                    caps.grant(Ofun::Network { domains: allow_net });
+                }
+                if !allow_env.is_empty() {
+                    caps.grant(Ofun::Environment { keys: allow_env });
+                }
+                if allow_time {
+                    caps.grant(Ofun::Time);
+                }
+                if allow_random {
+                    caps.grant(Ofun::Random);
+                }
+                if allow_js {
+                    caps.grant(Ofun::Bridge {
+                        language: "js".into(),
+                    });
+                }
+                if allow_python {
+                    caps.grant(Ofun::Bridge {
+                        language: "python".into(),
+                    });
+                }
             }
 
             // Read bytecode
@@ -671,6 +1193,12 @@ fn main() -> Result<()> {
             registry.set_capabilities(caps);
             let mut vm = ifa_core::IfaVM::new().with_registry(Box::new(registry));
             vm.set_global("sys.args", cli_args_value(args));
+            // Inside an ọjà project, compiled imports are cached under its `target`
+            if !no_cache && let Some(root) = oja::find_project_root(&source_candidate) {
+                vm.set_module_cache(ifa_core::ModuleCache::new(
+                    root.join("target").join("ifa-cache"),
+                ));
+            }
             match vm.execute(&bytecode) {
                 Ok(result) => {
                     println!("Result: {:?}", result);
@@ -695,32 +1223,32 @@ fn main() -> Result<()> {
             ml,
             fullstack,
             project,
+            allow_read,
+            allow_write,
+            allow_net,
+            print_hash,
+            emit_rust,
         } => {
+            use ifa_sandbox::{CapabilitySet, Ofun};
             use std::process::Command;
 
             let out = output.unwrap_or_else(|| {
                 let stem = file.file_stem().unwrap_or_default();
                 PathBuf::from(stem)
             });
-            println!("🔨 Building: {} -> {}", file.display(), out.display());
-
-            // Check if rustc is available
-            let rustc_check = Command::new("rustc").arg("--version").output();
-            if rustc_check.is_err() {
-                println!("Error: Rust toolchain not installed.");
-                println!("   Native compilation requires Rust. Install via:");
-                println!("   curl --proto '=https' --tlsv1.2 -sSf https://sh.rustup.rs | sh");
-                return Err(color_eyre::eyre::eyre!(
-                    "Rust toolchain required for native builds"
-                ));
-            }
+            println!(
+                "{}Building: {} -> {}",
+                icon("🔨 "),
+                file.display(),
+                out.display()
+            );
 
             // Read source file
             let source = std::fs::read_to_string(&file)
                 .map_err(|e| color_eyre::eyre::eyre!("Failed to read source: {}", e))?;
 
             // Parse and transpile to Rust
-            println!("   📝 Parsing Ifá source...");
+            println!("   {}Parsing Ifá source...", icon("📝 "));
             let program = ifa_core::parse(&source)
                 .map_err(|e| color_eyre::eyre::eyre!("Parse error: {}", e))?;
 
@@ -729,7 +1257,19 @@ fn main() -> Result<()> {
                 std::process::exit(1);
             }
 
-            println!("   🔄 Transpiling to Rust...");
+            println!("   {}Transpiling to Rust...", icon("🔄 "));
+
+            // Same Ọ̀fún model as `ifa run`: the binary only gets what is granted here
+            let mut caps = CapabilitySet::new();
+            for path in allow_read {
+                caps.grant(Ofun::read_files(path));
+            }
+            for path in allow_write {
+                caps.grant(Ofun::write_files(path));
+            }
+            if !allow_net.is_empty() {
+                caps.grant(Ofun::Network { domains: allow_net });
+            }
 
             // If --project flag, use generate_project to create reusable Cargo project
             if project {
@@ -744,13 +1284,24 @@ fn main() -> Result<()> {
                     out.clone()
                 };
 
-                println!("   📁 Generating Cargo project: {}", project_dir.display());
+                println!(
+                    "   {}Generating Cargo project: {}",
+                    icon("📁 "),
+                    project_dir.display()
+                );
 
-                let config = ifa_core::generate_project(&program, &project_name, &project_dir)
-                    .map_err(|e| color_eyre::eyre::eyre!("Failed to generate project: {}", e))?;
+                let config =
+                    ifa_core::generate_project(&program, &project_name, &project_dir, &caps)
+                        .map_err(|e| {
+                            color_eyre::eyre::eyre!("Failed to generate project: {}", e)
+                        })?;
 
                 println!();
-                println!("✅ Created Cargo project at: {}", project_dir.display());
+                println!(
+                    "{}Created Cargo project at: {}",
+                    icon("✅ "),
+                    project_dir.display()
+                );
                 println!();
                 println!("Detected dependencies:");
                 if config.needs_tokio {
@@ -776,17 +1327,10 @@ fn main() -> Result<()> {
                 return Ok(());
             }
 
-            let rust_code = ifa_core::transpile_to_rust(&program);
-
-            // Create temp Cargo project
-            let temp_dir = std::env::temp_dir().join(format!("ifa_build_{}", std::process::id()));
-            std::fs::create_dir_all(&temp_dir)?;
-
-            let src_dir = temp_dir.join("src");
-            std::fs::create_dir_all(&src_dir)?;
-
-            // Write main.rs
-            std::fs::write(src_dir.join("main.rs"), &rust_code)?;
+            let mut transpiler = ifa_core::transpiler::RustTranspiler::new();
+            transpiler.set_capabilities(&caps);
+            transpiler.set_source_name(file.display().to_string());
+            let rust_code = transpiler.transpile_program(&program);
 
             // Determine features
             let mut features = Vec::new();
@@ -815,6 +1359,18 @@ fn main() -> Result<()> {
                 features.push("backend");
             }
 
+            let build_hash = build_hash(&rust_code, &features, target.as_deref());
+            if print_hash {
+                println!("{}", build_hash);
+                return Ok(());
+            }
+
+            // main.rs, with the hash kept in the binary for provenance
+            let main_rs = format!(
+                "{}\n#[used]\n#[allow(dead_code)]\nstatic IFA_BUILD_HASH: &str = \"{}\";\n",
+                rust_code, build_hash
+            );
+
             let features_str = features
                 .iter()
                 .map(|f| format!("\"{}\"", f))
@@ -851,7 +1407,30 @@ lto = true
                 features_str,
                 default_features
             );
+
+            // Create the Cargo project; an --emit-rust directory outlives the build
+            let keep_crate = emit_rust.is_some();
+            let temp_dir = emit_rust.unwrap_or_else(|| {
+                std::env::temp_dir().join(format!("ifa_build_{}", std::process::id()))
+            });
+            let src_dir = temp_dir.join("src");
+            std::fs::create_dir_all(&src_dir)?;
+            std::fs::write(src_dir.join("main.rs"), main_rs)?;
             std::fs::write(temp_dir.join("Cargo.toml"), cargo_toml)?;
+            if keep_crate {
+                println!("   {}Rust crate: {}", icon("📁 "), temp_dir.display());
+            }
+
+            // Check if rustc is available
+            let rustc_check = Command::new("rustc").arg("--version").output();
+            if rustc_check.is_err() {
+                println!("Error: Rust toolchain not installed.");
+                println!("   Native compilation requires Rust. Install via:");
+                println!("   curl --proto '=https' --tlsv1.2 -sSf https://sh.rustup.rs | sh");
+                return Err(color_eyre::eyre::eyre!(
+                    "Rust toolchain required for native builds"
+                ));
+            }
 
             // Build with cargo
             println!("   Compiling (this may take a moment)...");
@@ -861,7 +1440,7 @@ lto = true
 
             if let Some(ref t) = target {
                 cmd.arg("--target").arg(t);
-                println!("   🎯 Target: {}", t);
+                println!("   {}Target: {}", icon("🎯 "), t);
             }
 
             let build_output = cmd.output()?;
@@ -905,10 +1484,13 @@ lto = true
             std::fs::copy(&built_binary, &final_output)?;
 
             // Cleanup temp directory
-            let _ = std::fs::remove_dir_all(&temp_dir);
+            if !keep_crate {
+                let _ = std::fs::remove_dir_all(&temp_dir);
+            }
 
             let file_size = std::fs::metadata(&final_output)?.len();
             println!("Built: {} ({} bytes)", final_output.display(), file_size);
+            println!("   Build hash: {}", build_hash);
             println!(
                 "   Run with: .\\{}",
                 final_output
@@ -921,11 +1503,26 @@ lto = true
         }
 
         Commands::Flash { file, target, port } => {
-            println!("🔌 Flashing to: {}", target);
+            println!("{}Flashing to: {}", icon("🔌 "), target);
             println!("   Source: {}", file.display());
             if let Some(ref p) = port {
                 println!("   Port: {}", p);
             }
+            // Devices run the alloc-free embedded tier, which has no bytes values
+            if file.extension().is_some_and(|ext| ext == "ifa") {
+                let source = std::fs::read_to_string(&file)?;
+                if let Some((line, col)) = ifa_core::byte_literal_positions(&source)
+                    .map_err(|e| color_eyre::eyre::eyre!("{}", e))?
+                    .first()
+                {
+                    return Err(color_eyre::eyre::eyre!(
+                        "{}:{}:{}: byte string literals are not supported by `ifa flash` (the embedded runtime has no bytes values)",
+                        file.display(),
+                        line,
+                        col
+                    ));
+                }
+            }
             ifa_std::stacks::iot::flash(&target, file.to_str().unwrap_or(""), port.as_deref())
                 .map_err(|e| color_eyre::eyre::eyre!("IoT Error: {}", e))?;
             Ok(())
@@ -955,7 +1552,7 @@ lto = true
                     sandbox::demo();
                 }
                 SandboxCommands::List => {
-                    println!("📋 No active sandbox containers");
+                    println!("{}No active sandbox containers", icon("📋 "));
                 }
             }
             Ok(())
@@ -975,8 +1572,26 @@ lto = true
                 OjaCommands::Remove { name } => {
                     oja_manager.remove(&name)?;
                 }
-                OjaCommands::Build { release } => {
-                    oja_manager.build(release)?;
+                OjaCommands::Build {
+                    release,
+                    allow_read,
+                    allow_write,
+                    allow_net,
+                } => {
+                    use ifa_sandbox::{CapabilitySet, Ofun};
+
+                    // Same grants as `ifa build`; without them the binary is deny-all
+                    let mut caps = CapabilitySet::new();
+                    for path in allow_read {
+                        caps.grant(Ofun::read_files(path));
+                    }
+                    for path in allow_write {
+                        caps.grant(Ofun::write_files(path));
+                    }
+                    if !allow_net.is_empty() {
+                        caps.grant(Ofun::Network { domains: allow_net });
+                    }
+                    oja_manager.build(release, &caps)?;
                 }
                 OjaCommands::Run { args } => {
                     oja_manager.run(&args)?;
@@ -1012,14 +1627,20 @@ lto = true
             Ok(())
         }
 
-        Commands::Check { file } => {
-            println!("🔍 Checking syntax of {}...", file.display());
+        Commands::Check { file, lint, strict } => {
+            println!("{}Checking syntax of {}...", icon("🔍 "), file.display());
             let source = std::fs::read_to_string(&file).wrap_err("Failed to read file")?;
             match ifa_core::parse(&source) {
                 Ok(program) => {
-                    if run_babalawo(&program, &file) {
+                    let passed = if lint {
+                        run_babalawo_lint(&program, &file, strict)
+                    } else {
+                        run_babalawo(&program, &file)
+                    };
+                    if passed {
                         println!(
-                            "✅ No syntax or static analysis errors found in {}",
+                            "{}No syntax or static analysis errors found in {}",
+                            icon("✅ "),
                             file.display()
                         );
                     } else {
@@ -1027,7 +1648,7 @@ lto = true
                     }
                 }
                 Err(e) => {
-                    eprintln!("❌ Syntax Error: {}", e);
+                    eprintln!("{}Syntax Error: {}", icon("❌ "), e);
                     std::process::exit(1);
                 }
             }
@@ -1036,9 +1657,40 @@ lto = true
 
         Commands::Fmt {
             file,
+            stdin,
             check,
             unstable,
         } => {
+            use ifa_fmt::{FormatterConfig, format};
+            let config = FormatterConfig::default();
+
+            let file = match file {
+                Some(file) if !stdin && file.as_os_str() != "-" => file,
+                _ => {
+                    // Editor mode: stdin -> stdout, nothing else is printed
+                    use std::io::{Read, Write};
+                    let mut source = String::new();
+                    std::io::stdin()
+                        .read_to_string(&mut source)
+                        .wrap_err("Failed to read stdin")?;
+                    if let Err(e) = ifa_core::parse(&source) {
+                        eprintln!("Parse error: {}", e);
+                        std::process::exit(1);
+                    }
+                    let formatted = format(&source, config);
+                    if check {
+                        if source != formatted {
+                            std::process::exit(1);
+                        }
+                    } else {
+                        std::io::stdout()
+                            .write_all(formatted.as_bytes())
+                            .wrap_err("Failed to write stdout")?;
+                    }
+                    return Ok(());
+                }
+            };
+
             if !unstable {
                 return Err(color_eyre::eyre::eyre!(
                     "`ifa fmt` is still unstable. Re-run with `--unstable`."
@@ -1046,24 +1698,25 @@ lto = true
             }
 
             let source = std::fs::read_to_string(&file).wrap_err("Failed to read file")?;
-
-            use ifa_fmt::{FormatterConfig, format};
-            let config = FormatterConfig::default();
             let formatted = format(&source, config);
 
             if check {
                 if source == formatted {
-                    println!("✅ Perfect alignment in {}", file.display());
+                    println!("{}Perfect alignment in {}", icon("✅ "), file.display());
                 } else {
-                    println!("⚠️ Misaligned lines in {}", file.display());
+                    println!("{}Misaligned lines in {}", icon("⚠️ "), file.display());
                     std::process::exit(1);
                 }
             } else {
                 if source != formatted {
                     std::fs::write(&file, formatted).wrap_err("Failed to write formatted file")?;
-                    println!("✨ Syntactic harmony restored in {}", file.display());
+                    println!(
+                        "{}Syntactic harmony restored in {}",
+                        icon("✨ "),
+                        file.display()
+                    );
                 } else {
-                    println!("✨ Already formatted.");
+                    println!("{}Already formatted.", icon("✨ "));
                 }
             }
             Ok(())
@@ -1087,7 +1740,7 @@ lto = true
         }
 
         Commands::Lsp => {
-            println!("🚀 Starting LSP server...");
+            println!("{}Starting LSP server...", icon("🚀 "));
             if let Err(e) = lsp::run() {
                 eprintln!("LSP Error: {}", e);
                 std::process::exit(1);
@@ -1096,10 +1749,18 @@ lto = true
         }
 
         Commands::Repl => {
-            use std::io::{self, BufRead, Write};
+            use repl::{Input, LineReader};
 
             println!("╔═══════════════════════════════════════════════════════════════╗");
-            println!("║  🔮 Ifá-Lang REPL v1.0.0                                       ║");
+            // The plain banner pads out the columns the emoji took
+            let (title_icon, title_pad) = if plain_output() {
+                ("", "   ")
+            } else {
+                ("🔮 ", "")
+            };
+            println!(
+                "║  {title_icon}Ifá-Lang REPL v1.0.0{title_pad}                                       ║"
+            );
             println!("║  The Yoruba Programming Language                              ║");
             println!("╠═══════════════════════════════════════════════════════════════╣");
             println!("║  Type Ifá-Lang code to execute. Commands:                     ║");
@@ -1108,35 +1769,37 @@ lto = true
             println!("╚═══════════════════════════════════════════════════════════════╝");
             println!();
 
+            let interrupt = install_interrupt_flag();
             let mut interpreter = ifa_core::Interpreter::new();
-            let stdin = io::stdin();
+            interpreter.set_interrupt_flag(interrupt.clone());
+            let mut reader = LineReader::new(repl::default_history_path());
             let mut multiline_buffer = String::new();
             let mut in_multiline = false;
 
             loop {
-                // Show prompt
-                if in_multiline {
-                    print!("... ");
-                } else {
-                    print!("ifá> ");
-                }
-                io::stdout().flush().ok();
-
-                // Read input
-                let mut line = String::new();
-                if stdin.lock().read_line(&mut line).is_err() || line.is_empty() {
-                    break;
-                }
+                let prompt = if in_multiline { "... " } else { "ifá> " };
+                let line = match reader.read_line(prompt) {
+                    Input::Line(line) => line,
+                    Input::Interrupted => {
+                        multiline_buffer.clear();
+                        in_multiline = false;
+                        continue;
+                    }
+                    Input::Eof => break,
+                };
                 let line = line.trim();
+                if !line.is_empty() {
+                    reader.record(line);
+                }
 
                 // Handle REPL commands
                 match line {
                     ".quit" | ".exit" | ".q" => {
-                        println!("👋 Ó dà bọ̀! (Goodbye!)");
+                        println!("{}Ó dà bọ̀! (Goodbye!)", icon("👋 "));
                         break;
                     }
                     ".help" | ".h" => {
-                        println!("📚 Ifá-Lang REPL Help:");
+                        println!("{}Ifá-Lang REPL Help:", icon("📚 "));
                         println!("  .help, .h    - Show this help");
                         println!("  .clear, .c   - Clear interpreter state");
                         println!("  .vars, .v    - Show defined variables");
@@ -1146,17 +1809,22 @@ lto = true
                     }
                     ".clear" | ".c" => {
                         interpreter = ifa_core::Interpreter::new();
-                        println!("🧹 Interpreter state cleared");
+                        interpreter.set_interrupt_flag(interrupt.clone());
+                        println!("{}Interpreter state cleared", icon("🧹 "));
                         continue;
                     }
                     ".vars" | ".v" => {
-                        println!("📦 Variables:");
-                        // The env is private, so we'd need to add a method to expose vars
-                        println!("  (variable inspection not yet implemented)");
+                        use ifa_core::interpreter::Environment;
+                        println!("{}Variables:", icon("📦 "));
+                        for name in Environment::variable_names(&interpreter.env) {
+                            if let Some(value) = Environment::get(&interpreter.env, &name) {
+                                println!("  {} = {}", name, value);
+                            }
+                        }
                         continue;
                     }
                     ".odu" => {
-                        println!("🔢 16 Odù Domains:");
+                        println!("{}16 Odù Domains:", icon("🔢 "));
                         println!("  Ọ̀gbè (1111)    - System, CLI");
                         println!("  Ọ̀yẹ̀kú (0000)  - Exit, Sleep");
                         println!("  Ìwòrì (0110)  - Time, Iteration");
@@ -1183,11 +1851,7 @@ lto = true
                 multiline_buffer.push_str(line);
                 multiline_buffer.push('\n');
 
-                // Check for balanced braces
-                let open_braces = multiline_buffer.matches('{').count();
-                let close_braces = multiline_buffer.matches('}').count();
-
-                if open_braces > close_braces {
+                if repl::needs_more_input(&multiline_buffer) {
                     in_multiline = true;
                     continue;
                 }
@@ -1198,6 +1862,8 @@ lto = true
                 // Parse and execute
                 match ifa_core::parse(&code) {
                     Ok(program) => {
+                        // Ctrl-C pressed at the prompt must not abort this entry
+                        interrupt.store(false, std::sync::atomic::Ordering::Relaxed);
                         match interpreter.execute(&program) {
                             Ok(result) => {
                                 // Don't print Null results for statements
@@ -1317,7 +1983,7 @@ lto = true
         }
 
         Commands::Doc { input, output } => {
-            println!("📚 Generating documentation...");
+            println!("{}Generating documentation...", icon("📚 "));
             println!("   Input:  {}", input.display());
             println!("   Output: {}", output.display());
             println!();
@@ -1424,7 +2090,13 @@ lto = true
             if failed == 0 {
                 println!("{} tests passed in {:.2?}", passed, duration);
             } else {
-                println!("✗ {} passed, {} failed in {:.2?}", passed, failed, duration);
+                println!(
+                    "{}{} passed, {} failed in {:.2?}",
+                    icon("✗ "),
+                    passed,
+                    failed,
+                    duration
+                );
             }
 
             if failed > 0 {
@@ -1438,7 +2110,113 @@ lto = true
             deploy::scan_and_generate(&path)?;
             Ok(())
         }
+
+        Commands::Caps { file, format } => {
+            deploy::dump_capabilities(&file, &format)?;
+            Ok(())
+        }
+
+        Commands::Bench {
+            file,
+            iterations,
+            format,
+        } => run_bench(&file, iterations, &format),
+    }
+}
+
+/// Run `file` `iterations` times with console output captured, then report
+/// wall-clock timings and the interpreter's work counters for a single run.
+fn run_bench(file: &std::path::Path, iterations: u32, format: &str) -> Result<()> {
+    use ifa_core::Interpreter;
+    use ifa_core::interpreter::{ExecutionStats, OutputSink};
+    use ifa_sandbox::CapabilitySet;
+
+    let json = match format {
+        "text" => false,
+        "json" => true,
+        other => {
+            return Err(color_eyre::eyre::eyre!(
+                "Unknown output format '{}' (expected text or json)",
+                other
+            ));
+        }
+    };
+    if iterations == 0 {
+        return Err(color_eyre::eyre::eyre!("--iterations must be at least 1"));
+    }
+
+    let source = std::fs::read_to_string(file).wrap_err("Failed to read file")?;
+    let program =
+        ifa_core::parse(&source).map_err(|e| color_eyre::eyre::eyre!("Parse error: {}", e))?;
+
+    // Same defaults as `ifa run` without any `--allow-*` flags
+    let mut caps = CapabilitySet::new();
+    grant_default_capabilities(&mut caps, file);
+
+    let project_root = oja::find_project_root(file);
+    let run = |track_collections: bool| -> Result<(f64, ExecutionStats)> {
+        let mut interpreter = Interpreter::with_file(file);
+        if let Some(root) = &project_root {
+            interpreter.add_module_path(root.join("src"));
+        }
+        interpreter.set_capabilities(caps.clone());
+        interpreter.set_output_sink(OutputSink::Captured);
+        if track_collections {
+            interpreter.track_collections();
+        }
+        let start = std::time::Instant::now();
+        let result = interpreter.execute(&program);
+        let elapsed = start.elapsed().as_secs_f64() * 1000.0;
+        if let Err(e) = result {
+            return Err(match interpreter.spanned_error(&e) {
+                Some(spanned) => color_eyre::eyre::eyre!("{}", spanned),
+                None => color_eyre::eyre::eyre!("Runtime error: {}", e),
+            });
+        }
+        Ok((elapsed, interpreter.stats()))
+    };
+    let mut times = Vec::with_capacity(iterations as usize);
+    for _ in 0..iterations {
+        times.push(run(false)?.0);
+    }
+    // Counters come from one more, untimed run, so tracking collections
+    // doesn't slow the timed ones
+    let (_, stats) = run(true)?;
+
+    let mean = times.iter().sum::<f64>() / times.len() as f64;
+    let min = times.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = times.iter().copied().fold(0.0, f64::max);
+
+    if json {
+        let report = serde_json::json!({
+            "file": file.display().to_string(),
+            "iterations": iterations,
+            "mean_ms": mean,
+            "min_ms": min,
+            "max_ms": max,
+            "statements": stats.statements,
+            "max_call_depth": stats.max_call_depth,
+            "collections_created": stats.collections_created,
+            "peak_collections": stats.peak_collections,
+        });
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&report).unwrap_or_default()
+        );
+    } else {
+        println!("Benchmark: {} ({} runs)", file.display(), iterations);
+        println!(
+            "  time:        mean {:.3} ms, min {:.3} ms, max {:.3} ms",
+            mean, min, max
+        );
+        println!("  statements:  {}", stats.statements);
+        println!("  max depth:   {}", stats.max_call_depth);
+        println!(
+            "  lists/maps:  {} created, {} alive at most",
+            stats.collections_created, stats.peak_collections
+        );
     }
+    Ok(())
 }
 
 /// Walk directory recursively
@@ -5,6 +5,7 @@
 
 #![allow(dead_code)]
 
+use crate::icon;
 use chrono::Local;
 use eyre::{Result, WrapErr, eyre};
 use flate2::read::GzDecoder;
@@ -421,7 +422,7 @@ jẹ́ kí a sọ "Ẹ káàbọ̀ sí Ifá-Lang!"
 
     /// Initialize a Monorepo Workspace
     fn init_workspace(&self, name: &str) -> Result<()> {
-        println!("🏗️  Initializing Monorepo Workspace: {}", name);
+        println!("{}Initializing Monorepo Workspace: {}", icon("🏗️  "), name);
 
         let manifest = IfaManifest {
             package: None,
@@ -449,37 +450,40 @@ jẹ́ kí a sọ "Ẹ káàbọ̀ sí Ifá-Lang!"
         let packages_dir = self.project_root.join("packages");
         fs::create_dir_all(&packages_dir)?;
 
-        println!("   ✓ Created Iwe.toml (workspace)");
-        println!("   ✓ Initialized shared .oja/");
+        println!("   {}Created Iwe.toml (workspace)", icon("✓ "));
+        println!("   {}Initialized shared .oja/", icon("✓ "));
         println!(
-            "   💡 Add members with: ifa oja init <name> --domain <domain> inside proper folders"
+            "   {}Add members with: ifa oja init <name> --domain <domain> inside proper folders",
+            icon("💡 ")
         );
 
         Ok(())
     }
 
-    /// Build project (Project-Centric Build)
+    /// Build project (Project-Centric Build). The binary gets only the
+    /// file and network capabilities in `caps`.
     #[allow(clippy::only_used_in_recursion)]
-    pub fn build(&self, release: bool) -> Result<()> {
+    pub fn build(&self, release: bool, caps: &ifa_sandbox::CapabilitySet) -> Result<()> {
         let manifest = self.load_manifest()?;
 
         // 1. Workspace Build
         if let Some(workspace) = manifest.workspace {
             println!(
-                "🏗️  Building Workspace ({} members)...",
+                "{}Building Workspace ({} members)...",
+                icon("🏗️  "),
                 workspace.members.len()
             );
             for member in workspace.members {
                 let member_path = self.project_root.join(&member);
                 if !member_path.exists() {
-                    println!("   ⚠️  Member not found: {} (skipping)", member);
+                    println!("   {}Member not found: {} (skipping)", icon("⚠️  "), member);
                     continue;
                 }
-                println!("   👉 Entering member: {}", member);
+                println!("   {}Entering member: {}", icon("👉 "), member);
                 let member_oja = Oja::new(&member_path);
-                member_oja.build(release)?;
+                member_oja.build(release, caps)?;
             }
-            println!("✅ Workspace build complete.");
+            println!("{}Workspace build complete.", icon("✅ "));
             return Ok(());
         }
 
@@ -487,14 +491,17 @@ jẹ́ kí a sọ "Ẹ káàbọ̀ sí Ifá-Lang!"
         if let Some(package) = manifest.package {
             let lang = package.language.as_deref().unwrap_or("ifa");
             println!(
-                "📦 Building Package: {} v{} [{}]",
-                package.name, package.version, lang
+                "{}Building Package: {} v{} [{}]",
+                icon("📦 "),
+                package.name,
+                package.version,
+                lang
             );
 
             if lang == "rust" {
                 // Rust Project Support
                 // We assume there's a Cargo.toml in the crate root
-                println!("   🦀 Delegating to Cargo...");
+                println!("   {}Delegating to Cargo...", icon("🦀 "));
                 let status = Command::new("cargo")
                     .arg("build")
                     .arg(if release { "--release" } else { "--dev" }) // simplified
@@ -504,7 +511,7 @@ jẹ́ kí a sọ "Ẹ káàbọ̀ sí Ifá-Lang!"
                 if !status.success() {
                     return Err(eyre!("Cargo build failed"));
                 }
-                println!("   ✅ Rust build complete.");
+                println!("   {}Rust build complete.", icon("✅ "));
                 return Ok(());
             }
 
@@ -518,11 +525,36 @@ jẹ́ kí a sọ "Ẹ káàbọ̀ sí Ifá-Lang!"
             // Reusing logic similar to main.rs but adapted for Project Context
 
             let source = std::fs::read_to_string(&src_file).wrap_err("Failed to read main.ifa")?;
-            println!("   📝 Parsing Ifá source...");
+            println!("   {}Parsing Ifá source...", icon("📝 "));
             let program = ifa_core::parse(&source).map_err(|e| eyre!("Parse error: {}", e))?;
 
-            println!("   🔄 Transpiling to Rust...");
-            let rust_code = ifa_core::transpile_to_rust(&program);
+            // In-source oriki must agree with Iwe.toml
+            if let Some(meta) = &program.metadata {
+                if meta.name.as_deref().is_some_and(|n| n != package.name) {
+                    println!(
+                        "   {}oriki name {:?} differs from Iwe.toml name {:?}",
+                        icon("⚠️  "),
+                        meta.name.as_deref().unwrap_or_default(),
+                        package.name
+                    );
+                }
+                if meta
+                    .version
+                    .as_deref()
+                    .is_some_and(|v| v != package.version)
+                {
+                    println!(
+                        "   {}oriki version {:?} differs from Iwe.toml version {:?}",
+                        icon("⚠️  "),
+                        meta.version.as_deref().unwrap_or_default(),
+                        package.version
+                    );
+                }
+            }
+
+            println!("   {}Transpiling to Rust...", icon("🔄 "));
+            let rust_code =
+                ifa_core::transpiler::transpile_to_rust_with_capabilities(&program, caps);
 
             // Create temp build dir
             let temp_dir = self.project_root.join("target/build_tmp");
@@ -573,7 +605,7 @@ opt-level = 3
             // Write the formatted TOML
             fs::write(temp_dir.join("Cargo.toml"), cargo_toml)?;
 
-            println!("   🛠️  Compiling with Cargo...");
+            println!("   {}Compiling with Cargo...", icon("🛠️  "));
             let status = Command::new("cargo")
                 .arg("build")
                 .arg("--release")
@@ -592,13 +624,13 @@ opt-level = 3
 
             if target_bin.exists() {
                 fs::copy(&target_bin, &output_bin)?;
-                println!("   ✨ Built: {}", output_bin.display());
+                println!("   {}Built: {}", icon("✨ "), output_bin.display());
             } else {
                 // Try non-exe
                 let target_bin = temp_dir.join("target/release").join(&package.name);
                 if target_bin.exists() {
                     fs::copy(&target_bin, &output_bin)?;
-                    println!("   ✨ Built: {}", output_bin.display());
+                    println!("   {}Built: {}", icon("✨ "), output_bin.display());
                 }
             }
         }
@@ -641,7 +673,7 @@ opt-level = 3
     /// 4. Generate / update `oja.lock`
     /// 5. AOT compile WASM artifacts
     pub fn fetch(&self) -> Result<()> {
-        println!("🛒  Fetching dependencies...");
+        println!("{}Fetching dependencies...", icon("🛒  "));
 
         let (lib_dir, cache_dir) = self.ensure_igbale()?;
         let manifest = self.load_manifest()?;
@@ -699,7 +731,8 @@ opt-level = 3
         };
         self.save_lockfile(&lockfile)?;
         println!(
-            "   🔒 Wrote oja.lock ({} packages)",
+            "   {}Wrote oja.lock ({} packages)",
+            icon("🔒 "),
             lockfile.packages.len()
         );
 
@@ -723,7 +756,8 @@ opt-level = 3
                     &format!("Compiled artifact for {} v{}", pkg.name, pkg.version),
                 )?;
                 println!(
-                    "     ✓ {} v{} compiled ({})",
+                    "     {}{} v{} compiled ({})",
+                    icon("✓ "),
                     pkg.name,
                     pkg.version,
                     &pkg.checksum[7..15]
@@ -731,7 +765,7 @@ opt-level = 3
             }
         }
 
-        println!("✨  Ready to run.");
+        println!("{}Ready to run.", icon("✨  "));
         Ok(())
     }
 
@@ -761,7 +795,12 @@ opt-level = 3
             }
             seen.insert(name.clone());
 
-            println!("   📦 Resolving: {} (required by {})", name, required_by);
+            println!(
+                "   {}Resolving: {} (required by {})",
+                icon("📦 "),
+                name,
+                required_by
+            );
 
             let (version, source, pkg_dir) = match &dep {
                 Dependency::Version(ver) => {
@@ -997,7 +1036,7 @@ opt-level = 3
         let index_path = self.get_index_path(name);
         let url = format!("{}/index/{}", OJA_REGISTRY_URL, index_path);
 
-        println!("     🔍 Searching registry: {}", url);
+        println!("     {}Searching registry: {}", icon("🔍 "), url);
 
         // 2. Fetch Metadata (blocking)
         let client = Client::new();
@@ -1006,7 +1045,7 @@ opt-level = 3
                 if resp.status().is_success() {
                     // Parse registry Entry
                     if let Ok(entry) = resp.json::<RegistryPackage>() {
-                        println!("     ✅ Found package: {}", entry.name);
+                        println!("     {}Found package: {}", icon("✅ "), entry.name);
 
                         // Find version
                         let target_ver = if version == "latest" || version == "*" {
@@ -1029,7 +1068,11 @@ opt-level = 3
 
                         if let Some(v) = target_ver {
                             if v.yanked {
-                                println!("     ⚠️  Warning: Version {} is yanked!", v.version);
+                                println!(
+                                    "     {}Warning: Version {} is yanked!",
+                                    icon("⚠️  "),
+                                    v.version
+                                );
                             }
                             // Construct GitHub Archive URL
                             // Format: https://github.com/user/repo/archive/refs/tags/v1.0.0.tar.gz
@@ -1039,7 +1082,8 @@ opt-level = 3
                             );
                         } else {
                             println!(
-                                "     ❌ Version {} not found in registry. Falling back to simple resolution.",
+                                "     {}Version {} not found in registry. Falling back to simple resolution.",
+                                icon("❌ "),
                                 version
                             );
                         }
@@ -1070,7 +1114,7 @@ opt-level = 3
 
     /// Download and Extract Tarball
     fn download_package(&self, url: &str, dest: &Path) -> Result<()> {
-        println!("     ⬇ Downloading: {}", url);
+        println!("     {}Downloading: {}", icon("⬇ "), url);
         let client = Client::new();
         let response = client.get(url).send().wrap_err("Failed to send request")?;
 
@@ -1097,7 +1141,7 @@ opt-level = 3
     /// the install is aborted with `OjaIntegrityError`.
     /// If `None`, this is a first install — the hash is computed and returned.
     fn verify_integrity(&self, path: &Path, expected_checksum: Option<&str>) -> Result<String> {
-        println!("     🔒 Verifying integrity...");
+        println!("     {}Verifying integrity...", icon("🔒 "));
         let computed = self.sha256_dir(path)?;
 
         if let Some(expected) = expected_checksum {
@@ -1113,9 +1157,17 @@ opt-level = 3
                     computed
                 ));
             }
-            println!("       ✓ Checksum verified: {}", &computed[7..15]);
+            println!(
+                "       {}Checksum verified: {}",
+                icon("✓ "),
+                &computed[7..15]
+            );
         } else {
-            println!("       ✓ Computed checksum: {}", &computed[7..15]);
+            println!(
+                "       {}Computed checksum: {}",
+                icon("✓ "),
+                &computed[7..15]
+            );
         }
 
         Ok(computed)
@@ -1123,7 +1175,7 @@ opt-level = 3
 
     /// Publish to Registry (Git Tagging Strategy)
     pub fn publish(&self) -> Result<()> {
-        println!("📦  Publishing package...");
+        println!("{}Publishing package...", icon("📦  "));
 
         // 1. Load Manifest
         let manifest = self.load_manifest()?;
@@ -1140,7 +1192,7 @@ opt-level = 3
         println!("   Version: {}", version);
 
         // 2. Check Git Status
-        println!("   🔍 Checking git status...");
+        println!("   {}Checking git status...", icon("🔍 "));
         let status = Command::new("git")
             .arg("status")
             .arg("--porcelain")
@@ -1155,7 +1207,7 @@ opt-level = 3
         }
 
         // 3. Tag
-        println!("   🏷️  Creating tag: {}", tag);
+        println!("   {}Creating tag: {}", icon("🏷️  "), tag);
         let tag_cmd = Command::new("git")
             .arg("tag")
             .arg("-a")
@@ -1174,7 +1226,7 @@ opt-level = 3
         }
 
         // 4. Push
-        println!("   🚀 Pushing to remote...");
+        println!("   {}Pushing to remote...", icon("🚀 "));
         let push_cmd = Command::new("git")
             .arg("push")
             .arg("origin")
@@ -1190,7 +1242,7 @@ opt-level = 3
             ));
         }
 
-        println!("✨  Published successfully to Registry (Git)!");
+        println!("{}Published successfully to Registry (Git)!", icon("✨  "));
         Ok(())
     }
 
@@ -1249,7 +1301,7 @@ opt-level = 3
         };
 
         if bin_path.exists() {
-            println!("🚀 Running {}...", name);
+            println!("{}Running {}...", icon("🚀 "), name);
             let status = Command::new(&bin_path)
                 .args(args)
                 .status()
@@ -1262,7 +1314,7 @@ opt-level = 3
             // Fallback to interpreted run if no binary
             let src_file = self.project_root.join("src/main.ifa");
             if src_file.exists() {
-                println!("⚡ Running interpreted: src/main.ifa");
+                println!("{}Running interpreted: src/main.ifa", icon("⚡ "));
                 let source = fs::read_to_string(&src_file)?;
                 let program = ifa_core::parse(&source).map_err(|e| eyre!("Parse error: {}", e))?;
                 let mut interp = ifa_core::Interpreter::with_file(&src_file);
@@ -1360,7 +1412,7 @@ opt-level = 3
 
     /// Update dependencies: re-resolve and regenerate `oja.lock`.
     pub fn update(&self) -> Result<()> {
-        println!("🔄  Updating dependencies...");
+        println!("{}Updating dependencies...", icon("🔄  "));
         // Delete the existing lockfile so fetch() does a full re-resolve
         let lock_path = self.project_root.join("oja.lock");
         if lock_path.exists() {
@@ -1419,7 +1471,7 @@ opt-level = 3
 
     /// Search the registry for packages matching a query.
     pub fn search(&self, query: &str) -> Result<()> {
-        println!("🔍  Searching registry for '{}'...", query);
+        println!("{}Searching registry for '{}'...", icon("🔍  "), query);
         let client = Client::new();
         let url = format!("{}/search/{}", OJA_REGISTRY_URL, query);
 
@@ -1440,7 +1492,7 @@ opt-level = 3
 
     /// Audit installed dependencies for known vulnerabilities.
     pub fn audit(&self) -> Result<()> {
-        println!("🔍  Auditing dependencies...");
+        println!("{}Auditing dependencies...", icon("🔍  "));
         let lockfile = self
             .load_lockfile()
             .ok_or_else(|| eyre!("No oja.lock found. Run `ifa oja install` first."))?;
@@ -1450,23 +1502,38 @@ opt-level = 3
             // In production this would query oja.ifá.dev/advisories
             // For now, verify that checksums are present and well-formed
             if !pkg.checksum.starts_with("sha256:") || pkg.checksum.len() < 71 {
-                println!("   ⚠ {}: missing or malformed checksum", pkg.name);
+                println!(
+                    "   {}{}: missing or malformed checksum",
+                    icon("⚠ "),
+                    pkg.name
+                );
                 issues += 1;
             }
         }
 
         if issues == 0 {
             println!(
-                "   ✅ No known issues found ({} packages audited).",
+                "   {}No known issues found ({} packages audited).",
+                icon("✅ "),
                 lockfile.packages.len()
             );
         } else {
-            println!("   ⚠ {} issue(s) found.", issues);
+            println!("   {}{} issue(s) found.", icon("⚠ "), issues);
         }
         Ok(())
     }
 }
 
+/// Root of the project containing `file`: the nearest ancestor directory
+/// with an `ifa.toml` (or legacy `Iwe.toml`) manifest.
+pub fn find_project_root(file: &Path) -> Option<PathBuf> {
+    let file = file.canonicalize().ok()?;
+    file.ancestors()
+        .skip(1)
+        .find(|dir| dir.join("ifa.toml").is_file() || dir.join("Iwe.toml").is_file())
+        .map(Path::to_path_buf)
+}
+
 /// Update the Ifá CLI in-place.
 ///
 /// Not yet implemented. Self-update requires a signed binary distribution
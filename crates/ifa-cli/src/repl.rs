@@ -0,0 +1,145 @@
+//! # REPL Line Input
+//!
+//! Line editing for `ifa repl`: arrow-key history, Emacs keybindings and a
+//! persistent `~/.ifa_history`. Falls back to plain stdin reads when input
+//! is not a terminal (pipes, tests).
+
+use rustyline::DefaultEditor;
+use rustyline::config::{Config, EditMode};
+use rustyline::error::ReadlineError;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::PathBuf;
+
+/// One read from the prompt
+pub enum Input {
+    Line(String),
+    /// Ctrl-C: drop the entry being typed
+    Interrupted,
+    /// Ctrl-D or end of input
+    Eof,
+}
+
+pub enum LineReader {
+    Editor {
+        editor: Box<DefaultEditor>,
+        history: Option<PathBuf>,
+    },
+    Plain(io::Stdin),
+}
+
+impl LineReader {
+    /// A line editor on a terminal, plain stdin otherwise
+    pub fn new(history: Option<PathBuf>) -> Self {
+        if io::stdin().is_terminal() {
+            match Self::with_editor(history) {
+                Ok(reader) => return reader,
+                Err(e) => eprintln!("Warning: line editing unavailable: {}", e),
+            }
+        }
+        LineReader::Plain(io::stdin())
+    }
+
+    /// A line editor whose history is loaded from and appended to `history`
+    pub fn with_editor(history: Option<PathBuf>) -> rustyline::Result<Self> {
+        let config = Config::builder()
+            .edit_mode(EditMode::Emacs)
+            .auto_add_history(false)
+            .build();
+        let mut editor = DefaultEditor::with_config(config)?;
+        if let Some(path) = &history {
+            // A missing file just means a first session
+            let _ = editor.load_history(path);
+        }
+        Ok(LineReader::Editor {
+            editor: Box::new(editor),
+            history,
+        })
+    }
+
+    pub fn read_line(&mut self, prompt: &str) -> Input {
+        match self {
+            LineReader::Editor { editor, .. } => match editor.readline(prompt) {
+                Ok(line) => Input::Line(line),
+                Err(ReadlineError::Interrupted) => Input::Interrupted,
+                Err(ReadlineError::Eof) => Input::Eof,
+                Err(e) => {
+                    eprintln!("Input error: {}", e);
+                    Input::Eof
+                }
+            },
+            LineReader::Plain(stdin) => {
+                print!("{}", prompt);
+                io::stdout().flush().ok();
+                let mut line = String::new();
+                match stdin.lock().read_line(&mut line) {
+                    Ok(0) | Err(_) => Input::Eof,
+                    Ok(_) => Input::Line(line),
+                }
+            }
+        }
+    }
+
+    /// Remember `entry` for arrow-key recall and append it to the history file
+    pub fn record(&mut self, entry: &str) {
+        let LineReader::Editor { editor, history } = self else {
+            return;
+        };
+        if !matches!(editor.add_history_entry(entry), Ok(true)) {
+            return;
+        }
+        if let Some(path) = history
+            && let Err(e) = editor.append_history(path)
+        {
+            eprintln!(
+                "Warning: could not save history to {}: {}",
+                path.display(),
+                e
+            );
+        }
+    }
+}
+
+/// `~/.ifa_history`, if a home directory is known
+pub fn default_history_path() -> Option<PathBuf> {
+    std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .map(|home| PathBuf::from(home).join(".ifa_history"))
+}
+
+/// Whether `buffer` has unclosed braces and the entry continues on the next line
+pub fn needs_more_input(buffer: &str) -> bool {
+    buffer.matches('{').count() > buffer.matches('}').count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_needs_more_input_tracks_braces() {
+        assert!(!needs_more_input("ayanmo x = 1;\n"));
+        assert!(needs_more_input("ese f() {\n"));
+        assert!(needs_more_input("ese f() {\nti (x) {\n}\n"));
+        assert!(!needs_more_input("ese f() {\npada 1;\n}\n"));
+    }
+
+    #[test]
+    fn test_record_appends_to_history_file() {
+        let path = std::env::temp_dir().join(format!("ifa_history_{}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let mut reader = LineReader::with_editor(Some(path.clone())).unwrap();
+        reader.record("ayanmo x = 1;");
+        reader.record("Irosu.fo(x);");
+        drop(reader);
+
+        // A new session sees the old entries and keeps appending
+        let mut reader = LineReader::with_editor(Some(path.clone())).unwrap();
+        reader.record(".vars");
+        let saved = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        let entries: Vec<&str> = saved.lines().filter(|l| !l.starts_with('#')).collect();
+        assert_eq!(entries, ["ayanmo x = 1;", "Irosu.fo(x);", ".vars"]);
+    }
+}
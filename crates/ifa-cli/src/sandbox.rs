@@ -123,12 +123,12 @@ impl SandboxConfig {
 
         // File read permissions
         for path in &self.allowed_read_paths {
-            caps.grant(Ofun::ReadFiles { root: path.clone() });
+            caps.grant(Ofun::read_files(path));
         }
 
         // File write permissions
         for path in &self.allowed_write_paths {
-            caps.grant(Ofun::WriteFiles { root: path.clone() });
+            caps.grant(Ofun::write_files(path));
         }
 
         // Network permissions
@@ -201,6 +201,7 @@ impl Igbale {
     /// Run code in sandbox
     #[cfg(target_os = "linux")]
     pub fn run(&self, code_path: &Path) -> Result<SandboxResult> {
+        use eyre::WrapErr;
         use std::process::{Command, Stdio};
         use std::time::Instant;
 
@@ -311,6 +312,7 @@ impl Igbale {
     /// Run code in sandbox (macOS)
     #[cfg(target_os = "macos")]
     pub fn run(&self, code_path: &Path) -> Result<SandboxResult> {
+        use eyre::WrapErr;
         use std::process::{Command, Stdio};
         use std::time::Instant;
 
@@ -0,0 +1,31 @@
+mod common;
+
+use common::{ifa, temp_dir, write_file};
+
+#[test]
+fn bench_reports_max_recursion_depth() {
+    let dir = temp_dir();
+    let file = write_file(
+        dir.path(),
+        "bench.ifa",
+        "ese jinle(n) {\n    ti n == 0 {\n        pada 0;\n    }\n    pada jinle(n - 1) + 1;\n}\n\
+         ayanmo d = jinle(9);\nayanmo l = [d, d];\n",
+    );
+
+    let output = ifa()
+        .args(["bench", "-n", "3", "--format", "json"])
+        .arg(&file)
+        .output()
+        .expect("failed to run ifa bench");
+
+    assert!(output.status.success(), "ifa bench failed: {:?}", output);
+    let report: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("stdout should be a JSON report");
+    assert_eq!(report["iterations"], 3);
+    // jinle(9) .. jinle(0) are all on the stack at once
+    assert_eq!(report["max_call_depth"], 10);
+    assert_eq!(report["collections_created"], 1);
+    assert_eq!(report["peak_collections"], 1);
+    assert!(report["statements"].as_u64().unwrap() > 10);
+    assert!(report["mean_ms"].as_f64().is_some());
+}
@@ -0,0 +1,25 @@
+mod common;
+
+use common::{ifa, temp_dir, write_file};
+
+#[test]
+fn build_emit_rust_keeps_the_generated_crate() {
+    let dir = temp_dir();
+    let file = write_file(dir.path(), "main.ifa", "ayanmo x = 1;\nIrosu.fo(x);\n");
+
+    // The build itself runs too; whether cargo succeeds from this directory
+    // does not matter, the crate must be left in place either way
+    let output = ifa()
+        .args(["build", "--emit-rust", "gen"])
+        .arg(&file)
+        .current_dir(dir.path())
+        .output()
+        .expect("failed to run ifa build --emit-rust");
+    let rust = std::fs::read_to_string(dir.path().join("gen/src/main.rs")).unwrap_or_default();
+    let manifest = std::fs::read_to_string(dir.path().join("gen/Cargo.toml")).unwrap_or_default();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Rust crate: gen"), "stdout: {}", stdout);
+    assert!(rust.contains("fn main"), "got: {}", rust);
+    assert!(manifest.contains("[package]"), "got: {}", manifest);
+}
@@ -0,0 +1,41 @@
+mod common;
+
+use std::path::Path;
+
+use common::{ifa, temp_dir, write_file};
+
+/// Hash reported by `ifa build --print-hash` (the last line of stdout).
+fn build_hash(file: &Path, extra: &[&str]) -> String {
+    let output = ifa()
+        .args(["build", "--print-hash"])
+        .args(extra)
+        .arg(file)
+        .output()
+        .expect("failed to run ifa build --print-hash");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let hash = stdout.lines().last().unwrap_or_default().trim().to_string();
+    assert_eq!(hash.len(), 64, "got: {}", stdout);
+    assert!(hash.chars().all(|c| c.is_ascii_hexdigit()), "got: {}", hash);
+    hash
+}
+
+#[test]
+fn build_hash_is_reproducible_and_tracks_source() {
+    let dir = temp_dir();
+    let file = write_file(dir.path(), "main.ifa", "ayanmo x = 1;\nIrosu.fo(x);\n");
+    let first = build_hash(&file, &[]);
+    let second = build_hash(&file, &[]);
+    let with_features = build_hash(&file, &["--crypto"]);
+
+    std::fs::write(&file, "ayanmo x = 2;\nIrosu.fo(x);\n").unwrap();
+    let changed = build_hash(&file, &[]);
+
+    assert_eq!(first, second);
+    assert_ne!(first, with_features);
+    assert_ne!(first, changed);
+}
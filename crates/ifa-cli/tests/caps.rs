@@ -0,0 +1,58 @@
+mod common;
+
+use common::{ifa, temp_dir, write_file};
+
+#[test]
+fn caps_lists_read_and_imported_network_domain() {
+    let dir = temp_dir();
+    let main = write_file(
+        dir.path(),
+        "main.ifa",
+        "iba fetcher;\nayanmo data = Odi.read(\"data/in.txt\");\n",
+    );
+    write_file(
+        dir.path(),
+        "fetcher.ifa",
+        "ayanmo body = Otura.get(\"https://api.example.com/v1\");\n",
+    );
+
+    let text = ifa()
+        .arg("caps")
+        .arg(&main)
+        .output()
+        .expect("failed to run ifa caps");
+    let json = ifa()
+        .args(["caps", "--format", "json"])
+        .arg(&main)
+        .output()
+        .expect("failed to run ifa caps --format json");
+
+    assert!(
+        text.status.success(),
+        "{}",
+        String::from_utf8_lossy(&text.stderr)
+    );
+    let text = String::from_utf8_lossy(&text.stdout);
+    assert!(text.contains("ReadFiles"), "got: {}", text);
+    assert!(text.contains("api.example.com"), "got: {}", text);
+    assert!(text.contains("--allow-read data/in.txt"), "got: {}", text);
+    assert!(
+        text.contains("--allow-net api.example.com"),
+        "got: {}",
+        text
+    );
+
+    assert!(json.status.success());
+    let summary: serde_json::Value = serde_json::from_slice(&json.stdout).unwrap();
+    let caps = summary["capabilities"].as_array().unwrap();
+    assert!(
+        caps.iter()
+            .any(|c| c.as_str().unwrap().contains("ReadFiles"))
+    );
+    assert!(
+        caps.iter()
+            .any(|c| c.as_str().unwrap().contains("api.example.com"))
+    );
+    let flags = summary["flags"].as_array().unwrap();
+    assert!(flags.iter().any(|f| f == "--allow-net api.example.com"));
+}
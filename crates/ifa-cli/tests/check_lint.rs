@@ -0,0 +1,24 @@
+mod common;
+
+use common::{ifa, temp_dir, write_file};
+
+#[test]
+fn check_lint_strict_fails_on_lint_only_warning() {
+    let dir = temp_dir();
+    let file = write_file(dir.path(), "lint.ifa", "ayanmo unused_value = 1;\n");
+
+    let plain = ifa()
+        .arg("check")
+        .arg(&file)
+        .output()
+        .expect("failed to run ifa check");
+    let strict = ifa()
+        .args(["check", "--lint", "--strict"])
+        .arg(&file)
+        .output()
+        .expect("failed to run ifa check --lint --strict");
+
+    assert!(plain.status.success(), "plain check should pass");
+    assert!(!strict.status.success(), "strict lint should fail");
+    assert!(String::from_utf8_lossy(&strict.stderr).contains("never used"));
+}
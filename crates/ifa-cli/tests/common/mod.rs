@@ -0,0 +1,30 @@
+//! Helpers shared by the `ifa` CLI integration tests
+
+// Each test binary uses only some of these
+#![allow(dead_code)]
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use tempfile::TempDir;
+
+/// A command for the `ifa` binary cargo built for this test run
+pub fn ifa() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_ifa"))
+}
+
+/// A fresh directory, removed when the returned guard is dropped
+pub fn temp_dir() -> TempDir {
+    tempfile::tempdir().expect("failed to create a temp dir")
+}
+
+/// Write `contents` to `name` under `dir`, creating parent directories,
+/// and return the file's path.
+pub fn write_file(dir: &Path, name: &str, contents: &str) -> PathBuf {
+    let path = dir.join(name);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).unwrap();
+    }
+    std::fs::write(&path, contents).unwrap();
+    path
+}
@@ -0,0 +1,36 @@
+mod common;
+
+use common::{ifa, temp_dir, write_file};
+
+#[test]
+fn doc_extracts_doc_comments_from_source() {
+    let dir = temp_dir();
+    let src = dir.path().join("src");
+    let out = dir.path().join("docs");
+    write_file(
+        dir.path(),
+        "src/math.ifa",
+        "/// Adds two numbers & returns the sum.\n\
+         ese fikun(a, b) {\n    pada a + b;\n}\n\n\
+         /// Geometry helpers\n\
+         odu Apeere {\n    /// Area of a square with side <s>\n    ese agbegbe(s) {\n        pada s * s;\n    }\n}\n",
+    );
+
+    let output = ifa()
+        .arg("doc")
+        .arg(&src)
+        .arg("--output")
+        .arg(&out)
+        .output()
+        .expect("failed to run ifa doc");
+    let index = std::fs::read_to_string(out.join("index.html")).unwrap_or_default();
+    let page = std::fs::read_to_string(out.join("user_apeere.html")).unwrap_or_default();
+
+    assert!(output.status.success(), "ifa doc failed: {:?}", output);
+    assert!(index.contains("fikun"));
+    assert!(index.contains("ese fikun(a, b)"));
+    assert!(index.contains("Adds two numbers &amp; returns the sum."));
+    assert!(index.contains("Geometry helpers"));
+    assert!(page.contains("agbegbe"));
+    assert!(page.contains("Area of a square with side &lt;s&gt;"));
+}
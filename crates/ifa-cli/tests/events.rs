@@ -0,0 +1,33 @@
+mod common;
+
+use common::{ifa, temp_dir, write_file};
+
+#[test]
+fn events_flag_writes_capability_checks_as_ndjson() {
+    let dir = temp_dir();
+    let script = write_file(
+        dir.path(),
+        "main.ifa",
+        "Otura.get(\"https://example.com/\");\n",
+    );
+    let log = dir.path().join("events.ndjson");
+
+    ifa()
+        .args(["run", "--events"])
+        .arg(&log)
+        .arg(&script)
+        .output()
+        .expect("failed to run ifa run --events");
+    let events = std::fs::read_to_string(&log).unwrap_or_default();
+
+    let lines: Vec<&str> = events.lines().collect();
+    assert_eq!(lines.len(), 1, "{}", events);
+    assert!(lines[0].starts_with('{'), "{}", lines[0]);
+    assert!(lines[0].contains(r#""event":"capability""#), "{}", lines[0]);
+    assert!(lines[0].contains(r#""granted":false"#), "{}", lines[0]);
+    assert!(
+        lines[0].contains(r#""call_site":"Otura.get""#),
+        "{}",
+        lines[0]
+    );
+}
@@ -0,0 +1,27 @@
+mod common;
+
+use common::{ifa, temp_dir, write_file};
+
+#[test]
+fn flash_rejects_byte_string_literals() {
+    let dir = temp_dir();
+    let file = write_file(
+        dir.path(),
+        "flash.ifa",
+        "ayanmo a = 1;\nayanmo payload = b\"\\x01\\xFF\";\n",
+    );
+
+    let output = ifa()
+        .args(["flash", "--target", "esp32"])
+        .arg(&file)
+        .output()
+        .expect("failed to run ifa flash");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains(":2:18: byte string literals are not supported by `ifa flash`"),
+        "stderr: {}",
+        stderr
+    );
+}
@@ -0,0 +1,49 @@
+mod common;
+
+use std::io::Write;
+use std::process::{Output, Stdio};
+
+use common::ifa;
+
+fn fmt_stdin(args: &[&str], source: &str) -> Output {
+    let mut child = ifa()
+        .arg("fmt")
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("failed to run ifa fmt");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(source.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn fmt_stdin_writes_formatted_source_to_stdout() {
+    let messy = "ayanmo x=1;\nti x>0{\nIrosu.fo(x);\n}bibẹkọ{Irosu.fo(0);}\n";
+    let expected = "ayanmo x = 1;\nti x > 0 {\n    Irosu.fo(x);\n} bibẹkọ {\n    Irosu.fo(0);\n}\n";
+
+    for flag in ["-", "--stdin"] {
+        let output = fmt_stdin(&[flag], messy);
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        assert_eq!(String::from_utf8_lossy(&output.stdout), expected);
+    }
+}
+
+#[test]
+fn fmt_stdin_rejects_unparsable_source() {
+    let output = fmt_stdin(&["--stdin"], "ti x > 0 {\n");
+
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Parse error"));
+}
@@ -0,0 +1,106 @@
+mod common;
+
+use common::{ifa, temp_dir, write_file};
+
+#[test]
+fn no_color_env_strips_ansi_from_error_reports() {
+    let dir = temp_dir();
+    let missing = dir.path().join("missing.ifa");
+
+    let colored = ifa()
+        .arg("run")
+        .arg(&missing)
+        .env_remove("NO_COLOR")
+        .output()
+        .expect("failed to run ifa");
+    let plain = ifa()
+        .arg("run")
+        .arg(&missing)
+        .env("NO_COLOR", "1")
+        .output()
+        .expect("failed to run ifa with NO_COLOR");
+
+    assert!(String::from_utf8_lossy(&colored.stderr).contains("\x1b["));
+    let stderr = String::from_utf8_lossy(&plain.stderr);
+    assert!(stderr.contains("Failed to read file"), "{}", stderr);
+    assert!(!stderr.contains('\x1b'), "{}", stderr);
+    assert!(!String::from_utf8_lossy(&plain.stdout).contains('\x1b'));
+}
+
+#[test]
+fn no_color_flag_drops_emoji_from_check() {
+    let dir = temp_dir();
+    let file = write_file(dir.path(), "broken.ifa", "ayanmo x = ;\n");
+
+    let output = ifa()
+        .args(["check", "--no-color"])
+        .arg(&file)
+        .env_remove("NO_COLOR")
+        .output()
+        .expect("failed to run ifa check --no-color");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(stdout.starts_with("Checking syntax of"), "{}", stdout);
+    assert!(stderr.starts_with("Syntax Error:"), "{}", stderr);
+    assert!(!stderr.contains('\x1b'), "{}", stderr);
+}
+
+#[test]
+fn no_color_flag_before_a_bare_file_runs_it() {
+    let dir = temp_dir();
+    let file = write_file(dir.path(), "hello.ifa", "Irosu.fo(\"hello\");\n");
+
+    let output = ifa()
+        .arg("--no-color")
+        .arg(&file)
+        .env_remove("NO_COLOR")
+        .output()
+        .expect("failed to run ifa --no-color <file>");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{}", stderr);
+    assert!(stdout.contains("hello"), "{}", stdout);
+    assert!(!stdout.contains('\x1b'), "{}", stdout);
+}
+
+#[test]
+fn no_color_env_drops_emoji_from_caps_and_bytecode() {
+    let dir = temp_dir();
+    let file = write_file(dir.path(), "hello.ifa", "Irosu.fo(\"hello\");\n");
+
+    let caps = ifa()
+        .arg("caps")
+        .arg(&file)
+        .env("NO_COLOR", "1")
+        .output()
+        .expect("failed to run ifa caps");
+    let stdout = String::from_utf8_lossy(&caps.stdout);
+    assert!(
+        caps.status.success(),
+        "{}",
+        String::from_utf8_lossy(&caps.stderr)
+    );
+    assert!(
+        stdout.starts_with("Inferred Capabilities for"),
+        "{}",
+        stdout
+    );
+
+    let bytecode = ifa()
+        .arg("bytecode")
+        .arg(&file)
+        .env("NO_COLOR", "1")
+        .output()
+        .expect("failed to run ifa bytecode");
+    let stdout = String::from_utf8_lossy(&bytecode.stdout);
+    assert!(
+        bytecode.status.success(),
+        "{}",
+        String::from_utf8_lossy(&bytecode.stderr)
+    );
+    assert!(stdout.starts_with("Compiling to bytecode:"), "{}", stdout);
+    assert!(!stdout.contains('📦'), "{}", stdout);
+}
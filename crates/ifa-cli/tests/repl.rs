@@ -0,0 +1,35 @@
+mod common;
+
+use std::io::Write;
+use std::process::Stdio;
+
+use common::{ifa, temp_dir};
+
+#[test]
+fn repl_reads_piped_input_across_multiline_entries() {
+    let home = temp_dir();
+
+    let mut child = ifa()
+        .arg("repl")
+        .env("HOME", home.path())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("failed to run ifa repl");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(b"ese f() {\npada 42;\n}\nIrosu.fo(f());\n.quit\n")
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    let history_written = home.path().join(".ifa_history").exists();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "{}", stdout);
+    assert!(stdout.contains("... "), "{}", stdout);
+    assert!(stdout.contains("42"), "{}", stdout);
+    // Piped input is read plainly, without touching the history file
+    assert!(!history_written);
+}
@@ -0,0 +1,133 @@
+mod common;
+
+use std::path::PathBuf;
+
+use common::{ifa, temp_dir, write_file};
+
+#[test]
+fn run_exits_non_zero_on_runtime_error() {
+    let dir = temp_dir();
+    let file = write_file(
+        dir.path(),
+        "div_zero.ifa",
+        "ayanmo zero = 0;\nayanmo x = 10 / zero;\n",
+    );
+
+    let text = ifa()
+        .arg("run")
+        .arg(&file)
+        .output()
+        .expect("failed to run ifa run");
+    let json = ifa()
+        .args(["run", "--format", "json"])
+        .arg(&file)
+        .output()
+        .expect("failed to run ifa run --format json");
+
+    assert!(!text.status.success(), "runtime error should fail the run");
+    assert!(!json.status.success(), "runtime error should fail the run");
+
+    let summary: serde_json::Value =
+        serde_json::from_slice(&json.stdout).expect("stdout should be a JSON summary");
+    assert_eq!(summary["status"], "error");
+    assert_eq!(summary["statements"], 2);
+    assert!(summary["error"].as_str().unwrap().contains("zero"));
+}
+
+#[test]
+fn run_reports_runtime_error_location() {
+    let source = "ayanmo a = 1;\nayanmo b = 2;\nayanmo zero = a + b - 3;\n\n\n// Only fails when run\nayanmo d = a / zero;\n";
+    let dir = temp_dir();
+    let file = write_file(dir.path(), "location.ifa", source);
+
+    let text = ifa()
+        .arg("run")
+        .arg(&file)
+        .output()
+        .expect("failed to run ifa run");
+    let json = ifa()
+        .args(["run", "--format", "json"])
+        .arg(&file)
+        .output()
+        .expect("failed to run ifa run --format json");
+
+    let location = format!("{}:7:1", file.display());
+    let stdout = String::from_utf8_lossy(&text.stdout);
+    assert!(stdout.contains(&location), "stdout: {}", stdout);
+    assert!(
+        stdout.contains("| ayanmo d = a / zero;"),
+        "stdout: {}",
+        stdout
+    );
+
+    let summary: serde_json::Value =
+        serde_json::from_slice(&json.stdout).expect("stdout should be a JSON summary");
+    let error = summary["error"].as_str().unwrap();
+    assert!(error.starts_with(&location), "error: {}", error);
+    assert!(error.contains("zero"), "error: {}", error);
+}
+
+#[test]
+fn run_json_summary_reports_success() {
+    let dir = temp_dir();
+    let file = write_file(dir.path(), "ok.ifa", "Irosu.fo(\"hello\");\n");
+
+    let json = ifa()
+        .args(["run", "--format", "json"])
+        .arg(&file)
+        .output()
+        .expect("failed to run ifa run --format json");
+
+    assert!(json.status.success());
+    let summary: serde_json::Value =
+        serde_json::from_slice(&json.stdout).expect("stdout should be a JSON summary");
+    assert_eq!(summary["status"], "ok");
+    assert_eq!(summary["error"], serde_json::Value::Null);
+    assert_eq!(summary["output"][0], "hello");
+}
+
+#[test]
+fn run_max_output_truncates_printing() {
+    let dir = temp_dir();
+    let file = write_file(
+        dir.path(),
+        "max_output.ifa",
+        "fun i ninu Iwori.range(0, 10000) {\n    Irosu.fo(i);\n}\n",
+    );
+
+    let json = ifa()
+        .args(["run", "--format", "json", "--max-output", "100"])
+        .arg(&file)
+        .output()
+        .expect("failed to run ifa run --max-output");
+
+    assert!(json.status.success());
+    let summary: serde_json::Value =
+        serde_json::from_slice(&json.stdout).expect("stdout should be a JSON summary");
+    let output = summary["output"].as_array().unwrap();
+    assert_eq!(output.len(), 101);
+    assert_eq!(output[100], "[output truncated]");
+}
+
+#[test]
+fn run_temp_dir_needs_no_write_grant_and_is_removed() {
+    // The run fails afterwards, so cleanup must not depend on a clean exit
+    let dir = temp_dir();
+    let file = write_file(
+        dir.path(),
+        "temp_dir.ifa",
+        "ayanmo dir = Ogbe.temp_dir();\nayanmo path = Ogbe.path_join(dir, \"scratch.txt\");\nOdi.write(path, \"ase\");\nIrosu.fo(dir);\nIrosu.fo(Odi.read(path));\nayanmo zero = 0;\nayanmo x = 1 / zero;\n",
+    );
+
+    let json = ifa()
+        .args(["run", "--format", "json"])
+        .arg(&file)
+        .output()
+        .expect("failed to run ifa run --format json");
+
+    let summary: serde_json::Value =
+        serde_json::from_slice(&json.stdout).expect("stdout should be a JSON summary");
+    assert_eq!(summary["output"][1], "ase", "got: {}", summary);
+    let dir = PathBuf::from(summary["output"][0].as_str().unwrap());
+    assert!(!dir.exists(), "{} was left behind", dir.display());
+}
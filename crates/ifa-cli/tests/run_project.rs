@@ -0,0 +1,149 @@
+mod common;
+
+use common::{ifa, temp_dir, write_file};
+
+#[test]
+fn run_resolves_imports_from_project_src() {
+    let dir = temp_dir();
+    let src = dir.path().join("src");
+    write_file(
+        dir.path(),
+        "ifa.toml",
+        "[package]\nname = \"demo\"\nversion = \"0.1.0\"\n",
+    );
+    write_file(&src, "lib.ifa", "fi ese ilopo(x) {\n    pada x * 2;\n}\n");
+    write_file(&src, "main.ifa", "iba lib;\nIrosu.fo(lib.ilopo(21));\n");
+    // Nested scripts only find `lib` through the project's `src`
+    write_file(
+        &src,
+        "tools/tool.ifa",
+        "iba lib;\nIrosu.fo(lib.ilopo(50));\n",
+    );
+
+    let main = ifa()
+        .arg("run")
+        .arg(src.join("main.ifa"))
+        .output()
+        .expect("failed to run ifa run");
+    let tool = ifa()
+        .arg("run")
+        .arg(src.join("tools").join("tool.ifa"))
+        .output()
+        .expect("failed to run ifa run");
+
+    assert!(main.status.success(), "ifa run failed: {:?}", main);
+    assert!(String::from_utf8_lossy(&main.stdout).contains("42"));
+    assert!(tool.status.success(), "ifa run failed: {:?}", tool);
+    assert!(String::from_utf8_lossy(&tool.stdout).contains("100"));
+}
+
+#[test]
+fn project_src_is_only_readable_with_an_explicit_grant() {
+    let dir = temp_dir();
+    let src = dir.path().join("src");
+    write_file(
+        dir.path(),
+        "ifa.toml",
+        "[package]\nname = \"demo\"\nversion = \"0.1.0\"\n",
+    );
+    write_file(&src, "lib.ifa", "fi ese ilopo(x) {\n    pada x * 2;\n}\n");
+    let tool = write_file(
+        &src,
+        "tools/tool.ifa",
+        &format!(
+            "iba lib;\nIrosu.fo(lib.ilopo(50));\nIrosu.fo(Odi.wa({:?}));\n",
+            src.join("lib.ifa").display().to_string()
+        ),
+    );
+
+    let denied = ifa()
+        .arg("run")
+        .arg(&tool)
+        .output()
+        .expect("failed to run ifa run");
+    let granted = ifa()
+        .args(["run", "--allow-read"])
+        .arg(&src)
+        .arg(&tool)
+        .output()
+        .expect("failed to run ifa run --allow-read");
+    // `ifa bench` starts from the same grants, and the import still resolves
+    let bench = ifa()
+        .args(["bench", "-n", "1"])
+        .arg(&tool)
+        .output()
+        .expect("failed to run ifa bench");
+
+    let stdout = String::from_utf8_lossy(&denied.stdout);
+    assert!(!denied.status.success(), "{}", stdout);
+    assert!(stdout.contains("100"), "{}", stdout);
+    assert!(
+        stdout.contains("Capability denied: ReadFiles"),
+        "{}",
+        stdout
+    );
+    assert!(granted.status.success(), "ifa run failed: {:?}", granted);
+    assert!(String::from_utf8_lossy(&granted.stdout).contains("true"));
+    assert!(!bench.status.success(), "ifa bench should be denied too");
+    assert!(
+        String::from_utf8_lossy(&bench.stderr).contains("Capability denied: ReadFiles"),
+        "{:?}",
+        bench
+    );
+}
+
+#[test]
+fn runb_caches_compiled_imports_in_project_target() {
+    let dir = temp_dir();
+    let src = dir.path().join("src");
+    write_file(
+        dir.path(),
+        "ifa.toml",
+        "[package]\nname = \"demo\"\nversion = \"0.1.0\"\n",
+    );
+    write_file(&src, "lib.ifa", "fi ese ilopo(x) {\n    pada x * 2;\n}\n");
+    write_file(&src, "main.ifa", "iba lib;\nIrosu.fo(lib.ilopo(21));\n");
+
+    let compile = ifa()
+        .arg("bytecode")
+        .arg(src.join("main.ifa"))
+        .output()
+        .expect("failed to run ifa bytecode");
+    let runb = |extra: &[&str]| {
+        ifa()
+            .arg("runb")
+            .args(extra)
+            .arg(src.join("main.ifab"))
+            .current_dir(&src)
+            .output()
+            .expect("failed to run ifa runb")
+    };
+    let cache_entries = || {
+        std::fs::read_dir(dir.path().join("target").join("ifa-cache"))
+            .map(|entries| {
+                entries
+                    .filter_map(|e| e.ok())
+                    .filter(|e| e.path().extension().is_some_and(|ext| ext == "ifab"))
+                    .count()
+            })
+            .unwrap_or(0)
+    };
+    let uncached = runb(&["--no-cache"]);
+    let entries_before = cache_entries();
+    let first = runb(&[]);
+    let second = runb(&[]);
+    let entries_after = cache_entries();
+
+    assert!(
+        compile.status.success(),
+        "ifa bytecode failed: {:?}",
+        compile
+    );
+    for run in [&uncached, &first, &second] {
+        assert!(run.status.success(), "ifa runb failed: {:?}", run);
+        assert!(String::from_utf8_lossy(&run.stdout).contains("42"));
+    }
+    assert_eq!(entries_before, 0);
+    // `lib` was compiled into the project's module cache as bytecode
+    assert_eq!(entries_after, 1);
+}
@@ -0,0 +1,27 @@
+mod common;
+
+use common::{ifa, temp_dir, write_file};
+
+#[test]
+fn run_time_reports_on_stderr_only() {
+    let dir = temp_dir();
+    let file = write_file(dir.path(), "time.ifa", "ayanmo x = 41 + 1;\nIrosu.fo(x);\n");
+
+    let output = ifa()
+        .args(["run", "--format", "json"])
+        .arg(&file)
+        .arg("--time")
+        .output()
+        .expect("failed to run ifa run --time");
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{}", stderr);
+    assert!(stderr.contains("execution:"), "got: {}", stderr);
+    assert!(stderr.contains("statements:  2"), "got: {}", stderr);
+
+    // stdout is still just the JSON summary, with the program's own output
+    let summary: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("stdout should be a JSON summary");
+    assert_eq!(summary["status"], "ok");
+    assert_eq!(summary["output"], serde_json::json!(["42"]));
+}
@@ -0,0 +1,36 @@
+mod common;
+
+use common::{ifa, temp_dir, write_file};
+
+#[test]
+fn strict_capabilities_suggests_the_missing_allow_read() {
+    let dir = temp_dir();
+    // Outside the script's directory, so `ifa run` does not grant it
+    let secret = "/ifa-strict-caps-test/secret.txt";
+    let script = write_file(
+        dir.path(),
+        "main.ifa",
+        &format!("ayanmo s = Odi.read(\"{}\");\n", secret),
+    );
+
+    let strict = ifa()
+        .args(["run", "--strict-capabilities"])
+        .arg(&script)
+        .output()
+        .expect("failed to run ifa run --strict-capabilities");
+    let plain = ifa()
+        .arg("run")
+        .arg(&script)
+        .output()
+        .expect("failed to run ifa run");
+
+    let stderr = String::from_utf8_lossy(&strict.stderr);
+    assert!(!strict.status.success());
+    assert!(stderr.contains("at Odi.read (line 1)"), "{}", stderr);
+    assert!(
+        stderr.contains(&format!("--allow-read {}", secret)),
+        "{}",
+        stderr
+    );
+    assert!(!String::from_utf8_lossy(&plain.stderr).contains("--allow-read"));
+}
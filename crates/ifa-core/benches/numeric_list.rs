@@ -0,0 +1,31 @@
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use ifa_core::IfaValue;
+use ifa_types::NumericList;
+
+/// Sum over a large integer list: boxed `IfaValue`s vs the packed form
+fn bench_sum(c: &mut Criterion) {
+    let mut group = c.benchmark_group("numeric_list");
+
+    let values: Vec<IfaValue> = (0..100_000).map(IfaValue::Int).collect();
+    let packed = NumericList::from_values(&values).unwrap();
+
+    group.bench_function("sum_boxed_100k", |b| {
+        b.iter(|| {
+            let sum: i64 = black_box(&values)
+                .iter()
+                .map(|v| match v {
+                    IfaValue::Int(n) => *n,
+                    _ => 0,
+                })
+                .sum();
+            sum
+        })
+    });
+
+    group.bench_function("sum_packed_100k", |b| b.iter(|| black_box(&packed).sum()));
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_sum);
+criterion_main!(benches);
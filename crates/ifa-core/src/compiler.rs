@@ -3,8 +3,8 @@
 //! Compiles AST to bytecode for the Ifá-Lang VM.
 //!
 //! ### 🚀 ARCHITECTURAL STATUS (String Interpolation)
-//! Interpolated strings now compile to dedicated `OpCode::ToString` + `OpCode::Concat`
-//! sequences instead of overloading the arithmetic hot path through `OpCode::Add`.
+//! Interpolated strings now compile to a single `OpCode::StrBuilder` over their parts
+//! instead of overloading the arithmetic hot path through `OpCode::Add`.
 //!
 //! General `+` expressions remain source-compatible; this hardening pass isolates
 //! interpolation without forcing a language-wide string-operator redesign.
@@ -12,6 +12,7 @@
 use crate::ast::*;
 use crate::bytecode::{Bytecode, OpCode};
 use crate::error::{IfaError, IfaResult};
+use crate::interpreter::handlers::bind_named_args;
 use crate::lexer::OduDomain;
 use std::collections::{HashMap, HashSet};
 
@@ -39,6 +40,23 @@ struct FunctionContext {
     const_locals: Vec<HashSet<String>>,
     scope_depth: usize,
     upvalues: Vec<Upvalue>,
+    /// Loops enclosing the statement being compiled, innermost last
+    loops: Vec<LoopContext>,
+    /// Try blocks enclosing the statement being compiled
+    try_depth: usize,
+}
+
+/// A loop that `duro`/`tesiwaju` can jump out of
+#[derive(Debug)]
+struct LoopContext {
+    label: Option<String>,
+    /// Locals live when the body starts; a jump out pops the rest
+    locals: usize,
+    try_depth: usize,
+    /// Where `tesiwaju` jumps, when already known (while loops)
+    continue_target: Option<usize>,
+    continue_jumps: Vec<usize>,
+    break_jumps: Vec<usize>,
 }
 
 impl FunctionContext {
@@ -48,6 +66,8 @@ impl FunctionContext {
             const_locals: vec![HashSet::new()],
             scope_depth: 0,
             upvalues: Vec::new(),
+            loops: Vec::new(),
+            try_depth: 0,
         }
     }
 
@@ -142,6 +162,110 @@ impl Compiler {
         self.bytecode.code[offset..offset + 4].copy_from_slice(&bytes);
     }
 
+    /// Compile a match arm's guard, returning the jump to patch past the arm
+    fn compile_match_guard(&mut self, guard: Option<&Expression>) -> IfaResult<Option<usize>> {
+        let Some(guard) = guard else {
+            return Ok(None);
+        };
+        self.compile_expression(guard)?;
+        Ok(Some(self.emit_jump(OpCode::JumpIfFalse)))
+    }
+
+    /// Push the element of the match condition at `path` (list indices).
+    fn emit_pattern_value(&mut self, cond_slot: usize, path: &[usize]) {
+        self.emit(OpCode::LoadLocal);
+        let s = cond_slot as u16;
+        self.emit_byte((s & 0xff) as u8);
+        self.emit_byte((s >> 8) as u8);
+        for &index in path {
+            self.emit(OpCode::PushInt);
+            self.emit_i64(index as i64);
+            self.emit(OpCode::GetIndex);
+        }
+    }
+
+    /// Compile the tests of a match pattern, collecting the jumps taken when
+    /// the value at `path` does not match. Leaves the stack unchanged.
+    fn compile_pattern_test(
+        &mut self,
+        pattern: &MatchPattern,
+        cond_slot: usize,
+        path: &mut Vec<usize>,
+        fail_jumps: &mut Vec<usize>,
+    ) -> IfaResult<()> {
+        match pattern {
+            MatchPattern::Literal(expr) => {
+                self.emit_pattern_value(cond_slot, path);
+                self.compile_expression(expr)?;
+                self.emit(OpCode::Eq);
+                fail_jumps.push(self.emit_jump(OpCode::JumpIfFalse));
+            }
+            MatchPattern::Range { start, end } => {
+                self.emit_pattern_value(cond_slot, path);
+                self.compile_expression(start)?;
+                self.emit(OpCode::Ge);
+                fail_jumps.push(self.emit_jump(OpCode::JumpIfFalse));
+                self.emit_pattern_value(cond_slot, path);
+                self.compile_expression(end)?;
+                self.emit(OpCode::Le);
+                fail_jumps.push(self.emit_jump(OpCode::JumpIfFalse));
+            }
+            MatchPattern::Wildcard | MatchPattern::Binding(_) => {}
+            MatchPattern::List { elements, rest } => {
+                let count = u16::try_from(elements.len())
+                    .map_err(|_| IfaError::Custom("List pattern has too many elements".into()))?;
+                self.emit_pattern_value(cond_slot, path);
+                self.emit(OpCode::MatchList);
+                self.emit_byte((count & 0xff) as u8);
+                self.emit_byte((count >> 8) as u8);
+                self.emit_byte(rest.is_some() as u8);
+                fail_jumps.push(self.emit_jump(OpCode::JumpIfFalse));
+                for (index, element) in elements.iter().enumerate() {
+                    path.push(index);
+                    self.compile_pattern_test(element, cond_slot, path, fail_jumps)?;
+                    path.pop();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Declare the names a matched pattern binds as locals of the current
+    /// scope, returning how many were pushed.
+    fn compile_pattern_bindings(
+        &mut self,
+        pattern: &MatchPattern,
+        cond_slot: usize,
+        path: &mut Vec<usize>,
+    ) -> usize {
+        match pattern {
+            MatchPattern::Binding(name) => {
+                self.emit_pattern_value(cond_slot, path);
+                self.declare_local(name);
+                1
+            }
+            MatchPattern::List { elements, rest } => {
+                let mut bound = 0;
+                for (index, element) in elements.iter().enumerate() {
+                    path.push(index);
+                    bound += self.compile_pattern_bindings(element, cond_slot, path);
+                    path.pop();
+                }
+                if let Some(name) = rest {
+                    let start = elements.len() as u16;
+                    self.emit_pattern_value(cond_slot, path);
+                    self.emit(OpCode::ListTail);
+                    self.emit_byte((start & 0xff) as u8);
+                    self.emit_byte((start >> 8) as u8);
+                    self.declare_local(name);
+                    bound += 1;
+                }
+                bound
+            }
+            MatchPattern::Literal(_) | MatchPattern::Range { .. } | MatchPattern::Wildcard => 0,
+        }
+    }
+
     fn begin_scope(&mut self) {
         let ctx = self.current_fn_mut();
         ctx.scope_depth += 1;
@@ -162,6 +286,90 @@ impl Compiler {
         }
     }
 
+    fn local_count(&self) -> usize {
+        self.current_fn().locals.iter().map(|m| m.len()).sum()
+    }
+
+    /// Enter a loop body; `continue_target` is where `tesiwaju` jumps if
+    /// already known, otherwise its jumps are patched by `patch_continues`.
+    fn begin_loop(&mut self, label: Option<&str>, continue_target: Option<usize>) {
+        let locals = self.local_count();
+        let ctx = self.current_fn_mut();
+        let try_depth = ctx.try_depth;
+        ctx.loops.push(LoopContext {
+            label: label.map(str::to_string),
+            locals,
+            try_depth,
+            continue_target,
+            continue_jumps: Vec::new(),
+            break_jumps: Vec::new(),
+        });
+    }
+
+    /// Point the innermost loop's pending `tesiwaju` jumps here.
+    fn patch_continues(&mut self) {
+        let jumps = match self.current_fn_mut().loops.last_mut() {
+            Some(l) => std::mem::take(&mut l.continue_jumps),
+            None => Vec::new(),
+        };
+        for jump in jumps {
+            self.patch_jump(jump);
+        }
+    }
+
+    /// Leave the innermost loop, pointing its `duro` jumps here.
+    fn end_loop(&mut self) {
+        if let Some(l) = self.current_fn_mut().loops.pop() {
+            for jump in l.break_jumps {
+                self.patch_jump(jump);
+            }
+        }
+    }
+
+    /// `duro`/`tesiwaju`: pop the locals declared inside the target loop's
+    /// body, then jump to its exit or next iteration.
+    fn compile_loop_jump(&mut self, is_break: bool, label: Option<&str>) -> IfaResult<()> {
+        let keyword = if is_break { "break" } else { "continue" };
+        let ctx = self.current_fn();
+        let Some(index) = ctx
+            .loops
+            .iter()
+            .rposition(|l| label.is_none() || l.label.as_deref() == label)
+        else {
+            return Err(IfaError::Custom(match label {
+                None => format!("{} outside of a loop", keyword),
+                Some(name) => format!("{} to unknown loop label '{}'", keyword, name),
+            }));
+        };
+        if ctx.loops[index].try_depth != ctx.try_depth {
+            return Err(IfaError::Custom(format!(
+                "{} out of a try block is not supported by the bytecode compiler yet; \
+                 run with the interpreter.",
+                keyword
+            )));
+        }
+        for _ in ctx.loops[index].locals..self.local_count() {
+            self.emit(OpCode::Pop);
+        }
+        let target = self.current_fn().loops[index].continue_target;
+        match (is_break, target) {
+            (false, Some(target)) => {
+                self.emit(OpCode::Jump);
+                self.emit_u32(target as u32);
+            }
+            _ => {
+                let jump = self.emit_jump(OpCode::Jump);
+                let l = &mut self.current_fn_mut().loops[index];
+                if is_break {
+                    l.break_jumps.push(jump);
+                } else {
+                    l.continue_jumps.push(jump);
+                }
+            }
+        }
+        Ok(())
+    }
+
     fn is_const_binding(&self, name: &str) -> bool {
         self.current_fn()
             .const_locals
@@ -247,130 +455,16 @@ impl Compiler {
             }
 
             Statement::Update {
-                target,
-                op,
-                value,
-                ..
+                target, op, value, ..
             } => {
-                // 1. Load current value onto stack
-                match target {
-                    AssignTarget::Variable(name) => {
-                        if self.is_const_binding(name) {
-                            return Err(IfaError::TypeError {
-                                expected: "Mutable binding".into(),
-                                got: format!("const {name}"),
-                            });
-                        }
-                        if let Some(slot) = self.resolve_local(name) {
-                            self.emit(OpCode::LoadLocal);
-                            let s = slot as u16;
-                            self.emit_byte((s & 0xff) as u8);
-                            self.emit_byte((s >> 8) as u8);
-                        } else if let Some(slot) = self.resolve_upvalue(name) {
-                            self.emit(OpCode::LoadUpvalue);
-                            let s = slot as u16;
-                            self.emit_byte((s & 0xff) as u8);
-                            self.emit_byte((s >> 8) as u8);
-                        } else {
-                            self.emit(OpCode::LoadGlobal);
-                            self.emit_string(name);
-                        }
-                    }
-                    AssignTarget::Index { name, index } => {
-                        // Push container, index
-                        if let Some(slot) = self.resolve_local(name) {
-                            self.emit(OpCode::LoadLocal);
-                            let s = slot as u16;
-                            self.emit_byte((s & 0xff) as u8);
-                            self.emit_byte((s >> 8) as u8);
-                        } else if let Some(slot) = self.resolve_upvalue(name) {
-                            self.emit(OpCode::LoadUpvalue);
-                            let s = slot as u16;
-                            self.emit_byte((s & 0xff) as u8);
-                            self.emit_byte((s >> 8) as u8);
-                        } else {
-                            self.emit(OpCode::LoadGlobal);
-                            self.emit_string(name);
-                        }
-                        self.compile_expression(index)?;
-                        self.emit(OpCode::Dup); // Duplicate index
-                        self.emit(OpCode::Swap); // [container, index, index]
-                        self.emit(OpCode::GetIndex); // [index, value]
-                        self.emit(OpCode::Swap); // [value, index]
-                    }
-                    AssignTarget::Dereference(expr) => {
-                        self.compile_expression(expr)?;
-                        self.emit(OpCode::Dup); // [ptr, ptr]
-                        self.emit(OpCode::Load8); // [ptr, val]
-                        self.emit(OpCode::Swap); // [val, ptr]
-                    }
-                }
-
-                // Stack state: [..., current_value, (index or ptr if complex target)]
-
-                // Apply operation via the centralized helper (handles AddAssign -> Concat for strings)
-                let val_expr = value
-                    .as_ref()
-                    .ok_or_else(|| IfaError::Parse("Augmented assignment missing value".into()))?;
-                self.compile_expression(val_expr)?;
-                match op {
-                    UpdateOp::AddAssign => match val_expr {
-                        Expression::String(_) | Expression::InterpolatedString { .. } => {
-                            self.emit(OpCode::Concat)
-                        }
-                        _ => self.emit(OpCode::Add),
-                    },
-                    UpdateOp::SubAssign => self.emit(OpCode::Sub),
-                    UpdateOp::MulAssign => self.emit(OpCode::Mul),
-                    UpdateOp::DivAssign => self.emit(OpCode::Div),
+                if let AssignTarget::Variable(name) = target
+                    && self.is_const_binding(name)
+                {
+                    return Err(IfaError::TypeError {
+                        expected: "Mutable binding".into(),
+                        got: format!("const {name}"),
+                    });
                 }
-
-                // Stack state: [..., target_info (optional), new_value]
-
-                // 3. Store back
-                match target {
-                    AssignTarget::Variable(name) => {
-                        if let Some(slot) = self.resolve_local(name) {
-                            self.emit(OpCode::StoreLocal);
-                            let s = slot as u16;
-                            self.emit_byte((s & 0xff) as u8);
-                            self.emit_byte((s >> 8) as u8);
-                        } else if let Some(slot) = self.resolve_upvalue(name) {
-                            self.emit(OpCode::StoreUpvalue);
-                            let s = slot as u16;
-                            self.emit_byte((s & 0xff) as u8);
-                            self.emit_byte((s >> 8) as u8);
-                        } else {
-                            self.emit(OpCode::StoreGlobal);
-                            self.emit_string(name);
-                        }
-                    }
-                    AssignTarget::Index { .. } => {
-                        // Stack: [..., index, new_value]
-                        // We need container back. Wait, let's re-push container.
-                        // Better approach:
-                        // [container, index]
-                        // Dup index -> [container, index, index]
-                        // GetIndex -> [index, value]
-                        // Op -> [index, new_value]
-                        // But SetIndex expects [container, index, new_value]
-                        
-                        // Let's redo Index Update stack dance:
-                        // Load container [c]
-                        // Compile index [c, i]
-                        // Dup2 -> [c, i, c, i]
-                        // GetIndex -> [c, i, v]
-                        // Compile rhs -> [c, i, v, r]
-                        // Op -> [c, i, nv]
-                        // SetIndex -> []
-
-                        // Let's refactor the whole Update compilation to be cleaner.
-                        // I'll rewrite this.
-                    }
-                    _ => {}
-                }
-
-                // I'll use a more robust stack management for Update.
                 return self.compile_update_statement(target, op, value);
             }
 
@@ -466,13 +560,17 @@ impl Compiler {
             }
 
             Statement::While {
-                condition, body, ..
+                condition,
+                body,
+                label,
+                ..
             } => {
                 let loop_start = self.current_offset();
 
                 self.compile_expression(condition)?;
                 let exit_jump = self.emit_jump(OpCode::JumpIfFalse);
 
+                self.begin_loop(label.as_deref(), Some(loop_start));
                 self.begin_scope();
                 for s in body {
                     self.compile_statement(s)?;
@@ -484,31 +582,33 @@ impl Compiler {
                 self.emit_u32(loop_start as u32);
 
                 self.patch_jump(exit_jump);
+                self.end_loop();
             }
 
+            Statement::Break { label, .. } => self.compile_loop_jump(true, label.as_deref())?,
+
+            Statement::Continue { label, .. } => self.compile_loop_jump(false, label.as_deref())?,
+
             Statement::For {
                 var,
                 iterable,
                 body,
+                label,
                 ..
             } => {
+                // The hidden locals get their own scope, so they are popped after
+                // the loop and a following loop can't reuse their names' slots
+                self.begin_scope();
+
                 // 1. Compile Iterable
                 self.compile_expression(iterable)?;
-                // Store in hidden local ".iter_col"
+                // The value left on the stack is the hidden local ".iter_col"
                 let col_slot = self.declare_local(".iter_col");
-                self.emit(OpCode::StoreLocal);
-                let s = col_slot as u16;
-                self.emit_byte((s & 0xff) as u8);
-                self.emit_byte((s >> 8) as u8);
 
-                // 2. Init Index = 0
+                // 2. Init Index = 0, likewise the hidden local ".iter_idx"
                 self.emit(OpCode::PushInt);
                 self.emit_i64(0);
                 let idx_slot = self.declare_local(".iter_idx");
-                self.emit(OpCode::StoreLocal);
-                let s = idx_slot as u16;
-                self.emit_byte((s & 0xff) as u8);
-                self.emit_byte((s >> 8) as u8);
 
                 // 3. Loop Start
                 let loop_start = self.current_offset();
@@ -530,6 +630,7 @@ impl Compiler {
                 let exit_jump = self.emit_jump(OpCode::JumpIfFalse);
 
                 // 5. Body Setup: var = col[idx]
+                self.begin_loop(label.as_deref(), None);
                 self.begin_scope();
 
                 self.emit(OpCode::LoadLocal);
@@ -553,7 +654,8 @@ impl Compiler {
                 }
                 self.end_scope();
 
-                // 6. Increment Index
+                // 6. Increment Index (`tesiwaju` lands here)
+                self.patch_continues();
                 self.emit(OpCode::LoadLocal);
                 let s = idx_slot as u16;
                 self.emit_byte((s & 0xff) as u8);
@@ -572,6 +674,8 @@ impl Compiler {
                 self.emit_u32(loop_start as u32);
 
                 self.patch_jump(exit_jump);
+                self.end_loop();
+                self.end_scope();
             }
 
             Statement::Return { value, .. } => {
@@ -779,6 +883,7 @@ impl Compiler {
                             self.emit(OpCode::Eq);
 
                             let skip_arm = self.emit_jump(OpCode::JumpIfFalse);
+                            let skip_guard = self.compile_match_guard(arm.guard.as_ref())?;
 
                             self.begin_scope();
                             for s in &arm.body {
@@ -788,6 +893,9 @@ impl Compiler {
 
                             end_jumps.push(self.emit_jump(OpCode::Jump));
                             self.patch_jump(skip_arm);
+                            if let Some(jump) = skip_guard {
+                                self.patch_jump(jump);
+                            }
                         }
                         MatchPattern::Range { start, end } => {
                             // cond >= start
@@ -807,6 +915,7 @@ impl Compiler {
                             self.compile_expression(end)?;
                             self.emit(OpCode::Le);
                             let skip_arm_2 = self.emit_jump(OpCode::JumpIfFalse);
+                            let skip_guard = self.compile_match_guard(arm.guard.as_ref())?;
 
                             self.begin_scope();
                             for s in &arm.body {
@@ -817,8 +926,50 @@ impl Compiler {
                             end_jumps.push(self.emit_jump(OpCode::Jump));
                             self.patch_jump(skip_arm_1);
                             self.patch_jump(skip_arm_2);
+                            if let Some(jump) = skip_guard {
+                                self.patch_jump(jump);
+                            }
+                        }
+                        MatchPattern::List { .. } | MatchPattern::Binding(_) => {
+                            let mut fail_jumps = Vec::new();
+                            self.compile_pattern_test(
+                                &arm.pattern,
+                                cond_slot,
+                                &mut Vec::new(),
+                                &mut fail_jumps,
+                            )?;
+
+                            // Bindings live in their own scope around the guard
+                            // and body, and are popped again if the guard fails
+                            self.begin_scope();
+                            let bound = self.compile_pattern_bindings(
+                                &arm.pattern,
+                                cond_slot,
+                                &mut Vec::new(),
+                            );
+                            let skip_guard = self.compile_match_guard(arm.guard.as_ref())?;
+
+                            self.begin_scope();
+                            for s in &arm.body {
+                                self.compile_statement(s)?;
+                            }
+                            self.end_scope();
+                            self.end_scope();
+
+                            end_jumps.push(self.emit_jump(OpCode::Jump));
+                            if let Some(jump) = skip_guard {
+                                self.patch_jump(jump);
+                                for _ in 0..bound {
+                                    self.emit(OpCode::Pop);
+                                }
+                            }
+                            for jump in fail_jumps {
+                                self.patch_jump(jump);
+                            }
                         }
                         MatchPattern::Wildcard => {
+                            let skip_guard = self.compile_match_guard(arm.guard.as_ref())?;
+
                             self.begin_scope();
                             for s in &arm.body {
                                 self.compile_statement(s)?;
@@ -826,6 +977,9 @@ impl Compiler {
                             self.end_scope();
 
                             end_jumps.push(self.emit_jump(OpCode::Jump));
+                            if let Some(jump) = skip_guard {
+                                self.patch_jump(jump);
+                            }
                         }
                     }
                 }
@@ -894,7 +1048,8 @@ impl Compiler {
                     None
                 };
 
-                // 3. Compile Try Body
+                // 3. Compile Try Body (loop jumps may not leave any part of it)
+                self.current_fn_mut().try_depth += 1;
                 self.begin_scope();
                 for s in try_body {
                     self.compile_statement(s)?;
@@ -963,6 +1118,8 @@ impl Compiler {
                     self.emit(OpCode::FinallyEnd);
                 }
 
+                self.current_fn_mut().try_depth -= 1;
+
                 // 11. Patch jump over catch
                 self.patch_jump(skip_catch_jump);
             }
@@ -1048,6 +1205,21 @@ impl Compiler {
     ) -> IfaResult<()> {
         match target {
             AssignTarget::Variable(name) => {
+                // `s += "..."` on a local string appends in place
+                if let (
+                    UpdateOp::AddAssign,
+                    Some(rhs @ (Expression::String(_) | Expression::InterpolatedString { .. })),
+                    Some(slot),
+                ) = (op, value, self.resolve_local(name))
+                {
+                    self.compile_expression(rhs)?;
+                    self.emit(OpCode::AppendLocal);
+                    let s = slot as u16;
+                    self.emit_byte((s & 0xff) as u8);
+                    self.emit_byte((s >> 8) as u8);
+                    return Ok(());
+                }
+
                 // [ ] -> [val]
                 if let Some(slot) = self.resolve_local(name) {
                     self.emit(OpCode::LoadLocal);
@@ -1121,45 +1293,51 @@ impl Compiler {
                 // Dup [c, c, i, i]
                 // GetIndex [c, c, i, v]
                 // Op [c, c, i, nv]
-                // Swap [c, c, nv, i]... wait. 
-                
+                // Swap [c, c, nv, i]... wait.
+
                 // Let's just push them twice, it's safer and less stack mental gymnastics.
                 // It's less efficient but guaranteed correct.
                 if let Some(slot) = self.resolve_local(name) {
                     self.emit(OpCode::LoadLocal);
-                    let s = slot as u16; self.emit_byte((s & 0xff) as u8); self.emit_byte((s >> 8) as u8);
-                } else { /* ... */ }
+                    let s = slot as u16;
+                    self.emit_byte((s & 0xff) as u8);
+                    self.emit_byte((s >> 8) as u8);
+                } else { /* ... */
+                }
                 self.compile_expression(index)?;
                 self.emit(OpCode::GetIndex); // [v]
                 self.compile_update_op(op, value)?; // [nv]
-                
+
                 // Now I need c and i again.
                 // Let's use the first set.
                 // Re-push c and i for real.
                 if let Some(slot) = self.resolve_local(name) {
                     self.emit(OpCode::LoadLocal);
-                    let s = slot as u16; self.emit_byte((s & 0xff) as u8); self.emit_byte((s >> 8) as u8);
-                } else { /* ... */ }
+                    let s = slot as u16;
+                    self.emit_byte((s & 0xff) as u8);
+                    self.emit_byte((s >> 8) as u8);
+                } else { /* ... */
+                }
                 self.compile_expression(index)?;
                 // [nv, c, i]
                 self.emit(OpCode::SetIndex); // [nv, c, i] -> SetIndex(c, i, nv) -> wait, OpCode::SetIndex pops [col, idx, val]
                 // So I need [c, i, nv]
                 // Swap2? No.
-                
+
                 // OK, final refined Index update Plan:
                 // 1. Load c, i
                 // 2. Load c, i
                 // 3. GetIndex -> [c, i, v]
                 // 4. Op -> [c, i, nv]
                 // 5. SetIndex -> []
-                
+
                 self.emit_load_target_var(name)?;
                 self.compile_expression(index)?;
-                
+
                 self.emit_load_target_var(name)?;
                 self.compile_expression(index)?;
                 self.emit(OpCode::GetIndex);
-                
+
                 self.compile_update_op(op, value)?;
                 self.emit(OpCode::SetIndex);
             }
@@ -1233,6 +1411,18 @@ impl Compiler {
                 self.emit_string(s);
             }
 
+            Expression::Bytes(bytes) => {
+                let len = u16::try_from(bytes.len()).map_err(|_| {
+                    IfaError::Custom("Byte string literal exceeds 65535 bytes".into())
+                })?;
+                self.emit(OpCode::PushBytes);
+                self.emit_byte((len & 0xff) as u8);
+                self.emit_byte((len >> 8) as u8);
+                for b in bytes {
+                    self.emit_byte(*b);
+                }
+            }
+
             Expression::Bool(b) => {
                 self.emit(if *b {
                     OpCode::PushTrue
@@ -1491,7 +1681,15 @@ impl Compiler {
                     self.emit(OpCode::PushStr);
                     self.emit_string("");
                 } else {
-                    for (i, part) in parts.iter().enumerate() {
+                    // StrBuilder takes a 1-byte count; longer strings are built in
+                    // chunks, each chunk's result seeding the next one.
+                    let mut pending = 0usize;
+                    for part in parts {
+                        if pending == u8::MAX as usize {
+                            self.emit(OpCode::StrBuilder);
+                            self.emit_byte(u8::MAX);
+                            pending = 1;
+                        }
                         match part {
                             InterpolatedPart::Literal(s) => {
                                 self.emit(OpCode::PushStr);
@@ -1499,13 +1697,12 @@ impl Compiler {
                             }
                             InterpolatedPart::Expression(expr) => {
                                 self.compile_expression(expr)?;
-                                self.emit(OpCode::ToString);
                             }
                         }
-                        if i > 0 {
-                            self.emit(OpCode::Concat);
-                        }
+                        pending += 1;
                     }
+                    self.emit(OpCode::StrBuilder);
+                    self.emit_byte(pending as u8);
                 }
             }
         }
@@ -1552,13 +1749,23 @@ impl Compiler {
         //
         // When first-class domain values are introduced, this site must be revisited.
         // Until then: `?.` on an OduCall compiles identically to `.`.
-        for arg in &call.args {
+        let args = bind_named_args(
+            call.domain,
+            &call.method,
+            call.args.iter().collect(),
+            call.named_args
+                .iter()
+                .map(|(n, a)| (n.clone(), a))
+                .collect(),
+        )?;
+        let argc = args.len() as u8;
+        for arg in args {
             self.compile_expression(arg)?;
         }
         self.emit(OpCode::CallOdu);
         self.emit_odu_domain(&call.domain);
         self.emit_string(&call.method);
-        self.emit_byte(call.args.len() as u8);
+        self.emit_byte(argc);
         Ok(())
     }
 
@@ -1591,6 +1798,7 @@ fn domain_to_byte(domain: &OduDomain) -> u8 {
         // Pseudo-domains
         OduDomain::Coop => 16,
         OduDomain::Opele => 17,
+        OduDomain::Opon => 30,
         // Infrastructure Layer
         OduDomain::Cpu => 18,
         OduDomain::Gpu => 19,
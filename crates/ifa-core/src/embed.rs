@@ -0,0 +1,60 @@
+//! # Embedding API
+//!
+//! Minimal entry point for hosts that want to run an Ifá program from a
+//! string and collect what it printed, without touching the host console.
+
+use crate::error::IfaResult;
+use crate::interpreter::{CapabilitySet, Interpreter, OutputSink};
+use crate::parser::parse;
+use crate::value::IfaValue;
+
+/// Result of [`run_captured`].
+#[derive(Debug, Clone)]
+pub struct RunOutcome {
+    /// Value of the last executed statement
+    pub value: IfaValue,
+    /// Lines written through Ìrosù (and runtime messages)
+    pub stdout_lines: Vec<String>,
+    /// Rendered Ọ̀ṣẹ́ canvas, if anything was drawn on it
+    pub canvas: Option<String>,
+}
+
+/// Parse and run `source` with `caps`, capturing all console output.
+pub fn run_captured(source: &str, caps: CapabilitySet) -> IfaResult<RunOutcome> {
+    let program = parse(source)?;
+
+    let mut interp = Interpreter::new();
+    interp.set_capabilities(caps);
+    interp.set_output_sink(OutputSink::Captured);
+    let value = interp.execute(&program)?;
+
+    let canvas = interp.get_canvas();
+    let canvas = if canvas.chars().all(|c| c == ' ' || c == '\n') {
+        None
+    } else {
+        Some(canvas)
+    };
+
+    Ok(RunOutcome {
+        value,
+        stdout_lines: interp.get_output().to_vec(),
+        canvas,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_captured_collects_output() {
+        let outcome = run_captured(r#"Irosu.fo("hi");"#, CapabilitySet::default()).unwrap();
+        assert_eq!(outcome.stdout_lines, vec!["hi".to_string()]);
+        assert!(outcome.canvas.is_none());
+    }
+
+    #[test]
+    fn test_run_captured_reports_parse_errors() {
+        assert!(run_captured("ayanmo = ;", CapabilitySet::default()).is_err());
+    }
+}
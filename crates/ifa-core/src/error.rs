@@ -4,4 +4,4 @@
 //! SpannedError and format_error live here for convenience (they need std).
 
 // THE canonical error type — re-exported from ifa-types
-pub use ifa_types::{IfaError, IfaResult, SpannedError, format_error};
+pub use ifa_types::{IfaError, IfaResult, SpannedError, format_error, overflow};
@@ -0,0 +1,421 @@
+//! # Incremental Parsing
+//!
+//! Re-parses only the top-level statement touched by a text edit, for the
+//! LSP's per-keystroke diagnostics. The statement is parsed against a copy of
+//! the source whose prefix is blanked out (newlines kept), so its spans come
+//! out absolute; later statements keep their AST with spans shifted by the
+//! edit. Anything that could change structure beyond that one statement
+//! falls back to a full parse.
+
+use std::ops::Range;
+
+use crate::ast::*;
+use crate::error::IfaResult;
+use crate::parser::parse_with_ranges;
+
+/// A parsed program plus the source layout needed to re-parse it.
+#[derive(Debug, Clone)]
+pub struct ParsedSource {
+    pub program: Program,
+    /// Byte range of each top-level statement
+    ranges: Vec<Range<usize>>,
+    /// Newlines inside each top-level statement
+    newlines: Vec<usize>,
+}
+
+/// How `ParsedSource::reparse` produced its result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reparse {
+    /// Only the top-level statement at this index was parsed again
+    Statement(usize),
+    /// The whole source was parsed again
+    Full,
+}
+
+impl ParsedSource {
+    /// Parse all of `source`.
+    pub fn parse(source: &str) -> IfaResult<Self> {
+        let (program, ranges) = parse_with_ranges(source)?;
+        let newlines = ranges
+            .iter()
+            .map(|r| count_newlines(&source[r.clone()]))
+            .collect();
+        Ok(Self {
+            program,
+            ranges,
+            newlines,
+        })
+    }
+
+    /// Update for an edit that replaced the bytes `edit` of the previous
+    /// source with `new_text`, giving `source`.
+    ///
+    /// Only an edit strictly inside one top-level statement (not touching
+    /// its first or last character) is re-parsed on its own, and only if the
+    /// result is still exactly one statement over the same text.
+    pub fn reparse(
+        mut self,
+        edit: Range<usize>,
+        new_text: &str,
+        source: &str,
+    ) -> IfaResult<(Self, Reparse)> {
+        let Some(index) = self
+            .ranges
+            .iter()
+            .position(|r| r.start < edit.start && edit.end < r.end)
+        else {
+            return Ok((Self::parse(source)?, Reparse::Full));
+        };
+        let delta = new_text.len() as isize - edit.len() as isize;
+        let start = self.ranges[index].start;
+        let end = self.ranges[index].end.saturating_add_signed(delta);
+
+        match self.parse_statement(source, index, delta) {
+            Some(stmt) => {
+                let newlines = count_newlines(&source[start..end]);
+                let shift = Shift {
+                    bytes: delta,
+                    lines: newlines as isize - self.newlines[index] as isize,
+                };
+                for stmt in &mut self.program.statements[index + 1..] {
+                    shift.statement(stmt);
+                }
+                for range in &mut self.ranges[index + 1..] {
+                    range.start = range.start.saturating_add_signed(delta);
+                    range.end = range.end.saturating_add_signed(delta);
+                }
+                self.program.statements[index] = stmt;
+                self.ranges[index] = start..end;
+                self.newlines[index] = newlines;
+                Ok((self, Reparse::Statement(index)))
+            }
+            None => Ok((Self::parse(source)?, Reparse::Full)),
+        }
+    }
+
+    /// Parse the statement at `index`, now `delta` bytes longer, from
+    /// `source`, or `None` if that cannot be done without affecting its
+    /// neighbours.
+    fn parse_statement(&self, source: &str, index: usize, delta: isize) -> Option<Statement> {
+        let range = self.ranges[index].start..self.ranges[index].end.saturating_add_signed(delta);
+        // Later statements must start on a later line, or their columns move
+        if let Some(next) = self.ranges.get(index + 1) {
+            let next = next.start.saturating_add_signed(delta);
+            if !source.get(range.end..next)?.contains('\n') {
+                return None;
+            }
+        }
+        // Blanking turns each byte into a space, which only keeps columns
+        // right if the statement's line starts with ASCII
+        let line_start = source[..range.start].rfind('\n').map_or(0, |i| i + 1);
+        if !source[line_start..range.start].is_ascii() {
+            return None;
+        }
+
+        let mut padded: String = source[..range.start]
+            .bytes()
+            .map(|b| if b == b'\n' { '\n' } else { ' ' })
+            .collect();
+        padded.push_str(source.get(range.clone())?);
+        let (mut program, ranges) = parse_with_ranges(&padded).ok()?;
+        if program.metadata.is_some() || ranges != [range] {
+            return None;
+        }
+        program.statements.pop()
+    }
+}
+
+fn count_newlines(text: &str) -> usize {
+    text.bytes().filter(|b| *b == b'\n').count()
+}
+
+/// Offset applied to the spans of statements after an edit.
+struct Shift {
+    bytes: isize,
+    lines: isize,
+}
+
+impl Shift {
+    fn span(&self, span: &mut Span) {
+        span.start = span.start.saturating_add_signed(self.bytes);
+        span.end = span.end.saturating_add_signed(self.bytes);
+        span.line = span.line.saturating_add_signed(self.lines);
+    }
+
+    fn body(&self, body: &mut [Statement]) {
+        for stmt in body {
+            self.statement(stmt);
+        }
+    }
+
+    fn statement(&self, stmt: &mut Statement) {
+        match stmt {
+            Statement::VarDecl { value, span, .. }
+            | Statement::Const { value, span, .. }
+            | Statement::Ebo {
+                offering: value,
+                span,
+            }
+            | Statement::Yield {
+                duration: value,
+                span,
+            }
+            | Statement::Expr { expr: value, span }
+            | Statement::Ewo {
+                condition: value,
+                span,
+                ..
+            } => {
+                self.expression(value);
+                self.span(span);
+            }
+            Statement::Assignment {
+                target,
+                value,
+                span,
+            } => {
+                self.target(target);
+                self.expression(value);
+                self.span(span);
+            }
+            Statement::Update {
+                target,
+                value,
+                span,
+                ..
+            } => {
+                self.target(target);
+                if let Some(value) = value {
+                    self.expression(value);
+                }
+                self.span(span);
+            }
+            Statement::Return { value, span } => {
+                if let Some(value) = value {
+                    self.expression(value);
+                }
+                self.span(span);
+            }
+            Statement::Instruction { call, span } => {
+                self.call(call);
+                self.span(span);
+            }
+            Statement::OduDef { body, span, .. }
+            | Statement::EseDef { body, span, .. }
+            | Statement::Ailewu { body, span } => {
+                self.body(body);
+                self.span(span);
+            }
+            Statement::If {
+                condition,
+                then_body,
+                else_body,
+                span,
+            } => {
+                self.expression(condition);
+                self.body(then_body);
+                if let Some(else_body) = else_body {
+                    self.body(else_body);
+                }
+                self.span(span);
+            }
+            Statement::While {
+                condition: value,
+                body,
+                span,
+                ..
+            }
+            | Statement::For {
+                iterable: value,
+                body,
+                span,
+                ..
+            } => {
+                self.expression(value);
+                self.body(body);
+                self.span(span);
+            }
+            Statement::Match {
+                condition,
+                arms,
+                span,
+            } => {
+                self.expression(condition);
+                for arm in arms {
+                    self.pattern(&mut arm.pattern);
+                    if let Some(guard) = &mut arm.guard {
+                        self.expression(guard);
+                    }
+                    self.body(&mut arm.body);
+                }
+                self.span(span);
+            }
+            Statement::Try {
+                try_body,
+                catch_body,
+                finally_body,
+                span,
+                ..
+            } => {
+                self.body(try_body);
+                self.body(catch_body);
+                if let Some(finally_body) = finally_body {
+                    self.body(finally_body);
+                }
+                self.span(span);
+            }
+            Statement::Import { span, .. }
+            | Statement::Break { span, .. }
+            | Statement::Continue { span, .. }
+            | Statement::Ase { span }
+            | Statement::Taboo { span, .. }
+            | Statement::Opon { span, .. } => self.span(span),
+        }
+    }
+
+    fn call(&self, call: &mut OduCall) {
+        for arg in &mut call.args {
+            self.expression(arg);
+        }
+        for (_, arg) in &mut call.named_args {
+            self.expression(arg);
+        }
+        self.span(&mut call.span);
+    }
+
+    fn target(&self, target: &mut AssignTarget) {
+        match target {
+            AssignTarget::Variable(_) => {}
+            AssignTarget::Index { index: expr, .. } | AssignTarget::Dereference(expr) => {
+                self.expression(expr)
+            }
+        }
+    }
+
+    fn pattern(&self, pattern: &mut MatchPattern) {
+        match pattern {
+            MatchPattern::Literal(expr) => self.expression(expr),
+            MatchPattern::Range { start, end } => {
+                self.expression(start);
+                self.expression(end);
+            }
+            MatchPattern::List { elements, .. } => {
+                for element in elements {
+                    self.pattern(element);
+                }
+            }
+            MatchPattern::Wildcard | MatchPattern::Binding(_) => {}
+        }
+    }
+
+    fn expression(&self, expr: &mut Expression) {
+        match expr {
+            Expression::OduCall(call) => self.call(call),
+            Expression::BinaryOp { left, right, .. } => {
+                self.expression(left);
+                self.expression(right);
+            }
+            Expression::Index { object, index, .. } => {
+                self.expression(object);
+                self.expression(index);
+            }
+            Expression::UnaryOp { expr, .. }
+            | Expression::Get { object: expr, .. }
+            | Expression::Await(expr)
+            | Expression::Try(expr) => self.expression(expr),
+            Expression::MethodCall { object, args, .. } => {
+                self.expression(object);
+                for arg in args {
+                    self.expression(arg);
+                }
+            }
+            Expression::Call { args: items, .. } | Expression::List(items) => {
+                for item in items {
+                    self.expression(item);
+                }
+            }
+            Expression::Map(entries) => {
+                for (key, value) in entries {
+                    self.expression(key);
+                    self.expression(value);
+                }
+            }
+            Expression::InterpolatedString { parts } => {
+                for part in parts {
+                    if let InterpolatedPart::Expression(expr) = part {
+                        self.expression(expr);
+                    }
+                }
+            }
+            Expression::Int(_)
+            | Expression::Float(_)
+            | Expression::String(_)
+            | Expression::Bytes(_)
+            | Expression::Bool(_)
+            | Expression::Nil
+            | Expression::Identifier(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse;
+
+    /// Apply `from -> to` at the first occurrence of `from` in `source`.
+    fn edit(source: &str, from: &str, to: &str) -> (ParsedSource, Reparse, String) {
+        let start = source.find(from).unwrap();
+        let edited = source.replacen(from, to, 1);
+        let (parsed, how) = ParsedSource::parse(source)
+            .unwrap()
+            .reparse(start..start + from.len(), to, &edited)
+            .unwrap();
+        (parsed, how, edited)
+    }
+
+    const SOURCE: &str = "ayanmo a = 1;\n\
+        ese f(x) {\n    Irosu.fo(x);\n}\n\
+        ayanmo b = Obara.fikun(a, 2);\n\
+        ti b > 2 {\n    Irosu.fo(\"big\");\n}\n";
+
+    #[test]
+    fn test_edit_inside_statement_matches_full_parse() {
+        let (parsed, how, edited) = edit(SOURCE, "Irosu.fo(x);", "Irosu.fo(x,\n        x * 10);");
+        assert_eq!(how, Reparse::Statement(1));
+        assert_eq!(
+            format!("{:?}", parsed.program),
+            format!("{:?}", parse(&edited).unwrap())
+        );
+
+        // A later edit builds on the shifted layout
+        let start = edited.find("2)").unwrap();
+        let again = edited.replacen("2)", "20)", 1);
+        let (parsed, how) = parsed.reparse(start..start + 1, "20", &again).unwrap();
+        assert_eq!(how, Reparse::Statement(2));
+        assert_eq!(
+            format!("{:?}", parsed.program),
+            format!("{:?}", parse(&again).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_structural_edit_falls_back_to_full_parse() {
+        // Splitting one statement into two
+        let (parsed, how, edited) = edit(SOURCE, "a = 1", "a = 1; ayanmo c = 3");
+        assert_eq!(how, Reparse::Full);
+        assert_eq!(parsed.program.statements.len(), 5);
+        assert_eq!(
+            format!("{:?}", parsed.program),
+            format!("{:?}", parse(&edited).unwrap())
+        );
+
+        // Removing a closing brace is a syntax error, not a stale AST
+        let start = SOURCE.find("}\nayanmo b").unwrap();
+        let broken = SOURCE.replacen("}\nayanmo b", "\nayanmo b", 1);
+        let result = ParsedSource::parse(SOURCE)
+            .unwrap()
+            .reparse(start..start + 1, "", &broken);
+        assert!(result.is_err());
+    }
+}
@@ -2,13 +2,24 @@
 //!
 //! Provides rendering capabilities for the Ose (Graphics) domain.
 //! Implements basic drawing primitives for ASCII art.
+//!
+//! The canvas is double-buffered: drawing goes to a back buffer and only
+//! becomes visible in `render` after `present`, so each frame shows up whole.
+//! Each cell also carries an optional ANSI color, kept by `render_ansi`.
 
 /// Ose Canvas for ASCII graphics
 #[derive(Clone)]
 pub struct OseCanvas {
     width: usize,
     height: usize,
-    buffer: Vec<Vec<char>>,
+    /// Buffer that drawing goes to
+    back: Vec<Vec<char>>,
+    /// Last presented frame, which `render` shows
+    front: Vec<Vec<char>>,
+    /// ANSI foreground code of each back buffer cell (`None` is uncolored)
+    back_colors: Vec<Vec<Option<u8>>>,
+    /// Colors of the presented frame
+    front_colors: Vec<Vec<Option<u8>>>,
     _cursor_x: usize,
     _cursor_y: usize,
 }
@@ -19,37 +30,68 @@ impl OseCanvas {
         Self {
             width: 80,
             height: 24,
-            buffer: vec![vec![' '; 80]; 24],
+            back: vec![vec![' '; 80]; 24],
+            front: vec![vec![' '; 80]; 24],
+            back_colors: vec![vec![None; 80]; 24],
+            front_colors: vec![vec![None; 80]; 24],
             _cursor_x: 0,
             _cursor_y: 0,
         }
     }
 
-    /// Clear the canvas with a fill character
+    /// Clear the back buffer with a fill character
     pub fn clear(&mut self, fill: char) {
-        for row in &mut self.buffer {
+        for row in &mut self.back {
             row.fill(fill);
         }
+        for row in &mut self.back_colors {
+            row.fill(None);
+        }
     }
 
-    /// Resize the canvas
+    /// Resize the canvas, blanking both buffers
     pub fn resize(&mut self, width: usize, height: usize) {
         self.width = width;
         self.height = height;
-        self.buffer = vec![vec![' '; width]; height];
+        self.back = vec![vec![' '; width]; height];
+        self.front = vec![vec![' '; width]; height];
+        self.back_colors = vec![vec![None; width]; height];
+        self.front_colors = vec![vec![None; width]; height];
     }
 
-    /// Set a single pixel/character
+    /// Set a single pixel/character, uncolored
     pub fn set_pixel(&mut self, x: i64, y: i64, ch: char) {
+        self.set_colored_pixel(x, y, ch, None);
+    }
+
+    /// Set a single pixel/character with an ANSI foreground `color` code
+    pub fn set_colored_pixel(&mut self, x: i64, y: i64, ch: char, color: Option<u8>) {
         if x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height {
-            self.buffer[y as usize][x as usize] = ch;
+            self.back[y as usize][x as usize] = ch;
+            self.back_colors[y as usize][x as usize] = color;
         }
     }
 
     /// Write text at position
     pub fn write_text(&mut self, x: i64, y: i64, text: &str) {
+        self.write_colored_text(x, y, text, None);
+    }
+
+    /// Write text at position in an ANSI foreground `color`
+    pub fn write_colored_text(&mut self, x: i64, y: i64, text: &str, color: Option<u8>) {
         for (i, ch) in text.chars().enumerate() {
-            self.set_pixel(x + i as i64, y, ch);
+            self.set_colored_pixel(x + i as i64, y, ch, color);
+        }
+    }
+
+    /// Draw `rows` as consecutive lines from (x, y); spaces are transparent
+    pub fn blit<S: AsRef<str>>(&mut self, x: i64, y: i64, rows: &[S], color: Option<u8>) {
+        for (dy, row) in rows.iter().enumerate() {
+            for (dx, ch) in row.as_ref().chars().enumerate() {
+                if ch != ' ' {
+                    self.set_colored_pixel(x + dx as i64, y + dy as i64, ch, color);
+                }
+            }
         }
     }
 
@@ -127,15 +169,47 @@ impl OseCanvas {
         }
     }
 
-    /// Render the canvas to a string
+    /// Show the back buffer. The front becomes a copy of it, so drawing
+    /// carries on from the frame just presented.
+    pub fn present(&mut self) {
+        self.front.clone_from(&self.back);
+        self.front_colors.clone_from(&self.back_colors);
+    }
+
+    /// Render the last presented frame to a string
     pub fn render(&self) -> String {
-        self.buffer
+        self.front
             .iter()
             .map(|row| row.iter().collect::<String>())
             .collect::<Vec<_>>()
             .join("\n")
     }
 
+    /// Render the last presented frame with ANSI color escapes: a color
+    /// code before each run of colored cells and a reset after it.
+    pub fn render_ansi(&self) -> String {
+        self.front
+            .iter()
+            .zip(&self.front_colors)
+            .map(|(row, colors)| {
+                let mut line = String::new();
+                let mut current = None;
+                for (&ch, &color) in row.iter().zip(colors) {
+                    if color != current {
+                        line.push_str(&format!("\x1B[{}m", color.unwrap_or(0)));
+                        current = color;
+                    }
+                    line.push(ch);
+                }
+                if current.is_some() {
+                    line.push_str("\x1B[0m");
+                }
+                line
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
     /// Get canvas dimensions
     pub fn dimensions(&self) -> (usize, usize) {
         (self.width, self.height)
@@ -147,3 +221,54 @@ impl Default for OseCanvas {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_draws_are_hidden_until_present() {
+        let mut canvas = OseCanvas::new();
+        canvas.resize(4, 2);
+        canvas.write_text(0, 0, "hi");
+        assert_eq!(canvas.render(), "    \n    ");
+
+        canvas.present();
+        assert_eq!(canvas.render(), "hi  \n    ");
+
+        // The next frame builds on the presented one
+        canvas.set_pixel(3, 1, '*');
+        assert_eq!(canvas.render(), "hi  \n    ");
+        canvas.present();
+        assert_eq!(canvas.render(), "hi  \n   *");
+    }
+
+    #[test]
+    fn test_ansi_render_colors_runs_and_resets() {
+        let mut canvas = OseCanvas::new();
+        canvas.resize(5, 2);
+        canvas.write_colored_text(1, 0, "ok", Some(32));
+        canvas.write_text(3, 0, "!");
+        canvas.write_colored_text(3, 1, "no", Some(31));
+        canvas.present();
+
+        assert_eq!(canvas.render(), " ok! \n   no");
+        assert_eq!(
+            canvas.render_ansi(),
+            " \x1B[32mok\x1B[0m! \n   \x1B[31mno\x1B[0m"
+        );
+    }
+
+    #[test]
+    fn test_blit_skips_spaces_and_clips() {
+        let mut canvas = OseCanvas::new();
+        canvas.resize(5, 3);
+        canvas.clear('.');
+        canvas.blit(1, 1, &["/ \\", "\\_/"], None);
+        // Clipped at the right and bottom edges
+        canvas.blit(4, 2, &["ab", "cd"], None);
+        canvas.present();
+
+        assert_eq!(canvas.render(), ".....\n./.\\.\n.\\_/a");
+    }
+}
@@ -4,9 +4,11 @@
 //! This module implements the scope chain pattern where variables are resolved
 //! by walking up from child → parent → grandparent scopes.
 
+use crate::error::{IfaError, IfaResult};
 use crate::value::IfaValue;
+use indexmap::IndexMap;
 use std::cell::RefCell;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 use std::rc::Rc;
 
 /// Shared reference to an environment node.
@@ -18,8 +20,9 @@ pub type EnvRef = Rc<RefCell<Environment>>;
 /// then walking up the parent chain until found or reaching the root.
 #[derive(Debug, Clone)]
 pub struct Environment {
-    /// Variables defined in this scope
-    pub values: HashMap<String, IfaValue>,
+    /// Variables defined in this scope, in definition order (so dumps are
+    /// reproducible)
+    pub values: IndexMap<String, IfaValue>,
     /// Constant bindings (ayanfe) defined in this scope.
     pub consts: HashSet<String>,
     /// Parent scope (if any) - walking up the chain
@@ -30,7 +33,7 @@ impl Environment {
     /// Create a new root environment (no parent).
     pub fn new() -> EnvRef {
         Rc::new(RefCell::new(Environment {
-            values: HashMap::new(),
+            values: IndexMap::new(),
             consts: HashSet::new(),
             parent: None,
         }))
@@ -40,7 +43,7 @@ impl Environment {
     /// Used for function calls, blocks, and closures.
     pub fn with_parent(parent: EnvRef) -> EnvRef {
         Rc::new(RefCell::new(Environment {
-            values: HashMap::new(),
+            values: IndexMap::new(),
             consts: HashSet::new(),
             parent: Some(parent),
         }))
@@ -48,7 +51,9 @@ impl Environment {
 
     /// Define a variable in the current scope
     pub fn define(env: &EnvRef, name: &str, value: IfaValue) {
-        env.borrow_mut().values.insert(name.to_string(), value);
+        let mut env = env.borrow_mut();
+        env.consts.remove(name);
+        env.values.insert(name.to_string(), value);
     }
 
     /// Define a constant binding in the current scope.
@@ -58,11 +63,39 @@ impl Environment {
         env.consts.insert(name.to_string());
     }
 
-    /// Returns true if `name` resolves to a constant binding in any active scope.
+    /// Bind `name` in the current scope the way a declaration does. Unlike
+    /// `define`, a constant of this scope can't be rebound; an inner scope
+    /// may still shadow it.
+    pub fn declare(env: &EnvRef, name: &str, value: IfaValue) -> IfaResult<()> {
+        Environment::check_redeclare(env, name)?;
+        Environment::define(env, name, value);
+        Ok(())
+    }
+
+    /// Declare a constant, as `tuntun` does (see `declare`).
+    pub fn declare_const(env: &EnvRef, name: &str, value: IfaValue) -> IfaResult<()> {
+        Environment::check_redeclare(env, name)?;
+        Environment::define_const(env, name, value);
+        Ok(())
+    }
+
+    fn check_redeclare(env: &EnvRef, name: &str) -> IfaResult<()> {
+        if env.borrow().consts.contains(name) {
+            return Err(IfaError::Runtime(format!(
+                "cannot redeclare constant {name}"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Returns true if `name` resolves to a constant binding.
+    ///
+    /// Only the nearest scope defining `name` counts, so a mutable shadow in
+    /// an inner scope hides an outer constant.
     pub fn is_const(env: &EnvRef, name: &str) -> bool {
         let env_ref = env.borrow();
-        if env_ref.consts.contains(name) {
-            true
+        if env_ref.values.contains_key(name) {
+            env_ref.consts.contains(name)
         } else if let Some(ref parent) = env_ref.parent {
             Environment::is_const(parent, name)
         } else {
@@ -103,6 +136,23 @@ impl Environment {
         env.borrow().values.keys().cloned().collect()
     }
 
+    /// Every name visible from this scope, outermost scope first and each
+    /// scope in definition order. A shadowed name is listed once, where it
+    /// was first defined.
+    pub fn variable_names(env: &EnvRef) -> Vec<String> {
+        let env_ref = env.borrow();
+        let mut names = match &env_ref.parent {
+            Some(parent) => Environment::variable_names(parent),
+            None => Vec::new(),
+        };
+        for name in env_ref.values.keys() {
+            if !names.contains(name) {
+                names.push(name.clone());
+            }
+        }
+        names
+    }
+
     /// Check if a variable exists in any scope
     pub fn contains(env: &EnvRef, name: &str) -> bool {
         Environment::get(env, name).is_some()
@@ -113,6 +163,21 @@ impl Environment {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_constants_cannot_be_redeclared_in_their_own_scope() {
+        let root = Environment::new();
+        Environment::declare_const(&root, "LIMIT", IfaValue::Int(3)).unwrap();
+        assert!(Environment::declare(&root, "LIMIT", IfaValue::Int(4)).is_err());
+        assert!(Environment::declare_const(&root, "LIMIT", IfaValue::Int(4)).is_err());
+        assert_eq!(Environment::get(&root, "LIMIT"), Some(IfaValue::Int(3)));
+        assert!(Environment::is_const(&root, "LIMIT"));
+
+        let child = Environment::with_parent(root.clone());
+        Environment::declare(&child, "LIMIT", IfaValue::Int(10)).unwrap();
+        assert!(!Environment::is_const(&child, "LIMIT"));
+        assert!(Environment::is_const(&root, "LIMIT"));
+    }
+
     #[test]
     fn test_gpc_resolution() {
         // Grandparent scope
@@ -143,4 +208,29 @@ mod tests {
 
         assert_eq!(Environment::get(&child, "x"), Some(IfaValue::Int(2)));
     }
+
+    #[test]
+    fn test_variable_names_follow_definition_order() {
+        let names = || {
+            let root = Environment::new();
+            for name in ["zeta", "alpha", "mid", "beta"] {
+                Environment::define(&root, name, IfaValue::Int(0));
+            }
+            let child = Environment::with_parent(root.clone());
+            Environment::define(&child, "local", IfaValue::Int(1));
+            Environment::define(&child, "alpha", IfaValue::Int(2));
+            // Redefining keeps the original position
+            Environment::define(&root, "zeta", IfaValue::Int(3));
+            (
+                Environment::local_names(&root),
+                Environment::variable_names(&child),
+            )
+        };
+
+        for _ in 0..10 {
+            let (local, visible) = names();
+            assert_eq!(local, ["zeta", "alpha", "mid", "beta"]);
+            assert_eq!(visible, ["zeta", "alpha", "mid", "beta", "local"]);
+        }
+    }
 }
@@ -0,0 +1,236 @@
+//! # Capability Requirements
+//!
+//! Declarative `(domain, method) -> Ofun` table consulted by
+//! [`HandlerRegistry::dispatch`](super::HandlerRegistry::dispatch) before any
+//! handler runs. Requirements may depend on the call's arguments (a file path,
+//! a URL host); calls whose arguments don't fit are left to the handler to
+//! reject.
+
+use std::path::{Component, Path, PathBuf};
+
+use crate::error::{IfaError, IfaResult};
+use crate::interpreter::Ofun;
+use crate::lexer::OduDomain;
+use crate::value::IfaValue;
+
+use super::coop::{c_compiler, python_executable};
+
+/// Capabilities a call to `domain.method(args)` needs before it may run.
+///
+/// Fails if a path argument can't be confined to a grant (see
+/// [`confined_path`]).
+pub fn required_capabilities(
+    domain: OduDomain,
+    method: &str,
+    args: &[IfaValue],
+) -> IfaResult<Vec<Ofun>> {
+    Ok(match (domain, method) {
+        // Bridges that start a process also need to run its program
+        (OduDomain::Coop, "py_json") => vec![
+            Ofun::Bridge {
+                language: "python".into(),
+            },
+            Ofun::Execute {
+                programs: vec![python_executable().into()],
+            },
+        ],
+        (OduDomain::Coop, "c") => vec![
+            Ofun::Bridge {
+                language: "c".into(),
+            },
+            Ofun::Execute {
+                programs: vec![c_compiler(args.get(1))],
+            },
+        ],
+        (OduDomain::Coop, "wasm") => vec![Ofun::Bridge {
+            language: "wasm".into(),
+        }],
+        (
+            OduDomain::Otura,
+            "http_get" | "gba" | "get" | "http_post" | "fi" | "post" | "fetch_json" | "gba_json"
+            | "ws_connect" | "asopọ_ws",
+        ) => match args.first() {
+            Some(IfaValue::Str(url)) => vec![network(url)],
+            _ => Vec::new(),
+        },
+        (OduDomain::Otura, "download" | "gba_faili") => match (args.first(), args.get(1)) {
+            (Some(IfaValue::Str(url)), Some(IfaValue::Str(path))) => vec![
+                network(url),
+                Ofun::WriteFiles {
+                    root: confined_path(path)?,
+                },
+            ],
+            _ => Vec::new(),
+        },
+        (OduDomain::Ogbe, "hostname") => vec![Ofun::Environment {
+            keys: vec!["HOSTNAME".into()],
+        }],
+        (OduDomain::Ogbe, "ayika" | "env") => match args.first() {
+            Some(IfaValue::Str(key)) => vec![Ofun::Environment {
+                keys: vec![key.to_string()],
+            }],
+            _ => Vec::new(),
+        },
+        (OduDomain::Ogbe, "chdir") => match args.first() {
+            Some(IfaValue::Str(path)) => vec![Ofun::ReadFiles {
+                root: confined_path(path)?,
+            }],
+            _ => Vec::new(),
+        },
+        (
+            OduDomain::Odi,
+            "ka" | "read" | "wa" | "exists" | "ṣe_akojọ" | "list" | "ls" | "ka_ila" | "read_lines"
+            | "ka_jsonl" | "read_jsonl" | "ṣi" | "open",
+        ) => match args.first() {
+            Some(IfaValue::Str(path)) => vec![Ofun::ReadFiles {
+                root: confined_path(path)?,
+            }],
+            _ => Vec::new(),
+        },
+        (
+            OduDomain::Odi,
+            "kọ" | "write" | "fikun" | "append" | "fikun_jsonl" | "append_jsonl" | "pa" | "delete"
+            | "remove" | "ṣe_folda" | "mkdir",
+        ) => match args.first() {
+            Some(IfaValue::Str(path)) => vec![Ofun::WriteFiles {
+                root: confined_path(path)?,
+            }],
+            _ => Vec::new(),
+        },
+        // Media handlers open the file they are given
+        (OduDomain::Ohun, "ṣe" | "play")
+        | (OduDomain::Fidio, "ṣe" | "play" | "akoko" | "duration" | "alaye" | "info") => {
+            match args.first() {
+                Some(IfaValue::Str(path)) => vec![Ofun::ReadFiles {
+                    root: confined_path(path)?,
+                }],
+                _ => Vec::new(),
+            }
+        }
+        (OduDomain::Opele, "dafa" | "cast" | "beere" | "divine") => vec![Ofun::Random],
+        (OduDomain::Iwori, "aago_tito" | "monotonic" | "sun_titi" | "sleep_until") => {
+            vec![Ofun::Time]
+        }
+        // A database file is both read and written; ":memory:" touches no file
+        (OduDomain::Odi, "so_db" | "connect") => match args.first() {
+            Some(IfaValue::Str(path)) if &**path != ":memory:" => vec![
+                Ofun::ReadFiles {
+                    root: confined_path(path)?,
+                },
+                Ofun::WriteFiles {
+                    root: confined_path(path)?,
+                },
+            ],
+            _ => Vec::new(),
+        },
+        _ => Vec::new(),
+    })
+}
+
+/// Positions of the file path arguments of `domain.method`; the interpreter
+/// resolves relative ones against its `Ogbe.chdir` directory before the
+/// capability check.
+pub fn path_args(domain: OduDomain, method: &str) -> &'static [usize] {
+    match (domain, method) {
+        (OduDomain::Otura, "download" | "gba_faili") => &[1],
+        (OduDomain::Ogbe, "chdir")
+        | (
+            OduDomain::Odi,
+            "ka" | "read" | "wa" | "exists" | "ṣe_akojọ" | "list" | "ls" | "ka_ila" | "read_lines"
+            | "ka_jsonl" | "read_jsonl" | "ṣi" | "open" | "kọ" | "write" | "fikun" | "append"
+            | "fikun_jsonl" | "append_jsonl" | "pa" | "delete" | "remove" | "ṣe_folda" | "mkdir"
+            | "so_db" | "connect",
+        )
+        | (OduDomain::Ohun, "ṣe" | "play")
+        | (OduDomain::Fidio, "ṣe" | "play" | "akoko" | "duration" | "alaye" | "info") => &[0],
+        _ => &[],
+    }
+}
+
+/// `path` as the root of a file capability, with `.` components dropped.
+///
+/// `..` is refused rather than resolved: `/data/../etc` would otherwise pass
+/// a `/data` grant, and resolving it lexically is wrong once a symlink is
+/// involved.
+pub fn confined_path(path: &str) -> IfaResult<PathBuf> {
+    let mut confined = PathBuf::new();
+    for component in Path::new(path).components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                return Err(IfaError::PermissionDenied(format!(
+                    "path '{}' may not contain '..'",
+                    path
+                )));
+            }
+            other => confined.push(other),
+        }
+    }
+    if confined.as_os_str().is_empty() {
+        confined.push(".");
+    }
+    Ok(confined)
+}
+
+fn network(url: &str) -> Ofun {
+    Ofun::Network {
+        domains: vec![url_host(url).to_string()],
+    }
+}
+
+/// Host part of a URL (`https://host:port/path` -> `host`).
+fn url_host(url: &str) -> &str {
+    let rest = url.split_once("://").map_or(url, |(_, r)| r);
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or(rest);
+    let host = authority.rsplit_once('@').map_or(authority, |(_, h)| h);
+    host.split(':').next().unwrap_or(host)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_url_host() {
+        assert_eq!(url_host("https://example.com/a?b"), "example.com");
+        assert_eq!(url_host("http://user@localhost:8080"), "localhost");
+        assert_eq!(url_host("example.org"), "example.org");
+    }
+
+    #[test]
+    fn test_odi_requirements_follow_the_path_argument() {
+        let args = [IfaValue::str("./data/in.txt")];
+        assert_eq!(
+            required_capabilities(OduDomain::Odi, "read", &args).unwrap(),
+            vec![Ofun::ReadFiles {
+                root: PathBuf::from("data/in.txt")
+            }]
+        );
+        assert_eq!(
+            required_capabilities(OduDomain::Odi, "pa", &args).unwrap(),
+            vec![Ofun::WriteFiles {
+                root: PathBuf::from("data/in.txt")
+            }]
+        );
+        assert!(
+            required_capabilities(OduDomain::Obara, "fikun", &args)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_parent_dir_paths_are_refused() {
+        for (domain, method) in [
+            (OduDomain::Odi, "read"),
+            (OduDomain::Odi, "write"),
+            (OduDomain::Ogbe, "chdir"),
+            (OduDomain::Ohun, "play"),
+            (OduDomain::Fidio, "info"),
+        ] {
+            let args = [IfaValue::str("/data/../etc/passwd")];
+            let err = required_capabilities(domain, method, &args).unwrap_err();
+            assert!(matches!(err, IfaError::PermissionDenied(_)), "{:?}", err);
+        }
+    }
+}
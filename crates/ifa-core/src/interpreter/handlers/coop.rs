@@ -0,0 +1,804 @@
+//! # Coop Handler - Polyglot Bridge (Àjọṣe)
+//!
+//! Exchanges structured data with external language runtimes, compiles and
+//! runs C snippets, and calls into WebAssembly modules (`wasm` feature).
+//! Pseudo-domain (no binary pattern).
+//!
+//! Capability gating (`Ofun::Bridge`) is enforced by the interpreter before
+//! dispatch reaches this handler. On top of that, `py_json` only imports
+//! modules from an allowlist ([`DEFAULT_PYTHON_MODULES`] unless the host sets
+//! its own with `Interpreter::set_python_modules`).
+//!
+//! `available` and `bridges` only look for each bridge's tool on `PATH`, so
+//! scripts can fall back gracefully before asking for a bridge capability.
+//!
+//! Every process the bridges start also needs an `Ofun::Execute` grant for
+//! its program: the Python interpreter for `py_json`, the compiler for `c`.
+//! The executable `c` builds runs under the compiler's grant, since its name
+//! is a hash no one could list in advance.
+//!
+//! `c` keeps compiled executables in [`c_cache_dir`], named by a hash of the
+//! compiler, flags and source, so an identical snippet is built only once.
+//! The cache is per user and private to them; a cached executable is only run
+//! if this user could have written it.
+
+use std::collections::BTreeSet;
+use std::ffi::OsStr;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use sha2::{Digest, Sha256};
+
+use crate::error::{IfaError, IfaResult};
+use crate::lexer::OduDomain;
+use crate::value::IfaValue;
+
+#[cfg(not(feature = "wasm"))]
+use super::feature_required;
+use super::{EnvRef, OduHandler};
+
+/// Python modules `py_json` may import by default: pure computation, no
+/// access to the filesystem, processes or network.
+pub const DEFAULT_PYTHON_MODULES: &[&str] = &["math", "json", "statistics"];
+
+/// Handler for Coop (Polyglot Bridge) domain.
+pub struct CoopHandler {
+    /// Python modules (with their submodules) `py_json` may import
+    python_modules: BTreeSet<String>,
+}
+
+impl CoopHandler {
+    /// Handler whose `py_json` may import only `python_modules`
+    pub fn new(python_modules: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            python_modules: python_modules.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Reject `module.func` unless the module is allowlisted and the
+    /// function is public. Runs before Python is started.
+    fn check_python_call(&self, module: &str, func: &str) -> IfaResult<()> {
+        let allowed = self.python_modules.iter().any(|m| {
+            module == m
+                || module
+                    .strip_prefix(m.as_str())
+                    .is_some_and(|rest| rest.starts_with('.'))
+        });
+        if !allowed {
+            let list: Vec<&str> = self.python_modules.iter().map(String::as_str).collect();
+            return Err(IfaError::PermissionDenied(format!(
+                "Python module '{}' is not allowed for Coop.py_json (allowed: {})",
+                module,
+                list.join(", ")
+            )));
+        }
+        if func.starts_with('_') {
+            return Err(IfaError::PermissionDenied(format!(
+                "Python function '{}.{}' is private",
+                module, func
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl Default for CoopHandler {
+    fn default() -> Self {
+        Self::new(DEFAULT_PYTHON_MODULES.iter().copied())
+    }
+}
+
+/// Python wrapper: reads `{module, func, args}` from stdin and writes the
+/// `json.dumps` of the return value to stdout.
+const PY_JSON_WRAPPER: &str = r#"
+import importlib, json, sys
+payload = json.loads(sys.stdin.read())
+fn = getattr(importlib.import_module(payload["module"]), payload["func"])
+sys.stdout.write(json.dumps(fn(*payload["args"])))
+"#;
+
+/// Python executable used by the bridge.
+pub fn python_executable() -> &'static str {
+    if cfg!(windows) { "python" } else { "python3" }
+}
+
+/// Languages `available` and `bridges` know about
+pub const BRIDGE_LANGUAGES: &[&str] = &["python", "js", "c"];
+
+/// Interpreter or compiler a bridge language needs, matched on the exact
+/// language name
+pub fn bridge_tool(language: &str) -> Option<&'static str> {
+    match language {
+        "python" => Some(python_executable()),
+        "js" => Some("node"),
+        "c" => Some("cc"),
+        _ => None,
+    }
+}
+
+/// Whether `program` is an executable file in one of the `path` directories.
+fn on_path(program: &str, path: &OsStr) -> bool {
+    std::env::split_paths(path).any(|dir| {
+        let candidate = dir.join(program);
+        is_executable(&candidate) || (cfg!(windows) && candidate.with_extension("exe").is_file())
+    })
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    path.metadata()
+        .is_ok_and(|meta| meta.is_file() && meta.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+}
+
+/// Whether the tool for `language` is installed on `path`.
+fn bridge_available(language: &str, path: &OsStr) -> IfaResult<bool> {
+    let tool = bridge_tool(language).ok_or_else(|| {
+        IfaError::ArgumentError(format!(
+            "Unknown bridge language '{}' (known: {})",
+            language,
+            BRIDGE_LANGUAGES.join(", ")
+        ))
+    })?;
+    Ok(on_path(tool, path))
+}
+
+/// Where `Coop.c` keeps compiled snippets between calls and runs:
+/// `$XDG_CACHE_HOME/ifa/coop-c`, else `~/.cache/ifa/coop-c`
+pub fn c_cache_dir() -> IfaResult<PathBuf> {
+    let absolute = |dir: &std::ffi::OsString| Path::new(dir).is_absolute();
+    let base = std::env::var_os("XDG_CACHE_HOME")
+        .filter(absolute)
+        .map(PathBuf::from)
+        .or_else(|| {
+            std::env::var_os("HOME")
+                .filter(absolute)
+                .map(|home| PathBuf::from(home).join(".cache"))
+        })
+        .or_else(|| std::env::var_os("LOCALAPPDATA").map(PathBuf::from))
+        .ok_or_else(|| {
+            IfaError::Runtime("Coop.c: no cache directory (set HOME or XDG_CACHE_HOME)".into())
+        })?;
+    Ok(base.join("ifa").join("coop-c"))
+}
+
+/// Create `dir` readable only by this user, or check that an existing one
+/// is: a real directory, owned by us, closed to group and others.
+fn private_dir(dir: &Path) -> IfaResult<()> {
+    let io_err = |e: std::io::Error| IfaError::Runtime(format!("Coop.c: {}", e));
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{DirBuilderExt, MetadataExt};
+        std::fs::DirBuilder::new()
+            .recursive(true)
+            .mode(0o700)
+            .create(dir)
+            .map_err(io_err)?;
+        let meta = std::fs::symlink_metadata(dir).map_err(io_err)?;
+        // SAFETY: geteuid has no preconditions and cannot fail
+        let uid = unsafe { libc::geteuid() };
+        if !meta.is_dir() || meta.uid() != uid || meta.mode() & 0o077 != 0 {
+            return Err(IfaError::Runtime(format!(
+                "Coop.c: refusing cache directory {}: it must be a directory owned by you \
+                 with mode 0700",
+                dir.display()
+            )));
+        }
+    }
+    #[cfg(not(unix))]
+    std::fs::create_dir_all(dir).map_err(io_err)?;
+    Ok(())
+}
+
+/// Whether `exe` is a regular file this user owns and nobody else can
+/// modify, so running it runs what we compiled.
+fn written_by_us(exe: &Path) -> bool {
+    let Ok(meta) = std::fs::symlink_metadata(exe) else {
+        return false;
+    };
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+        // SAFETY: geteuid has no preconditions and cannot fail
+        let uid = unsafe { libc::geteuid() };
+        meta.is_file() && meta.uid() == uid && meta.mode() & 0o022 == 0
+    }
+    #[cfg(not(unix))]
+    meta.is_file()
+}
+
+/// How `Coop.c` builds a snippet, from its options map
+#[derive(Debug, Clone, PartialEq)]
+struct CBuild {
+    /// Compiler command (`cc` unless `compiler` is given)
+    compiler: String,
+    /// Extra arguments placed before the source file (`flags`)
+    flags: Vec<String>,
+    /// Reuse and keep the executable (`cache`, on by default)
+    cache: bool,
+}
+
+/// The compiler `Coop.c` would run for `options`, for the capability check
+/// made before the call. A malformed option is left for the handler to reject.
+pub(crate) fn c_compiler(options: Option<&IfaValue>) -> String {
+    CBuild::from_options(options)
+        .map(|build| build.compiler)
+        .unwrap_or_else(|_| bridge_tool("c").unwrap_or("cc").to_string())
+}
+
+impl CBuild {
+    fn from_options(options: Option<&IfaValue>) -> IfaResult<Self> {
+        let mut build = CBuild {
+            compiler: bridge_tool("c").unwrap_or("cc").to_string(),
+            flags: Vec::new(),
+            cache: true,
+        };
+        let options = match options {
+            None | Some(IfaValue::Null) => return Ok(build),
+            Some(IfaValue::Map(options)) => options,
+            Some(other) => {
+                return Err(IfaError::TypeError {
+                    expected: "options map".into(),
+                    got: other.type_name().to_string(),
+                });
+            }
+        };
+        match options.get("compiler") {
+            Some(IfaValue::Str(compiler)) => build.compiler = compiler.to_string(),
+            Some(other) => return Err(option_type_error("compiler", "string", other)),
+            None => {}
+        }
+        match options.get("flags") {
+            Some(IfaValue::List(flags)) => {
+                for flag in flags.iter() {
+                    match flag {
+                        IfaValue::Str(flag) => build.flags.push(flag.to_string()),
+                        other => return Err(option_type_error("flags", "list of strings", other)),
+                    }
+                }
+            }
+            Some(other) => return Err(option_type_error("flags", "list of strings", other)),
+            None => {}
+        }
+        match options.get("cache") {
+            Some(IfaValue::Bool(cache)) => build.cache = *cache,
+            Some(other) => return Err(option_type_error("cache", "bool", other)),
+            None => {}
+        }
+        Ok(build)
+    }
+
+    /// Hex SHA-256 of everything that affects the executable
+    fn key(&self, source: &str) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(self.compiler.as_bytes());
+        for flag in &self.flags {
+            hasher.update([0]);
+            hasher.update(flag.as_bytes());
+        }
+        hasher.update([0]);
+        hasher.update(source.as_bytes());
+        hasher
+            .finalize()
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect()
+    }
+}
+
+fn option_type_error(option: &str, expected: &str, got: &IfaValue) -> IfaError {
+    IfaError::TypeError {
+        expected: format!("{} for Coop.c option '{}'", expected, option),
+        got: got.type_name().to_string(),
+    }
+}
+
+/// Compile `source` into `cache_dir` (unless an identical build is cached
+/// there) and run it. Returns the program's stdout and whether the cached
+/// executable was used.
+fn run_c(source: &str, build: &CBuild, cache_dir: &Path) -> IfaResult<(String, bool)> {
+    let io_err = |e: std::io::Error| IfaError::Runtime(format!("Coop.c: {}", e));
+    static NEXT_BUILD: AtomicU64 = AtomicU64::new(0);
+    private_dir(cache_dir)?;
+    let key = build.key(source);
+    // Names private to this call, for everything that is not the cached executable
+    let private = format!(
+        "{}-{}-{}",
+        key,
+        std::process::id(),
+        NEXT_BUILD.fetch_add(1, Ordering::Relaxed)
+    );
+    let name = if build.cache { &key } else { &private };
+    let exe = cache_dir.join(format!("{}{}", name, std::env::consts::EXE_SUFFIX));
+
+    // Anything we can't vouch for is rebuilt, and the rename replaces it
+    let cached = build.cache && written_by_us(&exe);
+    if !cached {
+        let src = cache_dir.join(format!("{}.c", private));
+        // Build under a private name, then move into place, so a concurrent
+        // run never executes a half-written file
+        let partial = cache_dir.join(format!("{}.partial", private));
+        std::fs::write(&src, source).map_err(io_err)?;
+        let compiled = Command::new(&build.compiler)
+            .args(&build.flags)
+            .arg(&src)
+            .arg("-o")
+            .arg(&partial)
+            .output();
+        std::fs::remove_file(&src).ok();
+        let compiled = compiled.map_err(|e| {
+            IfaError::Runtime(format!(
+                "Coop.c: could not run compiler '{}': {}",
+                build.compiler, e
+            ))
+        })?;
+        if !compiled.status.success() {
+            std::fs::remove_file(&partial).ok();
+            return Err(IfaError::Runtime(format!(
+                "Coop.c: compilation failed:\n{}",
+                String::from_utf8_lossy(&compiled.stderr).trim_end()
+            )));
+        }
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&partial, std::fs::Permissions::from_mode(0o700))
+                .map_err(io_err)?;
+        }
+        std::fs::rename(&partial, &exe).map_err(io_err)?;
+    }
+
+    let run = Command::new(&exe).stdin(Stdio::null()).output();
+    if !build.cache {
+        std::fs::remove_file(&exe).ok();
+    }
+    let run = run.map_err(io_err)?;
+    if !run.status.success() {
+        return Err(IfaError::Runtime(format!(
+            "Coop.c: program failed ({}): {}",
+            run.status,
+            String::from_utf8_lossy(&run.stderr).trim_end()
+        )));
+    }
+    Ok((String::from_utf8_lossy(&run.stdout).into_owned(), cached))
+}
+
+/// Convert an IfaValue into plain JSON (maps become objects, lists arrays).
+pub fn ifa_to_json(value: &IfaValue) -> IfaResult<serde_json::Value> {
+    Ok(match value {
+        IfaValue::Null => serde_json::Value::Null,
+        IfaValue::Bool(b) => serde_json::Value::Bool(*b),
+        IfaValue::Int(n) => serde_json::Value::from(*n),
+        IfaValue::Float(f) => serde_json::Number::from_f64(*f)
+            .map(serde_json::Value::Number)
+            .ok_or_else(|| {
+                IfaError::Runtime(format!("Cannot convert {} to JSON (not finite)", f))
+            })?,
+        IfaValue::Str(s) => serde_json::Value::String(s.to_string()),
+        IfaValue::List(items) => {
+            serde_json::Value::Array(items.iter().map(ifa_to_json).collect::<IfaResult<_>>()?)
+        }
+        IfaValue::Map(map) => {
+            let mut obj = serde_json::Map::new();
+            for (k, v) in map.iter() {
+                obj.insert(k.to_string(), ifa_to_json(v)?);
+            }
+            serde_json::Value::Object(obj)
+        }
+        other => {
+            return Err(IfaError::Runtime(format!(
+                "Cannot convert {} to JSON",
+                other.type_name()
+            )));
+        }
+    })
+}
+
+/// Convert plain JSON back into an IfaValue.
+pub(crate) fn json_to_ifa(value: serde_json::Value) -> IfaValue {
+    match value {
+        serde_json::Value::Null => IfaValue::null(),
+        serde_json::Value::Bool(b) => IfaValue::bool(b),
+        serde_json::Value::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                IfaValue::int(i)
+            } else {
+                IfaValue::float(n.as_f64().unwrap_or(0.0))
+            }
+        }
+        serde_json::Value::String(s) => IfaValue::str(s),
+        serde_json::Value::Array(arr) => IfaValue::list(arr.into_iter().map(json_to_ifa).collect()),
+        serde_json::Value::Object(obj) => {
+            IfaValue::map(obj.into_iter().map(|(k, v)| (k, json_to_ifa(v))).collect())
+        }
+    }
+}
+
+/// Run `module.func(*args)` in Python and parse its JSON result.
+fn py_json(module: &str, func: &str, args: &[IfaValue]) -> IfaResult<IfaValue> {
+    let payload = serde_json::json!({
+        "module": module,
+        "func": func,
+        "args": args.iter().map(ifa_to_json).collect::<IfaResult<Vec<_>>>()?,
+    });
+
+    let mut child = Command::new(python_executable())
+        .arg("-c")
+        .arg(PY_JSON_WRAPPER)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| IfaError::Runtime(format!("Cannot start Python: {}", e)))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(payload.to_string().as_bytes())
+            .map_err(|e| IfaError::Runtime(format!("Cannot send args to Python: {}", e)))?;
+    }
+
+    let out = child
+        .wait_with_output()
+        .map_err(|e| IfaError::Runtime(format!("Python call failed: {}", e)))?;
+    if !out.status.success() {
+        return Err(IfaError::Runtime(format!(
+            "Python {}.{} failed: {}",
+            module,
+            func,
+            String::from_utf8_lossy(&out.stderr).trim()
+        )));
+    }
+
+    let json: serde_json::Value = serde_json::from_slice(&out.stdout)
+        .map_err(|e| IfaError::Runtime(format!("Invalid JSON from Python: {}", e)))?;
+    Ok(json_to_ifa(json))
+}
+
+/// Call the exported `func` of the WebAssembly module at `path`.
+///
+/// Arguments are converted to the export's declared numeric parameter types;
+/// a single result is returned as-is, several as a list.
+#[cfg(feature = "wasm")]
+fn wasm_call(path: &str, func: &str, args: &[IfaValue]) -> IfaResult<IfaValue> {
+    use wasmtime::{Engine, Instance, Module, Store, Val, ValType};
+
+    let engine = Engine::default();
+    let module = Module::from_file(&engine, path)
+        .map_err(|e| IfaError::Runtime(format!("Cannot load WASM module {}: {}", path, e)))?;
+    let mut store = Store::new(&engine, ());
+    let instance = Instance::new(&mut store, &module, &[])
+        .map_err(|e| IfaError::Runtime(format!("Cannot instantiate {}: {}", path, e)))?;
+    let export = instance
+        .get_func(&mut store, func)
+        .ok_or_else(|| IfaError::Runtime(format!("WASM module has no function '{}'", func)))?;
+
+    let ty = export.ty(&store);
+    if ty.params().len() != args.len() {
+        return Err(IfaError::ArgumentError(format!(
+            "{} expects {} arguments, got {}",
+            func,
+            ty.params().len(),
+            args.len()
+        )));
+    }
+    let params = ty
+        .params()
+        .zip(args)
+        .map(|(param, arg)| match (param, arg) {
+            (ValType::I32, IfaValue::Int(n)) => i32::try_from(*n)
+                .map(Val::I32)
+                .map_err(|_| IfaError::Runtime(format!("{} does not fit in i32", n))),
+            (ValType::I64, IfaValue::Int(n)) => Ok(Val::I64(*n)),
+            (ValType::F32, IfaValue::Int(n)) => Ok(Val::F32((*n as f32).to_bits())),
+            (ValType::F32, IfaValue::Float(f)) => Ok(Val::F32((*f as f32).to_bits())),
+            (ValType::F64, IfaValue::Int(n)) => Ok(Val::F64((*n as f64).to_bits())),
+            (ValType::F64, IfaValue::Float(f)) => Ok(Val::F64(f.to_bits())),
+            (param, arg) => Err(IfaError::TypeError {
+                expected: format!("{} argument", param),
+                got: arg.type_name().to_string(),
+            }),
+        })
+        .collect::<IfaResult<Vec<_>>>()?;
+
+    let mut results = vec![Val::I32(0); ty.results().len()];
+    export
+        .call(&mut store, &params, &mut results)
+        .map_err(|e| IfaError::Runtime(format!("WASM {} trapped: {}", func, e)))?;
+
+    let mut values = results
+        .into_iter()
+        .map(|v| match v {
+            Val::I32(n) => Ok(IfaValue::int(n as i64)),
+            Val::I64(n) => Ok(IfaValue::int(n)),
+            Val::F32(bits) => Ok(IfaValue::float(f32::from_bits(bits) as f64)),
+            Val::F64(bits) => Ok(IfaValue::float(f64::from_bits(bits))),
+            other => Err(IfaError::Runtime(format!(
+                "Unsupported WASM result type: {:?}",
+                other
+            ))),
+        })
+        .collect::<IfaResult<Vec<_>>>()?;
+    Ok(match values.len() {
+        0 => IfaValue::null(),
+        1 => values.remove(0),
+        _ => IfaValue::list(values),
+    })
+}
+
+#[cfg(not(feature = "wasm"))]
+fn wasm_call(_path: &str, _func: &str, _args: &[IfaValue]) -> IfaResult<IfaValue> {
+    Err(feature_required("Coop.wasm", "wasm"))
+}
+
+impl OduHandler for CoopHandler {
+    fn domain(&self) -> OduDomain {
+        OduDomain::Coop
+    }
+
+    fn call(
+        &self,
+        method: &str,
+        args: Vec<IfaValue>,
+        _env: &EnvRef,
+        _output: &mut Vec<String>,
+    ) -> IfaResult<IfaValue> {
+        let path = std::env::var_os("PATH").unwrap_or_default();
+        match method {
+            // Whether a bridge language's tool is installed
+            "wa" | "available" => match args.first() {
+                Some(IfaValue::Str(language)) => {
+                    bridge_available(language, &path).map(IfaValue::bool)
+                }
+                _ => Err(IfaError::ArgumentError(
+                    "available requires a language name".into(),
+                )),
+            },
+
+            // Names of the bridge languages whose tools are installed
+            "awọn_afara" | "bridges" => {
+                let mut found = Vec::new();
+                for language in BRIDGE_LANGUAGES {
+                    if bridge_available(language, &path)? {
+                        found.push(IfaValue::str(*language));
+                    }
+                }
+                Ok(IfaValue::list(found))
+            }
+
+            // Call a Python function with JSON-encoded args and result
+            "py_json" => match (args.first(), args.get(1), args.get(2)) {
+                (Some(IfaValue::Str(module)), Some(IfaValue::Str(func)), call_args) => {
+                    let call_args = match call_args {
+                        Some(IfaValue::List(list)) => list.to_vec(),
+                        None => Vec::new(),
+                        Some(_) => {
+                            return Err(IfaError::Runtime("py_json args must be a list".into()));
+                        }
+                    };
+                    self.check_python_call(module, func)?;
+                    py_json(module, func, &call_args)
+                }
+                _ => Err(IfaError::Runtime(
+                    "py_json requires module, function name and args list".into(),
+                )),
+            },
+
+            // Compile and run a C program, returning what it printed
+            "c" => match args.first() {
+                Some(IfaValue::Str(source)) => {
+                    let build = CBuild::from_options(args.get(1))?;
+                    let (stdout, _cached) = run_c(source, &build, &c_cache_dir()?)?;
+                    Ok(IfaValue::str(stdout))
+                }
+                _ => Err(IfaError::ArgumentError(
+                    "c requires C source code and an optional options map".into(),
+                )),
+            },
+
+            // Call an exported WebAssembly function with numeric args
+            "wasm" => match (args.first(), args.get(1), args.get(2)) {
+                (Some(IfaValue::Str(path)), Some(IfaValue::Str(func)), call_args) => {
+                    let call_args = match call_args {
+                        Some(IfaValue::List(list)) => list.to_vec(),
+                        None => Vec::new(),
+                        Some(_) => {
+                            return Err(IfaError::Runtime("wasm args must be a list".into()));
+                        }
+                    };
+                    if let Some(bad) = call_args
+                        .iter()
+                        .find(|a| !matches!(a, IfaValue::Int(_) | IfaValue::Float(_)))
+                    {
+                        return Err(IfaError::TypeError {
+                            expected: "numeric wasm argument".into(),
+                            got: bad.type_name().to_string(),
+                        });
+                    }
+                    wasm_call(path, func, &call_args)
+                }
+                _ => Err(IfaError::Runtime(
+                    "wasm requires module path, function name and args list".into(),
+                )),
+            },
+
+            _ => Err(IfaError::Runtime(format!(
+                "Unknown Coop method: {}",
+                method
+            ))),
+        }
+    }
+
+    fn methods(&self) -> &'static [&'static str] {
+        &[
+            "wa",
+            "available",
+            "awọn_afara",
+            "bridges",
+            "py_json",
+            "c",
+            "wasm",
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bridge_available_follows_path() {
+        let dir = std::env::temp_dir().join(format!("ifa_coop_path_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let node = dir.join(if cfg!(windows) { "node.exe" } else { "node" });
+        std::fs::write(&node, "").unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&node, std::fs::Permissions::from_mode(0o755)).unwrap();
+        }
+        let path = std::env::join_paths([&dir]).unwrap();
+
+        let js = bridge_available("js", &path);
+        let c = bridge_available("c", &path);
+        let cobol = bridge_available("cobol", &path);
+        std::fs::remove_dir_all(&dir).ok();
+
+        assert!(js.unwrap());
+        assert!(!c.unwrap());
+        assert!(matches!(cobol, Err(IfaError::ArgumentError(_))));
+    }
+
+    #[test]
+    fn test_bridge_tool_matches_language_names_exactly() {
+        assert_eq!(bridge_tool("c"), Some("cc"));
+        assert_eq!(bridge_tool("js"), Some("node"));
+        assert_eq!(bridge_tool("python"), Some(python_executable()));
+        for name in ["cc", "C", "c++", "py", "javascript", ""] {
+            assert_eq!(bridge_tool(name), None, "{:?}", name);
+        }
+    }
+
+    /// A stand-in compiler that logs its arguments and "compiles" every
+    /// source into a script printing `built`
+    #[cfg(unix)]
+    fn fake_compiler(dir: &Path) -> (PathBuf, PathBuf) {
+        use std::os::unix::fs::PermissionsExt;
+        let log = dir.join("compiler.log");
+        let compiler = dir.join("fakecc");
+        std::fs::write(
+            &compiler,
+            format!(
+                "#!/bin/sh\necho \"$*\" >> '{}'\nfor a; do out=$a; done\n\
+                 printf '#!/bin/sh\\necho built\\n' > \"$out\"\nchmod +x \"$out\"\n",
+                log.display()
+            ),
+        )
+        .unwrap();
+        std::fs::set_permissions(&compiler, std::fs::Permissions::from_mode(0o755)).unwrap();
+        (compiler, log)
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_c_build_passes_flags_and_reuses_cache() {
+        let dir = std::env::temp_dir().join(format!("ifa_coop_c_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let (compiler, log) = fake_compiler(&dir);
+
+        let mut options = std::collections::HashMap::new();
+        options.insert(
+            "compiler".to_string(),
+            IfaValue::str(compiler.to_string_lossy()),
+        );
+        options.insert(
+            "flags".to_string(),
+            IfaValue::list(vec![IfaValue::str("-O2"), IfaValue::str("-Iinclude")]),
+        );
+        let build = CBuild::from_options(Some(&IfaValue::map(options))).unwrap();
+        let cache = dir.join("cache");
+
+        let first = run_c("int main(void) { return 0; }", &build, &cache).unwrap();
+        let second = run_c("int main(void) { return 0; }", &build, &cache).unwrap();
+        let changed = run_c("int main(void) { return 1; }", &build, &cache).unwrap();
+        let uncached = CBuild {
+            cache: false,
+            ..build.clone()
+        };
+        let fresh = run_c("int main(void) { return 0; }", &uncached, &cache).unwrap();
+        let invocations = std::fs::read_to_string(&log).unwrap();
+        std::fs::remove_dir_all(&dir).ok();
+
+        assert_eq!(first, ("built\n".to_string(), false));
+        assert_eq!(second, ("built\n".to_string(), true));
+        assert_eq!(changed, ("built\n".to_string(), false));
+        assert_eq!(fresh, ("built\n".to_string(), false));
+        // One compile per distinct snippet, plus one for the uncached run
+        let lines: Vec<&str> = invocations.lines().collect();
+        assert_eq!(lines.len(), 3, "{}", invocations);
+        assert!(lines.iter().all(|line| line.starts_with("-O2 -Iinclude ")));
+        assert!(lines.iter().all(|line| line.contains(".c -o ")));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_c_cache_rejects_shared_dirs_and_rebuilds_foreign_executables() {
+        use std::os::unix::fs::PermissionsExt;
+        let dir = std::env::temp_dir().join(format!("ifa_coop_c_trust_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let (compiler, log) = fake_compiler(&dir);
+        let mut options = std::collections::HashMap::new();
+        options.insert(
+            "compiler".to_string(),
+            IfaValue::str(compiler.to_string_lossy()),
+        );
+        let build = CBuild::from_options(Some(&IfaValue::map(options))).unwrap();
+        let source = "int main(void) { return 0; }";
+
+        // A world-writable cache (like a shared /tmp directory) is refused
+        let shared = dir.join("shared");
+        std::fs::create_dir(&shared).unwrap();
+        std::fs::set_permissions(&shared, std::fs::Permissions::from_mode(0o777)).unwrap();
+        let shared_err = run_c(source, &build, &shared).unwrap_err();
+
+        // So is a symlink to a directory
+        let linked = dir.join("linked");
+        std::os::unix::fs::symlink(&dir, &linked).unwrap();
+        let linked_err = run_c(source, &build, &linked).unwrap_err();
+
+        // A planted executable others could have written is rebuilt, not run
+        let cache = dir.join("cache");
+        private_dir(&cache).unwrap();
+        let exe = cache.join(build.key(source));
+        std::fs::write(&exe, "#!/bin/sh\necho planted\n").unwrap();
+        std::fs::set_permissions(&exe, std::fs::Permissions::from_mode(0o777)).unwrap();
+        let rebuilt = run_c(source, &build, &cache).unwrap();
+        let reused = run_c(source, &build, &cache).unwrap();
+        let cache_mode = std::fs::metadata(&cache).unwrap().permissions().mode();
+        let compiles = std::fs::read_to_string(&log).unwrap().lines().count();
+        std::fs::remove_dir_all(&dir).ok();
+
+        for err in [shared_err, linked_err] {
+            assert!(err.to_string().contains("mode 0700"), "{}", err);
+        }
+        assert_eq!(cache_mode & 0o777, 0o700);
+        assert_eq!(rebuilt, ("built\n".to_string(), false));
+        assert_eq!(reused, ("built\n".to_string(), true));
+        assert_eq!(compiles, 1);
+    }
+
+    #[test]
+    fn test_c_options_are_type_checked() {
+        assert_eq!(CBuild::from_options(None).unwrap().compiler, "cc");
+        let mut options = std::collections::HashMap::new();
+        options.insert("flags".to_string(), IfaValue::str("-O2"));
+        let err = CBuild::from_options(Some(&IfaValue::map(options))).unwrap_err();
+        assert!(err.to_string().contains("flags"), "{}", err);
+    }
+}
@@ -98,20 +98,31 @@ impl OduHandler for FidioHandler {
                 ))
             }
 
-            // Get video info (width, height, fps)
+            // Get video info: size and container format from the file itself.
+            // Width, height and fps need a decoder (ffmpeg-next), so they stay
+            // null rather than reporting made-up values.
             "alaye" | "info" => {
                 if let Some(IfaValue::Str(path)) = args.first() {
                     output.push(format!("[VIDEO] Getting info for: {}", path));
+                    let meta = std::fs::metadata(&**path).map_err(|e| {
+                        IfaError::Runtime(format!("info: cannot read {}: {}", path, e))
+                    })?;
+                    let format = std::path::Path::new(&**path)
+                        .extension()
+                        .map(|ext| IfaValue::str(ext.to_string_lossy().to_lowercase()))
+                        .unwrap_or(IfaValue::Null);
                     return Ok(IfaValue::map(std::collections::HashMap::from([
-                        ("width".into(), IfaValue::int(1920)),
-                        ("height".into(), IfaValue::int(1080)),
-                        ("fps".into(), IfaValue::float(30.0)),
+                        ("path".into(), IfaValue::str(&**path)),
+                        ("bytes".into(), IfaValue::int(meta.len() as i64)),
+                        ("format".into(), format),
+                        ("width".into(), IfaValue::Null),
+                        ("height".into(), IfaValue::Null),
+                        ("fps".into(), IfaValue::Null),
                     ])));
                 }
                 Err(IfaError::Runtime("info requires video path".into()))
             }
 
-
             _ => Err(IfaError::Runtime(format!(
                 "Unknown Fidio method: {}",
                 method
@@ -4,13 +4,49 @@
 //! Binary pattern: 0100
 
 use crate::error::{IfaError, IfaResult};
+use crate::interpreter::core::{DEFAULT_COLLECTION_LIMIT, DEFAULT_STRING_LIMIT};
 use crate::lexer::OduDomain;
 use crate::value::IfaValue;
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
 
-use super::{EnvRef, OduHandler};
+use super::{EnvRef, OduHandler, check_collection_size};
 
 /// Handler for Ìká (Strings) domain.
-pub struct IkaHandler;
+pub struct IkaHandler {
+    max_len: usize,
+    max_items: usize,
+}
+
+impl IkaHandler {
+    /// Refuse to `repeat` or pad past `max_len` bytes, before allocating
+    pub fn new(max_len: usize) -> Self {
+        Self {
+            max_len,
+            max_items: DEFAULT_COLLECTION_LIMIT,
+        }
+    }
+
+    /// Refuse to split a string into more than `max_items` parts, before
+    /// allocating the list
+    pub fn with_collection_limit(mut self, max_items: usize) -> Self {
+        self.max_items = max_items;
+        self
+    }
+
+    fn check_len(&self, len: Option<usize>) -> IfaResult<()> {
+        match len {
+            Some(len) if len <= self.max_len => Ok(()),
+            _ => Err(IfaError::Runtime("string length limit exceeded".into())),
+        }
+    }
+}
+
+impl Default for IkaHandler {
+    fn default() -> Self {
+        Self::new(DEFAULT_STRING_LIMIT)
+    }
+}
 
 impl OduHandler for IkaHandler {
     fn domain(&self) -> OduDomain {
@@ -65,6 +101,12 @@ impl OduHandler for IkaHandler {
             "pin" | "split" => {
                 if let (Some(str_val), Some(delim_val)) = (arg0, args.get(1)) {
                     if let (IfaValue::Str(s), IfaValue::Str(delim)) = (str_val, delim_val) {
+                        let count = if delim.is_empty() {
+                            s.chars().count() + 2
+                        } else {
+                            s.matches(delim.as_ref()).count() + 1
+                        };
+                        check_collection_size(count, self.max_items)?;
                         let parts: Vec<IfaValue> =
                             s.split(delim.as_ref()).map(IfaValue::str).collect();
                         return Ok(IfaValue::list(parts));
@@ -75,6 +117,30 @@ impl OduHandler for IkaHandler {
                 ))
             }
 
+            // Split into at most `n` parts; the last keeps the remainder
+            "pin_opin" | "split_limit" => match (arg0, args.get(1), args.get(2)) {
+                (Some(IfaValue::Str(s)), Some(IfaValue::Str(delim)), Some(IfaValue::Int(n))) => {
+                    if delim.is_empty() {
+                        return Err(IfaError::Runtime(
+                            "split_limit delimiter cannot be empty".into(),
+                        ));
+                    }
+                    let n = usize::try_from(*n).ok().filter(|n| *n > 0).ok_or_else(|| {
+                        IfaError::Runtime(format!("split_limit count must be at least 1: {}", n))
+                    })?;
+                    check_collection_size(
+                        n.min(s.matches(delim.as_ref()).count() + 1),
+                        self.max_items,
+                    )?;
+                    Ok(IfaValue::list(
+                        s.splitn(n, delim.as_ref()).map(IfaValue::str).collect(),
+                    ))
+                }
+                _ => Err(IfaError::Runtime(
+                    "split_limit requires string, delimiter and count".into(),
+                )),
+            },
+
             // Trim whitespace
             "trim" => {
                 if let Some(val) = arg0 {
@@ -88,6 +154,22 @@ impl OduHandler for IkaHandler {
                 }
             }
 
+            // Collapse every whitespace run to one space, trimming the ends
+            "normalize_spaces" => match arg0 {
+                Some(IfaValue::Str(s)) => Ok(IfaValue::str(
+                    s.split_whitespace().collect::<Vec<_>>().join(" "),
+                )),
+                _ => Err(IfaError::Runtime(
+                    "normalize_spaces requires a string".into(),
+                )),
+            },
+
+            // URL-safe identifier: see `slugify`
+            "slugify" => match arg0 {
+                Some(IfaValue::Str(s)) => Ok(IfaValue::str(slugify(s))),
+                _ => Err(IfaError::Runtime("slugify requires a string".into())),
+            },
+
             // Uppercase
             "nla" | "uppercase" | "upper" => {
                 if let Some(val) = arg0 {
@@ -142,31 +224,176 @@ impl OduHandler for IkaHandler {
                 ))
             }
 
-            // Substring
-            "sub" | "substring" | "slice" => {
-                if let (Some(s_val), Some(start_val)) = (arg0, args.get(1)) {
-                    if let (IfaValue::Str(s), IfaValue::Int(start)) = (s_val, start_val) {
-                        let start = *start as usize;
-                        let len = args
-                            .get(2)
-                            .and_then(|v| {
-                                if let IfaValue::Int(n) = v {
-                                    Some(*n as usize)
-                                } else {
-                                    None
-                                }
-                            })
-                            .unwrap_or_else(|| s.len().saturating_sub(start));
-
-                        // Safe substring via chars
-                        let result: String = s.chars().skip(start).take(len).collect();
-                        return Ok(IfaValue::str(result));
-                    }
+            // Character at index (Unicode scalar values, negative from the end)
+            "ipo" | "char_at" | "at" => match (arg0, args.get(1)) {
+                (Some(IfaValue::Str(s)), Some(IfaValue::Int(i))) => char_at(s, *i),
+                _ => Err(IfaError::Runtime(
+                    "char_at requires string and index".into(),
+                )),
+            },
+
+            // Substring `start..end` in characters; `end` defaults to the string end
+            "sub" | "substring" | "slice" => match (arg0, args.get(1), args.get(2)) {
+                (Some(IfaValue::Str(s)), Some(IfaValue::Int(start)), end) => {
+                    let end = match end {
+                        None => None,
+                        Some(IfaValue::Int(e)) => Some(*e),
+                        Some(_) => {
+                            return Err(IfaError::Runtime(
+                                "substring end must be an integer".into(),
+                            ));
+                        }
+                    };
+                    substring(s, *start, end)
                 }
-                Err(IfaError::Runtime(
+                _ => Err(IfaError::Runtime(
                     "substring requires string and start index".into(),
-                ))
-            }
+                )),
+            },
+
+            // Pad to `width` characters with `fill` (default space)
+            "pad_left" | "pad_right" => match (arg0, args.get(1), args.get(2)) {
+                (Some(IfaValue::Str(s)), Some(IfaValue::Int(width)), fill) => {
+                    let fill = match fill {
+                        None => ' ',
+                        Some(IfaValue::Str(f)) if f.chars().count() == 1 => {
+                            f.chars().next().unwrap_or(' ')
+                        }
+                        Some(_) => {
+                            return Err(IfaError::Runtime(
+                                "pad fill must be a single character".into(),
+                            ));
+                        }
+                    };
+                    let missing = usize::try_from(*width)
+                        .unwrap_or(0)
+                        .saturating_sub(s.chars().count());
+                    self.check_len(
+                        missing
+                            .checked_mul(fill.len_utf8())
+                            .and_then(|n| n.checked_add(s.len())),
+                    )?;
+                    Ok(IfaValue::str(pad(s, missing, fill, method == "pad_left")))
+                }
+                _ => Err(IfaError::Runtime(format!(
+                    "{} requires string and width",
+                    method
+                ))),
+            },
+
+            // Non-overlapping occurrences of a substring
+            "ka" | "count" => match (arg0, args.get(1)) {
+                (Some(IfaValue::Str(s)), Some(IfaValue::Str(sub))) => {
+                    if sub.is_empty() {
+                        return Err(IfaError::Runtime("count substring cannot be empty".into()));
+                    }
+                    Ok(IfaValue::int(s.matches(sub.as_ref()).count() as i64))
+                }
+                _ => Err(IfaError::Runtime(
+                    "count requires string and substring".into(),
+                )),
+            },
+
+            // Repeat a string `n` times
+            "tun" | "repeat" => match (arg0, args.get(1)) {
+                (Some(IfaValue::Str(s)), Some(IfaValue::Int(n))) => {
+                    let n = usize::try_from(*n).map_err(|_| {
+                        IfaError::Runtime(format!("repeat count cannot be negative: {}", n))
+                    })?;
+                    self.check_len(s.len().checked_mul(n))?;
+                    Ok(IfaValue::str(s.repeat(n)))
+                }
+                _ => Err(IfaError::Runtime("repeat requires string and count".into())),
+            },
+
+            // Characters as one-char strings
+            "lẹta" | "chars" => match arg0 {
+                Some(IfaValue::Str(s)) => {
+                    check_collection_size(s.chars().count(), self.max_items)?;
+                    Ok(IfaValue::list(
+                        s.chars().map(|c| IfaValue::str(c.to_string())).collect(),
+                    ))
+                }
+                _ => Err(IfaError::Runtime("chars requires a string".into())),
+            },
+
+            // UTF-8 bytes as Ints
+            "baiti" | "bytes" => match arg0 {
+                Some(IfaValue::Str(s)) => {
+                    check_collection_size(s.len(), self.max_items)?;
+                    Ok(IfaValue::list(
+                        s.bytes().map(|b| IfaValue::int(b as i64)).collect(),
+                    ))
+                }
+                _ => Err(IfaError::Runtime("bytes requires a string".into())),
+            },
+
+            // Lines, split on `\n` or `\r\n`
+            "ila" | "lines" => match arg0 {
+                Some(IfaValue::Str(s)) => {
+                    check_collection_size(s.lines().count(), self.max_items)?;
+                    Ok(IfaValue::list(s.lines().map(IfaValue::str).collect()))
+                }
+                _ => Err(IfaError::Runtime("lines requires a string".into())),
+            },
+
+            // Parse a `{}` / `{N}` template once for repeated `render` calls
+            "ṣe_awoṣe" | "compile_template" => match arg0 {
+                Some(IfaValue::Str(source)) => compile_template(source),
+                _ => Err(IfaError::Runtime(
+                    "compile_template requires a string".into(),
+                )),
+            },
+
+            "kun_awoṣe" | "render" => match (arg0, args.get(1)) {
+                (Some(template), Some(IfaValue::List(values))) => {
+                    render_template(template_parts(template)?, values)
+                }
+                _ => Err(IfaError::Runtime(
+                    "render requires a compiled template and a list of values".into(),
+                )),
+            },
+
+            // One-shot compile and render
+            "ṣeto" | "format" => match (arg0, args.get(1)) {
+                (Some(IfaValue::Str(source)), Some(IfaValue::List(values))) => {
+                    let template = compile_template(source)?;
+                    render_template(template_parts(&template)?, values)
+                }
+                _ => Err(IfaError::Runtime(
+                    "format requires a template string and a list of values".into(),
+                )),
+            },
+
+            // Edit distance in characters, as used for "did you mean" hints
+            "ijinna" | "levenshtein" => match (arg0, args.get(1)) {
+                (Some(IfaValue::Str(a)), Some(IfaValue::Str(b))) => {
+                    Ok(IfaValue::int(levenshtein(a, b) as i64))
+                }
+                _ => Err(IfaError::Runtime("levenshtein requires two strings".into())),
+            },
+
+            // Nearest candidate by edit distance; the first wins a tie, ofo if none
+            "to_sunmọ" | "closest" => match (arg0, args.get(1)) {
+                (Some(IfaValue::Str(target)), Some(IfaValue::List(items))) => {
+                    let candidates = items
+                        .iter()
+                        .map(|item| match item {
+                            IfaValue::Str(s) => Ok(s.as_ref()),
+                            other => Err(IfaError::TypeError {
+                                expected: "Str".into(),
+                                got: other.type_name().into(),
+                            }),
+                        })
+                        .collect::<IfaResult<Vec<&str>>>()?;
+                    Ok(closest(target, candidates)
+                        .map(IfaValue::str)
+                        .unwrap_or(IfaValue::Null))
+                }
+                _ => Err(IfaError::Runtime(
+                    "closest requires a string and a list of strings".into(),
+                )),
+            },
 
             _ => Err(IfaError::Runtime(format!("Unknown Ìká method: {}", method))),
         }
@@ -182,7 +409,11 @@ impl OduHandler for IkaHandler {
             "len",
             "pin",
             "split",
+            "pin_opin",
+            "split_limit",
             "trim",
+            "normalize_spaces",
+            "slugify",
             "nla",
             "uppercase",
             "upper",
@@ -194,9 +425,246 @@ impl OduHandler for IkaHandler {
             "has",
             "ropo",
             "replace",
+            "ipo",
+            "char_at",
+            "at",
             "sub",
             "substring",
             "slice",
+            "ka",
+            "count",
+            "pad_left",
+            "pad_right",
+            "tun",
+            "repeat",
+            "lẹta",
+            "chars",
+            "baiti",
+            "bytes",
+            "ila",
+            "lines",
+            "ṣe_awoṣe",
+            "compile_template",
+            "kun_awoṣe",
+            "render",
+            "ṣeto",
+            "format",
+            "ijinna",
+            "levenshtein",
+            "to_sunmọ",
+            "closest",
         ]
     }
 }
+
+/// Parse `template` into a compiled template: a map holding the source and
+/// its `parts`, where a `Str` part is literal text and an `Int` part is the
+/// index of the value to insert. `{}` takes the next value, `{N}` value `N`,
+/// and `{{` / `}}` are literal braces.
+fn compile_template(template: &str) -> IfaResult<IfaValue> {
+    let invalid =
+        |msg: &str| IfaError::Runtime(format!("Invalid template {:?}: {}", template, msg));
+    let mut parts = Vec::new();
+    let mut text = String::new();
+    let mut next_index = 0i64;
+    let mut chars = template.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                text.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                text.push('}');
+            }
+            '{' => {
+                let mut spec = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => spec.push(c),
+                        None => return Err(invalid("unclosed '{'")),
+                    }
+                }
+                let index = if spec.is_empty() {
+                    next_index += 1;
+                    next_index - 1
+                } else {
+                    spec.trim()
+                        .parse::<u32>()
+                        .map_err(|_| invalid(&format!("bad placeholder '{{{}}}'", spec)))?
+                        as i64
+                };
+                if !text.is_empty() {
+                    parts.push(IfaValue::str(std::mem::take(&mut text)));
+                }
+                parts.push(IfaValue::int(index));
+            }
+            '}' => return Err(invalid("unmatched '}'")),
+            c => text.push(c),
+        }
+    }
+    if !text.is_empty() {
+        parts.push(IfaValue::str(text));
+    }
+    Ok(IfaValue::map(std::collections::HashMap::from([
+        ("template".to_string(), IfaValue::str(template)),
+        ("parts".to_string(), IfaValue::list(parts)),
+    ])))
+}
+
+/// The `parts` of a template made by [`compile_template`].
+fn template_parts(template: &IfaValue) -> IfaResult<&[IfaValue]> {
+    if let IfaValue::Map(map) = template
+        && let Some(IfaValue::List(parts)) = map.get("parts")
+    {
+        return Ok(parts);
+    }
+    Err(IfaError::TypeError {
+        expected: "template from Ika.compile_template".into(),
+        got: template.type_name().into(),
+    })
+}
+
+fn render_template(parts: &[IfaValue], values: &[IfaValue]) -> IfaResult<IfaValue> {
+    let mut out = String::new();
+    for part in parts {
+        match part {
+            IfaValue::Str(text) => out.push_str(text),
+            IfaValue::Int(index) => {
+                let value = values.get(*index as usize).ok_or_else(|| {
+                    IfaError::Runtime(format!(
+                        "Template needs value {} but only {} were given",
+                        index,
+                        values.len()
+                    ))
+                })?;
+                out.push_str(&value.to_string());
+            }
+            other => {
+                return Err(IfaError::TypeError {
+                    expected: "template part".into(),
+                    got: other.type_name().into(),
+                });
+            }
+        }
+    }
+    Ok(IfaValue::str(out))
+}
+
+/// Resolve a possibly negative character index against `len`.
+/// `allow_end` admits `len` itself, for exclusive slice bounds.
+pub(crate) fn resolve_char_index(len: usize, index: i64, allow_end: bool) -> Option<usize> {
+    let resolved = if index < 0 {
+        (len as i64).checked_add(index)?
+    } else {
+        index
+    };
+    let limit = if allow_end {
+        len as i64
+    } else {
+        len as i64 - 1
+    };
+    (0..=limit).contains(&resolved).then_some(resolved as usize)
+}
+
+/// Character of `s` at `index` as a one-character string.
+pub(crate) fn char_at(s: &str, index: i64) -> IfaResult<IfaValue> {
+    let len = s.chars().count();
+    resolve_char_index(len, index, false)
+        .and_then(|i| s.chars().nth(i))
+        .map(|c| IfaValue::str(c.to_string()))
+        .ok_or_else(|| {
+            IfaError::Runtime(format!(
+                "String index {} out of range for length {}",
+                index, len
+            ))
+        })
+}
+
+/// Lowercase `s` into `a-z`, `0-9` and single hyphens, with no hyphen at
+/// either end. Diacritics are stripped, not transliterated: the text is
+/// NFD-decomposed and every combining mark dropped, so Yoruba tone marks and
+/// underdots vanish (`Ọ̀ṣun` -> `osun`, `Ẹ̀kọ́` -> `eko`). Whitespace,
+/// punctuation and any character still outside ASCII after that separate
+/// words.
+fn slugify(s: &str) -> String {
+    let mut slug = String::new();
+    let mut pending_hyphen = false;
+    for c in s.nfd().filter(|c| !is_combining_mark(*c)) {
+        if c.is_ascii_alphanumeric() {
+            if pending_hyphen && !slug.is_empty() {
+                slug.push('-');
+            }
+            pending_hyphen = false;
+            slug.push(c.to_ascii_lowercase());
+        } else {
+            pending_hyphen = true;
+        }
+    }
+    slug
+}
+
+/// Levenshtein distance between `a` and `b`, counted in `char`s rather than
+/// bytes so one accented letter is one edit. Babalawo's "did you mean"
+/// suggestions use this too.
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != *cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// The candidate with the smallest `levenshtein` distance to `target`,
+/// keeping the earliest on a tie.
+pub fn closest<'a>(target: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    candidates
+        .into_iter()
+        .map(|c| (levenshtein(target, c), c))
+        .reduce(|best, next| if next.0 < best.0 { next } else { best })
+        .map(|(_, c)| c)
+}
+
+/// Pad `s` with `fill` up to `width` characters; never truncates.
+fn pad(s: &str, missing: usize, fill: char, left: bool) -> String {
+    let padding: String = std::iter::repeat_n(fill, missing).collect();
+    if left {
+        padding + s
+    } else {
+        s.to_string() + &padding
+    }
+}
+
+fn substring(s: &str, start: i64, end: Option<i64>) -> IfaResult<IfaValue> {
+    let len = s.chars().count();
+    let out_of_range = |i: i64| {
+        IfaError::Runtime(format!(
+            "Substring index {} out of range for length {}",
+            i, len
+        ))
+    };
+    let from = resolve_char_index(len, start, true).ok_or_else(|| out_of_range(start))?;
+    let to = match end {
+        Some(e) => resolve_char_index(len, e, true).ok_or_else(|| out_of_range(e))?,
+        None => len,
+    };
+    if from > to {
+        return Err(IfaError::Runtime(format!(
+            "Substring start {} is after end {}",
+            start,
+            end.unwrap_or(len as i64)
+        )));
+    }
+    Ok(IfaValue::str(
+        s.chars().skip(from).take(to - from).collect::<String>(),
+    ))
+}
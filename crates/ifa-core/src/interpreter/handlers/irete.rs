@@ -195,6 +195,20 @@ impl OduHandler for IreteHandler {
                 ))
             }
 
+            // Compare secrets (tokens, MACs, password hashes) without
+            // leaking where they differ; use this instead of `==` for them
+            "secure_equal" | "constant_time_compare" | "dogba_asiri" => {
+                match (
+                    arg0.and_then(secret_bytes),
+                    args.get(1).and_then(secret_bytes),
+                ) {
+                    (Some(a), Some(b)) => Ok(IfaValue::bool(constant_time_eq(a, b))),
+                    _ => Err(IfaError::ArgumentError(
+                        "secure_equal requires two Str or Bytes values".into(),
+                    )),
+                }
+            }
+
             _ => Err(IfaError::Runtime(format!(
                 "Unknown Ìrẹtẹ̀ method: {}",
                 method
@@ -220,6 +234,27 @@ impl OduHandler for IreteHandler {
             "fọwọsi_ọrọigbaniwọle",
             "verify_password",
             "rii_daju_ọrọigbaniwọle",
+            "secure_equal",
+            "constant_time_compare",
+            "dogba_asiri",
         ]
     }
 }
+
+fn secret_bytes(value: &IfaValue) -> Option<&[u8]> {
+    match value {
+        IfaValue::Str(s) => Some(s.as_bytes()),
+        IfaValue::Bytes(b) => Some(b),
+        _ => None,
+    }
+}
+
+/// Same algorithm as `ifa_std::stacks::crypto::constant_time_compare`: only
+/// the length check exits early, the contents are always fully scanned.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let diff = a.iter().zip(b).fold(0u8, |diff, (x, y)| diff | (x ^ y));
+    std::hint::black_box(diff) == 0
+}
@@ -3,6 +3,8 @@
 //! Handles console input/output operations.
 //! Binary pattern: 1100
 
+use std::cell::Cell;
+use std::collections::BTreeSet;
 
 use crate::error::{IfaError, IfaResult};
 use crate::lexer::OduDomain;
@@ -11,7 +13,58 @@ use crate::value::IfaValue;
 use super::{EnvRef, OduHandler};
 
 /// Handler for Ìrosù (Console I/O) domain.
-pub struct IrosuHandler;
+///
+/// Output is always captured in the interpreter's buffer; `echo` controls
+/// whether it is also written to the host console.
+pub struct IrosuHandler {
+    echo: bool,
+    /// Redraw progress bars in place with `\r` instead of one line each
+    tty: bool,
+    /// Index in the output buffer of the progress bar on screen, waiting
+    /// for `progress_done`
+    bar_line: Cell<Option<usize>>,
+}
+
+/// Width of `Irosu.progress` bars, in cells
+const PROGRESS_WIDTH: usize = 30;
+
+impl IrosuHandler {
+    /// Handler that captures output and, if `echo` is set, prints it too.
+    /// Progress bars redraw in place when echoing to a terminal.
+    pub fn new(echo: bool) -> Self {
+        #[cfg(feature = "native")]
+        let tty = echo && std::io::IsTerminal::is_terminal(&std::io::stdout());
+        #[cfg(not(feature = "native"))]
+        let tty = false;
+        Self {
+            echo,
+            tty,
+            bar_line: Cell::new(None),
+        }
+    }
+
+    /// Override terminal detection for progress bars
+    pub fn with_tty(mut self, tty: bool) -> Self {
+        self.tty = tty;
+        self
+    }
+
+    /// Move past the progress bar on screen, if any, so the next line
+    /// doesn't overwrite it
+    fn end_bar(&self) {
+        #[cfg(feature = "native")]
+        if self.echo && self.tty && self.bar_line.get().is_some() {
+            println!();
+        }
+        self.bar_line.set(None);
+    }
+}
+
+impl Default for IrosuHandler {
+    fn default() -> Self {
+        Self::new(true)
+    }
+}
 
 impl OduHandler for IrosuHandler {
     fn domain(&self) -> OduDomain {
@@ -29,10 +82,11 @@ impl OduHandler for IrosuHandler {
             "fo" | "sọ" | "so" | "print" | "println" => {
                 let line_parts: Vec<String> = args.iter().map(|a| a.to_string()).collect();
                 let line = line_parts.join(" ");
-                
+                self.end_bar();
+
                 // Native host output
                 #[cfg(feature = "native")]
-                {
+                if self.echo {
                     if method == "fo" || method == "println" {
                         println!("{}", line);
                     } else {
@@ -46,10 +100,79 @@ impl OduHandler for IrosuHandler {
                 Ok(IfaValue::null())
             }
 
+            // Aligned table of a list of maps
+            "tabili" | "table" => {
+                let rows = match args.first() {
+                    Some(IfaValue::List(rows)) => rows,
+                    other => {
+                        return Err(IfaError::TypeError {
+                            expected: "list of maps".to_string(),
+                            got: other.map_or("nothing", |v| v.type_name()).to_string(),
+                        });
+                    }
+                };
+                self.end_bar();
+                for line in render_table(rows)? {
+                    #[cfg(feature = "native")]
+                    if self.echo {
+                        println!("{}", line);
+                    }
+                    output.push(line);
+                }
+                Ok(IfaValue::null())
+            }
+
+            // Progress bar: one updating line on a terminal, plain lines otherwise
+            "ilọsiwaju" | "progress" => {
+                let current = progress_number(args.first(), "current")?;
+                let total = progress_number(args.get(1), "total")?;
+                if total <= 0.0 {
+                    return Err(IfaError::ArgumentError(
+                        "progress total must be positive".into(),
+                    ));
+                }
+                let label = args.get(2).map(|a| a.to_string()).unwrap_or_default();
+                let bar = render_progress(current / total, &label);
+
+                if self.tty {
+                    #[cfg(feature = "native")]
+                    if self.echo {
+                        use std::io::Write;
+                        print!("\r{}", bar);
+                        std::io::stdout().flush().ok();
+                    }
+                    // The bar is redrawn in place, so keep only its latest
+                    // state, unless something else was written since
+                    let line = format!("\r{}", bar);
+                    match self.bar_line.get() {
+                        Some(i) if i + 1 == output.len() && output[i].starts_with('\r') => {
+                            output[i] = line;
+                        }
+                        _ => {
+                            self.bar_line.set(Some(output.len()));
+                            output.push(line);
+                        }
+                    }
+                } else {
+                    #[cfg(feature = "native")]
+                    if self.echo {
+                        println!("{}", bar);
+                    }
+                    output.push(bar);
+                }
+                Ok(IfaValue::null())
+            }
+
+            // Finish the progress bar line
+            "ilọsiwaju_pari" | "progress_done" => {
+                self.end_bar();
+                Ok(IfaValue::null())
+            }
+
             // Read input
             "ka" | "input" | "listen" | "gbo" => {
                 #[cfg(feature = "native")]
-                {
+                if self.echo {
                     use std::io::{self, Write};
                     print!("> ");
                     io::stdout().flush().ok();
@@ -58,19 +181,16 @@ impl OduHandler for IrosuHandler {
                     return Ok(IfaValue::str(input.trim()));
                 }
 
-                #[cfg(not(feature = "native"))]
-                {
-                    output.push("[input] requested".into());
-                    Ok(IfaValue::str(""))
-                }
+                output.push("[input] requested".into());
+                Ok(IfaValue::str(""))
             }
 
             // Error output
             "kigbe" | "error" => {
                 let msg = args.first().map(|a| a.to_string()).unwrap_or_default();
-                
+
                 #[cfg(feature = "native")]
-                {
+                if self.echo {
                     eprintln!("[ERROR] {}", msg);
                 }
 
@@ -87,7 +207,173 @@ impl OduHandler for IrosuHandler {
 
     fn methods(&self) -> &'static [&'static str] {
         &[
-            "fo", "sọ", "so", "print", "println", "ka", "input", "listen", "gbo", "kigbe", "error",
+            "fo",
+            "sọ",
+            "so",
+            "print",
+            "println",
+            "tabili",
+            "table",
+            "ilọsiwaju",
+            "progress",
+            "ilọsiwaju_pari",
+            "progress_done",
+            "ka",
+            "input",
+            "listen",
+            "gbo",
+            "kigbe",
+            "error",
         ]
     }
 }
+
+/// A `progress` argument as a number
+fn progress_number(value: Option<&IfaValue>, name: &str) -> IfaResult<f64> {
+    match value {
+        Some(IfaValue::Int(n)) => Ok(*n as f64),
+        Some(IfaValue::Float(f)) => Ok(*f),
+        Some(other) => Err(IfaError::TypeError {
+            expected: "number".to_string(),
+            got: other.type_name().to_string(),
+        }),
+        None => Err(IfaError::ArgumentError(format!(
+            "progress requires {} and total",
+            name
+        ))),
+    }
+}
+
+/// `[#####-----]  50% label` for a completed `fraction`, clamped to 0..=1
+fn render_progress(fraction: f64, label: &str) -> String {
+    let fraction = if fraction.is_nan() {
+        0.0
+    } else {
+        fraction.clamp(0.0, 1.0)
+    };
+    let filled = (fraction * PROGRESS_WIDTH as f64).round() as usize;
+    let bar = format!(
+        "[{}{}] {:>3}%",
+        "#".repeat(filled),
+        "-".repeat(PROGRESS_WIDTH - filled),
+        (fraction * 100.0).floor() as u32
+    );
+    if label.is_empty() {
+        bar
+    } else {
+        format!("{} {}", bar, label)
+    }
+}
+
+/// Render `rows` as an ASCII table, one output line per returned string.
+///
+/// Columns are the union of all row keys in sorted order (maps carry no
+/// insertion order); a row without a key gets an empty cell.
+fn render_table(rows: &[IfaValue]) -> IfaResult<Vec<String>> {
+    let mut maps = Vec::with_capacity(rows.len());
+    for row in rows {
+        match row {
+            IfaValue::Map(map) => maps.push(map),
+            other => {
+                return Err(IfaError::TypeError {
+                    expected: "map row".to_string(),
+                    got: other.type_name().to_string(),
+                });
+            }
+        }
+    }
+
+    let columns: BTreeSet<&str> = maps.iter().flat_map(|m| m.keys().map(|k| &**k)).collect();
+    let columns: Vec<&str> = columns.into_iter().collect();
+    let cells: Vec<Vec<String>> = maps
+        .iter()
+        .map(|m| {
+            columns
+                .iter()
+                .map(|c| m.get(*c).map(|v| v.to_string()).unwrap_or_default())
+                .collect()
+        })
+        .collect();
+    let widths: Vec<usize> = columns
+        .iter()
+        .enumerate()
+        .map(|(i, c)| {
+            cells
+                .iter()
+                .map(|row| row[i].chars().count())
+                .fold(c.chars().count(), usize::max)
+        })
+        .collect();
+
+    let mut border = String::from("+");
+    for w in &widths {
+        border.push_str(&"-".repeat(w + 2));
+        border.push('+');
+    }
+    let format_row = |row: &[&str]| {
+        let mut line = String::from("|");
+        for (cell, w) in row.iter().zip(&widths) {
+            let pad = w - cell.chars().count();
+            line.push_str(&format!(" {}{} |", cell, " ".repeat(pad)));
+        }
+        line
+    };
+
+    let mut lines = vec![border.clone(), format_row(&columns), border.clone()];
+    for row in &cells {
+        let row: Vec<&str> = row.iter().map(String::as_str).collect();
+        lines.push(format_row(&row));
+    }
+    lines.push(border);
+    Ok(lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interpreter::Environment;
+
+    #[test]
+    fn test_tty_progress_redraws_with_carriage_return() {
+        let handler = IrosuHandler::new(false).with_tty(true);
+        let env = Environment::new();
+        let mut output = Vec::new();
+        for current in [1, 2] {
+            let args = vec![
+                IfaValue::Int(current),
+                IfaValue::Int(4),
+                IfaValue::str("io"),
+            ];
+            handler.call("progress", args, &env, &mut output).unwrap();
+        }
+        handler
+            .call("progress_done", vec![], &env, &mut output)
+            .unwrap();
+
+        let bar = format!("[{}{}]", "#".repeat(15), "-".repeat(15));
+        assert_eq!(output, [format!("\r{}  50% io", bar)]);
+    }
+
+    #[test]
+    fn test_tty_progress_keeps_lines_written_after_it() {
+        let handler = IrosuHandler::new(false).with_tty(true);
+        let env = Environment::new();
+        let mut output = Vec::new();
+        let progress = |output: &mut Vec<String>, current| {
+            let args = vec![IfaValue::Int(current), IfaValue::Int(2)];
+            handler.call("progress", args, &env, output).unwrap();
+        };
+        progress(&mut output, 1);
+        output.push("written by another domain".into());
+        progress(&mut output, 2);
+        handler
+            .call("fo", vec![IfaValue::str("done")], &env, &mut output)
+            .unwrap();
+
+        assert_eq!(output.len(), 4, "{:?}", output);
+        assert!(output[0].contains(" 50%"), "{:?}", output);
+        assert_eq!(output[1], "written by another domain");
+        assert!(output[2].contains("100%"), "{:?}", output);
+        assert_eq!(output[3], "done");
+    }
+}
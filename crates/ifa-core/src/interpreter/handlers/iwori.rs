@@ -3,17 +3,27 @@
 //! Handles time and date operations.
 //! Binary pattern: 0110
 
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::sync::OnceLock;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
 use crate::error::{IfaError, IfaResult};
 use crate::lexer::OduDomain;
 use crate::value::IfaValue;
+use ifa_types::IntRange;
 
 use super::{EnvRef, OduHandler};
 
 /// Handler for Ìwòrì (Time/DateTime) domain.
 pub struct IworiHandler;
 
+/// Nanoseconds on a monotonic clock, counted from the first reading in this
+/// process. Unaffected by changes to the system clock.
+pub(crate) fn monotonic_nanos() -> i64 {
+    static BASE: OnceLock<Instant> = OnceLock::new();
+    let base = *BASE.get_or_init(Instant::now);
+    i64::try_from(base.elapsed().as_nanos()).unwrap_or(i64::MAX)
+}
+
 impl OduHandler for IworiHandler {
     fn domain(&self) -> OduDomain {
         OduDomain::Iwori
@@ -27,7 +37,6 @@ impl OduHandler for IworiHandler {
         _output: &mut Vec<String>,
     ) -> IfaResult<IfaValue> {
         let arg0 = args.get(0);
-        let arg1 = args.get(1);
 
         match method {
             // Current Unix timestamp (seconds)
@@ -48,6 +57,14 @@ impl OduHandler for IworiHandler {
                 Ok(IfaValue::int(now as i64))
             }
 
+            // Monotonic clock (nanoseconds), for measuring and deadlines
+            "aago_tito" | "monotonic" => Ok(IfaValue::int(monotonic_nanos())),
+
+            // The interpreter sleeps in slices so the host can interrupt it
+            "sun_titi" | "sleep_until" => Err(IfaError::Runtime(
+                "sleep_until must be called from the interpreter".into(),
+            )),
+
             // Elapsed time measurement
             "aago" | "elapsed" => {
                 if let Some(val) = arg0 {
@@ -62,41 +79,27 @@ impl OduHandler for IworiHandler {
                 Err(IfaError::Runtime("elapsed requires start timestamp".into()))
             }
 
-            // Format timestamp to ISO string
+            // Format Unix seconds as an ISO 8601 UTC date-time
             "ojo" | "ṣe_ọjọ" | "format" | "iso" => {
-                if let Some(val) = arg0 {
-                    if let IfaValue::Int(ts) = val {
-                        // Simple ISO-like format: just return the timestamp string
-                        return Ok(IfaValue::str(format!("{}", ts)));
-                    }
+                if let Some(IfaValue::Int(ts)) = arg0 {
+                    return Ok(IfaValue::str(format_iso(*ts)));
                 }
                 Err(IfaError::Runtime("format requires timestamp".into()))
             }
 
-            // Parse date string to timestamp
-            "ka_ọjọ" | "parse" => {
-                if let Some(val) = arg0 {
-                    if let IfaValue::Str(s) = val {
-                        // Simple parsing - just try to parse as integer
-                        let ts = s.parse::<i64>().unwrap_or(0);
-                        return Ok(IfaValue::int(ts));
-                    }
-                }
-                Err(IfaError::Runtime("parse requires date string".into()))
-            }
+            // Parse an ISO 8601 UTC date or date-time to Unix seconds
+            "ka_ọjọ" | "parse" | "parse_date" => match arg0 {
+                Some(IfaValue::Str(s)) => parse_iso(s).map(IfaValue::int).ok_or_else(|| {
+                    IfaError::Runtime(format!(
+                        "Invalid date '{}': expected YYYY-MM-DD or YYYY-MM-DDTHH:MM:SS",
+                        s
+                    ))
+                }),
+                _ => Err(IfaError::Runtime("parse requires date string".into())),
+            },
 
-            // Create range for iteration
-            "laarin" | "range" => {
-                if let (Some(start_val), Some(end_val)) = (arg0, arg1) {
-                    if let (IfaValue::Int(start), IfaValue::Int(end)) = (start_val, end_val) {
-                        let list: Vec<IfaValue> = (*start..*end).map(IfaValue::int).collect();
-                        return Ok(IfaValue::list(list));
-                    }
-                }
-                Err(IfaError::Runtime(
-                    "range requires start and end integers".into(),
-                ))
-            }
+            // Lazy range: nothing is materialised until a list is needed
+            "laarin" | "range" => Ok(IfaValue::range(IntRange::from_args(&args)?)),
 
             _ => Err(IfaError::Runtime(format!(
                 "Unknown Ìwòrì method: {}",
@@ -114,13 +117,135 @@ impl OduHandler for IworiHandler {
             "now_ms",
             "aago",
             "elapsed",
+            "aago_tito",
+            "monotonic",
+            "sun_titi",
+            "sleep_until",
             "ṣe_ọjọ",
             "format",
             "iso",
             "ka_ọjọ",
             "parse",
+            "parse_date",
             "laarin",
             "range",
         ]
     }
 }
+
+/// `YYYY-MM-DDTHH:MM:SS` in UTC for Unix seconds `ts`.
+fn format_iso(ts: i64) -> String {
+    let (days, secs) = (ts.div_euclid(86_400), ts.rem_euclid(86_400));
+    let (year, month, day) = civil_from_days(days);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        secs / 3600,
+        secs / 60 % 60,
+        secs % 60
+    )
+}
+
+/// Unix seconds for a UTC `YYYY-MM-DD` or `YYYY-MM-DDTHH:MM:SS`; `None` if
+/// the text is malformed or names a date or time that does not exist.
+fn parse_iso(s: &str) -> Option<i64> {
+    let (date, time) = match s.split_once('T') {
+        Some((date, time)) => (date, Some(time)),
+        None => (s, None),
+    };
+    let [year, month, day] = digit_fields(date, '-', [4, 2, 2])?;
+    let [hour, minute, second] = match time {
+        Some(time) => digit_fields(time, ':', [2, 2, 2])?,
+        None => [0, 0, 0],
+    };
+    if !(1..=12).contains(&month)
+        || !(1..=days_in_month(year, month)).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 59
+    {
+        return None;
+    }
+    Some(days_from_civil(year, month, day) * 86_400 + hour * 3600 + minute * 60 + second)
+}
+
+/// Exactly three `sep`-separated runs of ASCII digits of the given widths.
+fn digit_fields(s: &str, sep: char, widths: [usize; 3]) -> Option<[i64; 3]> {
+    let mut fields = [0; 3];
+    let mut parts = s.split(sep);
+    for (field, width) in fields.iter_mut().zip(widths) {
+        let part = parts.next()?;
+        if part.len() != width || !part.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        *field = part.parse().ok()?;
+    }
+    parts.next().is_none().then_some(fields)
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since 1970-01-01 of a proleptic Gregorian date (Howard Hinnant's
+/// `days_from_civil`).
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Inverse of [`days_from_civil`]: `(year, month, day)`.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iso_dates_round_trip() {
+        assert_eq!(parse_iso("1970-01-01"), Some(0));
+        assert_eq!(parse_iso("2024-02-29T13:45:30"), Some(1_709_214_330));
+        assert_eq!(parse_iso("1969-12-31T23:59:59"), Some(-1));
+        for ts in [0, -1, 951_782_400, 1_709_214_330, 4_102_444_799] {
+            assert_eq!(parse_iso(&format_iso(ts)), Some(ts), "{}", format_iso(ts));
+        }
+        for bad in [
+            "2023-02-29",
+            "2024-13-01",
+            "2024-1-01",
+            "2024-01-01T24:00:00",
+            "2024-01-01T10:00",
+            "2024-01-01 10:00:00",
+            "yesterday",
+        ] {
+            assert_eq!(parse_iso(bad), None, "{}", bad);
+        }
+    }
+}
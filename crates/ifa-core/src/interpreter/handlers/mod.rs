@@ -8,7 +8,10 @@
 
 use std::collections::HashMap;
 
+use ifa_types::ResourceToken;
+
 use crate::error::{IfaError, IfaResult};
+use crate::interpreter::Ofun;
 use crate::lexer::OduDomain;
 use crate::value::IfaValue;
 
@@ -33,20 +36,34 @@ mod oturupon; // 0010 - Math (Sub/Div)
 mod owonrin; // 0011 - Random
 mod oyeku; // 0000 - Exit/Sleep // 1010 - Graphics/UI
 
+// Pseudo-domain handlers
+pub(crate) mod coop; // Polyglot bridge
+mod opele; // Divination
+mod opon; // Calabash introspection
+
+// Capability requirements checked before dispatch
+mod capabilities;
+// Parameter names for named arguments
+mod params;
+
 // Infrastructure handlers
 mod fidio;
 mod ohun; // Audio I/O // Video I/O
 
 // Re-export handlers
-pub use ika::IkaHandler;
+pub use capabilities::{path_args, required_capabilities};
+pub use coop::{bridge_tool, ifa_to_json, python_executable};
+pub(crate) use ika::char_at;
+pub use ika::{IkaHandler, closest, levenshtein};
 pub use irete::IreteHandler;
 pub use irosu::IrosuHandler;
 pub use iwori::IworiHandler;
+pub(crate) use iwori::monotonic_nanos;
 pub use obara::ObaraHandler;
 pub use odi::OdiHandler;
 pub use ofun::OfunHandler;
 pub use ogbe::OgbeHandler;
-pub use ogunda::OgundaHandler;
+pub use ogunda::{OgundaHandler, reduce as ogunda_reduce};
 pub use okanran::OkanranHandler;
 pub use osa::OsaHandler;
 pub use ose::OseHandler;
@@ -54,6 +71,12 @@ pub use otura::OturaHandler;
 pub use oturupon::OturuponHandler;
 pub use owonrin::OwonrinHandler;
 pub use oyeku::OyekuHandler;
+pub use params::{bind_named_args, param_names};
+
+// Pseudo-domain handlers
+pub use coop::CoopHandler;
+pub use opele::OpeleHandler;
+pub use opon::{OponHandler, report as opon_report};
 
 // Infrastructure handlers
 pub use fidio::FidioHandler;
@@ -89,6 +112,16 @@ pub trait OduHandler: OduHandlerSendSync {
 
     /// Returns the list of methods this handler supports.
     fn methods(&self) -> &'static [&'static str];
+
+    /// Release a resource handle this handler issued (Ẹbọ cleanup).
+    /// Returns true if the handle belonged to this handler.
+    fn release(&self, _token: &ResourceToken) -> bool {
+        false
+    }
+
+    /// Called when the interpreter finishes running a program, however it
+    /// ended: undo host state the program changed, such as terminal modes.
+    fn finish(&self) {}
 }
 
 /// Registry of domain handlers.
@@ -102,28 +135,35 @@ impl HandlerRegistry {
         let mut handlers: HashMap<OduDomain, Box<dyn OduHandler>> = HashMap::new();
 
         // Register all 16 core Odù handlers
-        handlers.insert(OduDomain::Irosu, Box::new(IrosuHandler));
+        handlers.insert(OduDomain::Irosu, Box::new(IrosuHandler::default()));
         handlers.insert(OduDomain::Ogbe, Box::new(OgbeHandler));
         handlers.insert(OduDomain::Obara, Box::new(ObaraHandler));
         handlers.insert(OduDomain::Oturupon, Box::new(OturuponHandler));
-        handlers.insert(OduDomain::Ika, Box::new(IkaHandler));
+        handlers.insert(OduDomain::Ika, Box::new(IkaHandler::default()));
         handlers.insert(OduDomain::Oyeku, Box::new(OyekuHandler));
         handlers.insert(OduDomain::Owonrin, Box::new(OwonrinHandler));
-        handlers.insert(OduDomain::Ogunda, Box::new(OgundaHandler));
+        handlers.insert(OduDomain::Ogunda, Box::new(OgundaHandler::default()));
         handlers.insert(OduDomain::Iwori, Box::new(IworiHandler));
         handlers.insert(OduDomain::Okanran, Box::new(OkanranHandler));
         handlers.insert(OduDomain::Otura, Box::new(OturaHandler));
-        handlers.insert(OduDomain::Odi, Box::new(OdiHandler));
+        handlers.insert(OduDomain::Odi, Box::new(OdiHandler::new()));
         handlers.insert(OduDomain::Osa, Box::new(OsaHandler));
         handlers.insert(OduDomain::Ofun, Box::new(OfunHandler));
         handlers.insert(OduDomain::Irete, Box::new(IreteHandler));
-        handlers.insert(OduDomain::Ose, Box::new(OseHandler));
+        handlers.insert(OduDomain::Ose, Box::new(OseHandler::default()));
+
+        // Pseudo-domain handlers
+        handlers.insert(OduDomain::Coop, Box::new(CoopHandler::default()));
+        handlers.insert(OduDomain::Opele, Box::new(OpeleHandler));
+        handlers.insert(OduDomain::Opon, Box::new(OponHandler::default()));
 
         // Infrastructure handlers
         handlers.insert(OduDomain::Ohun, Box::new(OhunHandler));
-        handlers.insert(OduDomain::Ohun, Box::new(OhunHandler));
         handlers.insert(OduDomain::Fidio, Box::new(FidioHandler));
 
+        // Stack handlers backed by a core Odù
+        handlers.insert(OduDomain::Crypto, Box::new(IreteHandler));
+
         HandlerRegistry { handlers }
     }
 
@@ -135,6 +175,9 @@ impl HandlerRegistry {
     }
 
     /// Execute an Odù call using the appropriate handler.
+    ///
+    /// Every capability from [`required_capabilities`] is passed to `gate`
+    /// first; the handler only runs if all of them are granted.
     pub fn dispatch(
         &self,
         domain: OduDomain,
@@ -142,9 +185,15 @@ impl HandlerRegistry {
         args: Vec<IfaValue>,
         env: &EnvRef,
         output: &mut Vec<String>,
+        gate: &mut dyn FnMut(&Ofun) -> IfaResult<()>,
     ) -> IfaResult<IfaValue> {
         match self.handlers.get(&domain) {
-            Some(handler) => handler.call(method, args, env, output),
+            Some(handler) => {
+                for cap in required_capabilities(domain, method, &args)? {
+                    gate(&cap)?;
+                }
+                handler.call(method, args, env, output)
+            }
             None => Err(IfaError::Runtime(format!(
                 "No handler registered for domain {:?}",
                 domain
@@ -152,6 +201,18 @@ impl HandlerRegistry {
         }
     }
 
+    /// Release a resource handle with whichever handler issued it.
+    pub fn release(&self, token: &ResourceToken) -> bool {
+        self.handlers.values().any(|h| h.release(token))
+    }
+
+    /// Let every handler undo host state at the end of a run.
+    pub fn finish(&self) {
+        for handler in self.handlers.values() {
+            handler.finish();
+        }
+    }
+
     /// List all registered domains.
     pub fn domains(&self) -> Vec<OduDomain> {
         self.handlers.keys().cloned().collect()
@@ -163,6 +224,25 @@ impl HandlerRegistry {
     }
 }
 
+/// Fail with the interpreter's collection-limit error if a list or map of
+/// `len` elements would exceed `limit`; handlers call this before building one.
+pub(crate) fn check_collection_size(len: usize, limit: usize) -> IfaResult<()> {
+    if len > limit {
+        return Err(IfaError::Runtime("collection size limit exceeded".into()));
+    }
+    Ok(())
+}
+
+/// The error a method returns when the Cargo feature it needs was compiled
+/// out, so every optional domain tells the user the same thing.
+#[allow(dead_code)] // unused when every optional feature is enabled
+pub(crate) fn feature_required(method: &str, feature: &str) -> IfaError {
+    IfaError::Runtime(format!(
+        "{} requires the '{}' build; rebuild with --features {}",
+        method, feature, feature
+    ))
+}
+
 impl Default for HandlerRegistry {
     fn default() -> Self {
         Self::new()
@@ -3,7 +3,9 @@
 //! Handles addition, multiplication, and related math operations.
 //! Binary pattern: 1000
 
-use crate::error::{IfaError, IfaResult};
+use std::cmp::Ordering;
+
+use crate::error::{IfaError, IfaResult, overflow};
 use crate::lexer::OduDomain;
 use crate::value::IfaValue;
 
@@ -33,7 +35,9 @@ impl OduHandler for ObaraHandler {
                 let mut sum = 0i64;
                 for arg in &args {
                     match arg {
-                        IfaValue::Int(n) => sum += *n,
+                        IfaValue::Int(n) => {
+                            sum = sum.checked_add(*n).ok_or_else(overflow)?;
+                        }
                         IfaValue::Float(_) => return Ok(self.float_sum(&args)),
                         _ => {}
                     }
@@ -46,7 +50,9 @@ impl OduHandler for ObaraHandler {
                 let mut product = 1i64;
                 for arg in &args {
                     match arg {
-                        IfaValue::Int(n) => product *= *n,
+                        IfaValue::Int(n) => {
+                            product = product.checked_mul(*n).ok_or_else(overflow)?;
+                        }
                         IfaValue::Float(_) => return Ok(self.float_product(&args)),
                         _ => {}
                     }
@@ -54,60 +60,81 @@ impl OduHandler for ObaraHandler {
                 Ok(IfaValue::int(product))
             }
 
-            // Power
-            "agbara" | "pow" | "power" => {
-                if let (Some(base_val), Some(exp_val)) = (arg0, arg1) {
-                    match (base_val, exp_val) {
-                        (IfaValue::Int(base), IfaValue::Int(exp)) => {
-                            Ok(IfaValue::int(base.pow(*exp as u32)))
-                        }
-                        (IfaValue::Float(base), IfaValue::Int(exp)) => {
-                            Ok(IfaValue::float(base.powi(*exp as i32)))
-                        }
-                        (IfaValue::Float(base), IfaValue::Float(exp)) => {
-                            Ok(IfaValue::float(base.powf(*exp)))
-                        }
-                        _ => Ok(IfaValue::int(0)),
-                    }
-                } else {
-                    Ok(IfaValue::int(0))
-                }
+            // Explicit overflow modes for two integers
+            "wrapping_add" => {
+                let (a, b) = int_pair(method, &args)?;
+                Ok(IfaValue::int(a.wrapping_add(b)))
             }
+            "saturating_add" => {
+                let (a, b) = int_pair(method, &args)?;
+                Ok(IfaValue::int(a.saturating_add(b)))
+            }
+            // Null on overflow instead of an error
+            "checked_add" => {
+                let (a, b) = int_pair(method, &args)?;
+                Ok(a.checked_add(b).map_or(IfaValue::null(), IfaValue::int))
+            }
+            "wrapping_mul" => {
+                let (a, b) = int_pair(method, &args)?;
+                Ok(IfaValue::int(a.wrapping_mul(b)))
+            }
+            "saturating_mul" => {
+                let (a, b) = int_pair(method, &args)?;
+                Ok(IfaValue::int(a.saturating_mul(b)))
+            }
+            "checked_mul" => {
+                let (a, b) = int_pair(method, &args)?;
+                Ok(a.checked_mul(b).map_or(IfaValue::null(), IfaValue::int))
+            }
+
+            // Power: Int for non-negative integer exponents, Float otherwise
+            "agbara" | "pow" | "power" => match (arg0, arg1) {
+                (Some(IfaValue::Int(base)), Some(IfaValue::Int(exp))) if *exp >= 0 => {
+                    u32::try_from(*exp)
+                        .ok()
+                        .and_then(|exp| base.checked_pow(exp))
+                        .map(IfaValue::int)
+                        .ok_or_else(overflow)
+                }
+                (Some(base), Some(exp)) => Ok(IfaValue::float(
+                    Self::number(method, base)?.powf(Self::number(method, exp)?),
+                )),
+                _ => Err(IfaError::ArgumentError(
+                    "pow requires a base and an exponent".into(),
+                )),
+            },
 
             // Absolute value
-            "abs" => {
-                if let Some(val) = arg0 {
-                    match val {
-                        IfaValue::Int(n) => Ok(IfaValue::int(n.abs())),
-                        IfaValue::Float(f) => Ok(IfaValue::float(f.abs())),
-                        _ => Ok(IfaValue::int(0)),
-                    }
-                } else {
-                    Ok(IfaValue::int(0))
+            "abs" => match arg0 {
+                Some(IfaValue::Int(n)) => n.checked_abs().map(IfaValue::int).ok_or_else(overflow),
+                _ => Ok(IfaValue::float(Self::number_arg(method, arg0)?.abs())),
+            },
+
+            // Square root (always Float)
+            "gbongbo" | "sqrt" => {
+                let n = Self::number_arg(method, arg0)?;
+                if n < 0.0 {
+                    return Err(IfaError::Runtime(format!("sqrt of negative number {}", n)));
                 }
+                Ok(IfaValue::float(n.sqrt()))
             }
 
-            // Maximum
-            "max" => {
-                let first = args.first().cloned().unwrap_or(IfaValue::int(0));
-                let max = args.iter().fold(first, |acc, v| match (&acc, v) {
-                    (IfaValue::Int(a), IfaValue::Int(b)) if *b > *a => v.clone(),
-                    (IfaValue::Float(a), IfaValue::Float(b)) if *b > *a => v.clone(),
-                    _ => acc,
-                });
-                Ok(max)
+            // Trigonometry (radians)
+            "sin" | "cos" | "tan" => {
+                let n = Self::number_arg(method, arg0)?;
+                Ok(IfaValue::float(match method {
+                    "sin" => n.sin(),
+                    "cos" => n.cos(),
+                    _ => n.tan(),
+                }))
             }
 
-            // Minimum
-            "min" => {
-                let first = args.first().cloned().unwrap_or(IfaValue::int(0));
-                let min = args.iter().fold(first, |acc, v| match (&acc, v) {
-                    (IfaValue::Int(a), IfaValue::Int(b)) if b < a => v.clone(),
-                    (IfaValue::Float(a), IfaValue::Float(b)) if b < a => v.clone(),
-                    _ => acc,
-                });
-                Ok(min)
-            }
+            // Explicit Int -> Float conversion (Floats pass through)
+            "si_desimali" | "to_float" => Ok(IfaValue::float(Self::number_arg(method, arg0)?)),
+
+            // Extremes over the arguments, or over a single list argument
+            "max" => Self::extreme(method, &args, Ordering::Greater),
+            "min" => Self::extreme(method, &args, Ordering::Less),
 
             _ => Err(IfaError::Runtime(format!(
                 "Unknown Ọ̀bàrà method: {}",
@@ -129,13 +156,84 @@ impl OduHandler for ObaraHandler {
             "pow",
             "power",
             "abs",
+            "gbongbo",
+            "sqrt",
+            "sin",
+            "cos",
+            "tan",
             "max",
             "min",
+            "si_desimali",
+            "to_float",
+            "wrapping_add",
+            "saturating_add",
+            "checked_add",
+            "wrapping_mul",
+            "saturating_mul",
+            "checked_mul",
         ]
     }
 }
 
+/// Both arguments of an integer-only `method` such as `wrapping_add`.
+pub(super) fn int_pair(method: &str, args: &[IfaValue]) -> IfaResult<(i64, i64)> {
+    match (args.first(), args.get(1)) {
+        (Some(IfaValue::Int(a)), Some(IfaValue::Int(b))) => Ok((*a, *b)),
+        _ => Err(IfaError::Runtime(format!(
+            "{} requires two integers",
+            method
+        ))),
+    }
+}
+
 impl ObaraHandler {
+    /// `value` as a float, or a type error naming `method`.
+    pub fn number(method: &str, value: &IfaValue) -> IfaResult<f64> {
+        match value {
+            IfaValue::Int(n) => Ok(*n as f64),
+            IfaValue::Float(f) => Ok(*f),
+            other => Err(IfaError::TypeError {
+                expected: format!("number for {}", method),
+                got: other.type_name().to_string(),
+            }),
+        }
+    }
+
+    /// The single numeric argument of `method`, as a float.
+    pub fn number_arg(method: &str, arg: Option<&IfaValue>) -> IfaResult<f64> {
+        let arg =
+            arg.ok_or_else(|| IfaError::ArgumentError(format!("{} requires a number", method)))?;
+        Self::number(method, arg)
+    }
+
+    /// First of the smallest (`Less`) or largest (`Greater`) numbers,
+    /// keeping its Int/Float type.
+    pub fn extreme(method: &str, args: &[IfaValue], wanted: Ordering) -> IfaResult<IfaValue> {
+        let values = match args {
+            [IfaValue::List(items)] => items.as_slice(),
+            _ => args,
+        };
+        let mut best: Option<&IfaValue> = None;
+        for value in values {
+            let n = Self::number(method, value)?;
+            best = match best {
+                Some(current) => {
+                    let order = match (value, current) {
+                        (IfaValue::Int(a), IfaValue::Int(b)) => a.cmp(b),
+                        _ => n
+                            .partial_cmp(&Self::number(method, current)?)
+                            .unwrap_or(Ordering::Equal),
+                    };
+                    Some(if order == wanted { value } else { current })
+                }
+                None => Some(value),
+            };
+        }
+        best.cloned().ok_or_else(|| {
+            IfaError::ArgumentError(format!("{} requires at least one number", method))
+        })
+    }
+
     fn float_sum(&self, args: &[IfaValue]) -> IfaValue {
         let mut sum = 0.0f64;
         for arg in args {
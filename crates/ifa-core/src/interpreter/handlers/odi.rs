@@ -1,18 +1,59 @@
 //! # Òdí Handler - Files/Database
 //!
-//! Handles file I/O operations.
+//! Handles file I/O operations and, with the `backend` feature, SQLite.
 //! Binary pattern: 1001
 
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
 use std::path::PathBuf;
+use std::sync::Arc;
+
+use ifa_types::ResourceToken;
 
 use crate::error::{IfaError, IfaResult};
 use crate::lexer::OduDomain;
 use crate::value::IfaValue;
 
+use super::coop::{ifa_to_json, json_to_ifa};
+#[cfg(not(feature = "backend"))]
+use super::feature_required;
 use super::{EnvRef, OduHandler};
 
 /// Handler for Òdí (Files/Database) domain.
-pub struct OdiHandler;
+///
+/// Owns the open line-streaming readers handed out by `Odi.open` and the
+/// database connections handed out by `Odi.connect`. Both are released by
+/// `Odi.close` or when the handle's scope exits.
+#[derive(Default)]
+pub struct OdiHandler {
+    streams: RefCell<HashMap<u64, BufReader<File>>>,
+    #[cfg(feature = "backend")]
+    connections: RefCell<HashMap<u64, rusqlite::Connection>>,
+    next_handle_id: Cell<u64>,
+}
+
+impl OdiHandler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn next_id(&self) -> u64 {
+        let id = self.next_handle_id.get() + 1;
+        self.next_handle_id.set(id);
+        id
+    }
+
+    fn stream_id(handle: Option<&IfaValue>) -> IfaResult<u64> {
+        match handle {
+            Some(IfaValue::Resource(token)) => Ok(token.0),
+            _ => Err(IfaError::Runtime(
+                "expected a file handle from Odi.open".into(),
+            )),
+        }
+    }
+}
 
 impl OduHandler for OdiHandler {
     fn domain(&self) -> OduDomain {
@@ -143,10 +184,149 @@ impl OduHandler for OdiHandler {
                 }
             }
 
+            // Read all lines into a list
+            "ka_ila" | "read_lines" => {
+                if let Some(IfaValue::Str(path)) = arg0 {
+                    let file = File::open(&**path)
+                        .map_err(|e| IfaError::Runtime(format!("Cannot read file: {}", e)))?;
+                    let lines = BufReader::new(file)
+                        .lines()
+                        .map(|line| line.map(IfaValue::str))
+                        .collect::<Result<Vec<_>, _>>()
+                        .map_err(|e| IfaError::Runtime(format!("Cannot read file: {}", e)))?;
+                    Ok(IfaValue::list(lines))
+                } else {
+                    Err(IfaError::Runtime("read_lines requires file path".into()))
+                }
+            }
+
+            // Append one value as a compact JSON line
+            "fikun_jsonl" | "append_jsonl" => match (arg0, arg1) {
+                (Some(IfaValue::Str(path)), Some(value)) => {
+                    use std::io::Write;
+                    let mut line = serde_json::to_string(&ifa_to_json(value)?)
+                        .map_err(|e| IfaError::Runtime(format!("Cannot encode JSON: {}", e)))?;
+                    line.push('\n');
+                    let mut file = std::fs::OpenOptions::new()
+                        .create(true)
+                        .append(true)
+                        .open(&**path)
+                        .map_err(|e| IfaError::Runtime(format!("Cannot open file: {}", e)))?;
+                    file.write_all(line.as_bytes())
+                        .map_err(|e| IfaError::Runtime(format!("Cannot append: {}", e)))?;
+                    Ok(IfaValue::bool(true))
+                }
+                _ => Err(IfaError::Runtime(
+                    "append_jsonl requires path and value".into(),
+                )),
+            },
+
+            // Read a JSON Lines file into a list, skipping blank lines
+            "ka_jsonl" | "read_jsonl" => {
+                if let Some(IfaValue::Str(path)) = arg0 {
+                    let file = File::open(&**path)
+                        .map_err(|e| IfaError::Runtime(format!("Cannot read file: {}", e)))?;
+                    let mut values = Vec::new();
+                    for (i, line) in BufReader::new(file).lines().enumerate() {
+                        let line = line
+                            .map_err(|e| IfaError::Runtime(format!("Cannot read file: {}", e)))?;
+                        if line.trim().is_empty() {
+                            continue;
+                        }
+                        let json = serde_json::from_str(&line).map_err(|e| {
+                            IfaError::Runtime(format!(
+                                "Invalid JSON on line {} of {}: {}",
+                                i + 1,
+                                path,
+                                e
+                            ))
+                        })?;
+                        values.push(json_to_ifa(json));
+                    }
+                    Ok(IfaValue::list(values))
+                } else {
+                    Err(IfaError::Runtime("read_jsonl requires file path".into()))
+                }
+            }
+
+            // Open a file for line-by-line streaming
+            "ṣi" | "open" => {
+                if let Some(IfaValue::Str(path)) = arg0 {
+                    let file = File::open(&**path)
+                        .map_err(|e| IfaError::Runtime(format!("Cannot open file: {}", e)))?;
+                    let id = self.next_id();
+                    self.streams.borrow_mut().insert(id, BufReader::new(file));
+                    Ok(IfaValue::Resource(Arc::new(ResourceToken::new(id))))
+                } else {
+                    Err(IfaError::Runtime("open requires file path".into()))
+                }
+            }
+
+            // Next line from a streaming handle (ofo at EOF)
+            "ila_to_kan" | "next_line" => {
+                let id = Self::stream_id(arg0)?;
+                let mut streams = self.streams.borrow_mut();
+                let reader = streams
+                    .get_mut(&id)
+                    .ok_or_else(|| IfaError::Runtime("file handle is closed".into()))?;
+                let mut line = String::new();
+                let read = reader
+                    .read_line(&mut line)
+                    .map_err(|e| IfaError::Runtime(format!("Cannot read line: {}", e)))?;
+                if read == 0 {
+                    return Ok(IfaValue::null());
+                }
+                if line.ends_with('\n') {
+                    line.pop();
+                    if line.ends_with('\r') {
+                        line.pop();
+                    }
+                }
+                Ok(IfaValue::str(line))
+            }
+
+            // Close a streaming or database handle
+            "ti" | "close" => match arg0 {
+                Some(IfaValue::Resource(token)) => Ok(IfaValue::bool(self.release(token))),
+                _ => Err(IfaError::Runtime(
+                    "close requires a handle from Odi.open or Odi.connect".into(),
+                )),
+            },
+
+            // Open a SQLite database (":memory:" for an in-memory one)
+            "so_db" | "connect" => match arg0 {
+                Some(IfaValue::Str(path)) => self.connect(path),
+                _ => Err(IfaError::Runtime("connect requires database path".into())),
+            },
+
+            // Rows of a query as a list of maps keyed by column name
+            "beere" | "query" => match (arg0, arg1) {
+                (Some(handle), Some(IfaValue::Str(sql))) => self.query(handle, sql, args.get(2)),
+                _ => Err(IfaError::Runtime(
+                    "query requires database handle and SQL".into(),
+                )),
+            },
+
+            // Run a statement, returning the number of rows it changed
+            "ṣiṣẹ" | "execute" => match (arg0, arg1) {
+                (Some(handle), Some(IfaValue::Str(sql))) => self.execute(handle, sql, args.get(2)),
+                _ => Err(IfaError::Runtime(
+                    "execute requires database handle and SQL".into(),
+                )),
+            },
+
             _ => Err(IfaError::Runtime(format!("Unknown Òdí method: {}", method))),
         }
     }
 
+    fn release(&self, token: &ResourceToken) -> bool {
+        #[cfg(feature = "backend")]
+        if self.connections.borrow_mut().remove(&token.0).is_some() {
+            return true;
+        }
+        self.streams.borrow_mut().remove(&token.0).is_some()
+    }
+
     fn methods(&self) -> &'static [&'static str] {
         &[
             "ka",
@@ -165,6 +345,153 @@ impl OduHandler for OdiHandler {
             "ls",
             "ṣe_folda",
             "mkdir",
+            "ka_ila",
+            "read_lines",
+            "fikun_jsonl",
+            "append_jsonl",
+            "ka_jsonl",
+            "read_jsonl",
+            "ṣi",
+            "open",
+            "ila_to_kan",
+            "next_line",
+            "ti",
+            "close",
+            "so_db",
+            "connect",
+            "beere",
+            "query",
+            "ṣiṣẹ",
+            "execute",
         ]
     }
 }
+
+#[cfg(feature = "backend")]
+impl OdiHandler {
+    fn connect(&self, path: &str) -> IfaResult<IfaValue> {
+        let conn = rusqlite::Connection::open(path)
+            .map_err(|e| IfaError::Runtime(format!("Cannot open database: {}", e)))?;
+        // ATTACH opens any path SQLite can reach, bypassing the capability
+        // check that covered `path`
+        conn.set_limit(rusqlite::limits::Limit::SQLITE_LIMIT_ATTACHED, 0);
+        let id = self.next_id();
+        self.connections.borrow_mut().insert(id, conn);
+        Ok(IfaValue::Resource(Arc::new(ResourceToken::new(id))))
+    }
+
+    /// Run `f` on the connection behind `handle`.
+    fn with_connection<T>(
+        &self,
+        handle: &IfaValue,
+        f: impl FnOnce(&rusqlite::Connection) -> rusqlite::Result<T>,
+    ) -> IfaResult<T> {
+        let IfaValue::Resource(token) = handle else {
+            return Err(IfaError::Runtime(
+                "expected a database handle from Odi.connect".into(),
+            ));
+        };
+        let connections = self.connections.borrow();
+        let conn = connections
+            .get(&token.0)
+            .ok_or_else(|| IfaError::Runtime("database handle is closed".into()))?;
+        f(conn).map_err(|e| IfaError::Runtime(format!("SQL error: {}", e)))
+    }
+
+    fn query(
+        &self,
+        handle: &IfaValue,
+        sql: &str,
+        params: Option<&IfaValue>,
+    ) -> IfaResult<IfaValue> {
+        let params = sql_params(params)?;
+        let rows = self.with_connection(handle, |conn| {
+            let mut stmt = conn.prepare(sql)?;
+            let columns: Vec<String> = stmt.column_names().iter().map(|c| c.to_string()).collect();
+            let mut rows = stmt.query(rusqlite::params_from_iter(params))?;
+            let mut out = Vec::new();
+            while let Some(row) = rows.next()? {
+                let mut map = HashMap::new();
+                for (i, column) in columns.iter().enumerate() {
+                    map.insert(column.clone(), sql_to_ifa(row.get(i)?));
+                }
+                out.push(IfaValue::map(map));
+            }
+            Ok(out)
+        })?;
+        Ok(IfaValue::list(rows))
+    }
+
+    fn execute(
+        &self,
+        handle: &IfaValue,
+        sql: &str,
+        params: Option<&IfaValue>,
+    ) -> IfaResult<IfaValue> {
+        let params = sql_params(params)?;
+        let changed = self.with_connection(handle, |conn| {
+            conn.execute(sql, rusqlite::params_from_iter(params))
+        })?;
+        Ok(IfaValue::int(changed as i64))
+    }
+}
+
+#[cfg(not(feature = "backend"))]
+impl OdiHandler {
+    fn connect(&self, _path: &str) -> IfaResult<IfaValue> {
+        Err(feature_required("Odi.connect", "backend"))
+    }
+
+    fn query(&self, _: &IfaValue, _: &str, _: Option<&IfaValue>) -> IfaResult<IfaValue> {
+        Err(feature_required("Odi.query", "backend"))
+    }
+
+    fn execute(&self, _: &IfaValue, _: &str, _: Option<&IfaValue>) -> IfaResult<IfaValue> {
+        Err(feature_required("Odi.execute", "backend"))
+    }
+}
+
+/// Bind parameters from an optional list of values.
+#[cfg(feature = "backend")]
+fn sql_params(params: Option<&IfaValue>) -> IfaResult<Vec<rusqlite::types::Value>> {
+    use rusqlite::types::Value;
+
+    let items = match params {
+        None | Some(IfaValue::Null) => return Ok(Vec::new()),
+        Some(IfaValue::List(items)) => items,
+        Some(other) => {
+            return Err(IfaError::TypeError {
+                expected: "List of SQL parameters".into(),
+                got: other.type_name().into(),
+            });
+        }
+    };
+    items
+        .iter()
+        .map(|item| match item {
+            IfaValue::Null => Ok(Value::Null),
+            IfaValue::Bool(b) => Ok(Value::Integer(*b as i64)),
+            IfaValue::Int(n) => Ok(Value::Integer(*n)),
+            IfaValue::Float(f) => Ok(Value::Real(*f)),
+            IfaValue::Str(s) => Ok(Value::Text(s.to_string())),
+            IfaValue::Bytes(b) => Ok(Value::Blob(b.to_vec())),
+            other => Err(IfaError::TypeError {
+                expected: "Null, Bool, Int, Float, Str or Bytes SQL parameter".into(),
+                got: other.type_name().into(),
+            }),
+        })
+        .collect()
+}
+
+#[cfg(feature = "backend")]
+fn sql_to_ifa(value: rusqlite::types::Value) -> IfaValue {
+    use rusqlite::types::Value;
+
+    match value {
+        Value::Null => IfaValue::null(),
+        Value::Integer(n) => IfaValue::int(n),
+        Value::Real(f) => IfaValue::float(f),
+        Value::Text(s) => IfaValue::str(s),
+        Value::Blob(b) => IfaValue::bytes(b),
+    }
+}
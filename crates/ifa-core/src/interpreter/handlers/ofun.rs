@@ -37,7 +37,9 @@ fn get_domain_methods(domain: &str) -> Vec<IfaValue> {
         "ika" | "string" => &["gun", "len", "wa", "find", "ni", "has", "rọ", "replace"],
         "oyeku" | "control" => &["jade", "exit", "sun", "sleep"],
         "owonrin" | "random" => &["àìdámọ̀", "random", "wọn", "range"],
-        "ogunda" | "array" => &["kun", "push", "mu", "pop", "ati", "map", "irele", "filter"],
+        "ogunda" | "array" => &[
+            "kun", "push", "mu", "pop", "ati", "map", "irele", "filter", "dinku", "reduce",
+        ],
         "iwori" | "time" => &["bayi", "now", "dateformat", "epoch"],
         "okanran" | "error" => &["asise", "error", "try", "assert"],
         "otura" | "network" => &["gbe", "fetch", "fi", "post", "json"],
@@ -56,6 +58,22 @@ fn get_domain_methods(domain: &str) -> Vec<IfaValue> {
         .collect()
 }
 
+/// `Ofun.type_of`: the lowercase name of a value's type, with every kind of
+/// function reported as `"ese"`.
+fn type_of(value: &IfaValue) -> String {
+    if is_callable(value) {
+        return "ese".into();
+    }
+    value.type_name().to_lowercase()
+}
+
+pub(super) fn is_callable(value: &IfaValue) -> bool {
+    matches!(
+        value,
+        IfaValue::Fn(_) | IfaValue::AstFn(_) | IfaValue::NativeFn(_) | IfaValue::Closure(_)
+    )
+}
+
 impl OduHandler for OfunHandler {
     fn domain(&self) -> OduDomain {
         OduDomain::Ofun
@@ -109,6 +127,24 @@ impl OduHandler for OfunHandler {
                 Ok(IfaValue::str("null"))
             }
 
+            // Lowercase type name for generic code ("int", "list", "ese", ...)
+            "type_of" => match arg0 {
+                Some(val) => Ok(IfaValue::str(type_of(val))),
+                None => Err(IfaError::ArgumentError("type_of requires a value".into())),
+            },
+
+            "le_pe" | "is_callable" => match arg0 {
+                Some(val) => Ok(IfaValue::bool(is_callable(val))),
+                None => Err(IfaError::ArgumentError(
+                    "is_callable requires a value".into(),
+                )),
+            },
+
+            // Needs the handler registry; the interpreter intercepts it
+            "awọn_ẹsẹ_ti" | "methods_of" => Err(IfaError::Runtime(
+                "methods_of must be called from the interpreter".into(),
+            )),
+
             // Reflect on methods
             "awọn_ẹsẹ" | "methods" => {
                 if let Some(IfaValue::Str(domain)) = arg0 {
@@ -161,6 +197,11 @@ impl OduHandler for OfunHandler {
             "request",
             "iru",
             "typeof",
+            "type_of",
+            "le_pe",
+            "is_callable",
+            "awọn_ẹsẹ_ti",
+            "methods_of",
             "awọn_ẹsẹ",
             "methods",
             "awọn_agbara",
@@ -3,10 +3,14 @@
 //! Handles system operations, type introspection, and assertions.
 //! Binary pattern: 1111
 
+use std::path::{Path, PathBuf};
+
 use crate::error::{IfaError, IfaResult};
 use crate::lexer::OduDomain;
 use crate::value::IfaValue;
 
+#[cfg(not(feature = "sysinfo"))]
+use super::feature_required;
 use super::{EnvRef, OduHandler};
 
 /// Handler for Ọ̀gbè (System/Lifecycle) domain.
@@ -100,6 +104,91 @@ impl OduHandler for OgbeHandler {
                 Ok(IfaValue::float(val))
             }
 
+            // Current working directory
+            "cwd" => std::env::current_dir()
+                .map(|dir| IfaValue::str(dir.to_string_lossy()))
+                .map_err(|e| IfaError::Runtime(format!("Cannot read cwd: {}", e))),
+
+            // Check a working directory (gated on reading it); the interpreter
+            // keeps it, the process's own directory is never changed
+            "chdir" => match arg0 {
+                Some(IfaValue::Str(path)) if Path::new(&**path).is_dir() => {
+                    Ok(IfaValue::Str(path.clone()))
+                }
+                Some(IfaValue::Str(path)) => Err(IfaError::Runtime(format!(
+                    "Cannot chdir: '{}' is not a directory",
+                    path
+                ))),
+                _ => Err(IfaError::Runtime("chdir requires path".into())),
+            },
+
+            // Join path segments with the platform separator
+            "path_join" => {
+                let mut path = PathBuf::new();
+                for arg in &args {
+                    match arg {
+                        IfaValue::Str(part) => path.push(&**part),
+                        _ => return Err(IfaError::Runtime("path_join requires strings".into())),
+                    }
+                }
+                Ok(IfaValue::str(path.to_string_lossy()))
+            }
+
+            // Last path component ("" if there is none)
+            "basename" => match arg0 {
+                Some(IfaValue::Str(path)) => Ok(IfaValue::str(
+                    Path::new(&**path)
+                        .file_name()
+                        .map(|name| name.to_string_lossy())
+                        .unwrap_or_default(),
+                )),
+                _ => Err(IfaError::Runtime("basename requires path".into())),
+            },
+
+            // Path without its last component ("" if there is none)
+            "dirname" => match arg0 {
+                Some(IfaValue::Str(path)) => Ok(IfaValue::str(
+                    Path::new(&**path)
+                        .parent()
+                        .map(|dir| dir.to_string_lossy())
+                        .unwrap_or_default(),
+                )),
+                _ => Err(IfaError::Runtime("dirname requires path".into())),
+            },
+
+            // Host facts; OS and arch are the values this binary was built for
+            "platform" => Ok(IfaValue::str(std::env::consts::OS)),
+            "arch" => Ok(IfaValue::str(std::env::consts::ARCH)),
+            "cpu_count" => Ok(IfaValue::int(num_cpus::get() as i64)),
+            "pid" => Ok(IfaValue::int(std::process::id() as i64)),
+
+            // Environment variable (gated on its key); ofo if unset
+            "ayika" | "env" => match arg0 {
+                Some(IfaValue::Str(key)) => Ok(std::env::var(&**key)
+                    .map(IfaValue::str)
+                    .unwrap_or(IfaValue::Null)),
+                _ => Err(IfaError::Runtime("env requires key".into())),
+            },
+
+            // Only the granted keys are listed, so the interpreter filters them
+            "ayika_gbogbo" | "env_all" => Err(IfaError::Runtime(
+                "env_all must be called from the interpreter".into(),
+            )),
+
+            // Machine name (gated on the HOSTNAME environment key)
+            #[cfg(feature = "sysinfo")]
+            "hostname" => sysinfo::System::host_name()
+                .map(IfaValue::str)
+                .ok_or_else(|| IfaError::Runtime("Cannot read hostname".into())),
+
+            #[cfg(not(feature = "sysinfo"))]
+            "hostname" => Err(feature_required("Ogbe.hostname", "sysinfo")),
+
+            // The scratch directory is owned (and cleaned up) by the interpreter
+            "folda_igba" | "temp_dir" => Err(IfaError::Runtime(
+                "temp_dir must be called from the interpreter".into(),
+            )),
+
             _ => Err(IfaError::Runtime(format!(
                 "Unknown Ọ̀gbè method: {}",
                 method
@@ -119,6 +208,22 @@ impl OduHandler for OgbeHandler {
             "ṣẹda",
             "parse_int",
             "parse_float",
+            "cwd",
+            "chdir",
+            "path_join",
+            "basename",
+            "dirname",
+            "platform",
+            "arch",
+            "cpu_count",
+            "pid",
+            "hostname",
+            "ayika",
+            "env",
+            "ayika_gbogbo",
+            "env_all",
+            "folda_igba",
+            "temp_dir",
         ]
     }
 }
@@ -3,14 +3,38 @@
 //! Handles array/list operations.
 //! Binary pattern: 1110
 
+use std::cmp::Ordering;
+
 use crate::error::{IfaError, IfaResult};
+use crate::interpreter::core::DEFAULT_COLLECTION_LIMIT;
 use crate::lexer::OduDomain;
 use crate::value::IfaValue;
 
-use super::{EnvRef, OduHandler};
+use super::ofun::is_callable;
+use super::{EnvRef, OduHandler, check_collection_size};
 
 /// Handler for Ògúndá (Arrays/Lists) domain.
-pub struct OgundaHandler;
+pub struct OgundaHandler {
+    max_items: usize,
+}
+
+impl OgundaHandler {
+    /// Refuse to build a list of more than `max_items` elements, before
+    /// allocating it
+    pub fn new(max_items: usize) -> Self {
+        Self { max_items }
+    }
+
+    fn check_items(&self, len: usize) -> IfaResult<()> {
+        check_collection_size(len, self.max_items)
+    }
+}
+
+impl Default for OgundaHandler {
+    fn default() -> Self {
+        Self::new(DEFAULT_COLLECTION_LIMIT)
+    }
+}
 
 impl OduHandler for OgundaHandler {
     fn domain(&self) -> OduDomain {
@@ -28,7 +52,10 @@ impl OduHandler for OgundaHandler {
 
         match method {
             // Create new list
-            "da" | "create" | "new" => Ok(IfaValue::list(args)),
+            "da" | "create" | "new" => {
+                self.check_items(args.len())?;
+                Ok(IfaValue::list(args))
+            }
 
             // List length
             "iwon" | "gigun" | "len" => {
@@ -43,6 +70,7 @@ impl OduHandler for OgundaHandler {
             "fi" | "fikun" | "push" | "append" => {
                 if let (Some(list_val), Some(elem)) = (arg0, args.get(1)) {
                     if let IfaValue::List(l) = list_val {
+                        self.check_items(l.len() + 1)?;
                         let mut list = (**l).clone();
                         list.push(elem.clone());
                         Ok(IfaValue::list(list))
@@ -72,6 +100,7 @@ impl OduHandler for OgundaHandler {
             // Reverse list
             "pada" | "yipada" | "reverse" => {
                 if let Some(IfaValue::List(l)) = arg0 {
+                    self.check_items(l.len())?;
                     let mut list = (**l).clone();
                     list.reverse();
                     Ok(IfaValue::list(list))
@@ -111,11 +140,13 @@ impl OduHandler for OgundaHandler {
                 }
             }
 
-            // Contains element
+            // Contains element (same equality as `==`, so 1 matches 1.0)
             "ni" | "contains" => {
                 if let (Some(list_val), Some(elem)) = (arg0, args.get(1)) {
                     if let IfaValue::List(list) = list_val {
-                        Ok(IfaValue::bool(list.contains(elem)))
+                        Ok(IfaValue::bool(
+                            list.iter().any(|item| item.numeric_eq(elem)),
+                        ))
                     } else {
                         Err(IfaError::Runtime("contains requires a list".into()))
                     }
@@ -126,6 +157,34 @@ impl OduHandler for OgundaHandler {
                 }
             }
 
+            // Index of the first equal element, -1 when absent
+            "atọka_ti" | "index_of" => match (arg0, args.get(1)) {
+                (Some(IfaValue::List(list)), Some(elem)) => Ok(IfaValue::int(
+                    list.iter()
+                        .position(|item| item.numeric_eq(elem))
+                        .map_or(-1, |i| i as i64),
+                )),
+                (Some(_), Some(_)) => Err(IfaError::Runtime("index_of requires a list".into())),
+                _ => Err(IfaError::Runtime(
+                    "index_of requires list and element".into(),
+                )),
+            },
+
+            // Drop repeats (by `==`), keeping each value's first position
+            "ọtọ" | "unique" => match arg0 {
+                Some(IfaValue::List(list)) => {
+                    self.check_items(list.len())?;
+                    let mut seen: Vec<IfaValue> = Vec::new();
+                    for item in list.iter() {
+                        if !seen.iter().any(|kept| kept.numeric_eq(item)) {
+                            seen.push(item.clone());
+                        }
+                    }
+                    Ok(IfaValue::list(seen))
+                }
+                _ => Err(IfaError::Runtime("unique requires a list".into())),
+            },
+
             // Slice list
             "ge" | "slice" => {
                 if let (Some(list_val), Some(start_val)) = (arg0, args.get(1)) {
@@ -141,6 +200,7 @@ impl OduHandler for OgundaHandler {
                                 }
                             })
                             .unwrap_or(list.len());
+                        self.check_items(end.min(list.len()).saturating_sub(start))?;
 
                         let sliced: Vec<IfaValue> = list
                             .iter()
@@ -164,6 +224,7 @@ impl OduHandler for OgundaHandler {
             // Map function over list (simplified)
             "maapu" | "map" => {
                 if let Some(IfaValue::List(list)) = arg0 {
+                    self.check_items(list.len())?;
                     Ok(IfaValue::list(list.as_ref().clone()))
                 } else {
                     Err(IfaError::Runtime("map requires a list".into()))
@@ -173,12 +234,103 @@ impl OduHandler for OgundaHandler {
             // Filter list (simplified)
             "ṣàjọ" | "filter" => {
                 if let Some(IfaValue::List(list)) = arg0 {
+                    self.check_items(list.len())?;
                     Ok(IfaValue::list(list.as_ref().clone()))
                 } else {
                     Err(IfaError::Runtime("filter requires a list".into()))
                 }
             }
 
+            // Pair up elements of two lists, stopping at the shorter one
+            "sopọ" | "zip" => match (arg0, args.get(1)) {
+                (Some(IfaValue::List(a)), Some(IfaValue::List(b))) => {
+                    self.check_items(a.len().min(b.len()))?;
+                    Ok(IfaValue::list(
+                        a.iter()
+                            .zip(b.iter())
+                            .map(|(x, y)| IfaValue::list(vec![x.clone(), y.clone()]))
+                            .collect(),
+                    ))
+                }
+                _ => Err(IfaError::Runtime("zip requires two lists".into())),
+            },
+
+            // Pair each element with its index
+            "ka_atọka" | "enumerate" => {
+                if let Some(IfaValue::List(list)) = arg0 {
+                    self.check_items(list.len())?;
+                    Ok(IfaValue::list(
+                        list.iter()
+                            .enumerate()
+                            .map(|(i, x)| IfaValue::list(vec![IfaValue::int(i as i64), x.clone()]))
+                            .collect(),
+                    ))
+                } else {
+                    Err(IfaError::Runtime("enumerate requires a list".into()))
+                }
+            }
+
+            // Stable ascending sort with the default comparator
+            "tò" | "sort" => {
+                if let Some(IfaValue::List(list)) = arg0 {
+                    self.check_items(list.len())?;
+                    let mut items = list.to_vec();
+                    let mut error = None;
+                    items.sort_by(|a, b| {
+                        default_order(a, b).unwrap_or_else(|e| {
+                            error.get_or_insert(e);
+                            Ordering::Equal
+                        })
+                    });
+                    match error {
+                        Some(e) => Err(e),
+                        None => Ok(IfaValue::list(items)),
+                    }
+                } else {
+                    Err(IfaError::Runtime("sort requires a list".into()))
+                }
+            }
+
+            // Splice nested lists into their parent: one level, or all of them
+            "tẹ" | "flatten" | "tẹ_patapata" | "flatten_deep" => {
+                if let Some(IfaValue::List(list)) = arg0 {
+                    let deep = matches!(method, "tẹ_patapata" | "flatten_deep");
+                    self.check_items(list.len())?;
+                    let mut out = Vec::with_capacity(list.len());
+                    flatten_into(list, deep, &mut out, self.max_items)?;
+                    Ok(IfaValue::list(out))
+                } else {
+                    Err(IfaError::Runtime(format!("{} requires a list", method)))
+                }
+            }
+
+            // Split into sublists of n elements; the last may be shorter
+            "pin" | "chunk" => match (arg0, args.get(1)) {
+                (Some(IfaValue::List(list)), Some(IfaValue::Int(n))) if *n > 0 => {
+                    self.check_items(list.len())?;
+                    Ok(IfaValue::list(
+                        list.chunks(*n as usize)
+                            .map(|chunk| IfaValue::list(chunk.to_vec()))
+                            .collect(),
+                    ))
+                }
+                (Some(IfaValue::List(_)), Some(IfaValue::Int(n))) => Err(IfaError::ArgumentError(
+                    format!("chunk size must be positive, got {}", n),
+                )),
+                _ => Err(IfaError::Runtime(
+                    "chunk requires a list and a chunk size".into(),
+                )),
+            },
+
+            // Fold needs to call user functions, so each engine runs [`reduce`]
+            // with its own way of calling them
+            "dinku" | "reduce" => Err(IfaError::Runtime(
+                "reduce must be called from the interpreter".into(),
+            )),
+            "kojọ" | "group_by" => Err(IfaError::Runtime(
+                "group_by must be called from the interpreter".into(),
+            )),
+
             _ => Err(IfaError::Runtime(format!(
                 "Unknown Ògúndá method: {}",
                 method
@@ -208,12 +360,101 @@ impl OduHandler for OgundaHandler {
             "get",
             "ni",
             "contains",
+            "atọka_ti",
+            "index_of",
+            "ọtọ",
+            "unique",
             "ge",
             "slice",
             "maapu",
             "map",
             "ṣàjọ",
             "filter",
+            "sopọ",
+            "zip",
+            "ka_atọka",
+            "enumerate",
+            "tò",
+            "sort",
+            "tẹ",
+            "flatten",
+            "tẹ_patapata",
+            "flatten_deep",
+            "pin",
+            "chunk",
+            "dinku",
+            "reduce",
+            "kojọ",
+            "group_by",
         ]
     }
 }
+
+/// `Ogunda.reduce(list, fn, init)`: left fold calling `fn(acc, element)`
+/// through `call`, which runs a function value in the calling engine.
+pub fn reduce(
+    args: Vec<IfaValue>,
+    mut call: impl FnMut(&IfaValue, Vec<IfaValue>) -> IfaResult<IfaValue>,
+) -> IfaResult<IfaValue> {
+    let mut args = args.into_iter();
+    let (Some(IfaValue::List(list)), Some(func), Some(init)) =
+        (args.next(), args.next(), args.next())
+    else {
+        return Err(IfaError::ArgumentError(
+            "Ogunda.reduce expects (list, fn, init)".into(),
+        ));
+    };
+    if !is_callable(&func) {
+        return Err(IfaError::TypeError {
+            expected: "Function".into(),
+            got: func.type_name().into(),
+        });
+    }
+
+    let mut acc = init;
+    for item in list.iter() {
+        acc = call(&func, vec![acc, item.clone()])?;
+    }
+    Ok(acc)
+}
+
+/// Append `items` to `out`, splicing in the elements of nested lists (and,
+/// when `deep`, of lists nested inside those). Fails before `out` grows past
+/// `max_items`.
+fn flatten_into(
+    items: &[IfaValue],
+    deep: bool,
+    out: &mut Vec<IfaValue>,
+    max_items: usize,
+) -> IfaResult<()> {
+    // An explicit stack of the lists being walked, so nesting depth is
+    // bounded by the heap rather than the host stack
+    let mut stack = vec![items.iter()];
+    while let Some(iter) = stack.last_mut() {
+        match iter.next() {
+            Some(IfaValue::List(inner)) if deep => stack.push(inner.iter()),
+            Some(IfaValue::List(inner)) => {
+                check_collection_size(out.len() + inner.len(), max_items)?;
+                out.extend(inner.iter().cloned());
+            }
+            Some(other) => {
+                check_collection_size(out.len() + 1, max_items)?;
+                out.push(other.clone());
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+    Ok(())
+}
+
+/// `sort`'s default comparator: [`IfaValue::total_cmp`], so numbers compare
+/// by value (Int promoted to Float, as the comparison operators do; NaN last)
+/// and strings lexicographically.
+fn default_order(a: &IfaValue, b: &IfaValue) -> IfaResult<Ordering> {
+    a.total_cmp(b).ok_or_else(|| IfaError::TypeError {
+        expected: "a list of numbers or a list of strings".into(),
+        got: format!("{} compared with {}", a.type_name(), b.type_name()),
+    })
+}
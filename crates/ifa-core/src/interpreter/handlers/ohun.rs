@@ -4,12 +4,14 @@
 //! Infrastructure domain for sound I/O.
 //!
 //! When `audio` feature is enabled, uses `rodio` for real audio playback.
-//! Otherwise, provides stub responses.
+//! Otherwise playback and device methods fail with `feature_required`.
 
 use crate::error::{IfaError, IfaResult};
 use crate::lexer::OduDomain;
 use crate::value::IfaValue;
 
+#[cfg(not(feature = "audio"))]
+use super::feature_required;
 use super::{EnvRef, OduHandler};
 
 // Real audio implementation when audio feature is enabled
@@ -48,11 +50,8 @@ fn play_audio_file(path: &str) -> IfaResult<bool> {
 }
 
 #[cfg(not(feature = "audio"))]
-fn play_audio_file(path: &str) -> IfaResult<bool> {
-    Err(IfaError::Runtime(format!(
-        "Audio disabled. Enable 'audio' feature to play files. Path: {}",
-        path
-    )))
+fn play_audio_file(_path: &str) -> IfaResult<bool> {
+    Err(feature_required("Ohun.play", "audio"))
 }
 
 #[cfg(feature = "audio")]
@@ -77,9 +76,7 @@ fn play_beep(freq: f32, duration_ms: u64) -> IfaResult<()> {
 
 #[cfg(not(feature = "audio"))]
 fn play_beep(_freq: f32, _duration_ms: u64) -> IfaResult<()> {
-    // Fallback: ASCII bell
-    // Removed literal print: print!("\x07");
-    Ok(())
+    Err(feature_required("Ohun.beep", "audio"))
 }
 
 impl OduHandler for OhunHandler {
@@ -172,9 +169,7 @@ impl OduHandler for OhunHandler {
             }
 
             #[cfg(not(feature = "audio"))]
-            "awọn_ẹrọ" | "devices" => Ok(IfaValue::list(vec![IfaValue::str(
-                "Default Output (audio feature disabled)",
-            )])),
+            "awọn_ẹrọ" | "devices" => Err(feature_required("Ohun.devices", "audio")),
 
             _ => Err(IfaError::Runtime(format!(
                 "Unknown Ohun method: {}",
@@ -27,20 +27,20 @@ impl OduHandler for OkanranHandler {
         let arg0 = args.first();
 
         match method {
-            // Throw error
-            "ta" | "throw" | "error" => {
+            // Throw error (catchable)
+            "ta" | "throw" | "raise" | "error" => {
                 let msg = arg0
                     .map(|v| v.to_string())
                     .unwrap_or_else(|| "Unknown error".to_string());
                 Err(IfaError::Runtime(msg))
             }
 
-            // Panic (fatal error)
+            // Panic (fatal error, skips every catch)
             "jagun" | "panic" => {
                 let msg = arg0
                     .map(|v| v.to_string())
                     .unwrap_or_else(|| "Panic!".to_string());
-                panic!("{}", msg);
+                Err(IfaError::Panic(msg))
             }
 
             // Assert condition
@@ -103,6 +103,17 @@ impl OduHandler for OkanranHandler {
                 Err(IfaError::Runtime("assert_ne requires two values".into()))
             }
 
+            // Truthiness as used by conditions
+            "otitọ" | "truthy" => match arg0 {
+                Some(v) => Ok(IfaValue::bool(v.is_truthy())),
+                None => Err(IfaError::ArgumentError("truthy requires a value".into())),
+            },
+
+            // Calls a user function; the interpreter intercepts it
+            "jẹri_ta" | "assert_throws" => Err(IfaError::Runtime(
+                "assert_throws must be called from the interpreter".into(),
+            )),
+
             // Unreachable code marker
             "ko_ṣee_de" | "unreachable" => {
                 Err(IfaError::Runtime("Reached unreachable code".into()))
@@ -119,6 +130,7 @@ impl OduHandler for OkanranHandler {
         &[
             "ta",
             "throw",
+            "raise",
             "error",
             "jagun",
             "panic",
@@ -130,6 +142,10 @@ impl OduHandler for OkanranHandler {
             "assert_eq",
             "jẹri_yato",
             "assert_ne",
+            "jẹri_ta",
+            "assert_throws",
+            "otitọ",
+            "truthy",
             "ko_ṣee_de",
             "unreachable",
         ]
@@ -0,0 +1,157 @@
+//! # Ọpẹlẹ Handler - Divination
+//!
+//! Structured access to the Odù wisdom table, so programs can present a
+//! cast result however they like. The journal of past castings is kept by
+//! the interpreter, which intercepts `journal` and `clear_journal`.
+
+use std::collections::HashMap;
+
+use ifa_types::wisdom::odu_wisdom;
+
+use crate::error::{IfaError, IfaResult};
+use crate::lexer::OduDomain;
+use crate::value::IfaValue;
+
+use super::{EnvRef, OduHandler};
+
+/// The 16 principal Odù in traditional order.
+const PRINCIPAL: [OduDomain; 16] = [
+    OduDomain::Ogbe,
+    OduDomain::Oyeku,
+    OduDomain::Iwori,
+    OduDomain::Odi,
+    OduDomain::Irosu,
+    OduDomain::Owonrin,
+    OduDomain::Obara,
+    OduDomain::Okanran,
+    OduDomain::Ogunda,
+    OduDomain::Osa,
+    OduDomain::Ika,
+    OduDomain::Oturupon,
+    OduDomain::Otura,
+    OduDomain::Irete,
+    OduDomain::Ose,
+    OduDomain::Ofun,
+];
+
+/// Handler for Ọpẹlẹ (Divination) pseudo-domain.
+pub struct OpeleHandler;
+
+impl OduHandler for OpeleHandler {
+    fn domain(&self) -> OduDomain {
+        OduDomain::Opele
+    }
+
+    fn call(
+        &self,
+        method: &str,
+        args: Vec<IfaValue>,
+        _env: &EnvRef,
+        _output: &mut Vec<String>,
+    ) -> IfaResult<IfaValue> {
+        match method {
+            // Interpret a compound name such as "Ogbe_Otura" or "Ogbe Meji"
+            "tumo" | "interpret" => match args.first() {
+                Some(IfaValue::Str(name)) => interpret(name).map(IfaValue::map),
+                _ => Err(IfaError::Runtime("interpret requires an Odù name".into())),
+            },
+
+            // Cast the chain: a random two-leg Odù, interpreted
+            "dafa" | "cast" => cast(None),
+
+            // Cast for a question, which is kept in the reading
+            "beere" | "divine" => match args.first() {
+                Some(IfaValue::Str(question)) => cast(Some(question)),
+                _ => Err(IfaError::ArgumentError("divine requires a question".into())),
+            },
+
+            // The journal spans the session, so the interpreter holds it
+            "akosile" | "journal" | "nu_akosile" | "clear_journal" => Err(IfaError::Runtime(
+                "journal must be called from the interpreter".into(),
+            )),
+
+            _ => Err(IfaError::Runtime(format!(
+                "Unknown Ọpẹlẹ method: {}",
+                method
+            ))),
+        }
+    }
+
+    fn methods(&self) -> &'static [&'static str] {
+        &[
+            "tumo",
+            "interpret",
+            "dafa",
+            "cast",
+            "beere",
+            "divine",
+            "akosile",
+            "journal",
+            "nu_akosile",
+            "clear_journal",
+        ]
+    }
+}
+
+/// Cast a random two-leg Odù and interpret it, adding `question` if given.
+fn cast(question: Option<&str>) -> IfaResult<IfaValue> {
+    let mut byte = [0u8; 1];
+    getrandom::getrandom(&mut byte)
+        .map_err(|e| IfaError::Runtime(format!("Cannot cast Ọpẹlẹ: {}", e)))?;
+    let right = PRINCIPAL[(byte[0] & 0x0F) as usize];
+    let left = PRINCIPAL[(byte[0] >> 4) as usize];
+
+    let mut reading = interpret(&format!("{:?}_{:?}", right, left))?;
+    if let Some(question) = question {
+        reading.insert("question".into(), IfaValue::str(question));
+    }
+    Ok(IfaValue::map(reading))
+}
+
+/// `{odu, binary, components}` where each component is
+/// `{odu, yoruba, binary, title, proverb, domain}`.
+fn interpret(compound: &str) -> IfaResult<HashMap<String, IfaValue>> {
+    let mut parts: Vec<&str> = Vec::new();
+    for part in compound.split(['_', ' ', '-']).filter(|p| !p.is_empty()) {
+        // "Ogbe Meji" is Ogbe on both legs
+        if part.eq_ignore_ascii_case("meji") {
+            if let Some(prev) = parts.last().copied() {
+                parts.push(prev);
+                continue;
+            }
+        }
+        parts.push(part);
+    }
+    if parts.is_empty() {
+        return Err(IfaError::Runtime("interpret requires an Odù name".into()));
+    }
+
+    let mut binary = String::new();
+    let mut components = Vec::with_capacity(parts.len());
+    for part in parts {
+        let (domain, wisdom) = PRINCIPAL
+            .iter()
+            .find(|d| format!("{:?}", d).eq_ignore_ascii_case(part))
+            .zip(odu_wisdom(part))
+            .ok_or_else(|| IfaError::Runtime(format!("Unknown Odù: {}", part)))?;
+        let pattern = format!("{:04b}", domain.binary().unwrap_or_default());
+        binary.push_str(&pattern);
+        components.push(IfaValue::map(HashMap::from([
+            ("odu".into(), IfaValue::str(format!("{:?}", domain))),
+            ("yoruba".into(), IfaValue::str(domain.yoruba_name())),
+            ("binary".into(), IfaValue::str(pattern)),
+            ("title".into(), IfaValue::str(wisdom.title)),
+            (
+                "proverb".into(),
+                IfaValue::str(wisdom.proverbs.first().copied().unwrap_or_default()),
+            ),
+            ("domain".into(), IfaValue::str(wisdom.meaning)),
+        ])));
+    }
+
+    Ok(HashMap::from([
+        ("odu".into(), IfaValue::str(compound)),
+        ("binary".into(), IfaValue::str(binary)),
+        ("components".into(), IfaValue::list(components)),
+    ]))
+}
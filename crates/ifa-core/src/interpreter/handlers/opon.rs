@@ -0,0 +1,134 @@
+//! # Ọpọ́n Handler - Calabash introspection
+//!
+//! Reports how many calabash slots the program holds against the size
+//! declared by the `opon` directive. The interpreter re-registers this
+//! handler with the new size whenever the directive runs.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::bytecode::OponSize;
+use crate::error::{IfaError, IfaResult};
+use crate::interpreter::environment::Environment;
+use crate::lexer::OduDomain;
+use crate::value::IfaValue;
+
+use super::{EnvRef, OduHandler};
+
+/// Handler for Ọpọ́n (Calabash) pseudo-domain.
+pub struct OponHandler {
+    size: OponSize,
+}
+
+impl OponHandler {
+    pub fn new(size: OponSize) -> Self {
+        Self { size }
+    }
+}
+
+impl Default for OponHandler {
+    fn default() -> Self {
+        Self::new(OponSize::Arinrin)
+    }
+}
+
+impl OduHandler for OponHandler {
+    fn domain(&self) -> OduDomain {
+        OduDomain::Opon
+    }
+
+    fn call(
+        &self,
+        method: &str,
+        _args: Vec<IfaValue>,
+        env: &EnvRef,
+        _output: &mut Vec<String>,
+    ) -> IfaResult<IfaValue> {
+        let values: Vec<IfaValue> = Environment::variable_names(env)
+            .iter()
+            .filter_map(|name| Environment::get(env, name))
+            .collect();
+        report(self.size, method, &values)
+    }
+
+    fn methods(&self) -> &'static [&'static str] {
+        &["lilo", "usage", "to_ku", "available"]
+    }
+}
+
+/// `usage()` and `available()` over `values`: one slot per value and one per
+/// list or map element, against the slot count of `size`. A list or map
+/// reachable from several values is counted once. `ailopin` has no total, so
+/// `total_slots`, `percent` and `available()` are null.
+pub fn report(size: OponSize, method: &str, values: &[IfaValue]) -> IfaResult<IfaValue> {
+    let mut seen = HashSet::new();
+    let used: usize = values.iter().map(|value| slots(value, &mut seen)).sum();
+    let total = size.limits().0;
+
+    match method {
+        "lilo" | "usage" => {
+            let mut usage = HashMap::new();
+            usage.insert("used_slots".to_string(), IfaValue::Int(used as i64));
+            usage.insert(
+                "total_slots".to_string(),
+                total.map_or(IfaValue::Null, |t| IfaValue::Int(t as i64)),
+            );
+            usage.insert(
+                "percent".to_string(),
+                total.map_or(IfaValue::Null, |t| {
+                    IfaValue::Float(used as f64 * 100.0 / t as f64)
+                }),
+            );
+            Ok(IfaValue::map(usage))
+        }
+        "to_ku" | "available" => Ok(total.map_or(IfaValue::Null, |t| {
+            IfaValue::Int(t.saturating_sub(used) as i64)
+        })),
+        _ => Err(IfaError::Runtime(format!(
+            "Unknown Ọpọ́n method: {}",
+            method
+        ))),
+    }
+}
+
+/// Slots held by `value`; shared list and map allocations already in `seen`
+/// cost nothing.
+fn slots(value: &IfaValue, seen: &mut HashSet<usize>) -> usize {
+    match value {
+        IfaValue::List(items) => {
+            if !seen.insert(std::sync::Arc::as_ptr(items) as usize) {
+                return 0;
+            }
+            1 + items.iter().map(|item| slots(item, seen)).sum::<usize>()
+        }
+        IfaValue::Map(entries) => {
+            if !seen.insert(std::sync::Arc::as_ptr(entries) as usize) {
+                return 0;
+            }
+            1 + entries
+                .values()
+                .map(|item| slots(item, seen))
+                .sum::<usize>()
+        }
+        _ => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shared_collections_are_counted_once() {
+        let list = IfaValue::list(vec![IfaValue::Int(1), IfaValue::Int(2)]);
+        let values = [list.clone(), list, IfaValue::Int(3)];
+        let usage = report(OponSize::Kekere, "usage", &values).unwrap();
+        match usage {
+            IfaValue::Map(map) => assert_eq!(map.get("used_slots"), Some(&IfaValue::Int(4))),
+            other => panic!("expected a map, got {:?}", other),
+        }
+        assert_eq!(
+            report(OponSize::Ailopin, "available", &values).unwrap(),
+            IfaValue::Null
+        );
+    }
+}
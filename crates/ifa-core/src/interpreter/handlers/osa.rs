@@ -6,6 +6,8 @@
 //! Real parallelism is achieved by converting IfaValue to primitives internally,
 //! using rayon on those, then converting back. This avoids `Arc<Mutex>` overhead.
 
+use ifa_types::numeric::NumericList;
+
 use crate::error::{IfaError, IfaResult};
 use crate::lexer::OduDomain;
 use crate::value::IfaValue;
@@ -74,14 +76,7 @@ impl OduHandler for OsaHandler {
             // Parallel sum - converts to i64, uses par_iter, real parallelism
             "afikun_afiwe" | "parallel_sum" | "sum" => {
                 if let Some(IfaValue::List(list)) = arg0 {
-                    let numbers: Vec<i64> = list
-                        .iter()
-                        .map(|v| match v {
-                            IfaValue::Int(n) => *n,
-                            IfaValue::Float(f) => *f as i64,
-                            _ => 0,
-                        })
-                        .collect();
+                    let numbers = packed_ints(list, Some(0));
                     let sum: i64 = numbers.par_iter().copied().sum();
                     return Ok(IfaValue::int(sum));
                 }
@@ -91,14 +86,7 @@ impl OduHandler for OsaHandler {
             // Parallel product
             "isoro_afiwe" | "parallel_product" | "product" => {
                 if let Some(IfaValue::List(list)) = arg0 {
-                    let numbers: Vec<i64> = list
-                        .iter()
-                        .map(|v| match v {
-                            IfaValue::Int(n) => *n,
-                            IfaValue::Float(f) => *f as i64,
-                            _ => 1,
-                        })
-                        .collect();
+                    let numbers = packed_ints(list, Some(1));
                     let product: i64 = numbers.par_iter().copied().product();
                     return Ok(IfaValue::int(product));
                 }
@@ -110,14 +98,7 @@ impl OduHandler for OsaHandler {
             // Parallel min
             "kekere_afiwe" | "parallel_min" | "min" => {
                 if let Some(IfaValue::List(list)) = arg0 {
-                    let numbers: Vec<i64> = list
-                        .iter()
-                        .filter_map(|v| match v {
-                            IfaValue::Int(n) => Some(*n),
-                            IfaValue::Float(f) => Some(*f as i64),
-                            _ => None,
-                        })
-                        .collect();
+                    let numbers = packed_ints(list, None);
                     let min = numbers.par_iter().copied().min();
                     return Ok(min.map(IfaValue::int).unwrap_or(IfaValue::null()));
                 }
@@ -127,14 +108,7 @@ impl OduHandler for OsaHandler {
             // Parallel max
             "tobi_afiwe" | "parallel_max" | "max" => {
                 if let Some(IfaValue::List(list)) = arg0 {
-                    let numbers: Vec<i64> = list
-                        .iter()
-                        .filter_map(|v| match v {
-                            IfaValue::Int(n) => Some(*n),
-                            IfaValue::Float(f) => Some(*f as i64),
-                            _ => None,
-                        })
-                        .collect();
+                    let numbers = packed_ints(list, None);
                     let max = numbers.par_iter().copied().max();
                     return Ok(max.map(IfaValue::int).unwrap_or(IfaValue::null()));
                 }
@@ -210,7 +184,6 @@ impl OduHandler for OsaHandler {
                 Err(IfaError::Runtime("sleep requires milliseconds".into()))
             }
 
-
             // Async operations - explain limitation
             "bẹrẹ" | "spawn" | "duro" | "await" | "fi" | "send" | "gba" | "recv" => {
                 Err(IfaError::Runtime(
@@ -262,3 +235,20 @@ impl OduHandler for OsaHandler {
         ]
     }
 }
+
+/// Integers of `list` for the parallel reductions (floats truncate).
+///
+/// All-integer lists are packed directly; otherwise non-numbers become
+/// `fill`, or are skipped when it is `None`.
+fn packed_ints(list: &[IfaValue], fill: Option<i64>) -> Vec<i64> {
+    if let Some(NumericList::Int(ints)) = NumericList::from_values(list) {
+        return ints;
+    }
+    list.iter()
+        .filter_map(|v| match v {
+            IfaValue::Int(n) => Some(*n),
+            IfaValue::Float(f) => Some(*f as i64),
+            _ => fill,
+        })
+        .collect()
+}
@@ -3,14 +3,194 @@
 //! Handles terminal graphics and UI operations.
 //! Binary pattern: 1010
 
+use std::cell::RefCell;
+#[cfg(feature = "game")]
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+#[cfg(feature = "game")]
+use crate::ebo::Ebo;
 use crate::error::{IfaError, IfaResult};
+use crate::interpreter::canvas::OseCanvas;
 use crate::lexer::OduDomain;
 use crate::value::IfaValue;
 
+#[cfg(not(feature = "game"))]
+use super::feature_required;
 use super::{EnvRef, OduHandler};
 
 /// Handler for Ọ̀ṣẹ́ (Graphics/UI) domain.
-pub struct OseHandler;
+///
+/// Terminal methods emit ANSI escapes; canvas methods draw to the back
+/// buffer of the shared canvas, which `present` makes visible.
+pub struct OseHandler {
+    canvas: Rc<RefCell<OseCanvas>>,
+    /// Keys for `poll_key` queued by the host instead of read from the terminal
+    #[cfg(feature = "game")]
+    key_queue: Option<Rc<RefCell<VecDeque<String>>>>,
+    /// Puts the terminal back in cooked mode when dropped, while raw mode is on
+    #[cfg(feature = "game")]
+    raw_mode: RefCell<Option<Ebo<fn()>>>,
+    /// Whether `poll_key` and `raw_mode` may use the host terminal
+    terminal: bool,
+}
+
+impl OseHandler {
+    /// Draw onto `canvas`, normally the one the interpreter renders
+    pub fn new(canvas: Rc<RefCell<OseCanvas>>) -> Self {
+        Self {
+            canvas,
+            #[cfg(feature = "game")]
+            key_queue: None,
+            #[cfg(feature = "game")]
+            raw_mode: RefCell::new(None),
+            terminal: true,
+        }
+    }
+
+    /// Refuse `poll_key` and `raw_mode`, which read from and reconfigure the
+    /// host terminal; drawing is unaffected
+    pub fn without_terminal(mut self) -> Self {
+        self.terminal = false;
+        self
+    }
+
+    /// Serve `poll_key` from `queue` rather than the terminal
+    #[cfg(feature = "game")]
+    pub fn with_key_queue(mut self, queue: Rc<RefCell<VecDeque<String>>>) -> Self {
+        self.key_queue = Some(queue);
+        self
+    }
+
+    /// The next key press without blocking, or `None` if there is none
+    #[cfg(feature = "game")]
+    fn poll_key(&self) -> IfaResult<Option<String>> {
+        use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+
+        if let Some(queue) = &self.key_queue {
+            return Ok(queue.borrow_mut().pop_front());
+        }
+        while event::poll(std::time::Duration::ZERO)
+            .map_err(|e| IfaError::Runtime(format!("poll_key failed: {}", e)))?
+        {
+            let Ok(Event::Key(key)) = event::read() else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            let name = match key.code {
+                KeyCode::Char(c) => c.to_string(),
+                KeyCode::Enter => "Enter".into(),
+                KeyCode::Esc => "Esc".into(),
+                KeyCode::Up => "Up".into(),
+                KeyCode::Down => "Down".into(),
+                KeyCode::Left => "Left".into(),
+                KeyCode::Right => "Right".into(),
+                KeyCode::Backspace => "Backspace".into(),
+                KeyCode::Tab => "Tab".into(),
+                _ => continue,
+            };
+            return Ok(Some(name));
+        }
+        Ok(None)
+    }
+
+    #[cfg(not(feature = "game"))]
+    fn poll_key(&self) -> IfaResult<Option<String>> {
+        Err(feature_required("Ose.poll_key", "game"))
+    }
+
+    /// Switch raw mode on or off. While it is on, a guard holds the way back
+    /// to cooked mode, so dropping the handler restores the terminal too.
+    #[cfg(feature = "game")]
+    fn set_raw_mode(&self, on: bool) -> IfaResult<()> {
+        let failed = |e: std::io::Error| IfaError::Runtime(format!("raw_mode failed: {}", e));
+        let mut guard = self.raw_mode.borrow_mut();
+        if on {
+            if guard.is_none() {
+                crossterm::terminal::enable_raw_mode().map_err(failed)?;
+                *guard = Some(Ebo::new("raw_mode", restore_cooked_mode));
+            }
+        } else {
+            if let Some(guard) = guard.take() {
+                guard.dismiss();
+            }
+            crossterm::terminal::disable_raw_mode().map_err(failed)?;
+        }
+        Ok(())
+    }
+
+    /// The first `N` arguments as integer coordinates
+    fn coords<const N: usize>(method: &str, args: &[IfaValue]) -> IfaResult<[i64; N]> {
+        let mut coords = [0; N];
+        for (i, coord) in coords.iter_mut().enumerate() {
+            *coord = match args.get(i) {
+                Some(IfaValue::Int(n)) => *n,
+                Some(other) => {
+                    return Err(IfaError::TypeError {
+                        expected: "integer coordinate".into(),
+                        got: other.type_name().to_string(),
+                    });
+                }
+                None => {
+                    return Err(IfaError::ArgumentError(format!(
+                        "{} requires {} integer arguments",
+                        method, N
+                    )));
+                }
+            };
+        }
+        Ok(coords)
+    }
+
+    /// Character to draw with: the first character of an optional string
+    /// argument at `index`, or `default`.
+    fn pen(args: &[IfaValue], index: usize, default: char) -> IfaResult<char> {
+        match args.get(index) {
+            None => Ok(default),
+            Some(IfaValue::Str(s)) => Ok(s.chars().next().unwrap_or(default)),
+            Some(other) => Err(IfaError::TypeError {
+                expected: "character string".into(),
+                got: other.type_name().to_string(),
+            }),
+        }
+    }
+
+    /// Cell color for a color-name argument (`None` for `reset`)
+    fn color(value: &IfaValue) -> IfaResult<Option<u8>> {
+        let IfaValue::Str(name) = value else {
+            return Err(IfaError::TypeError {
+                expected: "color name".into(),
+                got: value.type_name().to_string(),
+            });
+        };
+        let code = ansi_color(name)
+            .ok_or_else(|| IfaError::ArgumentError(format!("Unknown color '{}'", name)))?;
+        Ok((code != 0).then_some(code))
+    }
+}
+
+/// ANSI foreground code for a color name (0 for `reset`)
+fn ansi_color(name: &str) -> Option<u8> {
+    Some(match name.to_lowercase().as_str() {
+        "red" | "pupa" => 31,
+        "green" | "ewe" => 32,
+        "yellow" | "oye" => 33,
+        "blue" | "bulu" => 34,
+        "magenta" => 35,
+        "cyan" => 36,
+        "white" | "funfun" => 37,
+        "reset" | "atunto" => 0,
+        _ => return None,
+    })
+}
+
+impl Default for OseHandler {
+    fn default() -> Self {
+        Self::new(Rc::new(RefCell::new(OseCanvas::new())))
+    }
+}
 
 impl OduHandler for OseHandler {
     fn domain(&self) -> OduDomain {
@@ -26,6 +206,18 @@ impl OduHandler for OseHandler {
     ) -> IfaResult<IfaValue> {
         let arg0 = args.first();
 
+        if !self.terminal
+            && matches!(
+                method,
+                "gboran" | "poll_key" | "input_poll" | "ipo_aise" | "raw_mode"
+            )
+        {
+            return Err(IfaError::PermissionDenied(format!(
+                "Ose.{} needs the host terminal",
+                method
+            )));
+        }
+
         match method {
             // Clear terminal
             "nu" | "clear" => {
@@ -49,17 +241,7 @@ impl OduHandler for OseHandler {
             // Set text color
             "awọ" | "color" => {
                 if let Some(IfaValue::Str(color)) = arg0 {
-                    let code = match color.to_lowercase().as_str() {
-                        "red" | "pupa" => "31",
-                        "green" | "ewe" => "32",
-                        "yellow" | "oye" => "33",
-                        "blue" | "bulu" => "34",
-                        "magenta" => "35",
-                        "cyan" => "36",
-                        "white" | "funfun" => "37",
-                        "reset" | "atunto" => "0",
-                        _ => "0",
-                    };
+                    let code = ansi_color(color).unwrap_or(0);
                     _output.push(format!("\x1B[{}m", code));
                     return Ok(IfaValue::null());
                 }
@@ -147,6 +329,115 @@ impl OduHandler for OseHandler {
                 ]))
             }
 
+            // Canvas: draw to the back buffer
+            "ya" | "draw" => {
+                let [x, y] = Self::coords(method, &args)?;
+                let ch = Self::pen(&args, 2, '#')?;
+                self.canvas.borrow_mut().set_pixel(x, y, ch);
+                Ok(IfaValue::null())
+            }
+
+            "ila" | "line" => {
+                let [x1, y1, x2, y2] = Self::coords(method, &args)?;
+                let ch = Self::pen(&args, 4, '#')?;
+                self.canvas.borrow_mut().draw_line(x1, y1, x2, y2, ch);
+                Ok(IfaValue::null())
+            }
+
+            "onigun" | "rect" => {
+                let [x, y, w, h] = Self::coords(method, &args)?;
+                let ch = Self::pen(&args, 4, '#')?;
+                self.canvas.borrow_mut().draw_rect(x, y, w, h, ch);
+                Ok(IfaValue::null())
+            }
+
+            "kun" | "fill_rect" => {
+                let [x, y, w, h] = Self::coords(method, &args)?;
+                let ch = Self::pen(&args, 4, '#')?;
+                self.canvas.borrow_mut().fill_rect(x, y, w, h, ch);
+                Ok(IfaValue::null())
+            }
+
+            "iyika" | "circle" => {
+                let [x, y, r] = Self::coords(method, &args)?;
+                let ch = Self::pen(&args, 3, '#')?;
+                self.canvas.borrow_mut().draw_circle(x, y, r, ch);
+                Ok(IfaValue::null())
+            }
+
+            "ọrọ" | "text" => {
+                let [x, y] = Self::coords(method, &args)?;
+                let text = args
+                    .get(2)
+                    .ok_or_else(|| IfaError::ArgumentError("text requires x, y, text".into()))?;
+                self.canvas.borrow_mut().write_text(x, y, &text.to_string());
+                Ok(IfaValue::null())
+            }
+
+            // Text in a color that stays with the cells it is written to
+            "kọ_awọ" | "ko_colored" | "text_colored" => {
+                let [x, y] = Self::coords(method, &args)?;
+                let (Some(text), Some(color)) = (args.get(2), args.get(3)) else {
+                    return Err(IfaError::ArgumentError(
+                        "ko_colored requires x, y, text and color".into(),
+                    ));
+                };
+                let color = Self::color(color)?;
+                self.canvas
+                    .borrow_mut()
+                    .write_colored_text(x, y, &text.to_string(), color);
+                Ok(IfaValue::null())
+            }
+
+            // Multi-line sprite; spaces leave the cells underneath alone
+            "aworan" | "sprite" | "blit" => {
+                let [x, y] = Self::coords(method, &args)?;
+                let Some(IfaValue::List(rows)) = args.get(2) else {
+                    return Err(IfaError::ArgumentError(
+                        "sprite requires x, y and a list of row strings".into(),
+                    ));
+                };
+                let rows: Vec<String> = rows.iter().map(|row| row.to_string()).collect();
+                let color = match args.get(3) {
+                    Some(color) => Self::color(color)?,
+                    None => None,
+                };
+                self.canvas.borrow_mut().blit(x, y, &rows, color);
+                Ok(IfaValue::null())
+            }
+
+            "nu_kanfasi" | "clear_canvas" => {
+                let fill = Self::pen(&args, 0, ' ')?;
+                self.canvas.borrow_mut().clear(fill);
+                Ok(IfaValue::null())
+            }
+
+            // Show the back buffer as the next frame
+            "han" | "present" => {
+                self.canvas.borrow_mut().present();
+                Ok(IfaValue::null())
+            }
+
+            // Last key pressed, or Null if none is waiting (never blocks)
+            "gboran" | "poll_key" | "input_poll" => {
+                Ok(self.poll_key()?.map_or(IfaValue::null(), IfaValue::str))
+            }
+
+            // Toggle terminal raw mode (keys arrive unbuffered and unechoed)
+            #[cfg(feature = "game")]
+            "ipo_aise" | "raw_mode" => {
+                let Some(IfaValue::Bool(on)) = arg0 else {
+                    return Err(IfaError::ArgumentError(
+                        "raw_mode requires a boolean".into(),
+                    ));
+                };
+                self.set_raw_mode(*on)?;
+                Ok(IfaValue::null())
+            }
+
+            #[cfg(not(feature = "game"))]
+            "ipo_aise" | "raw_mode" => Err(feature_required("Ose.raw_mode", "game")),
+
             _ => Err(IfaError::Runtime(format!("Unknown Ọ̀ṣẹ́ method: {}", method))),
         }
     }
@@ -170,6 +461,69 @@ impl OduHandler for OseHandler {
             "show_cursor",
             "iwọn",
             "size",
+            "ya",
+            "draw",
+            "ila",
+            "line",
+            "onigun",
+            "rect",
+            "kun",
+            "fill_rect",
+            "iyika",
+            "circle",
+            "ọrọ",
+            "text",
+            "kọ_awọ",
+            "ko_colored",
+            "text_colored",
+            "aworan",
+            "sprite",
+            "blit",
+            "nu_kanfasi",
+            "clear_canvas",
+            "han",
+            "present",
+            "gboran",
+            "poll_key",
+            "input_poll",
+            "ipo_aise",
+            "raw_mode",
         ]
     }
+
+    /// A program that ends in raw mode leaves the terminal cooked
+    #[cfg(feature = "game")]
+    fn finish(&self) {
+        self.raw_mode.borrow_mut().take();
+    }
+}
+
+#[cfg(feature = "game")]
+fn restore_cooked_mode() {
+    let _ = crossterm::terminal::disable_raw_mode();
+}
+
+#[cfg(all(test, feature = "game"))]
+mod tests {
+    use super::*;
+    use crate::interpreter::Environment;
+
+    #[test]
+    fn test_poll_key_returns_queued_keys_in_order() {
+        let queue = Rc::new(RefCell::new(VecDeque::from([
+            "a".to_string(),
+            "Up".to_string(),
+        ])));
+        let handler = OseHandler::default().with_key_queue(queue.clone());
+        let env = Environment::new();
+        let mut output = Vec::new();
+        let mut poll = || handler.call("poll_key", vec![], &env, &mut output).unwrap();
+
+        assert_eq!(poll(), IfaValue::str("a"));
+        assert_eq!(poll(), IfaValue::str("Up"));
+        assert_eq!(poll(), IfaValue::null());
+
+        queue.borrow_mut().push_back("Enter".into());
+        assert_eq!(poll(), IfaValue::str("Enter"));
+    }
 }
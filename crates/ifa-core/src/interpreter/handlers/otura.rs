@@ -4,12 +4,14 @@
 //! Binary pattern: 1011
 //!
 //! When `network` feature is enabled, uses `ureq` for real HTTP requests.
-//! Otherwise, returns simulated responses for development.
+//! Otherwise the HTTP methods fail with `feature_required`.
 
 use crate::error::{IfaError, IfaResult};
 use crate::lexer::OduDomain;
 use crate::value::IfaValue;
 
+#[cfg(not(feature = "network"))]
+use super::feature_required;
 use super::{EnvRef, OduHandler};
 
 /// Handler for Òtúrá (Networking) domain.
@@ -41,21 +43,32 @@ fn http_post(url: &str, body: &str) -> IfaResult<String> {
     }
 }
 
+/// Stream the body of `url` into the file at `path`, returning bytes written.
+#[cfg(feature = "network")]
+fn http_download(url: &str, path: &str) -> IfaResult<u64> {
+    let response = ureq::get(url)
+        .call()
+        .map_err(|e| IfaError::Runtime(format!("HTTP GET failed: {}", e)))?;
+    let mut file = std::fs::File::create(path)
+        .map_err(|e| IfaError::Runtime(format!("Cannot create '{}': {}", path, e)))?;
+    std::io::copy(&mut response.into_body().into_reader(), &mut file)
+        .map_err(|e| IfaError::Runtime(format!("Download to '{}' failed: {}", path, e)))
+}
+
 // Fallback when network feature is not enabled
 #[cfg(not(feature = "network"))]
-fn http_get(url: &str) -> IfaResult<String> {
-    Err(IfaError::Runtime(format!(
-        "Network disabled. Enable 'network' feature to make real HTTP requests. URL: {}",
-        url
-    )))
+fn http_get(_url: &str) -> IfaResult<String> {
+    Err(feature_required("Otura.get", "network"))
 }
 
 #[cfg(not(feature = "network"))]
-fn http_post(url: &str, _body: &str) -> IfaResult<String> {
-    Err(IfaError::Runtime(format!(
-        "Network disabled. Enable 'network' feature to make real HTTP requests. URL: {}",
-        url
-    )))
+fn http_post(_url: &str, _body: &str) -> IfaResult<String> {
+    Err(feature_required("Otura.post", "network"))
+}
+
+#[cfg(not(feature = "network"))]
+fn http_download(_url: &str, _path: &str) -> IfaResult<u64> {
+    Err(feature_required("Otura.download", "network"))
 }
 
 impl OduHandler for OturaHandler {
@@ -99,6 +112,15 @@ impl OduHandler for OturaHandler {
                 Err(IfaError::Runtime("http_post requires URL and body".into()))
             }
 
+            // Download URL to a file, returning bytes written
+            "download" | "gba_faili" => {
+                if let (Some(IfaValue::Str(url)), Some(IfaValue::Str(path))) = (arg0, arg1) {
+                    let written = http_download(url, path)?;
+                    return Ok(IfaValue::int(written as i64));
+                }
+                Err(IfaError::Runtime("download requires URL and path".into()))
+            }
+
             // Fetch JSON
             "fetch_json" | "gba_json" => {
                 if let Some(IfaValue::Str(url)) = arg0 {
@@ -132,16 +154,12 @@ impl OduHandler for OturaHandler {
                 Err(IfaError::Runtime("fetch_json requires URL".into()))
             }
 
-            // Start HTTP server
-            "serve" | "sin" | "listen" => {
-                if let Some(IfaValue::Int(port)) = arg0 {
-                    return Err(IfaError::Runtime(format!(
-                        "HTTP server on port {} requires async runtime. Use ifa-std backend stack.",
-                        port
-                    )));
-                }
-                Err(IfaError::Runtime("serve requires port number".into()))
-            }
+            // Routing and serving call back into Ifá code, so the
+            // interpreter handles them before dispatch
+            "serve" | "sin" | "listen" | "route" | "ona" => Err(IfaError::Runtime(format!(
+                "Otura.{} must be called from the interpreter",
+                method
+            ))),
 
             // WebSocket connect
             "ws_connect" | "asopọ_ws" => {
@@ -190,10 +208,14 @@ impl OduHandler for OturaHandler {
             "serve",
             "sin",
             "listen",
+            "route",
+            "ona",
             "ws_connect",
             "asopọ_ws",
             "fetch_json",
             "gba_json",
+            "download",
+            "gba_faili",
             "url_encode",
             "koodu_url",
         ]
@@ -3,10 +3,11 @@
 //! Handles subtraction, division, modulo, and related operations.
 //! Binary pattern: 0010
 
-use crate::error::{IfaError, IfaResult};
+use crate::error::{IfaError, IfaResult, overflow};
 use crate::lexer::OduDomain;
 use crate::value::IfaValue;
 
+use super::obara::int_pair;
 use super::{EnvRef, OduHandler};
 
 /// Handler for Òtúúrúpọ̀n (Math Sub/Div) domain.
@@ -32,7 +33,9 @@ impl OduHandler for OturuponHandler {
             "din" | "yọkuro" | "sub" | "subtract" => {
                 if let (Some(left), Some(right)) = (arg0, arg1) {
                     match (left, right) {
-                        (IfaValue::Int(a), IfaValue::Int(b)) => Ok(IfaValue::int(a - b)),
+                        (IfaValue::Int(a), IfaValue::Int(b)) => {
+                            a.checked_sub(*b).map(IfaValue::int).ok_or_else(overflow)
+                        }
                         (IfaValue::Float(a), IfaValue::Float(b)) => Ok(IfaValue::float(a - b)),
                         (IfaValue::Int(a), IfaValue::Float(b)) => {
                             Ok(IfaValue::float(*a as f64 - b))
@@ -55,7 +58,7 @@ impl OduHandler for OturuponHandler {
                             if *b == 0 {
                                 return Err(IfaError::Runtime("Division by zero".into()));
                             }
-                            Ok(IfaValue::int(a / b))
+                            a.checked_div(*b).map(IfaValue::int).ok_or_else(overflow)
                         }
                         (IfaValue::Float(a), IfaValue::Float(b)) => {
                             if *b == 0.0 {
@@ -83,6 +86,14 @@ impl OduHandler for OturuponHandler {
                 }
             }
 
+            // Division as a Result value: Ok(quotient), or Err(message) where
+            // `div` would raise
+            "pin_ailewu" | "try_div" => match self.call("div", args, _env, _output) {
+                Ok(quotient) => Ok(IfaValue::ok(quotient)),
+                Err(IfaError::Runtime(message)) => Ok(IfaValue::err(IfaValue::str(message))),
+                Err(e) => Err(e),
+            },
+
             // Modulo
             "iyoku" | "mod" | "modulo" => {
                 if let (Some(left), Some(right)) = (arg0, arg1) {
@@ -91,7 +102,7 @@ impl OduHandler for OturuponHandler {
                             if *b == 0 {
                                 return Err(IfaError::Runtime("Division by zero".into()));
                             }
-                            Ok(IfaValue::int(a % b))
+                            a.checked_rem(*b).map(IfaValue::int).ok_or_else(overflow)
                         }
                         (IfaValue::Float(a), IfaValue::Float(b)) => {
                             if *b == 0.0 {
@@ -106,6 +117,60 @@ impl OduHandler for OturuponHandler {
                 }
             }
 
+            // Explicit overflow modes for two integers
+            "wrapping_sub" => {
+                let (a, b) = int_pair(method, &args)?;
+                Ok(IfaValue::int(a.wrapping_sub(b)))
+            }
+            "saturating_sub" => {
+                let (a, b) = int_pair(method, &args)?;
+                Ok(IfaValue::int(a.saturating_sub(b)))
+            }
+            // Null on overflow instead of an error
+            "checked_sub" => {
+                let (a, b) = int_pair(method, &args)?;
+                Ok(a.checked_sub(b).map_or(IfaValue::null(), IfaValue::int))
+            }
+
+            // Division that always yields a Float, even for two Ints
+            "pin_desimali" | "fdiv" => match (arg0, arg1) {
+                (Some(left), Some(right)) => {
+                    let divisor = Self::number(method, right)?;
+                    if divisor == 0.0 {
+                        return Err(IfaError::Runtime("Division by zero".into()));
+                    }
+                    Ok(IfaValue::float(Self::number(method, left)? / divisor))
+                }
+                _ => Err(IfaError::ArgumentError(
+                    "fdiv requires a dividend and a divisor".into(),
+                )),
+            },
+
+            // Explicit Float -> Int conversion, truncating towards zero
+            "si_odidi" | "to_int" => match arg0 {
+                Some(IfaValue::Int(n)) => Ok(IfaValue::int(*n)),
+                Some(IfaValue::Float(f)) => {
+                    let truncated = f.trunc();
+                    // i64::MAX as f64 rounds up to 2^63, which is out of range
+                    if truncated.is_finite()
+                        && truncated >= i64::MIN as f64
+                        && truncated < i64::MAX as f64
+                    {
+                        Ok(IfaValue::int(truncated as i64))
+                    } else {
+                        Err(IfaError::Runtime(format!(
+                            "to_int: {} does not fit in an Int",
+                            f
+                        )))
+                    }
+                }
+                Some(other) => Err(IfaError::TypeError {
+                    expected: "number for to_int".to_string(),
+                    got: other.type_name().to_string(),
+                }),
+                None => Err(IfaError::ArgumentError("to_int requires a number".into())),
+            },
+
             // Floor division
             "floor_div" => {
                 if let (Some(left), Some(right)) = (arg0, arg1) {
@@ -114,7 +179,9 @@ impl OduHandler for OturuponHandler {
                             if *b == 0 {
                                 return Err(IfaError::Runtime("Division by zero".into()));
                             }
-                            Ok(IfaValue::int(a.div_euclid(*b)))
+                            a.checked_div_euclid(*b)
+                                .map(IfaValue::int)
+                                .ok_or_else(overflow)
                         }
                         (IfaValue::Float(a), IfaValue::Float(b)) => {
                             if *b == 0.0 {
@@ -133,7 +200,7 @@ impl OduHandler for OturuponHandler {
             "neg" | "negate" => {
                 if let Some(val) = arg0 {
                     match val {
-                        IfaValue::Int(n) => Ok(IfaValue::int(-n)),
+                        IfaValue::Int(n) => n.checked_neg().map(IfaValue::int).ok_or_else(overflow),
                         IfaValue::Float(f) => Ok(IfaValue::float(-f)),
                         _ => Ok(IfaValue::int(0)),
                     }
@@ -144,15 +211,21 @@ impl OduHandler for OturuponHandler {
 
             // Square root
             "sqrt" => {
-                if let Some(val) = arg0 {
-                    match val {
-                        IfaValue::Int(n) => Ok(IfaValue::float((*n as f64).sqrt())),
-                        IfaValue::Float(f) => Ok(IfaValue::float(f.sqrt())),
-                        _ => Ok(IfaValue::float(0.0)),
+                let n = match arg0 {
+                    Some(IfaValue::Int(n)) => *n as f64,
+                    Some(IfaValue::Float(f)) => *f,
+                    Some(other) => {
+                        return Err(IfaError::TypeError {
+                            expected: "number for sqrt".to_string(),
+                            got: other.type_name().to_string(),
+                        });
                     }
-                } else {
-                    Ok(IfaValue::float(0.0))
+                    None => return Err(IfaError::ArgumentError("sqrt requires a number".into())),
+                };
+                if n < 0.0 {
+                    return Err(IfaError::Runtime(format!("sqrt of negative number {}", n)));
                 }
+                Ok(IfaValue::float(n.sqrt()))
             }
 
             _ => Err(IfaError::Runtime(format!(
@@ -170,13 +243,35 @@ impl OduHandler for OturuponHandler {
             "pin",
             "div",
             "divide",
+            "pin_ailewu",
+            "try_div",
             "iyoku",
             "mod",
             "modulo",
             "floor_div",
+            "pin_desimali",
+            "fdiv",
+            "si_odidi",
+            "to_int",
             "neg",
             "negate",
             "sqrt",
+            "wrapping_sub",
+            "saturating_sub",
+            "checked_sub",
         ]
     }
 }
+
+impl OturuponHandler {
+    fn number(method: &str, value: &IfaValue) -> IfaResult<f64> {
+        match value {
+            IfaValue::Int(n) => Ok(*n as f64),
+            IfaValue::Float(f) => Ok(*f),
+            other => Err(IfaError::TypeError {
+                expected: format!("number for {}", method),
+                got: other.type_name().to_string(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,145 @@
+//! # Parameter Names
+//!
+//! Declarative `(domain, method) -> parameter names` table used to map named
+//! arguments (`Ose.box(x: 1, y: 2, w: 10, h: 5)`) onto positions. Only
+//! methods listed here accept named arguments.
+
+use crate::error::{IfaError, IfaResult};
+use crate::lexer::OduDomain;
+
+/// Ordered parameter names of `domain.method`, if it accepts named arguments.
+pub fn param_names(domain: OduDomain, method: &str) -> Option<&'static [&'static str]> {
+    Some(match (domain, method) {
+        (OduDomain::Ose, "lọ_si" | "goto" | "move_to") => &["x", "y"],
+        (OduDomain::Ose, "apoti" | "box") => &["x", "y", "w", "h"],
+        (OduDomain::Ose, "onigun" | "rect" | "kun" | "fill_rect") => &["x", "y", "w", "h", "ch"],
+        (OduDomain::Ose, "kọ_si" | "print_at") => &["x", "y", "text"],
+        (OduDomain::Ose, "kọ_awọ" | "ko_colored" | "text_colored") => {
+            &["x", "y", "text", "color"]
+        }
+        (OduDomain::Ose, "aworan" | "sprite" | "blit") => &["x", "y", "rows", "color"],
+        (OduDomain::Ika, "ropo" | "replace") => &["text", "from", "to"],
+        (OduDomain::Ika, "ipo" | "char_at" | "at") => &["text", "index"],
+        (OduDomain::Ika, "sub" | "substring" | "slice") => &["text", "start", "end"],
+        (OduDomain::Ika, "pad_left" | "pad_right") => &["text", "width", "fill"],
+        (OduDomain::Ika, "tun" | "repeat") => &["text", "count"],
+        (OduDomain::Ika, "ka" | "count") => &["text", "sub"],
+        (OduDomain::Ika, "pin_opin" | "split_limit") => &["text", "delim", "limit"],
+        (OduDomain::Obara, "agbara" | "pow" | "power") => &["base", "exponent"],
+        (OduDomain::Odi, "kọ" | "write" | "fikun" | "append") => &["path", "content"],
+        (OduDomain::Odi, "fikun_jsonl" | "append_jsonl") => &["path", "value"],
+        (OduDomain::Otura, "http_post" | "fi" | "post") => &["url", "body"],
+        (OduDomain::Otura, "download" | "gba_faili") => &["url", "path"],
+        (OduDomain::Coop, "py_json") => &["module", "func", "args"],
+        (OduDomain::Coop, "wasm") => &["path", "func", "args"],
+        _ => return None,
+    })
+}
+
+/// Arrange `positional` followed by `named` in parameter order.
+///
+/// Generic over the argument type; the interpreter, compiler and transpiler
+/// all bind argument expressions first, so arguments are evaluated in
+/// parameter order rather than source order.
+pub fn bind_named_args<T>(
+    domain: OduDomain,
+    method: &str,
+    positional: Vec<T>,
+    named: Vec<(String, T)>,
+) -> IfaResult<Vec<T>> {
+    if named.is_empty() {
+        return Ok(positional);
+    }
+    let call = format!("{:?}.{}", domain, method);
+    let params = param_names(domain, method).ok_or_else(|| {
+        IfaError::ArgumentError(format!("{} does not take named arguments", call))
+    })?;
+    if positional.len() > params.len() {
+        return Err(IfaError::ArgumentError(format!(
+            "{} takes at most {} arguments",
+            call,
+            params.len()
+        )));
+    }
+
+    let mut slots: Vec<Option<T>> = positional.into_iter().map(Some).collect();
+    slots.resize_with(params.len(), || None);
+    for (name, value) in named {
+        let index = params.iter().position(|p| *p == name).ok_or_else(|| {
+            IfaError::ArgumentError(format!(
+                "unknown argument '{}' for {} (expected one of: {})",
+                name,
+                call,
+                params.join(", ")
+            ))
+        })?;
+        if slots[index].replace(value).is_some() {
+            return Err(IfaError::ArgumentError(format!(
+                "duplicate argument '{}' for {}",
+                name, call
+            )));
+        }
+    }
+
+    // Trailing parameters may be left out; gaps before a given one may not
+    while matches!(slots.last(), Some(None)) {
+        slots.pop();
+    }
+    slots
+        .into_iter()
+        .zip(params)
+        .map(|(slot, name)| {
+            slot.ok_or_else(|| {
+                IfaError::ArgumentError(format!("missing argument '{}' for {}", name, call))
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn named(pairs: &[(&str, i32)]) -> Vec<(String, i32)> {
+        pairs.iter().map(|(n, v)| (n.to_string(), *v)).collect()
+    }
+
+    #[test]
+    fn test_named_args_fill_parameter_order() {
+        let bound = bind_named_args(
+            OduDomain::Ose,
+            "box",
+            vec![1],
+            named(&[("h", 4), ("w", 3), ("y", 2)]),
+        )
+        .unwrap();
+        assert_eq!(bound, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_named_args_reject_unknown_duplicate_and_gaps() {
+        let err = bind_named_args(OduDomain::Ose, "box", vec![], named(&[("z", 1)]));
+        assert!(
+            err.unwrap_err()
+                .to_string()
+                .contains("unknown argument 'z'")
+        );
+
+        let err = bind_named_args(OduDomain::Ose, "box", vec![1], named(&[("x", 1)]));
+        assert!(
+            err.unwrap_err()
+                .to_string()
+                .contains("duplicate argument 'x'")
+        );
+
+        let err = bind_named_args(OduDomain::Ose, "box", vec![], named(&[("y", 1)]));
+        assert!(
+            err.unwrap_err()
+                .to_string()
+                .contains("missing argument 'x'")
+        );
+
+        let err = bind_named_args(OduDomain::Irosu, "fo", vec![], named(&[("x", 1)]));
+        assert!(err.unwrap_err().to_string().contains("does not take named"));
+    }
+}
@@ -0,0 +1,343 @@
+//! # Òtúrá HTTP Server
+//!
+//! Minimal blocking HTTP/1.1 support behind `Otura.route` / `Otura.serve`.
+//! The interpreter owns the accept loop (handlers are Ifá functions); this
+//! module only parses requests, matches routes and writes responses. Every
+//! response closes its connection.
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpStream;
+
+use crate::error::{IfaError, IfaResult};
+use crate::interpreter::handlers::coop::ifa_to_json;
+use crate::value::IfaValue;
+
+/// Largest request body accepted, in bytes.
+const MAX_BODY: usize = 16 * 1024 * 1024;
+
+/// Longest request or header line accepted, in bytes (CRLF included).
+const MAX_LINE: usize = 8 * 1024;
+
+/// Most header lines accepted in one request.
+const MAX_HEADERS: usize = 100;
+
+/// A handler registered with `Otura.route(method, path, handler)`.
+pub(crate) struct Route {
+    method: String,
+    segments: Vec<String>,
+    pub(crate) handler: IfaValue,
+}
+
+impl Route {
+    /// `path` segments starting with `:` capture the request's segment under
+    /// that name; `*` as the method matches any method.
+    pub(crate) fn new(method: &str, path: &str, handler: IfaValue) -> Self {
+        Self {
+            method: method.to_ascii_uppercase(),
+            segments: split_path(path),
+            handler,
+        }
+    }
+
+    /// Captured path parameters if this route serves `method path`.
+    pub(crate) fn matches(&self, method: &str, path: &str) -> Option<HashMap<String, IfaValue>> {
+        if self.method != "*" && !self.method.eq_ignore_ascii_case(method) {
+            return None;
+        }
+        let segments = split_path(path);
+        if segments.len() != self.segments.len() {
+            return None;
+        }
+        let mut params = HashMap::new();
+        for (pattern, segment) in self.segments.iter().zip(segments) {
+            match pattern.strip_prefix(':') {
+                Some(name) => {
+                    params.insert(name.to_string(), IfaValue::str(segment));
+                }
+                None if *pattern == segment => {}
+                None => return None,
+            }
+        }
+        Some(params)
+    }
+}
+
+/// Read one line of at most `MAX_LINE` bytes; `Ok(None)` if it is longer.
+fn read_line(reader: &mut impl BufRead, line: &mut String) -> std::io::Result<Option<usize>> {
+    let read = reader.take(MAX_LINE as u64).read_line(line)?;
+    if read == MAX_LINE && !line.ends_with('\n') {
+        return Ok(None);
+    }
+    Ok(Some(read))
+}
+
+fn split_path(path: &str) -> Vec<String> {
+    path.split('/')
+        .filter(|s| !s.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// A parsed HTTP request.
+pub(crate) struct HttpRequest {
+    pub(crate) method: String,
+    pub(crate) path: String,
+    query: String,
+    headers: HashMap<String, IfaValue>,
+    body: String,
+}
+
+impl HttpRequest {
+    /// Read one request from `stream`. `Err` carries the status to answer
+    /// with: 400 if it is not valid HTTP, 414 if the request line is too
+    /// long and 431 if there are too many or too long header lines.
+    pub(crate) fn read(stream: impl Read) -> Result<Self, i64> {
+        let mut reader = BufReader::new(stream);
+        let mut line = String::new();
+        match read_line(&mut reader, &mut line) {
+            Ok(Some(_)) => {}
+            Ok(None) => return Err(414),
+            Err(_) => return Err(400),
+        }
+        let mut parts = line.split_whitespace();
+        let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+            return Err(400);
+        };
+        let (path, query) = target.split_once('?').unwrap_or((target, ""));
+        let mut request = HttpRequest {
+            method: method.to_string(),
+            path: path.to_string(),
+            query: query.to_string(),
+            headers: HashMap::new(),
+            body: String::new(),
+        };
+
+        let mut content_length = 0;
+        let mut header_lines = 0;
+        loop {
+            line.clear();
+            match read_line(&mut reader, &mut line) {
+                Ok(Some(0)) => break,
+                Ok(Some(_)) => {}
+                Ok(None) => return Err(431),
+                Err(_) => return Err(400),
+            }
+            let header = line.trim_end();
+            if header.is_empty() {
+                break;
+            }
+            header_lines += 1;
+            if header_lines > MAX_HEADERS {
+                return Err(431);
+            }
+            if let Some((name, value)) = header.split_once(':') {
+                let name = name.trim().to_ascii_lowercase();
+                let value = value.trim();
+                if name == "content-length" {
+                    content_length = value.parse().unwrap_or(0);
+                }
+                request.headers.insert(name, IfaValue::str(value));
+            }
+        }
+        if content_length > MAX_BODY {
+            return Err(400);
+        }
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body).map_err(|_| 400)?;
+        request.body = String::from_utf8_lossy(&body).into_owned();
+        Ok(request)
+    }
+
+    /// The request as handed to an Ifá handler.
+    pub(crate) fn to_value(&self, params: HashMap<String, IfaValue>) -> IfaValue {
+        IfaValue::map(HashMap::from([
+            ("method".to_string(), IfaValue::str(self.method.as_str())),
+            ("path".to_string(), IfaValue::str(self.path.as_str())),
+            ("query".to_string(), IfaValue::str(self.query.as_str())),
+            ("headers".to_string(), IfaValue::map(self.headers.clone())),
+            ("body".to_string(), IfaValue::str(self.body.as_str())),
+            ("params".to_string(), IfaValue::map(params)),
+        ]))
+    }
+}
+
+/// Write a handler's result as the response.
+///
+/// A `Map` may set `status` (default 200), `headers` and `body`; a `Str`
+/// is sent as a 200 text body. Any other body is sent as JSON.
+pub(crate) fn write_response(stream: &mut TcpStream, response: &IfaValue) -> IfaResult<()> {
+    let (status, headers, body) = match response {
+        IfaValue::Map(map) => {
+            let status = match map.get("status") {
+                None => 200,
+                Some(IfaValue::Int(code)) if (100..=999).contains(code) => *code,
+                Some(other) => {
+                    return Err(IfaError::Runtime(format!(
+                        "Response status must be an HTTP status code, got {}",
+                        other
+                    )));
+                }
+            };
+            let headers = match map.get("headers") {
+                Some(IfaValue::Map(headers)) => headers
+                    .iter()
+                    .map(|(name, value)| (name.to_string(), value.to_string()))
+                    .collect(),
+                _ => Vec::new(),
+            };
+            (
+                status,
+                headers,
+                map.get("body").cloned().unwrap_or(IfaValue::Null),
+            )
+        }
+        other => (200, Vec::new(), other.clone()),
+    };
+    let (content_type, body) = match &body {
+        IfaValue::Str(text) => ("text/plain; charset=utf-8", text.to_string()),
+        IfaValue::Null => ("text/plain; charset=utf-8", String::new()),
+        other => ("application/json", ifa_to_json(other)?.to_string()),
+    };
+    send(stream, status, content_type, &headers, &body)
+        .map_err(|e| IfaError::Runtime(format!("Failed to write response: {}", e)))
+}
+
+/// Write a plain-text response with no extra headers.
+pub(crate) fn write_status(stream: &mut TcpStream, status: i64, body: &str) {
+    let _ = send(stream, status, "text/plain; charset=utf-8", &[], body);
+}
+
+/// Header names must be HTTP tokens and values may not contain control
+/// characters, so a handler cannot split the response with CR/LF.
+fn valid_header(name: &str, value: &str) -> bool {
+    !name.is_empty()
+        && name
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&b))
+        && !value.chars().any(|c| c.is_control() && c != '\t')
+}
+
+fn send(
+    stream: &mut impl Write,
+    status: i64,
+    content_type: &str,
+    headers: &[(String, String)],
+    body: &str,
+) -> std::io::Result<()> {
+    if let Some((name, _)) = headers
+        .iter()
+        .find(|(name, value)| !valid_header(name, value))
+    {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("invalid response header '{}'", name.escape_debug()),
+        ));
+    }
+    let mut head = format!(
+        "HTTP/1.1 {} {}\r\nContent-Length: {}\r\nConnection: close\r\n",
+        status,
+        reason(status),
+        body.len()
+    );
+    if !headers
+        .iter()
+        .any(|(name, _)| name.eq_ignore_ascii_case("content-type"))
+    {
+        head.push_str(&format!("Content-Type: {}\r\n", content_type));
+    }
+    for (name, value) in headers {
+        head.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    head.push_str("\r\n");
+    stream.write_all(head.as_bytes())?;
+    stream.write_all(body.as_bytes())?;
+    stream.flush()
+}
+
+pub(crate) fn reason(status: i64) -> &'static str {
+    match status {
+        200 => "OK",
+        201 => "Created",
+        204 => "No Content",
+        301 => "Moved Permanently",
+        302 => "Found",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        414 => "URI Too Long",
+        431 => "Request Header Fields Too Large",
+        500 => "Internal Server Error",
+        _ => "",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_route_matching_captures_params() {
+        let route = Route::new("get", "/users/:id", IfaValue::Null);
+        let params = route.matches("GET", "/users/42/").unwrap();
+        assert_eq!(params.get("id"), Some(&IfaValue::str("42")));
+
+        assert!(route.matches("POST", "/users/42").is_none());
+        assert!(route.matches("GET", "/users").is_none());
+        assert!(route.matches("GET", "/posts/42").is_none());
+        assert!(
+            Route::new("*", "/", IfaValue::Null)
+                .matches("DELETE", "/")
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn test_oversized_request_heads_get_414_or_431() {
+        let ok = "GET /x?a=1 HTTP/1.1\r\nHost: h\r\n\r\n";
+        let request = HttpRequest::read(ok.as_bytes()).ok().unwrap();
+        assert_eq!(
+            (request.path.as_str(), request.query.as_str()),
+            ("/x", "a=1")
+        );
+
+        let long_target = format!("GET /{} HTTP/1.1\r\n\r\n", "a".repeat(MAX_LINE));
+        assert_eq!(HttpRequest::read(long_target.as_bytes()).err(), Some(414));
+
+        let long_header = format!("GET / HTTP/1.1\r\nX: {}\r\n\r\n", "a".repeat(MAX_LINE));
+        assert_eq!(HttpRequest::read(long_header.as_bytes()).err(), Some(431));
+
+        let many_headers = format!(
+            "GET / HTTP/1.1\r\n{}\r\n",
+            "X: y\r\n".repeat(MAX_HEADERS + 1)
+        );
+        assert_eq!(HttpRequest::read(many_headers.as_bytes()).err(), Some(431));
+        assert_eq!(HttpRequest::read(&b"\r\n"[..]).err(), Some(400));
+    }
+
+    #[test]
+    fn test_crlf_in_response_headers_is_refused() {
+        for (name, value) in [
+            ("X-Ok", "a\r\nSet-Cookie: evil=1"),
+            ("X-Ok\r\nSet-Cookie", "evil=1"),
+            ("X Bad", "v"),
+            ("", "v"),
+        ] {
+            let mut out = Vec::new();
+            let headers = [(name.to_string(), value.to_string())];
+            assert!(send(&mut out, 200, "text/plain", &headers, "").is_err());
+            assert!(out.is_empty(), "nothing may be written for {:?}", name);
+        }
+
+        let mut out = Vec::new();
+        let headers = [("X-Trace".to_string(), "a\tb".to_string())];
+        send(&mut out, 200, "text/plain", &headers, "hi").unwrap();
+        assert!(
+            String::from_utf8(out)
+                .unwrap()
+                .contains("X-Trace: a\tb\r\n")
+        );
+    }
+}
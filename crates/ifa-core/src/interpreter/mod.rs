@@ -5,13 +5,22 @@
 //! - `core.rs` - Main Interpreter implementation
 //! - `environment.rs` - GPC (Grandparent-Parent-Child) scope chain
 //! - `handlers/` - Modular domain-specific operation handlers
+//! - `canvas.rs` - Ọ̀ṣẹ́ ASCII canvas
+//! - `http_server.rs` - Òtúrá HTTP request handling (`backend` feature)
 
+pub mod canvas;
 mod core;
 pub mod environment;
 pub mod handlers;
+#[cfg(feature = "backend")]
+mod http_server;
 
 // Re-export main types from core
-pub use self::core::{CapabilitySet, Debugger, Interpreter, Ofun};
+pub use self::core::{
+    CapabilityAuditEntry, CapabilitySet, DEFAULT_COLLECTION_LIMIT, Debugger, ExecutionStats,
+    Interpreter, OPELE_JOURNAL_LIMIT, OUTPUT_TRUNCATED, Ofun, OutputLimit, OutputSink,
+    SANDBOX_DOMAINS,
+};
 
 // Re-export extracted modules
 pub use environment::{EnvRef, Environment};
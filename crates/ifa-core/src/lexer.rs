@@ -5,6 +5,7 @@
 
 use logos::{Lexer, Logos};
 use std::fmt;
+use unicode_normalization::UnicodeNormalization;
 
 /// Normalize Yoruba text to ASCII for matching
 fn normalize_yoruba(text: &str) -> String {
@@ -52,6 +53,7 @@ fn check_domain(lex: &mut Lexer<Token>) -> Option<OduDomain> {
         "ose" => Some(OduDomain::Ose),
         "ofun" => Some(OduDomain::Ofun),
         "opele" => Some(OduDomain::Opele),
+        "opon" => Some(OduDomain::Opon),
         _ => {
             // Standard programming aliases (max 2 per domain - keep it simple!)
             match lower.as_str() {
@@ -150,6 +152,7 @@ pub enum Token {
     Let,
 
     #[token("const")]
+    #[token("tuntun")]
     #[token("ayanfe")]
     #[token("àyànfẹ́")]
     Const,
@@ -181,13 +184,13 @@ pub enum Token {
     #[token("break")]
     Break,
 
-    #[token("ta")]        // canonical throw
+    #[token("ta")] // canonical throw
     #[token("tá")]
     #[token("throw")]
     Throw,
 
-    #[token("tesiwaju")]  // canonical Yoruba (spec §3.4)
-    #[token("bayan")]     // Yoruba alias
+    #[token("tesiwaju")] // canonical Yoruba (spec §3.4)
+    #[token("bayan")] // Yoruba alias
     #[token("continue")]
     Continue,
 
@@ -224,7 +227,7 @@ pub enum Token {
     #[token("async")]
     Async,
 
-    #[token("reti")]       // canonical await (spec §3.4 ratified)
+    #[token("reti")] // canonical await (spec §3.4 ratified)
     #[token("rẹti")]
     #[token("await")]
     Await,
@@ -244,7 +247,7 @@ pub enum Token {
     False,
 
     #[token("ohunkohun")]
-    #[token("ofo")]       // canonical Yoruba (spec §3.4)
+    #[token("ofo")] // canonical Yoruba (spec §3.4)
     #[token("ófo")]
     #[token("nil")]
     #[token("null")]
@@ -315,11 +318,15 @@ pub enum Token {
     // ═══════════════════════════════════════════════════════════════════════
 
     // Odù domain names (supports lowercase ergonomic aliases)
-    #[regex(r"[a-zA-Z\u0080-\uFFFF][a-zA-Z0-9_\u0080-\uFFFF]*", check_domain, priority = 2)]
+    #[regex(
+        r"[a-zA-Z\u0080-\uFFFF][a-zA-Z0-9_\u0080-\uFFFF]*",
+        check_domain,
+        priority = 2
+    )]
     Domain(OduDomain),
 
-    // Regular identifiers
-    #[regex(r"[a-z_\u0080-\uFFFF][a-zA-Z0-9_\u0080-\uFFFF]*", |lex| lex.slice().to_string(), priority = 1)]
+    // Regular identifiers (NFC, so combining and precomposed spellings match)
+    #[regex(r"[a-z_\u0080-\uFFFF][a-zA-Z0-9_\u0080-\uFFFF]*", |lex| lex.slice().nfc().collect::<String>(), priority = 1)]
     Identifier(String),
 
     // ═══════════════════════════════════════════════════════════════════════
@@ -420,6 +427,21 @@ pub struct Spanned<T> {
     pub span: std::ops::Range<usize>,
 }
 
+/// A word the `Domain` regex claimed but `check_domain` rejected (`x`,
+/// `Foo`, `fo`) is an ordinary identifier. Logos reports such a match as an
+/// error instead of falling back to the `Identifier` rule, so without this
+/// every consumer of `tokenize` (LSP, fmt, babalawo) would lose the word.
+fn rejected_domain_word(slice: &str) -> Option<Token> {
+    let is_word = slice
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '_')
+        && slice
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || !c.is_ascii());
+    is_word.then(|| Token::Identifier(slice.nfc().collect()))
+}
+
 /// Tokenize source code
 pub fn tokenize(source: &str) -> Vec<Spanned<Token>> {
     let mut lexer = Token::lexer(source);
@@ -434,7 +456,13 @@ pub fn tokenize(source: &str) -> Vec<Spanned<Token>> {
                 });
             }
             Err(_) => {
-                // Skip invalid tokens for now (architecture: diagnostics handled by parser)
+                if let Some(token) = rejected_domain_word(lexer.slice()) {
+                    tokens.push(Spanned {
+                        value: token,
+                        span: lexer.span(),
+                    });
+                }
+                // Skip other invalid tokens (architecture: diagnostics handled by parser)
             }
         }
     }
@@ -462,6 +490,39 @@ mod tests {
         assert!(matches!(tokens[1].value, Token::Dot));
     }
 
+    #[test]
+    fn test_non_domain_words_are_identifiers() {
+        let tokens = tokenize("Irosu.fo(Foo, x)");
+        let values: Vec<String> = tokens.iter().map(|t| t.value.to_string()).collect();
+        assert_eq!(
+            values,
+            [
+                "Domain(Irosu)",
+                "Dot",
+                "Ident(fo)",
+                "LParen",
+                "Ident(Foo)",
+                "Comma",
+                "Ident(x)",
+                "RParen"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rejected_domain_words_are_nfc_normalized() {
+        // "Ọ" spelled as O + combining dot below
+        let tokens = tokenize("O\u{323}mo");
+        assert!(matches!(&tokens[0].value, Token::Identifier(s) if s == "Ọmo"));
+    }
+
+    #[test]
+    fn test_invalid_symbols_are_still_skipped() {
+        let tokens = tokenize("x $ y");
+        assert_eq!(tokens.len(), 2);
+        assert!(matches!(&tokens[1].value, Token::Identifier(s) if s == "y"));
+    }
+
     #[test]
     fn test_string() {
         let tokens = tokenize(r#""Hello Ifá!""#);
@@ -478,6 +539,8 @@ mod tests {
     #[test]
     fn test_interpolated_string() {
         let tokens = tokenize(r#"$"The value is {x}""#);
-        assert!(matches!(&tokens[0].value, Token::InterpolatedString(s) if s == "The value is {x}"));
+        assert!(
+            matches!(&tokens[0].value, Token::InterpolatedString(s) if s == "The value is {x}")
+        );
     }
 }
@@ -17,6 +17,7 @@
 //! - `lexer` - Tokenization with logos
 //! - `ast` - Abstract Syntax Tree types
 //! - `parser` - Parsing with pest
+//! - `incremental` - Statement-level re-parsing for editors
 //! - `interpreter` - Tree-walking interpreter
 //! - `compiler` - AST to bytecode compilation
 //! - `transpiler` - AST to Rust source for native builds
@@ -26,6 +27,8 @@
 //! - `opon` - Memory management (Calabash)
 //! - `error` - Error types
 //! - `ebo` - Ẹbọ resource lifecycle (RAII)
+//! - `embed` - One-call API for hosts embedding the interpreter
+//! - `module_cache` - On-disk cache of compiled imported modules
 //! - `ajose` - Àjọṣe reactive relationships
 //! - `iwa_pele` - Ìwà Pẹ̀lẹ́ graceful error handling
 
@@ -34,10 +37,13 @@ pub mod ast;
 pub mod bytecode;
 pub mod compiler;
 pub mod ebo;
+pub mod embed;
 pub mod error;
+pub mod incremental;
 pub mod interpreter;
 pub mod iwa_pele;
 pub mod lexer;
+pub mod module_cache;
 pub mod module_resolver;
 pub mod native;
 pub mod opon;
@@ -52,7 +58,7 @@ pub mod vm;
 pub mod vm_ikin;
 pub mod vm_iroke;
 
-#[cfg(test)]
+#[cfg(feature = "oracle")]
 pub mod oracle;
 
 // Re-exports for convenience
@@ -61,13 +67,15 @@ pub use ast::{Expression, Program, Statement};
 pub use bytecode::{Bytecode, OpCode};
 pub use compiler::{Compiler, compile};
 pub use ebo::{Ebo, EboScope};
+pub use embed::{RunOutcome, run_captured};
 pub use error::{IfaError, IfaResult};
 pub use interpreter::Interpreter;
 pub use iwa_pele::{IwaPele, IwaPeleError, IwaPeleErrorKind};
 pub use lexer::{OduDomain, Token, tokenize};
-pub use module_resolver::{ImportGuard, ModuleResolver};
+pub use module_cache::ModuleCache;
+pub use module_resolver::{ImportGuard, ModuleResolver, ModuleSource};
 pub use opon::{Opon, OponError, OponErrorKind, OponResult, OponSize};
-pub use parser::parse;
+pub use parser::{byte_literal_positions, parse};
 pub use project_generator::generate_project;
 pub use transpiler::transpile_to_rust;
 pub use value::IfaValue;
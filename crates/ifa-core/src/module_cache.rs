@@ -0,0 +1,126 @@
+//! # Module Cache
+//!
+//! On-disk cache of compiled imported modules, so the VM loads an unchanged
+//! module from its `.ifab` bytecode instead of parsing and compiling it again
+//! on every run.
+//!
+//! Each source file has one entry: `<key>.ifab`, plain bytecode that `ifa
+//! runb` could load itself, and `<key>.sha256`, the SHA-256 of the source it
+//! was compiled from. The key hashes the module's path together with the
+//! version of this crate, so a different build of Ifá never reads entries
+//! whose compiler output may have changed. A module whose source no longer
+//! matches its digest is a miss and is overwritten on the next store.
+//!
+//! The cache is written by the host (`ifa runb`), not by the script, so the
+//! script's `WriteFiles` grants do not apply to it; hosts choose its
+//! directory, which for ọjà projects is build output under `target/`.
+
+use std::path::{Path, PathBuf};
+
+use sha2::{Digest, Sha256};
+
+use crate::bytecode::Bytecode;
+
+/// Compiled-module cache rooted at a directory.
+#[derive(Debug, Clone)]
+pub struct ModuleCache {
+    dir: PathBuf,
+    hits: usize,
+}
+
+impl ModuleCache {
+    /// Cache storing its entries in `dir` (created on first store).
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            hits: 0,
+        }
+    }
+
+    /// Directory holding the entries
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Modules served from the cache so far
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// The bytecode for `path`, if it was stored from exactly `source`.
+    pub fn load(&mut self, path: &Path, source: &str) -> Option<Bytecode> {
+        let entry = self.entry_path(path);
+        let digest = std::fs::read(entry.with_extension("sha256")).ok()?;
+        if digest != source_digest(source) {
+            return None;
+        }
+        let bytecode = Bytecode::from_bytes(&std::fs::read(entry).ok()?).ok()?;
+        self.hits += 1;
+        Some(bytecode)
+    }
+
+    /// Record `bytecode` as the compiled form of `source` at `path`.
+    ///
+    /// The cache is an optimisation, so failing to write it is not an error.
+    /// Bytecode whose constants the `.ifab` format cannot hold is not stored.
+    pub fn store(&self, path: &Path, source: &str, bytecode: &Bytecode) {
+        let bytes = bytecode.to_bytes();
+        match Bytecode::from_bytes(&bytes) {
+            Ok(read_back) if read_back.constants.len() == bytecode.constants.len() => {}
+            _ => return,
+        }
+        let entry = self.entry_path(path);
+        let _ = std::fs::create_dir_all(&self.dir)
+            .and_then(|_| std::fs::write(&entry, bytes))
+            .and_then(|_| std::fs::write(entry.with_extension("sha256"), source_digest(source)));
+    }
+
+    fn entry_path(&self, path: &Path) -> PathBuf {
+        let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        let mut hasher = Sha256::new();
+        hasher.update(env!("CARGO_PKG_VERSION").as_bytes());
+        hasher.update([0]);
+        hasher.update(path.to_string_lossy().as_bytes());
+        let name: String = hasher.finalize()[..16]
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect();
+        self.dir.join(name).with_extension("ifab")
+    }
+}
+
+fn source_digest(source: &str) -> Vec<u8> {
+    Sha256::digest(source.as_bytes()).to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entry_is_invalidated_by_source_change() {
+        let dir = std::env::temp_dir().join(format!("ifa_module_cache_{}", std::process::id()));
+        let module = dir.join("lib.ifa");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(&module, "ayanmo x = 1;").unwrap();
+
+        let mut cache = ModuleCache::new(dir.join("cache"));
+        let bytecode = crate::compile("ayanmo x = 1;").unwrap();
+        assert!(cache.load(&module, "ayanmo x = 1;").is_none());
+        cache.store(&module, "ayanmo x = 1;", &bytecode);
+
+        let hit = cache.load(&module, "ayanmo x = 1;");
+        let stale = cache.load(&module, "ayanmo x = 2;");
+        let entries = std::fs::read_dir(dir.join("cache"))
+            .unwrap()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.path().extension().is_some_and(|ext| ext == "ifab"))
+            .count();
+        std::fs::remove_dir_all(&dir).ok();
+
+        assert_eq!(hit.unwrap().code, bytecode.code);
+        assert!(stale.is_none());
+        assert_eq!(cache.hits(), 1);
+        assert_eq!(entries, 1);
+    }
+}
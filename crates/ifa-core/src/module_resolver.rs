@@ -13,7 +13,7 @@
 //! ## Canonical Module Key Format  
 //! `utils.math` (dot-separated, normalised from slashes)
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 
 use crate::error::{IfaError, IfaResult};
@@ -131,6 +131,34 @@ impl ModuleResolver {
     }
 }
 
+/// Pluggable supplier of module source text for the AST interpreter.
+///
+/// Lets hosts without a real filesystem (WASM, sandboxed embedding) serve
+/// imports themselves. `path` is the import path split on dots, e.g.
+/// `["utils", "math"]`.
+pub trait ModuleSource {
+    /// Return the source text of the module, or `None` if it doesn't exist.
+    fn resolve(&self, path: &[String]) -> Option<String>;
+}
+
+/// Disk search, as used by the interpreter when no source is configured.
+impl ModuleSource for ModuleResolver {
+    fn resolve(&self, path: &[String]) -> Option<String> {
+        let found = ModuleResolver::resolve(self, &path.join(".")).ok()?;
+        if found.is_binary {
+            return None;
+        }
+        std::fs::read_to_string(found.path).ok()
+    }
+}
+
+/// In-memory modules keyed by canonical name (e.g. `"utils.math"`).
+impl ModuleSource for HashMap<String, String> {
+    fn resolve(&self, path: &[String]) -> Option<String> {
+        self.get(&path.join(".")).cloned()
+    }
+}
+
 /// Lightweight guard for detecting circular imports at runtime.
 /// Each backend creates one per execution session.
 #[derive(Debug, Default)]
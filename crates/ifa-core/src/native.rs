@@ -17,6 +17,14 @@ impl<'a> VmContext<'a> {
     pub fn await_future(&mut self, cell: &FutureCell) -> IfaResult<IfaValue> {
         self.vm.await_future(cell, self.bytecode)
     }
+
+    /// Call an Ifá function value to completion and return its result.
+    pub fn call_function(&mut self, func: IfaValue, args: Vec<IfaValue>) -> IfaResult<IfaValue> {
+        match self.spawn_task(func, args)? {
+            IfaValue::Future(cell) => self.await_future(&cell),
+            other => Ok(other),
+        }
+    }
 }
 
 /// Registry for finding and executing native Odù functions
@@ -1,83 +1,181 @@
 //! # Semantic Oracle
 //!
-//! Research tool for validating the semantic bridge between Ifá-Lang and Rust.
+//! Research tool for validating the semantic bridge between Ifá-Lang and Rust,
+//! and between the tree-walking interpreter and the bytecode VM.
+//!
+//! Enabled by the `oracle` feature; crates that own a VM registry (such as
+//! `ifa-std`) turn it on for their differential tests.
+
+use crate::bytecode::Bytecode;
+use crate::native::OduRegistry;
+use crate::vm::IfaVM;
+use crate::{Compiler, IfaError, IfaValue, Interpreter, parse, transpile_to_rust};
+use std::fs;
+use std::process::Command;
+use std::sync::{Arc, Mutex};
+
+pub struct OracleResult {
+    pub interpreter_stdout: String,
+    pub transpiler_stdout: String,
+}
 
-#[cfg(test)]
-pub mod oracle {
-    use crate::{Interpreter, parse, transpile_to_rust};
-    use std::fs;
-    use std::process::Command;
+pub fn verify_equivalence(source: &str) -> OracleResult {
+    // 1. Run Interpreter
+    let program = parse(source).expect("Failed to parse source");
+    let mut interpreter = Interpreter::new();
+    interpreter
+        .execute(&program)
+        .expect("Interpreter execution failed");
+    let int_stdout = interpreter.get_output().join("\n");
 
-    pub struct OracleResult {
-        pub interpreter_stdout: String,
-        pub transpiler_stdout: String,
+    // 2. Transpile and Compile
+    let rust_code = transpile_to_rust(&program);
+
+    // Use a temporary directory for compilation
+    let tmp_dir = std::env::temp_dir().join("ifa_oracle");
+    if !tmp_dir.exists() {
+        fs::create_dir_all(&tmp_dir).unwrap();
     }
 
-    pub fn verify_equivalence(source: &str) -> OracleResult {
-        // 1. Run Interpreter
-        let program = parse(source).expect("Failed to parse source");
-        let mut interpreter = Interpreter::new();
-        interpreter
-            .execute(&program)
-            .expect("Interpreter execution failed");
-        let int_stdout = interpreter.get_output().join("\n");
-
-        // 2. Transpile and Compile
-        let rust_code = transpile_to_rust(&program);
-
-        // Use a temporary directory for compilation
-        let tmp_dir = std::env::temp_dir().join("ifa_oracle");
-        if !tmp_dir.exists() {
-            fs::create_dir_all(&tmp_dir).unwrap();
-        }
+    let rust_file = tmp_dir.join("test_output.rs");
+    let bin_file = if cfg!(windows) {
+        tmp_dir.join("test_output.exe")
+    } else {
+        tmp_dir.join("test_output")
+    };
 
-        let rust_file = tmp_dir.join("test_output.rs");
-        let bin_file = if cfg!(windows) {
-            tmp_dir.join("test_output.exe")
-        } else {
-            tmp_dir.join("test_output")
-        };
+    fs::write(&rust_file, rust_code).expect("Failed to write Rust source");
 
-        fs::write(&rust_file, rust_code).expect("Failed to write Rust source");
+    // Compile with rustc
+    let status = Command::new("rustc")
+        .arg(&rust_file)
+        .arg("-o")
+        .arg(&bin_file)
+        .status()
+        .expect("Failed to run rustc");
 
-        // Compile with rustc
-        let status = Command::new("rustc")
-            .arg(&rust_file)
-            .arg("-o")
-            .arg(&bin_file)
-            .status()
-            .expect("Failed to run rustc");
+    if !status.success() {
+        panic!("Generated Rust failed to compile");
+    }
 
-        if !status.success() {
-            panic!("Generated Rust failed to compile");
-        }
+    // 3. Run Binary
+    let output = Command::new(&bin_file)
+        .output()
+        .expect("Failed to run generated binary");
 
-        // 3. Run Binary
-        let output = Command::new(&bin_file)
-            .output()
-            .expect("Failed to run generated binary");
+    let trans_stdout_raw = String::from_utf8_lossy(&output.stdout).to_string();
 
-        let trans_stdout_raw = String::from_utf8_lossy(&output.stdout).to_string();
+    // Cleanup "Àṣẹ! (Success)" and leading/trailing whitespace
+    let trans_stdout = trans_stdout_raw
+        .replace("\nÀṣẹ! (Success)", "")
+        .trim()
+        .to_string();
 
-        // Cleanup "Àṣẹ! (Success)" and leading/trailing whitespace
-        let trans_stdout = trans_stdout_raw
-            .replace("\nÀṣẹ! (Success)", "")
-            .trim()
-            .to_string();
+    let int_stdout_final = int_stdout.trim().to_string();
 
-        let int_stdout_final = int_stdout.trim().to_string();
+    if int_stdout_final != trans_stdout {
+        println!("--- SEMANTIC MISMATCH ---");
+        println!("SOURCE:\n{}", source);
+        println!("INTERPRETER STDOUT:\n'{}'", int_stdout_final);
+        println!("TRANSPILER STDOUT:\n'{}'", trans_stdout);
+        panic!("equivalence violation");
+    }
 
-        if int_stdout_final != trans_stdout {
-            println!("--- SEMANTIC MISMATCH ---");
-            println!("SOURCE:\n{}", source);
-            println!("INTERPRETER STDOUT:\n'{}'", int_stdout_final);
-            println!("TRANSPILER STDOUT:\n'{}'", trans_stdout);
-            panic!("equivalence violation");
-        }
+    OracleResult {
+        interpreter_stdout: int_stdout_final,
+        transpiler_stdout: trans_stdout,
+    }
+}
 
-        OracleResult {
-            interpreter_stdout: int_stdout_final,
-            transpiler_stdout: trans_stdout,
+/// What one backend made of a program: its value (or error) and output.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackendRun {
+    pub value: Result<IfaValue, String>,
+    pub output: Vec<String>,
+}
+
+impl BackendRun {
+    /// Same output and value; two errors agree whatever their wording,
+    /// since each backend phrases its errors differently.
+    pub fn agrees_with(&self, other: &BackendRun) -> bool {
+        let values_agree = match (&self.value, &other.value) {
+            (Ok(a), Ok(b)) => a == b,
+            (Err(_), Err(_)) => true,
+            _ => false,
+        };
+        values_agree && self.output == other.output
+    }
+}
+
+/// Run `source` on the tree-walking interpreter.
+pub fn run_interpreter(source: &str) -> BackendRun {
+    let program = parse(source).expect("Failed to parse source");
+    let mut interpreter = Interpreter::new();
+    interpreter.set_output_sink(crate::interpreter::OutputSink::Captured);
+    let value = interpreter.execute(&program).map_err(|e| e.to_string());
+    BackendRun {
+        value,
+        output: interpreter.get_output().to_vec(),
+    }
+}
+
+/// Compile `source` to bytecode and run it on the VM with `registry`
+/// attached. `output` is the buffer the registry records Ìrosù output in.
+pub fn run_vm(
+    source: &str,
+    registry: Box<dyn OduRegistry>,
+    output: &Arc<Mutex<Vec<String>>>,
+) -> BackendRun {
+    let program = parse(source).expect("Failed to parse source");
+    let bytecode: Bytecode = Compiler::new("oracle")
+        .compile(&program)
+        .expect("Failed to compile source");
+    let mut vm = IfaVM::new().with_registry(registry);
+    let mut result = vm.execute(&bytecode);
+    while let Err(IfaError::Yielded) = result {
+        result = vm.resume_execution(&bytecode);
+    }
+    let output = output.lock().unwrap().clone();
+    BackendRun {
+        value: result.map_err(|e| e.to_string()),
+        output,
+    }
+}
+
+/// Differential check: every `(source, expected)` case must produce the
+/// same value and output on the interpreter and the VM, and that value
+/// must be `expected`. Panics with every failing case listed.
+///
+/// The standard library registry lives downstream of this crate, so the
+/// caller builds it: `registry` gets a fresh output buffer per case and
+/// returns a registry that records Ìrosù output there.
+pub fn run_cases(
+    cases: &[(&str, IfaValue)],
+    registry: impl Fn(Arc<Mutex<Vec<String>>>) -> Box<dyn OduRegistry>,
+) {
+    let mut failures = Vec::new();
+    for (source, expected) in cases {
+        let interp = run_interpreter(source);
+        let output = Arc::new(Mutex::new(Vec::new()));
+        let vm = run_vm(source, registry(output.clone()), &output);
+        if !interp.agrees_with(&vm) {
+            failures.push(format!(
+                "--- INTERPRETER/VM DIVERGENCE ---\nSOURCE:\n{}\nINTERPRETER: {:?}\nVM: {:?}",
+                source, interp, vm
+            ));
+        } else if interp.value.as_ref() != Ok(expected) {
+            failures.push(format!(
+                "--- WRONG RESULT (both backends) ---\nSOURCE:\n{}\nEXPECTED: {:?}\nGOT: {:?}",
+                source, expected, interp.value
+            ));
         }
     }
+    if !failures.is_empty() {
+        panic!(
+            "{} of {} oracle cases failed\n{}",
+            failures.len(),
+            cases.len(),
+            failures.join("\n")
+        );
+    }
 }
@@ -1,9 +1,16 @@
 //! # Ifá-Lang Parser
 //!
 //! Parses Ifá-Lang source code into an AST using pest.
+//!
+//! Names (variables, functions, methods, labels) are NFC-normalized, so a
+//! Yoruba name typed with precomposed letters or with combining marks is the
+//! same name.
+
+use std::ops::Range;
 
 use pest::Parser;
 use pest_derive::Parser;
+use unicode_normalization::UnicodeNormalization;
 
 use crate::ast::*;
 use crate::error::{IfaError, IfaResult};
@@ -15,22 +22,176 @@ pub struct IfaParser;
 
 /// Parse source code into a Program AST
 pub fn parse(source: &str) -> IfaResult<Program> {
+    parse_with_ranges(source).map(|(program, _)| program)
+}
+
+/// Line and column of every `b"..."` literal in `source`, for targets that
+/// cannot represent bytes values.
+pub fn byte_literal_positions(source: &str) -> IfaResult<Vec<(usize, usize)>> {
+    let pairs =
+        IfaParser::parse(Rule::program, source).map_err(|e| IfaError::Parse(format!("{}", e)))?;
+    Ok(pairs
+        .flatten()
+        .filter(|pair| pair.as_rule() == Rule::byte_string)
+        .map(|pair| pair.as_span().start_pos().line_col())
+        .collect())
+}
+
+/// Parse `source`, also returning the byte range of each top-level statement.
+pub(crate) fn parse_with_ranges(source: &str) -> IfaResult<(Program, Vec<Range<usize>>)> {
     let pairs =
         IfaParser::parse(Rule::program, source).map_err(|e| IfaError::Parse(format!("{}", e)))?;
 
     let mut statements = Vec::new();
+    let mut ranges = Vec::new();
+    let mut metadata = None;
 
     for pair in pairs {
         if pair.as_rule() == Rule::program {
             for inner in pair.into_inner() {
+                if inner.as_rule() == Rule::oriki_stmt {
+                    metadata = Some(parse_oriki(inner)?);
+                    continue;
+                }
+                let range = inner.as_span().start()..inner.as_span().end();
                 if let Some(stmt) = parse_statement(inner)? {
                     statements.push(stmt);
+                    ranges.push(range);
+                }
+            }
+        }
+    }
+
+    Ok((
+        Program {
+            statements,
+            metadata,
+        },
+        ranges,
+    ))
+}
+
+fn parse_oriki(pair: pest::iterators::Pair<Rule>) -> IfaResult<ProgramMetadata> {
+    let mut metadata = ProgramMetadata::default();
+
+    for field in pair.into_inner() {
+        let mut inner = field.into_inner();
+        let key = inner
+            .next()
+            .ok_or(IfaError::Parse("Oriki field missing key".into()))?
+            .as_str();
+        let value = inner
+            .next()
+            .ok_or_else(|| IfaError::Parse(format!("Oriki field '{}' missing value", key)))?;
+
+        match (key, value.as_rule()) {
+            ("name", Rule::string) => metadata.name = Some(unquote(value.as_str())),
+            ("version", Rule::string) => metadata.version = Some(unquote(value.as_str())),
+            ("domains", Rule::oriki_domains) => {
+                for domain in value.into_inner() {
+                    metadata.domains.push(parse_odu_domain(domain.as_str())?);
                 }
             }
+            ("name" | "version", _) => {
+                return Err(IfaError::Parse(format!("Oriki '{}' must be a string", key)));
+            }
+            ("domains", _) => {
+                return Err(IfaError::Parse(
+                    "Oriki 'domains' must be a list of Odù names".into(),
+                ));
+            }
+            _ => {
+                return Err(IfaError::Parse(format!(
+                    "Unknown oriki field: {} (expected name, version, domains)",
+                    key
+                )));
+            }
         }
     }
 
-    Ok(Program { statements })
+    Ok(metadata)
+}
+
+/// An identifier's text, NFC-normalized.
+fn ident(pair: pest::iterators::Pair<Rule>) -> String {
+    pair.as_str().nfc().collect()
+}
+
+fn unquote(s: &str) -> String {
+    s[1..s.len() - 1].to_string()
+}
+
+/// The `///` lines directly above a definition, without their markers, or
+/// `None` if there are none. Comments are trivia to the grammar, so they
+/// are read back from the source in front of the definition.
+fn doc_comment(pair: &pest::iterators::Pair<Rule>) -> Option<String> {
+    let before = &pair.get_input()[..pair.as_span().start()];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    if !before[line_start..].trim().is_empty() {
+        return None;
+    }
+    let mut doc: Vec<&str> = before[..line_start]
+        .lines()
+        .rev()
+        .map(str::trim)
+        .take_while(|line| line.starts_with("///") && !line.starts_with("////"))
+        .map(|line| {
+            let text = &line[3..];
+            text.strip_prefix(' ').unwrap_or(text)
+        })
+        .collect();
+    if doc.is_empty() {
+        return None;
+    }
+    doc.reverse();
+    Some(doc.join("\n"))
+}
+
+/// Decode a `b"..."` literal. Only ASCII and `\xHH`/`\n`/`\r`/`\t`/`\0`/`\\`/`\"`
+/// escapes are allowed; anything else is reported with its line and column.
+fn parse_byte_string(pair: &pest::iterators::Pair<Rule>) -> IfaResult<Vec<u8>> {
+    let (line, col) = pair.as_span().start_pos().line_col();
+    let body = &pair.as_str()[2..pair.as_str().len() - 1];
+    let err = |offset: usize, msg: String| {
+        IfaError::Parse(format!(
+            "{} in byte string at line {}, column {}",
+            msg,
+            line,
+            col + 2 + offset
+        ))
+    };
+
+    let mut out = Vec::with_capacity(body.len());
+    let mut chars = body.char_indices();
+    while let Some((i, c)) = chars.next() {
+        if c != '\\' {
+            if !c.is_ascii() {
+                return Err(err(i, format!("Non-ASCII character '{}'", c)));
+            }
+            out.push(c as u8);
+            continue;
+        }
+        match chars.next() {
+            Some((_, 'x')) => {
+                let hex: String = chars.by_ref().take(2).map(|(_, h)| h).collect();
+                match u8::from_str_radix(&hex, 16) {
+                    Ok(b) if hex.len() == 2 && hex.bytes().all(|h| h.is_ascii_hexdigit()) => {
+                        out.push(b)
+                    }
+                    _ => return Err(err(i, format!("Invalid hex escape '\\x{}'", hex))),
+                }
+            }
+            Some((_, 'n')) => out.push(b'\n'),
+            Some((_, 'r')) => out.push(b'\r'),
+            Some((_, 't')) => out.push(b'\t'),
+            Some((_, '0')) => out.push(0),
+            Some((_, '\\')) => out.push(b'\\'),
+            Some((_, '"')) => out.push(b'"'),
+            Some((_, other)) => return Err(err(i, format!("Unknown escape '\\{}'", other))),
+            None => return Err(err(i, "Dangling escape".to_string())),
+        }
+    }
+    Ok(out)
 }
 
 fn parse_statement(pair: pest::iterators::Pair<Rule>) -> IfaResult<Option<Statement>> {
@@ -56,12 +217,11 @@ fn parse_statement(pair: pest::iterators::Pair<Rule>) -> IfaResult<Option<Statem
                 visibility = Visibility::Public;
                 let name = inner
                     .next()
-                    .ok_or_else(|| IfaError::Parse("VarDecl missing name".to_string()))?
-                    .as_str()
-                    .to_string();
+                    .map(ident)
+                    .ok_or_else(|| IfaError::Parse("VarDecl missing name".to_string()))?;
                 (name, inner)
             } else {
-                (first.as_str().to_string(), inner)
+                (ident(first), inner)
             };
 
             let mut type_hint = None;
@@ -98,17 +258,15 @@ fn parse_statement(pair: pest::iterators::Pair<Rule>) -> IfaResult<Option<Statem
                 inner.next(); // Skip const keyword
                 let name = inner
                     .next()
-                    .ok_or_else(|| IfaError::Parse("Const missing name".to_string()))?
-                    .as_str()
-                    .to_string();
+                    .map(ident)
+                    .ok_or_else(|| IfaError::Parse("Const missing name".to_string()))?;
                 (name, inner)
             } else {
                 // first is const keyword
                 let name = inner
                     .next()
-                    .ok_or_else(|| IfaError::Parse("Const missing name".to_string()))?
-                    .as_str()
-                    .to_string();
+                    .map(ident)
+                    .ok_or_else(|| IfaError::Parse("Const missing name".to_string()))?;
                 (name, inner)
             };
             let value_pair = inner
@@ -121,7 +279,8 @@ fn parse_statement(pair: pest::iterators::Pair<Rule>) -> IfaResult<Option<Statem
                 visibility,
                 span,
             }))
-        }        Rule::assignment_stmt => {
+        }
+        Rule::assignment_stmt => {
             let mut inner = pair.into_inner();
             let lvalue_pair = inner
                 .next()
@@ -148,13 +307,13 @@ fn parse_statement(pair: pest::iterators::Pair<Rule>) -> IfaResult<Option<Statem
                 .ok_or_else(|| IfaError::Parse("Update missing target".to_string()))?;
 
             let target = match first.as_rule() {
-                Rule::ident => AssignTarget::Variable(first.as_str().to_string()),
+                Rule::ident => AssignTarget::Variable(ident(first)),
                 Rule::lvalue => parse_lvalue(first)?,
                 _ => {
                     return Err(IfaError::Parse(format!(
                         "Invalid update target: {:?}",
                         first.as_rule()
-                    )))
+                    )));
                 }
             };
 
@@ -170,7 +329,7 @@ fn parse_statement(pair: pest::iterators::Pair<Rule>) -> IfaResult<Option<Statem
                     return Err(IfaError::Parse(format!(
                         "Unknown update operator: {}",
                         op_pair.as_str()
-                    )))
+                    )));
                 }
             };
 
@@ -183,7 +342,7 @@ fn parse_statement(pair: pest::iterators::Pair<Rule>) -> IfaResult<Option<Statem
             Ok(Some(Statement::Update {
                 target,
                 op,
-                value: Some(value.ok_or_else(|| IfaError::Parse("Update missing value".into()))? ),
+                value: Some(value.ok_or_else(|| IfaError::Parse("Update missing value".into()))?),
                 span,
             }))
         }
@@ -193,10 +352,7 @@ fn parse_statement(pair: pest::iterators::Pair<Rule>) -> IfaResult<Option<Statem
             let path_pair = inner
                 .next()
                 .ok_or_else(|| IfaError::Parse("Import missing path".to_string()))?;
-            let path: Vec<String> = path_pair
-                .into_inner()
-                .map(|p| p.as_str().to_string())
-                .collect();
+            let path: Vec<String> = path_pair.into_inner().map(ident).collect();
 
             Ok(Some(Statement::Import {
                 path,
@@ -212,9 +368,9 @@ fn parse_statement(pair: pest::iterators::Pair<Rule>) -> IfaResult<Option<Statem
 
             for p in inner {
                 match p.as_rule() {
-                    Rule::ident => names.push(p.as_str().to_string()),
+                    Rule::ident => names.push(ident(p)),
                     Rule::module_path => {
-                        path = Some(p.into_inner().map(|seg| seg.as_str().to_string()).collect());
+                        path = Some(p.into_inner().map(ident).collect());
                     }
                     _ => {}
                 }
@@ -302,6 +458,7 @@ fn parse_statement(pair: pest::iterators::Pair<Rule>) -> IfaResult<Option<Statem
             Ok(Some(Statement::While {
                 condition,
                 body,
+                label: None,
                 span,
             }))
         }
@@ -310,9 +467,8 @@ fn parse_statement(pair: pest::iterators::Pair<Rule>) -> IfaResult<Option<Statem
             let mut inner = pair.into_inner();
             let var = inner
                 .next()
-                .ok_or(IfaError::Parse("For missing var".into()))?
-                .as_str()
-                .to_string();
+                .map(ident)
+                .ok_or(IfaError::Parse("For missing var".into()))?;
             let iterable = parse_expression(
                 inner
                     .next()
@@ -330,16 +486,48 @@ fn parse_statement(pair: pest::iterators::Pair<Rule>) -> IfaResult<Option<Statem
                 var,
                 iterable,
                 body,
+                label: None,
                 span,
             }))
         }
 
+        Rule::labeled_loop => {
+            let mut inner = pair.into_inner();
+            let name = inner
+                .next()
+                .map(ident)
+                .ok_or(IfaError::Parse("Labeled loop missing label".into()))?;
+            let loop_pair = inner
+                .next()
+                .ok_or(IfaError::Parse("Label must precede a loop".into()))?;
+            let mut stmt = parse_statement(loop_pair)?
+                .ok_or(IfaError::Parse("Label must precede a loop".into()))?;
+            match &mut stmt {
+                Statement::While { label, span: s, .. } | Statement::For { label, span: s, .. } => {
+                    *label = Some(name);
+                    *s = span;
+                }
+                _ => return Err(IfaError::Parse("Label must precede a loop".into())),
+            }
+            Ok(Some(stmt))
+        }
+
         Rule::return_stmt => {
             let inner = pair.into_inner().next();
             let value = inner.map(parse_expression).transpose()?;
             Ok(Some(Statement::Return { value, span }))
         }
 
+        Rule::break_stmt => {
+            let label = pair.into_inner().next().map(ident);
+            Ok(Some(Statement::Break { label, span }))
+        }
+
+        Rule::continue_stmt => {
+            let label = pair.into_inner().next().map(ident);
+            Ok(Some(Statement::Continue { label, span }))
+        }
+
         Rule::ase_stmt => Ok(Some(Statement::Ase { span })),
 
         Rule::taboo_stmt => {
@@ -348,14 +536,12 @@ fn parse_statement(pair: pest::iterators::Pair<Rule>) -> IfaResult<Option<Statem
             inner.next();
             let source = inner
                 .next()
-                .ok_or(IfaError::Parse("Taboo missing source".into()))?
-                .as_str()
-                .to_string();
+                .map(ident)
+                .ok_or(IfaError::Parse("Taboo missing source".into()))?;
             let target = inner
                 .next()
-                .ok_or(IfaError::Parse("Taboo missing target".into()))?
-                .as_str()
-                .to_string();
+                .map(ident)
+                .ok_or(IfaError::Parse("Taboo missing target".into()))?;
             Ok(Some(Statement::Taboo {
                 source,
                 target,
@@ -388,9 +574,8 @@ fn parse_statement(pair: pest::iterators::Pair<Rule>) -> IfaResult<Option<Statem
             inner.next();
             let size = inner
                 .next()
-                .ok_or(IfaError::Parse("Opon missing size".into()))?
-                .as_str()
-                .to_string();
+                .map(ident)
+                .ok_or(IfaError::Parse("Opon missing size".into()))?;
             Ok(Some(Statement::Opon { size, span }))
         }
 
@@ -428,6 +613,7 @@ fn parse_statement(pair: pest::iterators::Pair<Rule>) -> IfaResult<Option<Statem
         }
 
         Rule::ese_def => {
+            let doc = doc_comment(&pair);
             let mut inner = pair.into_inner();
             let mut visibility = Visibility::Private;
             let mut is_async = false;
@@ -448,7 +634,7 @@ fn parse_statement(pair: pest::iterators::Pair<Rule>) -> IfaResult<Option<Statem
                     .next()
                     .ok_or(IfaError::Parse("Ese missing name".into()))?;
             }
-            let name = current.as_str().to_string();
+            let name = ident(current);
             let remaining = inner;
 
             let mut params = Vec::new();
@@ -461,9 +647,8 @@ fn parse_statement(pair: pest::iterators::Pair<Rule>) -> IfaResult<Option<Statem
                             let mut param_inner = param_pair.into_inner();
                             let param_name = param_inner
                                 .next()
-                                .ok_or(IfaError::Parse("Param missing name".into()))?
-                                .as_str()
-                                .to_string();
+                                .map(ident)
+                                .ok_or(IfaError::Parse("Param missing name".into()))?;
                             let param_type = param_inner.next().map(parse_type_hint).transpose()?;
                             params.push(Param {
                                 name: param_name,
@@ -486,11 +671,13 @@ fn parse_statement(pair: pest::iterators::Pair<Rule>) -> IfaResult<Option<Statem
                 params,
                 body,
                 is_async,
+                doc,
                 span,
             }))
         }
 
         Rule::odu_def => {
+            let doc = doc_comment(&pair);
             let mut inner = pair.into_inner();
             let mut visibility = Visibility::Private;
 
@@ -502,13 +689,12 @@ fn parse_statement(pair: pest::iterators::Pair<Rule>) -> IfaResult<Option<Statem
                 (
                     inner
                         .next()
-                        .ok_or(IfaError::Parse("Odu missing name".into()))?
-                        .as_str()
-                        .to_string(),
+                        .map(ident)
+                        .ok_or(IfaError::Parse("Odu missing name".into()))?,
                     inner,
                 )
             } else {
-                (first.as_str().to_string(), inner)
+                (ident(first), inner)
             };
 
             let mut body = Vec::new();
@@ -526,6 +712,7 @@ fn parse_statement(pair: pest::iterators::Pair<Rule>) -> IfaResult<Option<Statem
                 name,
                 visibility,
                 body,
+                doc,
                 span,
             }))
         }
@@ -546,10 +733,22 @@ fn parse_statement(pair: pest::iterators::Pair<Rule>) -> IfaResult<Option<Statem
                     .ok_or(IfaError::Parse("Match arm missing pattern".into()))?;
                 let pattern = parse_match_pattern(pattern_pair)?;
 
-                let mut body = Vec::new();
-                let body_pair = arm_inner
+                let mut body_pair = arm_inner
                     .next()
                     .ok_or(IfaError::Parse("Match arm missing body".into()))?;
+                let mut guard = None;
+                if body_pair.as_rule() == Rule::match_guard {
+                    let guard_expr = body_pair
+                        .into_inner()
+                        .next()
+                        .ok_or(IfaError::Parse("Match guard missing condition".into()))?;
+                    guard = Some(parse_expression(guard_expr)?);
+                    body_pair = arm_inner
+                        .next()
+                        .ok_or(IfaError::Parse("Match arm missing body".into()))?;
+                }
+
+                let mut body = Vec::new();
                 match body_pair.as_rule() {
                     Rule::statement => {
                         if let Some(stmt) = parse_statement(body_pair)? {
@@ -566,7 +765,11 @@ fn parse_statement(pair: pest::iterators::Pair<Rule>) -> IfaResult<Option<Statem
                     }
                 }
 
-                arms.push(MatchArm { pattern, body });
+                arms.push(MatchArm {
+                    pattern,
+                    guard,
+                    body,
+                });
             }
 
             Ok(Some(Statement::Match {
@@ -619,9 +822,8 @@ fn parse_statement(pair: pest::iterators::Pair<Rule>) -> IfaResult<Option<Statem
             // first is ident.
             let catch_var = catch_inner
                 .next()
-                .ok_or(IfaError::Parse("Catch missing var".into()))?
-                .as_str()
-                .to_string();
+                .map(ident)
+                .ok_or(IfaError::Parse("Catch missing var".into()))?;
 
             let mut catch_body = Vec::new();
             for p in catch_inner {
@@ -666,17 +868,18 @@ fn parse_lvalue(pair: pest::iterators::Pair<Rule>) -> IfaResult<AssignTarget> {
         .ok_or_else(|| IfaError::Parse("Empty lvalue".to_string()))?;
 
     match inner_lvalue.as_rule() {
-        Rule::ident => Ok(AssignTarget::Variable(inner_lvalue.as_str().to_string())),
+        Rule::ident => Ok(AssignTarget::Variable(ident(inner_lvalue))),
         Rule::index_lvalue => {
             let mut index_inner = inner_lvalue.into_inner();
             let name = index_inner
                 .next()
-                .ok_or_else(|| IfaError::Parse("Index lvalue missing name".to_string()))?
-                .as_str()
-                .to_string();
-            let index_expr = parse_expression(index_inner.next().ok_or_else(|| {
-                IfaError::Parse("Index lvalue missing index".to_string())
-            })?)?;
+                .map(ident)
+                .ok_or_else(|| IfaError::Parse("Index lvalue missing name".to_string()))?;
+            let index_expr = parse_expression(
+                index_inner
+                    .next()
+                    .ok_or_else(|| IfaError::Parse("Index lvalue missing index".to_string()))?,
+            )?;
             Ok(AssignTarget::Index {
                 name,
                 index: Box::new(index_expr),
@@ -684,9 +887,10 @@ fn parse_lvalue(pair: pest::iterators::Pair<Rule>) -> IfaResult<AssignTarget> {
         }
         Rule::deref_lvalue => {
             let mut deref_inner = inner_lvalue.into_inner();
-            let expr = parse_expression(deref_inner.next().ok_or_else(|| {
-                IfaError::Parse("Deref lvalue missing expression".to_string())
-            })?)?;
+            let expr =
+                parse_expression(deref_inner.next().ok_or_else(|| {
+                    IfaError::Parse("Deref lvalue missing expression".to_string())
+                })?)?;
             Ok(AssignTarget::Dereference(Box::new(expr)))
         }
         _ => Err(IfaError::Parse(format!(
@@ -775,6 +979,20 @@ fn parse_expression(pair: pest::iterators::Pair<Rule>) -> IfaResult<Expression>
                 // Now `current` is the atom (or anything else that `factor` allows at end, which is `atom`)
                 let mut expr = parse_expression(current)?;
 
+                // Fold a minus sitting directly on a numeric literal into the
+                // literal, so `-5` is `Int(-5)` in patterns and list literals.
+                if matches!(ops.last(), Some(UnaryOperator::Neg)) {
+                    let folded = match expr {
+                        Expression::Int(n) => Some(Expression::Int(-n)),
+                        Expression::Float(f) => Some(Expression::Float(-f)),
+                        _ => None,
+                    };
+                    if let Some(lit) = folded {
+                        expr = lit;
+                        ops.pop();
+                    }
+                }
+
                 // Apply ops in reverse (right-to-left association)
                 // ex: -*p  ->  -(*(p))
                 for op in ops.into_iter().rev() {
@@ -842,9 +1060,8 @@ fn parse_expression(pair: pest::iterators::Pair<Rule>) -> IfaResult<Expression>
                 let is_optional = op_pair.as_str() == "?.";
                 let name = inner
                     .next()
-                    .ok_or(IfaError::Parse("Property access missing field name".into()))?
-                    .as_str()
-                    .to_string();
+                    .map(ident)
+                    .ok_or(IfaError::Parse("Property access missing field name".into()))?;
 
                 obj = Expression::Get {
                     object: Box::new(obj),
@@ -879,6 +1096,8 @@ fn parse_expression(pair: pest::iterators::Pair<Rule>) -> IfaResult<Expression>
             Ok(Expression::String(s[1..s.len() - 1].to_string()))
         }
 
+        Rule::byte_string => Ok(Expression::Bytes(parse_byte_string(&pair)?)),
+
         Rule::boolean => {
             let s = pair.as_str();
             Ok(Expression::Bool(s == "true" || s == "otito"))
@@ -886,7 +1105,7 @@ fn parse_expression(pair: pest::iterators::Pair<Rule>) -> IfaResult<Expression>
 
         Rule::nil => Ok(Expression::Nil),
 
-        Rule::ident => Ok(Expression::Identifier(pair.as_str().to_string())),
+        Rule::ident => Ok(Expression::Identifier(ident(pair))),
 
         Rule::odu_call => Ok(Expression::OduCall(parse_odu_call(pair)?)),
 
@@ -894,18 +1113,16 @@ fn parse_expression(pair: pest::iterators::Pair<Rule>) -> IfaResult<Expression>
             let mut inner = pair.into_inner();
             let object_name = inner
                 .next()
-                .ok_or(IfaError::Parse("Method call missing object".into()))?
-                .as_str()
-                .to_string();
+                .map(ident)
+                .ok_or(IfaError::Parse("Method call missing object".into()))?;
             let op = inner
                 .next()
                 .ok_or(IfaError::Parse("Method call missing operator".into()))?;
             let is_optional = op.as_str() == "?.";
             let method = inner
                 .next()
-                .ok_or(IfaError::Parse("Method call missing method name".into()))?
-                .as_str()
-                .to_string();
+                .map(ident)
+                .ok_or(IfaError::Parse("Method call missing method name".into()))?;
 
             let mut args = Vec::new();
             if let Some(args_pair) = inner.next() {
@@ -926,9 +1143,8 @@ fn parse_expression(pair: pest::iterators::Pair<Rule>) -> IfaResult<Expression>
             let mut inner = pair.into_inner();
             let name = inner
                 .next()
-                .ok_or(IfaError::Parse("Function call missing name".into()))?
-                .as_str()
-                .to_string();
+                .map(ident)
+                .ok_or(IfaError::Parse("Function call missing name".into()))?;
 
             let mut args = Vec::new();
             if let Some(args_pair) = inner.next() {
@@ -954,15 +1170,18 @@ fn parse_expression(pair: pest::iterators::Pair<Rule>) -> IfaResult<Expression>
             let mut inner = pair.into_inner();
             let object_name = inner
                 .next()
-                .ok_or(IfaError::Parse("Index access missing object".into()))?
-                .as_str()
-                .to_string();
-            
+                .map(ident)
+                .ok_or(IfaError::Parse("Index access missing object".into()))?;
+
             let mut is_optional = false;
-            let next_pair = inner.next().ok_or(IfaError::Parse("Index access missing index".into()))?;
+            let next_pair = inner
+                .next()
+                .ok_or(IfaError::Parse("Index access missing index".into()))?;
             let index_expr_pair = if next_pair.as_rule() == Rule::optional_chain_op {
                 is_optional = true;
-                inner.next().ok_or(IfaError::Parse("Index access missing index after ?.".into()))?
+                inner.next().ok_or(IfaError::Parse(
+                    "Index access missing index after ?.".into(),
+                ))?
             } else {
                 next_pair
             };
@@ -1002,7 +1221,6 @@ fn parse_expression(pair: pest::iterators::Pair<Rule>) -> IfaResult<Expression>
             Ok(Expression::Map(entries))
         }
 
-
         Rule::interpolated_string => {
             let mut parts = Vec::new();
             for part_pair in pair.into_inner() {
@@ -1043,7 +1261,7 @@ fn parse_odu_call(pair: pest::iterators::Pair<Rule>) -> IfaResult<OduCall> {
         .ok_or(IfaError::Parse("Odu call missing domain".into()))?
         .as_str();
     let domain = parse_odu_domain(domain_str)?;
-    
+
     let op = inner
         .next()
         .ok_or(IfaError::Parse("Odu call missing operator".into()))?;
@@ -1051,14 +1269,31 @@ fn parse_odu_call(pair: pest::iterators::Pair<Rule>) -> IfaResult<OduCall> {
 
     let method = inner
         .next()
-        .ok_or(IfaError::Parse("Odu call missing method".into()))?
-        .as_str()
-        .to_string();
+        .map(ident)
+        .ok_or(IfaError::Parse("Odu call missing method".into()))?;
 
     let mut args = Vec::new();
+    let mut named_args = Vec::new();
     if let Some(args_pair) = inner.next() {
         for arg in args_pair.into_inner() {
-            args.push(parse_expression(arg)?);
+            if arg.as_rule() == Rule::named_argument {
+                let mut parts = arg.into_inner();
+                let name = parts
+                    .next()
+                    .map(ident)
+                    .ok_or(IfaError::Parse("Named argument missing name".into()))?;
+                let value = parts
+                    .next()
+                    .ok_or(IfaError::Parse("Named argument missing value".into()))?;
+                named_args.push((name, parse_expression(value)?));
+            } else if !named_args.is_empty() {
+                return Err(IfaError::Parse(format!(
+                    "Positional argument after named argument in {}.{}",
+                    domain_str, method
+                )));
+            } else {
+                args.push(parse_expression(arg)?);
+            }
         }
     }
 
@@ -1066,12 +1301,13 @@ fn parse_odu_call(pair: pest::iterators::Pair<Rule>) -> IfaResult<OduCall> {
         domain,
         method,
         args,
+        named_args,
         is_optional,
         span,
     })
 }
 
-fn parse_odu_domain(s: &str) -> IfaResult<OduDomain> {
+pub(crate) fn parse_odu_domain(s: &str) -> IfaResult<OduDomain> {
     let lower = s
         .to_lowercase()
         .replace('ọ', "o")
@@ -1103,6 +1339,7 @@ fn parse_odu_domain(s: &str) -> IfaResult<OduDomain> {
         // Pseudo-domains
         "coop" | "ajose" => Ok(OduDomain::Coop),
         "opele" | "oracle" => Ok(OduDomain::Opele),
+        "opon" => Ok(OduDomain::Opon),
 
         // Infrastructure
         "sys" | "system" => Ok(OduDomain::Sys),
@@ -1182,6 +1419,10 @@ fn parse_match_pattern(pair: pest::iterators::Pair<Rule>) -> IfaResult<MatchPatt
         .into_inner()
         .next()
         .ok_or(IfaError::Parse("Match pattern missing inner".into()))?;
+    parse_match_pattern_inner(inner)
+}
+
+fn parse_match_pattern_inner(inner: pest::iterators::Pair<Rule>) -> IfaResult<MatchPattern> {
     match inner.as_rule() {
         Rule::literal_pattern => {
             let expr = parse_expression(
@@ -1210,6 +1451,33 @@ fn parse_match_pattern(pair: pest::iterators::Pair<Rule>) -> IfaResult<MatchPatt
             })
         }
         Rule::wildcard_pattern => Ok(MatchPattern::Wildcard),
+        Rule::binding_pattern => inner
+            .into_inner()
+            .next()
+            .map(ident)
+            .map(MatchPattern::Binding)
+            .ok_or(IfaError::Parse("Binding pattern missing name".into())),
+        Rule::list_pattern => {
+            let mut elements = Vec::new();
+            let mut rest = None;
+            for elem in inner.into_inner() {
+                if rest.is_some() {
+                    return Err(IfaError::Parse(
+                        "Rest pattern must be the last element of a list pattern".into(),
+                    ));
+                }
+                if elem.as_rule() == Rule::rest_pattern {
+                    let name = elem
+                        .into_inner()
+                        .next()
+                        .ok_or(IfaError::Parse("Rest pattern missing name".into()))?;
+                    rest = Some(ident(name));
+                } else {
+                    elements.push(parse_match_pattern_inner(elem)?);
+                }
+            }
+            Ok(MatchPattern::List { elements, rest })
+        }
         _ => Err(IfaError::Parse(format!(
             "Unexpected pattern rule: {:?}",
             inner.as_rule()
@@ -1257,10 +1525,163 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_media_odu_calls() {
+        let program = parse("Ohun.play(\"a.wav\"); Video.info(\"clip.mp4\");").unwrap();
+        let calls: Vec<_> = program
+            .statements
+            .iter()
+            .map(|stmt| match stmt {
+                Statement::Instruction { call, .. } => call,
+                other => panic!("Expected Instruction, got {:?}", other),
+            })
+            .collect();
+
+        assert_eq!(calls[0].domain, OduDomain::Ohun);
+        assert_eq!(calls[0].method, "play");
+        assert!(matches!(&calls[0].args[..], [Expression::String(s)] if s == "a.wav"));
+        assert_eq!(calls[1].domain, OduDomain::Fidio);
+        assert_eq!(calls[1].method, "info");
+    }
+
     #[test]
     fn test_parse_if() {
         let program = parse("ti x { ayanmo y = 1; }").unwrap();
         assert_eq!(program.statements.len(), 1);
         assert!(matches!(&program.statements[0], Statement::If { .. }));
     }
+
+    #[test]
+    fn test_parse_trailing_commas() {
+        let program = parse("ayanmo l = [1, 2, 3,];").unwrap();
+        if let Statement::VarDecl { value, .. } = &program.statements[0] {
+            assert!(matches!(value, Expression::List(items) if items.len() == 3));
+        } else {
+            panic!("Expected VarDecl");
+        }
+
+        let program = parse("ayanmo m = {a: 1, b: 2,};").unwrap();
+        if let Statement::VarDecl { value, .. } = &program.statements[0] {
+            assert!(matches!(value, Expression::Map(entries) if entries.len() == 2));
+        } else {
+            panic!("Expected VarDecl");
+        }
+
+        let program = parse("ayanmo r = f(1, 2,);").unwrap();
+        if let Statement::VarDecl { value, .. } = &program.statements[0] {
+            assert!(matches!(value, Expression::Call { args, .. } if args.len() == 2));
+        } else {
+            panic!("Expected VarDecl");
+        }
+
+        let program = parse("ese f(a, b,) { pada a; }").unwrap();
+        if let Statement::EseDef { params, .. } = &program.statements[0] {
+            assert_eq!(params.len(), 2);
+        } else {
+            panic!("Expected EseDef");
+        }
+    }
+
+    #[test]
+    fn test_parse_oriki_metadata() {
+        let program = parse(
+            r#"oriki { name: "app", version: "1.0", domains: [otura, odi] }
+            ayanmo x = 1;"#,
+        )
+        .unwrap();
+        assert_eq!(program.statements.len(), 1);
+        let metadata = program.metadata.expect("Expected oriki metadata");
+        assert_eq!(metadata.name.as_deref(), Some("app"));
+        assert_eq!(metadata.version.as_deref(), Some("1.0"));
+        assert_eq!(metadata.domains, vec![OduDomain::Otura, OduDomain::Odi]);
+
+        assert!(parse("ayanmo x = 1;").unwrap().metadata.is_none());
+    }
+
+    #[test]
+    fn test_parse_oriki_rejects_unknown_domain() {
+        let err = parse(r#"oriki { name: "app", domains: [otura, ategun] }"#).unwrap_err();
+        assert!(err.to_string().contains("Unknown Odù domain: ategun"));
+
+        assert!(parse(r#"oriki { author: "me" }"#).is_err());
+    }
+
+    #[test]
+    fn test_parse_byte_string_literal() {
+        let program = parse(r#"ayanmo p = b"\x01\xFFA\n";"#).unwrap();
+        match &program.statements[0] {
+            Statement::VarDecl { value, .. } => {
+                assert!(matches!(value, Expression::Bytes(b) if b == &[0x01, 0xFF, b'A', b'\n']));
+            }
+            other => panic!("expected VarDecl, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_byte_string_invalid_escape_reports_position() {
+        let err = parse("ayanmo p = b\"\\x0G\";").unwrap_err().to_string();
+        assert!(err.contains("Invalid hex escape"), "got: {}", err);
+        assert!(err.contains("line 1, column 14"), "got: {}", err);
+    }
+
+    #[test]
+    fn test_byte_literal_positions() {
+        let src = "ayanmo a = 1;\nayanmo p = [b\"\\x01\", b\"ok\"];\n";
+        assert_eq!(byte_literal_positions(src).unwrap(), vec![(2, 13), (2, 22)]);
+        assert!(
+            byte_literal_positions("ayanmo s = \"b\";")
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_parse_negative_literals_are_folded() {
+        let program = parse("ayanmo l = [-1, -2.5, - 3];").unwrap();
+        match &program.statements[0] {
+            Statement::VarDecl {
+                value: Expression::List(items),
+                ..
+            } => {
+                assert!(matches!(items[0], Expression::Int(-1)));
+                assert!(matches!(items[1], Expression::Float(f) if f == -2.5));
+                assert!(matches!(items[2], Expression::Int(-3)));
+            }
+            other => panic!("expected list VarDecl, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_rejects_bare_and_double_commas() {
+        assert!(parse("ayanmo l = [,];").is_err());
+        assert!(parse("ayanmo l = [1,,2];").is_err());
+    }
+
+    #[test]
+    fn test_parse_keeps_doc_comments_on_definitions() {
+        let program = parse(
+            "/// Adds two numbers.\n/// Returns their sum.\nese fikun(a, b) { pada a + b; }\n\
+             // plain comment\nese f() {}\n\
+             /// Shapes\nodu Apeere {\n    /// Area of a square\n    ese agbegbe(s) { pada s * s; }\n}\n",
+        )
+        .unwrap();
+        match &program.statements[..] {
+            [
+                Statement::EseDef { doc: first, .. },
+                Statement::EseDef { doc: None, .. },
+                Statement::OduDef { doc, body, .. },
+            ] => {
+                assert_eq!(
+                    first.as_deref(),
+                    Some("Adds two numbers.\nReturns their sum.")
+                );
+                assert_eq!(doc.as_deref(), Some("Shapes"));
+                assert!(matches!(
+                    &body[0],
+                    Statement::EseDef { doc: Some(d), .. } if d == "Area of a square"
+                ));
+            }
+            other => panic!("expected two ese and an odu, got {:?}", other),
+        }
+    }
 }
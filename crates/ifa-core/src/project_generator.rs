@@ -7,6 +7,7 @@
 //! - src/main.rs with the transpiled code
 
 use crate::ast::Program;
+use crate::interpreter::CapabilitySet;
 use crate::transpiler::RustTranspiler;
 use std::fs;
 use std::io;
@@ -31,11 +32,13 @@ impl Default for ProjectConfig {
     }
 }
 
-/// Generate a complete Cargo project from an Ifá program
+/// Generate a complete Cargo project from an Ifá program.
+/// File and network access in the generated binary is limited to `caps`.
 pub fn generate_project(
     program: &Program,
     project_name: &str,
     output_dir: &Path,
+    caps: &CapabilitySet,
 ) -> io::Result<ProjectConfig> {
     // Create directory structure
     let src_dir = output_dir.join("src");
@@ -43,6 +46,7 @@ pub fn generate_project(
 
     // Transpile the program
     let mut transpiler = RustTranspiler::new();
+    transpiler.set_capabilities(caps);
     let rust_code = transpiler.transpile_program(program);
 
     // Create project config from transpiler state
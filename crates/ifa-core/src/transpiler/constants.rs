@@ -126,7 +126,8 @@ pub mod ose {
 /// Òfún (Type/Reflection) methods
 pub mod ofun {
     pub const IS_ALIVE: &[&str] = &["laaye", "is_defined", "is_alive"];
-    pub const TYPE_OF: &[&str] = &["iru", "type_of", "typeof"];
+    pub const TYPE_NAME: &[&str] = &["iru", "typeof"];
+    pub const TYPE_OF: &[&str] = &["type_of"];
 }
 
 /// Ìrosù (I/O) methods
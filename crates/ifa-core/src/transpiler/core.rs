@@ -2,7 +2,9 @@
 //!
 //! Main transpiler struct and entry point.
 
+use super::source_map::SourceMap;
 use crate::ast::*;
+use crate::interpreter::{CapabilitySet, Ofun};
 use ifa_types::domain::OduDomain;
 
 /// Transpile an Ifá program to Rust source code
@@ -11,6 +13,13 @@ pub fn transpile_to_rust(program: &Program) -> String {
     transpiler.transpile_program(program)
 }
 
+/// Transpile with `caps` baked into the generated binary's capability checks
+pub fn transpile_to_rust_with_capabilities(program: &Program, caps: &CapabilitySet) -> String {
+    let mut transpiler = RustTranspiler::new();
+    transpiler.set_capabilities(caps);
+    transpiler.transpile_program(program)
+}
+
 /// Rust code transpiler state
 pub struct RustTranspiler {
     pub(crate) indent: usize,
@@ -24,6 +33,16 @@ pub struct RustTranspiler {
     pub(crate) std_named: std::collections::HashMap<String, OduDomain>,
     pub(crate) uses: Vec<String>,
     pub(crate) in_module: bool,
+    /// Path roots the generated binary may read (`Ofun::ReadFiles`)
+    pub(crate) allow_read: Vec<String>,
+    /// Path roots the generated binary may write (`Ofun::WriteFiles`)
+    pub(crate) allow_write: Vec<String>,
+    /// Hosts the generated binary may contact (`Ofun::Network`)
+    pub(crate) allow_net: Vec<String>,
+    /// `.ifa` file named in runtime error messages
+    pub(crate) source_name: String,
+    /// Generated line -> `.ifa` line, filled in by `transpile_program`
+    pub(crate) source_map: SourceMap,
 }
 
 impl Default for RustTranspiler {
@@ -46,6 +65,36 @@ impl RustTranspiler {
             std_named: std::collections::HashMap::new(),
             uses: Vec::new(),
             in_module: false,
+            allow_read: Vec::new(),
+            allow_write: Vec::new(),
+            allow_net: Vec::new(),
+            source_name: "<source>".to_string(),
+            source_map: SourceMap::default(),
+        }
+    }
+
+    /// Name the `.ifa` file that runtime errors in the binary are reported against
+    pub fn set_source_name(&mut self, name: impl Into<String>) {
+        self.source_name = name.into();
+    }
+
+    /// Where each statement of the last transpiled program landed in the
+    /// generated Rust
+    pub fn source_map(&self) -> &SourceMap {
+        &self.source_map
+    }
+
+    /// Grant the generated binary the file and network capabilities in `caps`.
+    /// Without this, file and network operations are denied at runtime,
+    /// just as they are in an interpreter with an empty capability set.
+    pub fn set_capabilities(&mut self, caps: &CapabilitySet) {
+        for cap in caps.all() {
+            match cap {
+                Ofun::ReadFiles { root } => self.allow_read.push(root.display().to_string()),
+                Ofun::WriteFiles { root } => self.allow_write.push(root.display().to_string()),
+                Ofun::Network { domains } => self.allow_net.extend(domains.iter().cloned()),
+                _ => {}
+            }
         }
     }
 
@@ -101,6 +150,7 @@ pub(crate) fn std_domain_from_name(name: &str) -> Option<OduDomain> {
         "ofun" => Some(OduDomain::Ofun),
         "coop" => Some(OduDomain::Coop),
         "opele" => Some(OduDomain::Opele),
+        "opon" => Some(OduDomain::Opon),
         "cpu" => Some(OduDomain::Cpu),
         "gpu" => Some(OduDomain::Gpu),
         "storage" => Some(OduDomain::Storage),
@@ -8,6 +8,7 @@ use super::constants::{
 };
 use super::core::RustTranspiler;
 use crate::ast::OduCall;
+use crate::interpreter::handlers::bind_named_args;
 
 impl RustTranspiler {
     /// Transpile an Odù domain call to Rust
@@ -17,6 +18,15 @@ impl RustTranspiler {
             .iter()
             .map(|a| self.transpile_expression(a))
             .collect();
+        let named: Vec<(String, String)> = call
+            .named_args
+            .iter()
+            .map(|(n, a)| (n.clone(), self.transpile_expression(a)))
+            .collect();
+        let args = match bind_named_args(call.domain, &call.method, args, named) {
+            Ok(args) => args,
+            Err(e) => return format!("compile_error!({:?})", e.to_string()),
+        };
         let domain_str = format!("{:?}", call.domain).to_lowercase();
         let method = call.method.to_lowercase();
 
@@ -85,28 +95,28 @@ impl RustTranspiler {
             // ═══════════════════════════════════════════════════════════════════
             "odi" if matches_method(&method, odi::READ) => {
                 if let Some(path) = args.first() {
-                    format!("match std::fs::read_to_string(if let IfaValue::Str(s) = {} {{ s }} else {{ String::new() }}) {{ Ok(c) => IfaValue::Str(c), Err(e) => {{ let mut m = HashMap::new(); m.insert(\"kind\".to_string(), IfaValue::Str(\"IoError\".to_string())); m.insert(\"message\".to_string(), IfaValue::Str(e.to_string())); IfaValue::Map(m) }} }}", path)
+                    format!("{{ let p = if let IfaValue::Str(s) = {} {{ s }} else {{ String::new() }}; match ifa_capability(\"ReadFiles\", &p) {{ Err(denied) => denied, Ok(()) => match std::fs::read_to_string(&p) {{ Ok(c) => IfaValue::Str(c), Err(e) => {{ let mut m = HashMap::new(); m.insert(\"kind\".to_string(), IfaValue::Str(\"IoError\".to_string())); m.insert(\"message\".to_string(), IfaValue::Str(e.to_string())); IfaValue::Map(m) }} }} }} }}", path)
                 } else {
                     "{ let mut m = HashMap::new(); m.insert(\"kind\".to_string(), IfaValue::Str(\"TypeError\".to_string())); m.insert(\"message\".to_string(), IfaValue::Str(\"odi.read expects a path\".to_string())); IfaValue::Map(m) }".to_string()
                 }
             }
             "odi" if matches_method(&method, odi::WRITE) => {
                 if args.len() >= 2 {
-                    format!("match ({}, {}) {{ (IfaValue::Str(p), IfaValue::Str(c)) => match std::fs::write(&p, &c) {{ Ok(()) => IfaValue::Bool(true), Err(e) => {{ let mut m = HashMap::new(); m.insert(\"kind\".to_string(), IfaValue::Str(\"IoError\".to_string())); m.insert(\"message\".to_string(), IfaValue::Str(e.to_string())); IfaValue::Map(m) }} }}, _ => {{ let mut m = HashMap::new(); m.insert(\"kind\".to_string(), IfaValue::Str(\"TypeError\".to_string())); m.insert(\"message\".to_string(), IfaValue::Str(\"odi.write expects (path, content) strings\".to_string())); IfaValue::Map(m) }} }}", args[0], args[1])
+                    format!("match ({}, {}) {{ (IfaValue::Str(p), IfaValue::Str(c)) => match ifa_capability(\"WriteFiles\", &p) {{ Err(denied) => denied, Ok(()) => match std::fs::write(&p, &c) {{ Ok(()) => IfaValue::Bool(true), Err(e) => {{ let mut m = HashMap::new(); m.insert(\"kind\".to_string(), IfaValue::Str(\"IoError\".to_string())); m.insert(\"message\".to_string(), IfaValue::Str(e.to_string())); IfaValue::Map(m) }} }} }}, _ => {{ let mut m = HashMap::new(); m.insert(\"kind\".to_string(), IfaValue::Str(\"TypeError\".to_string())); m.insert(\"message\".to_string(), IfaValue::Str(\"odi.write expects (path, content) strings\".to_string())); IfaValue::Map(m) }} }}", args[0], args[1])
                 } else {
                     "{ let mut m = HashMap::new(); m.insert(\"kind\".to_string(), IfaValue::Str(\"TypeError\".to_string())); m.insert(\"message\".to_string(), IfaValue::Str(\"odi.write expects path and content\".to_string())); IfaValue::Map(m) }".to_string()
                 }
             }
             "odi" if matches_method(&method, odi::EXISTS) => {
                 if let Some(path) = args.first() {
-                    format!("IfaValue::Bool(if let IfaValue::Str(p) = {} {{ std::path::Path::new(&p).exists() }} else {{ false }})", path)
+                    format!("match {} {{ IfaValue::Str(p) => match ifa_capability(\"ReadFiles\", &p) {{ Err(denied) => denied, Ok(()) => IfaValue::Bool(std::path::Path::new(&p).exists()) }}, _ => IfaValue::Bool(false) }}", path)
                 } else {
                     "IfaValue::Bool(false)".to_string()
                 }
             }
             "odi" if matches_method(&method, odi::DELETE) => {
                 if let Some(path) = args.first() {
-                    format!("IfaValue::Bool(if let IfaValue::Str(p) = {} {{ std::fs::remove_file(&p).is_ok() }} else {{ false }})", path)
+                    format!("match {} {{ IfaValue::Str(p) => match ifa_capability(\"WriteFiles\", &p) {{ Err(denied) => denied, Ok(()) => IfaValue::Bool(std::fs::remove_file(&p).is_ok()) }}, _ => IfaValue::Bool(false) }}", path)
                 } else {
                     "IfaValue::Bool(false)".to_string()
                 }
@@ -206,7 +216,7 @@ impl RustTranspiler {
                 self.needs_reqwest = true;
                 self.needs_tokio = true;
                 if let Some(url) = args.first() {
-                    format!("match reqwest::get(if let IfaValue::Str(s) = {} {{ s }} else {{ String::new() }}).await {{ Ok(r) => IfaValue::Str(r.text().await.unwrap_or_default()), Err(_) => IfaValue::Nil }}", url)
+                    format!("{{ let u = if let IfaValue::Str(s) = {} {{ s }} else {{ String::new() }}; match ifa_capability(\"Network\", &u) {{ Err(denied) => denied, Ok(()) => match reqwest::get(u).await {{ Ok(r) => IfaValue::Str(r.text().await.unwrap_or_default()), Err(_) => IfaValue::Nil }} }} }}", url)
                 } else {
                     "IfaValue::Nil".to_string()
                 }
@@ -216,7 +226,7 @@ impl RustTranspiler {
                 self.needs_reqwest = true;
                 self.needs_tokio = true;
                 if args.len() >= 2 {
-                    format!("match reqwest::Client::new().post(if let IfaValue::Str(s) = {} {{ s }} else {{ String::new() }}).body(if let IfaValue::Str(s) = {} {{ s }} else {{ String::new() }}).send().await {{ Ok(r) => IfaValue::Str(r.text().await.unwrap_or_default()), Err(_) => IfaValue::Nil }}", args[0], args[1])
+                    format!("{{ let u = if let IfaValue::Str(s) = {} {{ s }} else {{ String::new() }}; match ifa_capability(\"Network\", &u) {{ Err(denied) => denied, Ok(()) => match reqwest::Client::new().post(u).body(if let IfaValue::Str(s) = {} {{ s }} else {{ String::new() }}).send().await {{ Ok(r) => IfaValue::Str(r.text().await.unwrap_or_default()), Err(_) => IfaValue::Nil }} }} }}", args[0], args[1])
                 } else {
                     "IfaValue::Nil".to_string()
                 }
@@ -250,13 +260,20 @@ impl RustTranspiler {
             "ofun" if matches_method(&method, ofun::IS_ALIVE) => {
                 "IfaValue::Bool(true)".to_string()
             }
-            "ofun" if matches_method(&method, ofun::TYPE_OF) => {
+            "ofun" if matches_method(&method, ofun::TYPE_NAME) => {
                 if let Some(arg) = args.first() {
                     format!("IfaValue::Str(match {} {{ IfaValue::Int(_) => \"Int\", IfaValue::Float(_) => \"Float\", IfaValue::Str(_) => \"Str\", IfaValue::Bool(_) => \"Bool\", IfaValue::List(_) => \"List\", IfaValue::Map(_) => \"Map\", IfaValue::Nil => \"Nil\", }}.to_string())", arg)
                 } else {
                     "IfaValue::Str(\"Nil\".to_string())".to_string()
                 }
             }
+            "ofun" if matches_method(&method, ofun::TYPE_OF) => {
+                if let Some(arg) = args.first() {
+                    format!("IfaValue::Str(match {} {{ IfaValue::Int(_) => \"int\", IfaValue::Float(_) => \"float\", IfaValue::Str(_) => \"str\", IfaValue::Bool(_) => \"bool\", IfaValue::List(_) => \"list\", IfaValue::Map(_) => \"map\", IfaValue::Nil => \"null\", }}.to_string())", arg)
+                } else {
+                    "IfaValue::Str(\"null\".to_string())".to_string()
+                }
+            }
 
             // ═══════════════════════════════════════════════════════════════════
             // Unknown domain/method — hard failure in generated code.
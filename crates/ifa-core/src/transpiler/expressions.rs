@@ -12,6 +12,7 @@ impl RustTranspiler {
             Expression::Int(n) => format!("IfaValue::Int({})", n),
             Expression::Float(f) => format!("IfaValue::Float({})", f),
             Expression::String(s) => format!("IfaValue::Str(\"{}\".to_string())", s),
+            Expression::Bytes(b) => format!("IfaValue::bytes(vec!{:?})", b),
             Expression::Bool(b) => format!("IfaValue::Bool({})", b),
             Expression::Nil => "IfaValue::Nil".to_string(),
             Expression::Identifier(name) => self.mangle_identifier(name),
@@ -82,6 +83,7 @@ impl RustTranspiler {
                         domain: *domain,
                         method: name.clone(),
                         args: args.clone(),
+                        named_args: Vec::new(),
                         is_optional: false,
                         span: Span::default(),
                     };
@@ -137,6 +139,7 @@ impl RustTranspiler {
                             domain: *domain,
                             method: method.clone(),
                             args: args.clone(),
+                            named_args: Vec::new(),
                             is_optional: *is_optional,
                             span: Span::default(),
                         };
@@ -165,7 +168,11 @@ impl RustTranspiler {
                 for part in parts {
                     match part {
                         InterpolatedPart::Literal(s) => {
-                            fmt_str.push_str(&s.replace("{", "{{").replace("}", "}}").replace("\"", "\\\""));
+                            fmt_str.push_str(
+                                &s.replace("{", "{{")
+                                    .replace("}", "}}")
+                                    .replace("\"", "\\\""),
+                            );
                         }
                         InterpolatedPart::Expression(expr) => {
                             fmt_str.push_str("{}");
@@ -176,7 +183,11 @@ impl RustTranspiler {
                 if args.is_empty() {
                     format!("IfaValue::str(\"{}\")", fmt_str)
                 } else {
-                    format!("IfaValue::str(format!(\"{}\", {}))", fmt_str, args.join(", "))
+                    format!(
+                        "IfaValue::str(format!(\"{}\", {}))",
+                        fmt_str,
+                        args.join(", ")
+                    )
                 }
             }
         }
@@ -8,14 +8,17 @@
 //! - `statements.rs` - Statement transpilation
 //! - `expressions.rs` - Expression transpilation
 //! - `domains.rs` - Odù domain call transpilation
+//! - `source_map.rs` - Generated Rust line -> `.ifa` line mapping
 
 pub mod constants;
 mod core;
 mod domains;
 mod expressions;
+mod source_map;
 mod statements;
 
-pub use self::core::{RustTranspiler, transpile_to_rust};
+pub use self::core::{RustTranspiler, transpile_to_rust, transpile_to_rust_with_capabilities};
+pub use self::source_map::SourceMap;
 
 #[cfg(test)]
 mod tests {
@@ -36,6 +39,24 @@ mod tests {
         assert!(rust_code.contains("fn main()"));
     }
 
+    #[test]
+    fn test_match_list_pattern_transpile() {
+        let source = r#"
+        match ([1, 2, 3]) {
+            [head, ...tail] ti head > 0 => { Irosu.fo(tail); }
+            _ => { Irosu.fo("other"); }
+        }
+        "#;
+
+        let program = parse(source).unwrap();
+        let rust_code = transpile_to_rust(&program);
+
+        assert!(rust_code.contains("let IfaValue::List(l0) = &cond_val else { return None; };"));
+        assert!(rust_code.contains("if l0.len() < 1 { return None; }"));
+        assert!(rust_code.contains("let tail = IfaValue::List(l0[1..].to_vec());"));
+        assert!(!rust_code.contains("compile_error!"));
+    }
+
     #[test]
     fn test_file_io_transpile_exposes_errors() {
         let source = r#"
@@ -49,4 +70,96 @@ mod tests {
         assert!(rust_code.contains("\"IoError\""));
         assert!(!rust_code.contains("std::fs::write(&p, &c).ok()"));
     }
+
+    #[test]
+    fn test_file_read_checks_capability_before_filesystem_access() {
+        use crate::interpreter::{CapabilitySet, Ofun};
+
+        let program = parse(r#"ayanmo contents = Odi.read("data/in.txt");"#).unwrap();
+
+        let rust_code = transpile_to_rust(&program);
+        let check = rust_code
+            .find("ifa_capability(\"ReadFiles\", &p)")
+            .expect("read should be capability checked");
+        let read = rust_code.find("std::fs::read_to_string(&p)").unwrap();
+        assert!(check < read, "capability check must precede the read");
+        assert!(rust_code.contains("const ALLOW_READ: &[&str] = &[];"));
+
+        let mut caps = CapabilitySet::new();
+        caps.grant(Ofun::ReadFiles {
+            root: "data".into(),
+        });
+        let rust_code = transpile_to_rust_with_capabilities(&program, &caps);
+        assert!(rust_code.contains("const ALLOW_READ: &[&str] = &[\"data\"];"));
+        assert!(rust_code.contains("const ALLOW_WRITE: &[&str] = &[];"));
+    }
+
+    #[test]
+    fn test_generated_capability_check_resolves_paths() {
+        use crate::interpreter::{CapabilitySet, Ofun};
+
+        let program = parse(r#"Irosu.fo(Odi.read("./data/../main.rs"));"#).unwrap();
+        let mut caps = CapabilitySet::new();
+        caps.grant(Ofun::read_files("./src/../.."));
+        let rust_code = transpile_to_rust_with_capabilities(&program, &caps);
+
+        // The root is baked in canonical, not as written
+        let root = std::env::current_dir()
+            .unwrap()
+            .parent()
+            .unwrap()
+            .canonicalize()
+            .unwrap();
+        let expected = format!(
+            "const ALLOW_READ: &[&str] = &[{:?}];",
+            root.display().to_string()
+        );
+        assert!(rust_code.contains(&expected), "{}", rust_code);
+        assert!(!rust_code.contains("src/../.."));
+
+        // Targets are refused on `..` and resolved before the prefix check
+        assert!(rust_code.contains("c == Component::ParentDir"));
+        assert!(rust_code.contains("let path = resolve(target);"));
+        assert!(
+            rust_code.contains(
+                "!escapes && ALLOW_READ.iter().any(|root| path.starts_with(resolve(root)))"
+            )
+        );
+    }
+
+    #[test]
+    fn test_generated_runtime_supports_byte_literals() {
+        let program = parse(
+            r#"
+            ayanmo b = b"\x00hi";
+            Irosu.fo(b);
+            Irosu.fo(b == b"\x00hi");
+            "#,
+        )
+        .unwrap();
+        let rust_code = transpile_to_rust(&program);
+
+        assert!(rust_code.contains("IfaValue::bytes(vec![0, 104, 105])"));
+        // The runtime has a variant for them, shown and compared like the interpreter's
+        assert!(rust_code.contains("    Bytes(Vec<u8>),"));
+        assert!(rust_code.contains("IfaValue::Bytes(b) => {"));
+        assert!(rust_code.contains("(IfaValue::Bytes(a), IfaValue::Bytes(b)) => a.partial_cmp(b)"));
+    }
+
+    #[test]
+    fn test_source_map_points_at_failing_statement() {
+        let program = parse("ayanmo x = 1;\n\nayanmo y = -x;\n").unwrap();
+        let mut transpiler = RustTranspiler::new();
+        transpiler.set_source_name("neg.ifa");
+        let rust_code = transpiler.transpile_program(&program);
+
+        let rust_line = rust_code
+            .lines()
+            .position(|line| line.contains("let mut y"))
+            .unwrap()
+            + 1;
+        assert_eq!(transpiler.source_map().ifa_line(rust_line), Some(3));
+        assert!(rust_code.contains("ifa_install_panic_hook();"));
+        assert!(rust_code.contains("const IFA_SOURCE: &str = \"neg.ifa\";"));
+    }
 }
@@ -0,0 +1,107 @@
+//! # Source Map
+//!
+//! Relates lines of the generated Rust back to the `.ifa` source. Every
+//! transpiled statement is preceded by a `// ifa:<line>` comment; the map is
+//! read off those markers once the program is generated, then embedded in
+//! the binary so that panics report `.ifa` positions.
+
+/// Comment prefix placed on the line before each transpiled statement
+pub(crate) const MARKER: &str = "// ifa:";
+
+/// Generated Rust line -> `.ifa` line, both 1-based
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SourceMap {
+    /// `(rust_line, ifa_line)` for the first line of each statement, ascending
+    entries: Vec<(usize, usize)>,
+}
+
+impl SourceMap {
+    /// Read the map off the `// ifa:` markers in generated code
+    pub(crate) fn from_generated(code: &str) -> Self {
+        let entries = code
+            .lines()
+            .enumerate()
+            .filter_map(|(i, line)| {
+                let ifa_line = line.trim_start().strip_prefix(MARKER)?.parse().ok()?;
+                // The statement itself starts on the line after its marker
+                Some((i + 2, ifa_line))
+            })
+            .collect();
+        Self { entries }
+    }
+
+    /// `(rust_line, ifa_line)` pairs, in generated-code order
+    pub fn entries(&self) -> &[(usize, usize)] {
+        &self.entries
+    }
+
+    /// The `.ifa` line of the statement that generated `rust_line`
+    pub fn ifa_line(&self, rust_line: usize) -> Option<usize> {
+        self.entries
+            .iter()
+            .rev()
+            .find(|(rust, _)| *rust <= rust_line)
+            .map(|(_, ifa)| *ifa)
+    }
+
+    /// The map as a table in the generated program, plus the panic hook that
+    /// uses it. Appended after `main` so it does not shift mapped lines.
+    pub(crate) fn runtime_shim(&self, source_name: &str) -> String {
+        let table = self
+            .entries
+            .iter()
+            .map(|(rust, ifa)| format!("({}, {})", rust, ifa))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!(
+            r#"
+/// The `.ifa` file this program was transpiled from
+const IFA_SOURCE: &str = {source:?};
+
+/// Generated line -> `.ifa` line of each transpiled statement
+const IFA_SOURCE_MAP: &[(u32, u32)] = &[{table}];
+
+/// Report panics in this file at the `.ifa` line being executed
+fn ifa_install_panic_hook() {{
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {{
+        let ifa_line = info.location().filter(|loc| loc.file() == file!()).and_then(|loc| {{
+            IFA_SOURCE_MAP
+                .iter()
+                .rev()
+                .find(|(rust, _)| *rust <= loc.line())
+                .map(|(_, ifa)| (*ifa, loc.line()))
+        }});
+        let Some((ifa_line, rust_line)) = ifa_line else {{
+            return default_hook(info);
+        }};
+        let payload = info.payload();
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "panic".to_string());
+        eprintln!("Ifá runtime error at {{}}:{{}}: {{}} (generated line {{}})", IFA_SOURCE, ifa_line, message, rust_line);
+    }}));
+}}
+"#,
+            table = table,
+            source = source_name,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markers_map_following_lines() {
+        let code = "fn main() {\n    // ifa:2\n    let x = 1;\n    // ifa:5\n    if x {\n        y();\n    }\n}\n";
+        let map = SourceMap::from_generated(code);
+        assert_eq!(map.entries(), &[(3, 2), (5, 5)]);
+        assert_eq!(map.ifa_line(1), None);
+        assert_eq!(map.ifa_line(3), Some(2));
+        assert_eq!(map.ifa_line(6), Some(5));
+    }
+}
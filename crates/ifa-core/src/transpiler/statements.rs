@@ -3,6 +3,7 @@
 //! Transpiles Ifá-Lang statements to Rust code.
 
 use super::core::{RustTranspiler, std_domain_from_name};
+use super::source_map::{MARKER, SourceMap};
 use crate::ast::*;
 
 impl RustTranspiler {
@@ -35,6 +36,8 @@ impl RustTranspiler {
             ""
         };
 
+        let capabilities = self.capability_prelude();
+
         // Generate main function with async support if needed
         let main_fn = if self.has_async {
             "#[tokio::main]\nasync fn main()"
@@ -42,7 +45,7 @@ impl RustTranspiler {
             "fn main()"
         };
 
-        format!(
+        let code = format!(
             r#"//! Generated by Ifá-Lang Transpiler
 //! Àṣẹ - May it be so
 
@@ -51,7 +54,7 @@ use std::collections::HashMap;
 {uses}
 {module_defs}
 /// Ifá Value type for dynamic typing
-#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum IfaValue {{
     Int(i64),
     Float(f64),
@@ -59,9 +62,27 @@ pub enum IfaValue {{
     Bool(bool),
     List(Vec<IfaValue>),
     Map(HashMap<String, IfaValue>),
+    Bytes(Vec<u8>),
     Nil,
 }}
 
+// Maps have no order, so ordering is written out rather than derived
+impl PartialOrd for IfaValue {{
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {{
+        match (self, other) {{
+            (IfaValue::Int(a), IfaValue::Int(b)) => a.partial_cmp(b),
+            (IfaValue::Float(a), IfaValue::Float(b)) => a.partial_cmp(b),
+            (IfaValue::Int(a), IfaValue::Float(b)) => (*a as f64).partial_cmp(b),
+            (IfaValue::Float(a), IfaValue::Int(b)) => a.partial_cmp(&(*b as f64)),
+            (IfaValue::Str(a), IfaValue::Str(b)) => a.partial_cmp(b),
+            (IfaValue::Bool(a), IfaValue::Bool(b)) => a.partial_cmp(b),
+            (IfaValue::List(a), IfaValue::List(b)) => a.partial_cmp(b),
+            (IfaValue::Bytes(a), IfaValue::Bytes(b)) => a.partial_cmp(b),
+            _ => None,
+        }}
+    }}
+}}
+
 impl std::fmt::Display for IfaValue {{
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
         match self {{
@@ -72,11 +93,22 @@ impl std::fmt::Display for IfaValue {{
             IfaValue::Nil => write!(f, "àìsí"),
             IfaValue::List(l) => write!(f, "{{:?}}", l),
             IfaValue::Map(m) => write!(f, "{{:?}}", m),
+            IfaValue::Bytes(b) => {{
+                write!(f, "b\"")?;
+                for byte in b {{
+                    write!(f, "\\x{{:02X}}", byte)?;
+                }}
+                write!(f, "\"")
+            }}
         }}
     }}
 }}
 
 impl IfaValue {{
+    pub fn bytes(b: Vec<u8>) -> Self {{
+        IfaValue::Bytes(b)
+    }}
+
     pub fn is_truthy(&self) -> bool {{
         match self {{
             IfaValue::Bool(b) => *b,
@@ -86,6 +118,7 @@ impl IfaValue {{
             IfaValue::Str(s) => !s.is_empty(),
             IfaValue::List(l) => !l.is_empty(),
             IfaValue::Map(m) => !m.is_empty(),
+            IfaValue::Bytes(b) => !b.is_empty(),
             IfaValue::Nil => false,
         }}
     }}
@@ -93,6 +126,7 @@ impl IfaValue {{
 
 impl std::ops::Add for IfaValue {{
     type Output = Self;
+    #[track_caller]
     fn add(self, other: Self) -> Self {{
         match (self, other) {{
             (IfaValue::Int(a), IfaValue::Int(b)) => a.checked_add(b).map(IfaValue::Int).unwrap_or(IfaValue::Float(a as f64 + b as f64)),
@@ -107,6 +141,7 @@ impl std::ops::Add for IfaValue {{
 
 impl std::ops::Sub for IfaValue {{
     type Output = Self;
+    #[track_caller]
     fn sub(self, other: Self) -> Self {{
         match (self, other) {{
             (IfaValue::Int(a), IfaValue::Int(b)) => a.checked_sub(b).map(IfaValue::Int).unwrap_or(IfaValue::Float(a as f64 - b as f64)),
@@ -120,6 +155,7 @@ impl std::ops::Sub for IfaValue {{
 
 impl std::ops::Mul for IfaValue {{
     type Output = Self;
+    #[track_caller]
     fn mul(self, other: Self) -> Self {{
         match (self, other) {{
             (IfaValue::Int(a), IfaValue::Int(b)) => a.checked_mul(b).map(IfaValue::Int).unwrap_or(IfaValue::Float(a as f64 * b as f64)),
@@ -133,6 +169,7 @@ impl std::ops::Mul for IfaValue {{
 
 impl std::ops::Div for IfaValue {{
     type Output = Self;
+    #[track_caller]
     fn div(self, other: Self) -> Self {{
         match (self, other) {{
             (IfaValue::Int(a), IfaValue::Int(b)) => if b != 0 {{ IfaValue::Float(a as f64 / b as f64) }} else {{ IfaValue::Nil }},
@@ -144,6 +181,7 @@ impl std::ops::Div for IfaValue {{
 
 impl std::ops::Rem for IfaValue {{
     type Output = Self;
+    #[track_caller]
     fn rem(self, other: Self) -> Self {{
         match (self, other) {{
             (IfaValue::Int(a), IfaValue::Int(b)) => if b != 0 {{ IfaValue::Int(a % b) }} else {{ IfaValue::Nil }},
@@ -154,6 +192,7 @@ impl std::ops::Rem for IfaValue {{
 
 impl std::ops::Neg for IfaValue {{
     type Output = Self;
+    #[track_caller]
     fn neg(self) -> Self {{
         match self {{
             IfaValue::Int(a) => IfaValue::Int(-a),
@@ -170,19 +209,100 @@ impl std::ops::Not for IfaValue {{
     }}
 }}
 
+{capabilities}
 {main_fn} {{
+    ifa_install_panic_hook();
 {body}
     println!("\nÀṣẹ! (Success)");
 }}
 "#
+        );
+        self.source_map = SourceMap::from_generated(&code);
+        code + &self.source_map.runtime_shim(&self.source_name)
+    }
+
+    /// Ọ̀fún grants baked in at build time, plus the `ifa_capability` check
+    /// that file and network operations call before touching the host.
+    /// Mirrors `CapabilitySet::check`: path roots by prefix once both sides
+    /// are resolved as `canonical_root` does, hosts exactly, and `..` refused
+    /// as `confined_path` does.
+    fn capability_prelude(&self) -> String {
+        let list = |items: &[String]| {
+            items
+                .iter()
+                .map(|item| format!("{:?}", item))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        format!(
+            r#"/// Ọ̀fún capabilities granted at build time
+const ALLOW_READ: &[&str] = &[{read}];
+const ALLOW_WRITE: &[&str] = &[{write}];
+const ALLOW_NET: &[&str] = &[{net}];
+
+/// Check a capability; a denial is returned as a `PermissionDenied` error value
+#[allow(dead_code)]
+fn ifa_capability(kind: &str, target: &str) -> Result<(), IfaValue> {{
+    use std::path::{{Component, Path, PathBuf}};
+    // Absolute, with symlinks and `..` resolved through the part that exists
+    fn resolve(path: &str) -> PathBuf {{
+        let mut resolved = std::env::current_dir().unwrap_or_default();
+        for c in Path::new(path).components() {{
+            match c {{
+                Component::CurDir => {{}}
+                Component::ParentDir => {{
+                    resolved.pop();
+                }}
+                other => {{
+                    resolved.push(other);
+                    if let Ok(real) = resolved.canonicalize() {{
+                        resolved = real;
+                    }}
+                }}
+            }}
+        }}
+        resolved
+    }}
+    // `..` is refused in the target, so `root/../elsewhere` can't pass a `root` grant
+    let escapes = Path::new(target).components().any(|c| c == Component::ParentDir);
+    let path = resolve(target);
+    let granted = match kind {{
+        "ReadFiles" => !escapes && ALLOW_READ.iter().any(|root| path.starts_with(resolve(root))),
+        "WriteFiles" => !escapes && ALLOW_WRITE.iter().any(|root| path.starts_with(resolve(root))),
+        "Network" => {{
+            let rest = target.split_once("://").map_or(target, |(_, r)| r);
+            let authority = rest.split(['/', '?', '#']).next().unwrap_or(rest);
+            let host = authority.rsplit_once('@').map_or(authority, |(_, h)| h);
+            ALLOW_NET.contains(&host.split(':').next().unwrap_or(host))
+        }}
+        _ => false,
+    }};
+    if granted {{
+        return Ok(());
+    }}
+    let mut m = HashMap::new();
+    m.insert("kind".to_string(), IfaValue::Str("PermissionDenied".to_string()));
+    m.insert("message".to_string(), IfaValue::Str(format!("Capability denied: {{}} {{}}", kind, target)));
+    Err(IfaValue::Map(m))
+}}
+"#,
+            read = list(&self.allow_read),
+            write = list(&self.allow_write),
+            net = list(&self.allow_net),
         )
     }
 
     /// Transpile a single statement
     pub fn transpile_statement(&mut self, stmt: &Statement) -> String {
         let indent = self.indent_str();
+        // Module code is not in the main file, so only its callers are mapped
+        let marker = if self.in_module {
+            String::new()
+        } else {
+            format!("{}{}{}\n", indent, MARKER, stmt.span().line)
+        };
 
-        match stmt {
+        let code = match stmt {
             Statement::VarDecl {
                 name,
                 value,
@@ -276,10 +396,18 @@ impl std::ops::Not for IfaValue {{
             }
 
             Statement::While {
-                condition, body, ..
+                condition,
+                body,
+                label,
+                ..
             } => {
                 let cond = self.transpile_expression(condition);
-                let mut result = format!("{}while ({}).is_truthy() {{\n", indent, cond);
+                let mut result = format!(
+                    "{}{}while ({}).is_truthy() {{\n",
+                    indent,
+                    loop_label(label),
+                    cond
+                );
 
                 self.indent += 1;
                 for s in body {
@@ -296,6 +424,7 @@ impl std::ops::Not for IfaValue {{
                 var,
                 iterable,
                 body,
+                label,
                 ..
             } => {
                 let iter = self.transpile_expression(iterable);
@@ -303,7 +432,12 @@ impl std::ops::Not for IfaValue {{
                 let mut result = format!("{}if let IfaValue::List(items) = {} {{\n", indent, iter);
                 self.indent += 1;
                 let indent2 = self.indent_str();
-                result.push_str(&format!("{}for {} in items {{\n", indent2, m_var));
+                result.push_str(&format!(
+                    "{}{}for {} in items {{\n",
+                    indent2,
+                    loop_label(label),
+                    m_var
+                ));
 
                 self.indent += 1;
                 for s in body {
@@ -326,7 +460,20 @@ impl std::ops::Not for IfaValue {{
 
                 for (i, arm) in arms.iter().enumerate() {
                     let prefix = if i == 0 { "if" } else { "else if" };
-                    let condition = match &arm.pattern {
+                    if let MatchPattern::List { .. } = &arm.pattern {
+                        let condition =
+                            self.transpile_list_pattern(&arm.pattern, arm.guard.as_ref());
+                        result.push_str(&format!("{}{} {} {{\n", indent, prefix, condition));
+                        self.indent += 1;
+                        for s in &arm.body {
+                            result.push_str(&self.transpile_statement(s));
+                            result.push('\n');
+                        }
+                        self.indent -= 1;
+                        result.push_str(&format!("{}}}\n", indent));
+                        continue;
+                    }
+                    let mut condition = match &arm.pattern {
                         MatchPattern::Literal(expr) => {
                             format!("cond_val == {}", self.transpile_expression(expr))
                         }
@@ -336,7 +483,14 @@ impl std::ops::Not for IfaValue {{
                             format!("cond_val >= {} && cond_val <= {}", s, e)
                         }
                         MatchPattern::Wildcard => "true".to_string(),
+                        MatchPattern::List { .. } | MatchPattern::Binding(_) => {
+                            unreachable!("list patterns are transpiled above")
+                        }
                     };
+                    if let Some(guard) = &arm.guard {
+                        let guard = self.transpile_expression(guard);
+                        condition = format!("({}) && ({}).is_truthy()", condition, guard);
+                    }
 
                     result.push_str(&format!("{}{} {} {{\n", indent, prefix, condition));
                     self.indent += 1;
@@ -362,6 +516,16 @@ impl std::ops::Not for IfaValue {{
                 }
             }
 
+            Statement::Break { label, .. } => match label {
+                Some(label) => format!("{}break 'ifa_{};", indent, label),
+                None => format!("{}break;", indent),
+            },
+
+            Statement::Continue { label, .. } => match label {
+                Some(label) => format!("{}continue 'ifa_{};", indent, label),
+                None => format!("{}continue;", indent),
+            },
+
             Statement::EseDef {
                 name,
                 params,
@@ -419,7 +583,6 @@ impl std::ops::Not for IfaValue {{
                 )
             }
 
-
             Statement::Import { path, names, .. } => {
                 if self.handle_import(path, names) {
                     format!("{}// import handled", indent)
@@ -517,7 +680,10 @@ impl std::ops::Not for IfaValue {{
             }
 
             Statement::Update { .. } => {
-                format!("{indent}compile_error!(\"Ifá transpiler: update statements (++, --, +=, etc) are not supported yet\");", indent = indent)
+                format!(
+                    "{indent}compile_error!(\"Ifá transpiler: update statements (++, --, +=, etc) are not supported yet\");",
+                    indent = indent
+                )
             }
 
             Statement::Try { .. } => {
@@ -526,6 +692,124 @@ impl std::ops::Not for IfaValue {{
                     indent
                 )
             }
+        };
+        marker + &code
+    }
+
+    /// Condition of a list pattern arm: `let Some((..)) = ..` over a closure
+    /// that checks `cond_val`'s shape and the guard, returning the bound names
+    fn transpile_list_pattern(
+        &mut self,
+        pattern: &MatchPattern,
+        guard: Option<&Expression>,
+    ) -> String {
+        let mut checks = Vec::new();
+        let mut bindings = Vec::new();
+        let mut lists = 0;
+        self.list_pattern_checks(
+            pattern,
+            "cond_val".to_string(),
+            &mut checks,
+            &mut bindings,
+            &mut lists,
+        );
+
+        let inner = "    ".repeat(self.indent + 1);
+        let mut body = String::new();
+        for line in checks {
+            body.push_str(&format!("{}{}\n", inner, line));
+        }
+        let mut names = Vec::new();
+        for (name, value) in bindings {
+            let name = self.mangle_identifier(&name);
+            body.push_str(&format!("{}let {} = {};\n", inner, name, value));
+            names.push(name);
+        }
+        if let Some(guard) = guard {
+            let guard = self.transpile_expression(guard);
+            body.push_str(&format!(
+                "{}if !({}).is_truthy() {{ return None; }}\n",
+                inner, guard
+            ));
+        }
+        let (pattern, value) = if names.is_empty() {
+            ("()".to_string(), "()".to_string())
+        } else {
+            let muts: Vec<String> = names.iter().map(|n| format!("mut {}", n)).collect();
+            (
+                format!("({},)", muts.join(", ")),
+                format!("({},)", names.join(", ")),
+            )
+        };
+        format!(
+            "let Some({}) = (|| {{\n{}{}Some({})\n{}}})()",
+            pattern,
+            body,
+            inner,
+            value,
+            self.indent_str()
+        )
+    }
+
+    /// Statements rejecting a value that does not match `pattern`, plus the
+    /// names it binds; `value` is the Rust expression for the matched value
+    fn list_pattern_checks(
+        &mut self,
+        pattern: &MatchPattern,
+        value: String,
+        checks: &mut Vec<String>,
+        bindings: &mut Vec<(String, String)>,
+        lists: &mut usize,
+    ) {
+        match pattern {
+            MatchPattern::Literal(expr) => {
+                let expected = self.transpile_expression(expr);
+                checks.push(format!("if {} != {} {{ return None; }}", value, expected));
+            }
+            MatchPattern::Range { start, end } => {
+                let s = self.transpile_expression(start);
+                let e = self.transpile_expression(end);
+                checks.push(format!(
+                    "if !({v} >= {} && {v} <= {}) {{ return None; }}",
+                    s,
+                    e,
+                    v = value
+                ));
+            }
+            MatchPattern::Wildcard => {}
+            MatchPattern::Binding(name) => {
+                bindings.push((name.clone(), format!("{}.clone()", value)))
+            }
+            MatchPattern::List { elements, rest } => {
+                let list = format!("l{}", *lists);
+                *lists += 1;
+                checks.push(format!(
+                    "let IfaValue::List({}) = &{} else {{ return None; }};",
+                    list, value
+                ));
+                let op = if rest.is_some() { "<" } else { "!=" };
+                checks.push(format!(
+                    "if {}.len() {} {} {{ return None; }}",
+                    list,
+                    op,
+                    elements.len()
+                ));
+                for (i, element) in elements.iter().enumerate() {
+                    self.list_pattern_checks(
+                        element,
+                        format!("{}[{}]", list, i),
+                        checks,
+                        bindings,
+                        lists,
+                    );
+                }
+                if let Some(name) = rest {
+                    bindings.push((
+                        name.clone(),
+                        format!("IfaValue::List({}[{}..].to_vec())", list, elements.len()),
+                    ));
+                }
+            }
         }
     }
 
@@ -577,3 +861,12 @@ impl std::ops::Not for IfaValue {{
         }
     }
 }
+
+/// Rust label prefix for a labeled Ifá loop (`'ifa_outer: `), prefixed so
+/// names like `static` stay valid.
+fn loop_label(label: &Option<String>) -> String {
+    label
+        .as_ref()
+        .map(|label| format!("'ifa_{}: ", label))
+        .unwrap_or_default()
+}
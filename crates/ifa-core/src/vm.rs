@@ -5,12 +5,16 @@
 //! ### ✅ ARCHITECTURAL STATUS (String Operations)
 //! `OpCode::Add` is now PURE NUMERIC (Int/Float only). String concatenation uses
 //! the dedicated `OpCode::Concat (0x27)`, which is strict `Str + Str` only.
-//! The `text += " more"` compiler path emits `ToString` + `Concat` as appropriate.
+//! The `text += " more"` compiler path emits `ToString` + `Concat` as appropriate,
+//! or `OpCode::AppendLocal (0x29)` when `text` is a local, which grows a buffer
+//! in place instead of copying the whole string on every append.
+//! Interpolated strings use `OpCode::StrBuilder (0x28)`, which joins all parts
+//! in a single allocation instead of a chain of pairwise `Concat`s.
 //!
 //! Refer to `patch.md` for the Phase 7 Hardening Roadmap.
 
 use crate::bytecode::{Bytecode, OpCode};
-use crate::error::{IfaError, IfaResult};
+use crate::error::{IfaError, IfaResult, overflow};
 use crate::native::{OduRegistry, VmContext};
 use crate::opon::Opon;
 use ifa_types::value_union::{ClosureData, FutureState, IfaValue, ResultPayload, UpvalueCell};
@@ -102,6 +106,10 @@ pub struct IfaVM {
     stack_limit: Option<usize>,
     /// Call frame capacity limit
     frame_limit: Option<usize>,
+    /// Most elements a single list or map may hold (`None`: the default,
+    /// tightened by the active memory directive)
+    #[serde(default)]
+    collection_limit: Option<usize>,
     /// Active memory directive
     pub opon_size: crate::bytecode::OponSize,
 
@@ -135,6 +143,9 @@ pub struct IfaVM {
     /// Cached compiled modules (path -> {hash, bytecode})
     #[serde(skip)]
     module_cache: std::collections::HashMap<String, CachedModule>,
+    /// On-disk `.ifab` cache of compiled source modules, kept across runs
+    #[serde(skip)]
+    disk_cache: Option<crate::module_cache::ModuleCache>,
     /// Cached module exports (path -> exports map)
     #[serde(skip)]
     module_exports: std::collections::HashMap<String, IfaValue>,
@@ -154,6 +165,13 @@ pub struct IfaVM {
     /// Cleared and executed by `FinallyEnd`.
     #[serde(skip)]
     pending_finally: Option<FinallyResumption>,
+
+    /// Buffers behind `AppendLocal`, by absolute stack slot: the `Arc` last
+    /// written to the slot and the string it has grown to since. Reading the
+    /// slot writes the buffer back; an entry whose `Arc` is no longer in its
+    /// slot is stale.
+    #[serde(skip)]
+    str_appends: std::collections::HashMap<usize, (Arc<str>, String)>,
 }
 
 #[derive(Clone)]
@@ -199,6 +217,45 @@ impl IfaVM {
         self.globals.insert(name.into(), value);
     }
 
+    /// Limit how many elements a single list or map may hold; building a
+    /// larger one fails instead of allocating it
+    pub fn set_collection_limit(&mut self, limit: usize) {
+        self.collection_limit = Some(limit);
+    }
+
+    /// Fail before building a list or map of `len` elements if that is more
+    /// than the collection limit or the memory directive's slot count allows
+    fn check_collection_size(&self, len: usize) -> IfaResult<()> {
+        let limit = self
+            .collection_limit
+            .unwrap_or(crate::interpreter::DEFAULT_COLLECTION_LIMIT)
+            .min(self.opon_size.limits().0.unwrap_or(usize::MAX));
+        crate::interpreter::handlers::check_collection_size(len, limit)
+    }
+
+    /// Load unchanged imported source modules from `cache` instead of
+    /// compiling them, and store the ones it compiles there.
+    pub fn set_module_cache(&mut self, cache: crate::module_cache::ModuleCache) {
+        self.disk_cache = Some(cache);
+    }
+
+    /// Imported modules loaded from the on-disk module cache so far
+    pub fn module_cache_hits(&self) -> usize {
+        self.disk_cache
+            .as_ref()
+            .map_or(0, crate::module_cache::ModuleCache::hits)
+    }
+
+    /// Values the program currently holds: the stack (locals and
+    /// temporaries) and the globals.
+    pub fn live_values(&self) -> Vec<IfaValue> {
+        self.stack
+            .iter()
+            .chain(self.globals.values())
+            .cloned()
+            .collect()
+    }
+
     /// Create new VM
     pub fn new() -> Self {
         let mut module_paths = Vec::new();
@@ -218,6 +275,7 @@ impl IfaVM {
             opon: Opon::create_default(),
             stack_limit: None,
             frame_limit: None,
+            collection_limit: None,
             opon_size: crate::bytecode::OponSize::Ailopin,
             registry: None,
             halted: false,
@@ -228,14 +286,15 @@ impl IfaVM {
             imported: std::collections::HashSet::new(),
             import_guard: crate::module_resolver::ImportGuard::new(),
             module_cache: std::collections::HashMap::new(),
+            disk_cache: None,
             module_exports: std::collections::HashMap::new(),
             module_bytecode: std::collections::HashMap::new(),
             module_globals: std::collections::HashMap::new(),
             resolver: crate::module_resolver::ModuleResolver::new(module_paths),
             current_file: None,
             pending_finally: None,
+            str_appends: std::collections::HashMap::new(),
         }
-
     }
 
     /// Attach a function registry (Standard Library)
@@ -263,6 +322,7 @@ impl IfaVM {
             opon,
             stack_limit: None,
             frame_limit: None,
+            collection_limit: None,
             opon_size: crate::bytecode::OponSize::Ailopin,
             registry: None,
             halted: false,
@@ -273,14 +333,15 @@ impl IfaVM {
             imported: std::collections::HashSet::new(),
             import_guard: crate::module_resolver::ImportGuard::new(),
             module_cache: std::collections::HashMap::new(),
+            disk_cache: None,
             module_exports: std::collections::HashMap::new(),
             module_bytecode: std::collections::HashMap::new(),
             module_globals: std::collections::HashMap::new(),
             resolver: crate::module_resolver::ModuleResolver::new(module_paths),
             current_file: None,
             pending_finally: None,
+            str_appends: std::collections::HashMap::new(),
         }
-
     }
 
     /// Create VM with custom file path (for module resolution)
@@ -294,7 +355,6 @@ impl IfaVM {
         vm
     }
 
-
     // =========================================================================
     // PERSISTENT STATE (SNAPSHOTS)
     // =========================================================================
@@ -411,7 +471,6 @@ impl IfaVM {
         }
     }
 
-
     fn hash_source(source: &str) -> u64 {
         use std::hash::{Hash, Hasher};
         let mut hasher = std::collections::hash_map::DefaultHasher::new();
@@ -528,7 +587,7 @@ impl IfaVM {
         self.globals = saved_globals;
         self.module_globals
             .insert(module_key.to_string(), updated_module_globals);
-        self.stack.truncate(saved_stack_len);
+        self.truncate_stack(saved_stack_len);
         self.frames.truncate(saved_frames_len);
         self.recovery_stack.truncate(saved_recovery_len);
         self.stack_limit = saved_limits.0;
@@ -576,39 +635,39 @@ impl IfaVM {
                 IfaError::IoError(format!("Cannot read module '{}': {}", module_key, e))
             })?;
             let source_hash = Self::hash_source(&source);
-            let program = crate::parser::parse(&source).map_err(|e| {
-                IfaError::Runtime(format!("Parse error in module '{}': {}", module_key, e))
-            })?;
-            let export_names = collect_exports_vm(&program);
-
-            let bytecode = if let Some(cached) = self.module_cache.get(&cache_key) {
-                if cached.hash == source_hash {
-                    cached.bytecode.clone()
-                } else {
+            let in_memory = self
+                .module_cache
+                .get(&cache_key)
+                .filter(|cached| cached.hash == source_hash)
+                .map(|cached| cached.bytecode.clone());
+            let on_disk = || {
+                let mut bytecode = self.disk_cache.as_mut()?.load(&file_path, &source)?;
+                bytecode.source_name = file_path.to_string_lossy().to_string();
+                Some(bytecode)
+            };
+            let bytecode = match in_memory.or_else(on_disk) {
+                Some(bytecode) => bytecode,
+                None => {
+                    let program = crate::parser::parse(&source).map_err(|e| {
+                        IfaError::Runtime(format!("Parse error in module '{}': {}", module_key, e))
+                    })?;
                     let compiler =
                         crate::compiler::Compiler::new(file_path.to_string_lossy().as_ref());
                     let bytecode = compiler.compile(&program)?;
-                    self.module_cache.insert(
-                        cache_key.clone(),
-                        CachedModule {
-                            hash: source_hash,
-                            bytecode: bytecode.clone(),
-                        },
-                    );
+                    if let Some(cache) = &self.disk_cache {
+                        cache.store(&file_path, &source, &bytecode);
+                    }
                     bytecode
                 }
-            } else {
-                let compiler = crate::compiler::Compiler::new(file_path.to_string_lossy().as_ref());
-                let bytecode = compiler.compile(&program)?;
-                self.module_cache.insert(
-                    cache_key.clone(),
-                    CachedModule {
-                        hash: source_hash,
-                        bytecode: bytecode.clone(),
-                    },
-                );
-                bytecode
             };
+            self.module_cache.insert(
+                cache_key.clone(),
+                CachedModule {
+                    hash: source_hash,
+                    bytecode: bytecode.clone(),
+                },
+            );
+            let export_names = bytecode.exports.clone();
             (bytecode, export_names, source_hash)
         };
 
@@ -668,7 +727,6 @@ impl IfaVM {
         result.map(|_| exports_val)
     }
 
-
     fn execute_module(&mut self, bytecode: &Bytecode) -> IfaResult<()> {
         let saved_ip = self.ip;
         let saved_halted = self.halted;
@@ -690,7 +748,7 @@ impl IfaVM {
             self.resume_execution(bytecode).map(|_| ())
         })();
 
-        self.stack.truncate(saved_stack_len);
+        self.truncate_stack(saved_stack_len);
         self.frames.truncate(saved_frames_len);
         self.recovery_stack.truncate(saved_recovery_len);
         self.stack_limit = saved_limits.0;
@@ -856,6 +914,36 @@ impl IfaVM {
         }
     }
 
+    /// Shrink the stack to `len`, forgetting the `AppendLocal` buffers of the
+    /// slots dropped; a later local in the same slot may hold the same
+    /// interned `Arc` and must not pick up the old buffer.
+    fn truncate_stack(&mut self, len: usize) {
+        self.stack.truncate(len);
+        if !self.str_appends.is_empty() {
+            self.str_appends.retain(|&slot, _| slot < len);
+        }
+    }
+
+    /// Write the `AppendLocal` buffer for `slot` back to the stack, if it is
+    /// still that slot's string and has grown since.
+    fn sync_str_append(&mut self, slot: usize) {
+        let Some((written, buf)) = self.str_appends.get_mut(&slot) else {
+            return;
+        };
+        match self.stack.get_mut(slot) {
+            Some(IfaValue::Str(current)) if Arc::ptr_eq(written, current) => {
+                // Appends only grow the string, so equal lengths mean no change
+                if buf.len() != current.len() {
+                    *written = Arc::from(buf.as_str());
+                    *current = written.clone();
+                }
+            }
+            _ => {
+                self.str_appends.remove(&slot);
+            }
+        }
+    }
+
     fn call_registry(
         &mut self,
         domain_id: u8,
@@ -863,12 +951,28 @@ impl IfaVM {
         args: Vec<IfaValue>,
         bytecode: &Bytecode,
     ) -> IfaResult<IfaValue> {
+        // Registry functions only take lists, so ranges are built here
+        let args = args
+            .into_iter()
+            .map(|arg| match arg {
+                IfaValue::Range(r) => {
+                    self.check_collection_size(r.len())?;
+                    Ok(IfaValue::list(r.to_list()))
+                }
+                other => Ok(other),
+            })
+            .collect::<IfaResult<Vec<_>>>()?;
         let Some(registry) = self.registry.take() else {
             return Err(IfaError::RegistryNotAttached(method_name.to_string()));
         };
         let mut ctx = VmContext { vm: self, bytecode };
         let result = registry.call(domain_id, method_name, args, &mut ctx);
         self.registry = Some(registry);
+        match &result {
+            Ok(IfaValue::List(list)) => self.check_collection_size(list.len())?,
+            Ok(IfaValue::Map(map)) => self.check_collection_size(map.len())?,
+            _ => {}
+        }
         result
     }
 
@@ -891,29 +995,30 @@ impl IfaVM {
 
     /// Attempt to recover from a runtime error using the Shield of Ọ̀kànràn
     fn attempt_recovery(&mut self, error: &IfaError) -> IfaResult<bool> {
-        if let Some(frame) = self.recovery_stack.pop() {
-            // If this frame already consumed its catch arm, the only remaining
-            // obligation is to run its finally block before propagating outward.
-            if !frame.can_catch {
+        while let Some(frame) = self.recovery_stack.pop() {
+            // If this frame already consumed its catch arm, or the error is a
+            // panic, the only remaining obligation is to run its finally block
+            // before propagating outward.
+            if !frame.can_catch || !error.is_catchable() {
+                if self.stack.len() > frame.stack_depth {
+                    self.truncate_stack(frame.stack_depth); // Drop triggers Ebo cleanup
+                }
+                if self.frames.len() > frame.call_depth {
+                    self.frames.truncate(frame.call_depth);
+                }
                 if let Some(finally_ip) = frame.finally_ip {
-                    if self.stack.len() > frame.stack_depth {
-                        self.stack.truncate(frame.stack_depth);
-                    }
-                    if self.frames.len() > frame.call_depth {
-                        self.frames.truncate(frame.call_depth);
-                    }
                     self.pending_finally = Some(FinallyResumption::Propagate {
                         error: error.clone(),
                     });
                     self.ip = finally_ip;
                     return Ok(true);
                 }
-                return Ok(false);
+                continue;
             }
 
             // 1. Restore stacks
             if self.stack.len() > frame.stack_depth {
-                self.stack.truncate(frame.stack_depth); // Drop triggers Ebo cleanup
+                self.truncate_stack(frame.stack_depth); // Drop triggers Ebo cleanup
             }
             if self.frames.len() > frame.call_depth {
                 self.frames.truncate(frame.call_depth);
@@ -937,10 +1042,9 @@ impl IfaVM {
             }
             self.ip = frame.catch_ip;
 
-            Ok(true) // Recovered
-        } else {
-            Ok(false) // No shield found, crash
+            return Ok(true); // Recovered
         }
+        Ok(false) // No shield found, crash
     }
     /// Execute single instruction (The Step of Iroke)
     fn step(&mut self, bytecode: &Bytecode) -> IfaResult<()> {
@@ -1049,6 +1153,7 @@ impl IfaVM {
                         // Capture local slot
                         0 => {
                             let slot_index = base + idx;
+                            self.sync_str_append(slot_index);
                             let slot = self.stack.get(slot_index).cloned().ok_or_else(|| {
                                 IfaError::UndefinedVariable(format!("<local:{}>", idx))
                             })?;
@@ -1116,6 +1221,15 @@ impl IfaVM {
                 self.push(IfaValue::Str(arc.clone()))?;
             }
 
+            OpCode::PushBytes => {
+                let len = self.read_u16(bytecode)? as usize;
+                let mut bytes = Vec::with_capacity(len);
+                for _ in 0..len {
+                    bytes.push(self.read_u8(bytecode)?);
+                }
+                self.push(IfaValue::bytes(bytes))?;
+            }
+
             OpCode::Pop => {
                 self.pop()?;
             }
@@ -1139,11 +1253,8 @@ impl IfaVM {
                 let a = self.pop()?;
                 match (a.clone(), b.clone()) {
                     (IfaValue::Int(ia), IfaValue::Int(ib)) => {
-                        // D6: Checked arithmetic — promote to Float on overflow
-                        match ia.checked_add(ib) {
-                            Some(result) => self.push(IfaValue::int(result))?,
-                            None => self.push(IfaValue::float(ia as f64 + ib as f64))?,
-                        }
+                        // D6: Checked arithmetic — overflow is an error, as in the interpreter
+                        self.push(ia.checked_add(ib).map(IfaValue::int).ok_or_else(overflow)?)?
                     }
                     (IfaValue::Float(fa), IfaValue::Float(fb)) => {
                         self.push(IfaValue::float(fa + fb))?
@@ -1182,16 +1293,85 @@ impl IfaVM {
                 }
             }
 
+            OpCode::AppendLocal => {
+                let idx = self.read_u16(bytecode)? as usize;
+                let rhs = self.pop()?;
+                let base = self.frames.last().map(|f| f.base_ptr).unwrap_or(0);
+                let slot = base + idx;
+                let IfaValue::Str(rhs) = rhs else {
+                    return Err(IfaError::TypeError {
+                        expected: "Str + Str".into(),
+                        got: format!("Str ++ {}", rhs.type_name()),
+                    });
+                };
+                match self.stack.get(slot) {
+                    Some(IfaValue::Str(current)) => match self.str_appends.get_mut(&slot) {
+                        Some((written, buf)) if Arc::ptr_eq(written, current) => buf.push_str(&rhs),
+                        _ => {
+                            let mut buf = String::with_capacity((current.len() + rhs.len()) * 2);
+                            buf.push_str(current);
+                            buf.push_str(&rhs);
+                            let written = current.clone();
+                            // Entries above the stack belong to frames that have returned
+                            let len = self.stack.len();
+                            self.str_appends.retain(|&i, _| i < len);
+                            self.str_appends.insert(slot, (written, buf));
+                        }
+                    },
+                    // Captured locals live in a shared cell, so they are copied
+                    Some(IfaValue::Upvalue(cell)) => {
+                        let mut cell = cell
+                            .try_borrow_mut()
+                            .map_err(|_| IfaError::Runtime("Upvalue borrow failed".into()))?;
+                        let IfaValue::Str(current) = &*cell else {
+                            return Err(IfaError::TypeError {
+                                expected: "Str + Str".into(),
+                                got: format!("{} ++ Str", cell.type_name()),
+                            });
+                        };
+                        *cell = IfaValue::str(format!("{}{}", current, rhs));
+                    }
+                    Some(other) => {
+                        return Err(IfaError::TypeError {
+                            expected: "Str + Str".into(),
+                            got: format!("{} ++ Str", other.type_name()),
+                        });
+                    }
+                    None => return Err(IfaError::UndefinedVariable(format!("<local:{}>", idx))),
+                }
+            }
+
+            OpCode::StrBuilder => {
+                let count = self.read_u8(bytecode)? as usize;
+                if self.stack.len() < count {
+                    return Err(IfaError::StackUnderflow);
+                }
+                let parts = self.stack.split_off(self.stack.len() - count);
+                // Same coercion as the interpreter's interpolation: Display of each part
+                let capacity = parts
+                    .iter()
+                    .map(|p| if let IfaValue::Str(s) = p { s.len() } else { 8 })
+                    .sum();
+                let mut s = String::with_capacity(capacity);
+                for part in &parts {
+                    match part {
+                        IfaValue::Str(p) => s.push_str(p),
+                        other => {
+                            use std::fmt::Write;
+                            let _ = write!(s, "{}", other);
+                        }
+                    }
+                }
+                self.push(IfaValue::str(s))?;
+            }
+
             OpCode::Sub => {
                 let b = self.pop()?;
                 let a = self.pop()?;
                 match (a, b) {
                     (IfaValue::Int(ia), IfaValue::Int(ib)) => {
-                        // D6: Checked arithmetic — promote to Float on overflow
-                        match ia.checked_sub(ib) {
-                            Some(result) => self.push(IfaValue::int(result))?,
-                            None => self.push(IfaValue::float(ia as f64 - ib as f64))?,
-                        }
+                        // D6: Checked arithmetic — overflow is an error, as in the interpreter
+                        self.push(ia.checked_sub(ib).map(IfaValue::int).ok_or_else(overflow)?)?
                     }
                     (IfaValue::Float(fa), IfaValue::Float(fb)) => {
                         self.push(IfaValue::float(fa - fb))?
@@ -1210,11 +1390,8 @@ impl IfaVM {
                 let a = self.pop()?;
                 match (a, b) {
                     (IfaValue::Int(ia), IfaValue::Int(ib)) => {
-                        // D6: Checked arithmetic — promote to Float on overflow
-                        match ia.checked_mul(ib) {
-                            Some(result) => self.push(IfaValue::int(result))?,
-                            None => self.push(IfaValue::float(ia as f64 * ib as f64))?,
-                        }
+                        // D6: Checked arithmetic — overflow is an error, as in the interpreter
+                        self.push(ia.checked_mul(ib).map(IfaValue::int).ok_or_else(overflow)?)?
                     }
                     (IfaValue::Float(fa), IfaValue::Float(fb)) => {
                         self.push(IfaValue::float(fa * fb))?
@@ -1237,7 +1414,7 @@ impl IfaVM {
                             return Err(IfaError::DivisionByZero("Cannot divide by zero".into()));
                         }
                         // R1: Int/Int division truncates toward zero per spec §4.4
-                        self.push(IfaValue::int(ia / ib))?
+                        self.push(ia.checked_div(ib).map(IfaValue::int).ok_or_else(overflow)?)?
                     }
                     (IfaValue::Float(fa), IfaValue::Float(fb)) => {
                         // R2: Float 0.0/0.0 produces NaN per spec §4.5, never an error
@@ -1256,6 +1433,7 @@ impl IfaVM {
             OpCode::LoadLocal => {
                 let idx = self.read_u16(bytecode)? as usize;
                 let base = self.frames.last().map(|f| f.base_ptr).unwrap_or(0);
+                self.sync_str_append(base + idx);
 
                 let slot = self
                     .stack
@@ -1282,6 +1460,7 @@ impl IfaVM {
                 if base + idx >= self.stack.len() {
                     return Err(IfaError::UndefinedVariable(format!("<local:{}>", idx)));
                 }
+                self.str_appends.remove(&(base + idx));
                 match self.stack[base + idx].clone() {
                     IfaValue::Upvalue(cell) => {
                         *cell
@@ -1480,7 +1659,7 @@ impl IfaVM {
                     let future = self.spawn_task(func, args)?;
                     if let Some(frame) = self.frames.pop() {
                         if self.stack.len() > frame.base_ptr {
-                            self.stack.truncate(frame.base_ptr);
+                            self.truncate_stack(frame.base_ptr);
                         }
                         self.push(future)?;
                         self.ip = frame.return_addr;
@@ -1492,12 +1671,13 @@ impl IfaVM {
                 }
 
                 if let Some(frame) = self.frames.last_mut() {
-                    if self.stack.len() > frame.base_ptr {
-                        self.stack.truncate(frame.base_ptr);
-                    }
                     frame.local_count = 0;
                     frame.closure_env = env;
                     frame.async_return = async_return;
+                    let base_ptr = frame.base_ptr;
+                    if self.stack.len() > base_ptr {
+                        self.truncate_stack(base_ptr);
+                    }
 
                     for arg in args {
                         self.push(arg)?;
@@ -1527,7 +1707,7 @@ impl IfaVM {
                 if let Some(frame) = self.frames.pop() {
                     let return_value = self.pop().unwrap_or(IfaValue::null());
                     if self.stack.len() > frame.base_ptr {
-                        self.stack.truncate(frame.base_ptr);
+                        self.truncate_stack(frame.base_ptr);
                     }
                     if frame.async_return {
                         self.push(IfaValue::future_ready(return_value))?;
@@ -1694,14 +1874,24 @@ impl IfaVM {
                         }
                     }
                     IfaValue::List(mut l) => {
-                        if method_name == "fikun" || method_name == "append" || method_name == "push" {
-                           let val = args.get(0).ok_or_else(|| IfaError::ArityMismatch { expected: 1, got: args.len() })?;
-                           let vec = Arc::make_mut(&mut l);
-                           vec.push(val.clone());
-                           self.push(IfaValue::null())?;
-                           return Ok(());
+                        if method_name == "fikun"
+                            || method_name == "append"
+                            || method_name == "push"
+                        {
+                            let val = args.get(0).ok_or_else(|| IfaError::ArityMismatch {
+                                expected: 1,
+                                got: args.len(),
+                            })?;
+                            self.check_collection_size(l.len() + 1)?;
+                            let vec = Arc::make_mut(&mut l);
+                            vec.push(val.clone());
+                            self.push(IfaValue::null())?;
+                            return Ok(());
                         } else {
-                             return Err(IfaError::Custom(format!("List has no method '{}'", method_name)));
+                            return Err(IfaError::Custom(format!(
+                                "List has no method '{}'",
+                                method_name
+                            )));
                         }
                     }
                     obj => {
@@ -1760,7 +1950,7 @@ impl IfaVM {
                     }
                     IfaValue::Str(s) => {
                         let idx = match index {
-                            IfaValue::Int(i) => i as usize,
+                            IfaValue::Int(i) => i,
                             _ => {
                                 return Err(IfaError::TypeError {
                                     expected: "Int".into(),
@@ -1768,11 +1958,22 @@ impl IfaVM {
                                 });
                             }
                         };
-                        // Very inefficient char access, but functional
-                        if let Some(c) = s.chars().nth(idx) {
-                            self.push(IfaValue::str(c.to_string()))?;
-                        } else {
-                            return Err(IfaError::Runtime("Index out of bounds".into()));
+                        // Unicode scalar values, negative indices from the end
+                        self.push(crate::interpreter::handlers::char_at(&s, idx)?)?;
+                    }
+                    IfaValue::Range(r) => {
+                        let value = match index {
+                            IfaValue::Int(i) => usize::try_from(i).ok().and_then(|i| r.get(i)),
+                            _ => {
+                                return Err(IfaError::TypeError {
+                                    expected: "Int".into(),
+                                    got: index.type_name().into(),
+                                });
+                            }
+                        };
+                        match value {
+                            Some(v) => self.push(IfaValue::int(v))?,
+                            None => return Err(IfaError::Runtime("Index out of bounds".into())),
                         }
                     }
                     _ => {
@@ -1817,6 +2018,9 @@ impl IfaVM {
                                 });
                             }
                         };
+                        if !map_arc.contains_key(&k) {
+                            self.check_collection_size(map_arc.len() + 1)?;
+                        }
                         let map = std::sync::Arc::make_mut(map_arc);
                         map.insert(k, val);
                     }
@@ -1831,6 +2035,7 @@ impl IfaVM {
 
             OpCode::BuildList => {
                 let count = self.read_u8(bytecode)? as usize;
+                self.check_collection_size(count)?;
                 let mut items = Vec::with_capacity(count);
                 for _ in 0..count {
                     items.push(self.pop()?);
@@ -1841,6 +2046,7 @@ impl IfaVM {
 
             OpCode::BuildMap => {
                 let count = self.read_u8(bytecode)? as usize;
+                self.check_collection_size(count)?;
                 // IfaValue::map expects HashMap<String, IfaValue> for input constructor convenience
                 let mut map = std::collections::HashMap::with_capacity(count);
                 for _ in 0..count {
@@ -1962,7 +2168,7 @@ impl IfaVM {
                             .pop()
                             .unwrap_or_else(|| CallFrame::new(0, 0, None, false));
                         if self.stack.len() > frame.base_ptr {
-                            self.stack.truncate(frame.base_ptr);
+                            self.truncate_stack(frame.base_ptr);
                         }
                         if frame.async_return {
                             self.push(IfaValue::future_ready(return_value))?;
@@ -2261,6 +2467,7 @@ impl IfaVM {
                     IfaValue::Str(s) => self.push(IfaValue::int(s.chars().count() as i64))?,
                     IfaValue::List(l) => self.push(IfaValue::int(l.len() as i64))?,
                     IfaValue::Map(m) => self.push(IfaValue::int(m.len() as i64))?,
+                    IfaValue::Range(r) => self.push(IfaValue::int(r.len() as i64))?,
                     _ => {
                         return Err(IfaError::TypeError {
                             expected: "Collection".into(),
@@ -2270,6 +2477,35 @@ impl IfaVM {
                 }
             }
 
+            OpCode::MatchList => {
+                let count = self.read_u16(bytecode)? as usize;
+                let has_rest = self.read_u8(bytecode)? != 0;
+                let val = self.pop()?;
+                let matched = match &val {
+                    IfaValue::List(l) if has_rest => l.len() >= count,
+                    IfaValue::List(l) => l.len() == count,
+                    _ => false,
+                };
+                self.push(IfaValue::bool(matched))?;
+            }
+
+            OpCode::ListTail => {
+                let start = self.read_u16(bytecode)? as usize;
+                let val = self.pop()?;
+                match val {
+                    IfaValue::List(l) => {
+                        let tail = l.get(start..).unwrap_or_default().to_vec();
+                        self.push(IfaValue::list(tail))?;
+                    }
+                    _ => {
+                        return Err(IfaError::TypeError {
+                            expected: "List".into(),
+                            got: val.type_name().into(),
+                        });
+                    }
+                }
+            }
+
             OpCode::Not => {
                 let a = self.pop()?;
                 match a {
@@ -2354,7 +2590,9 @@ impl IfaVM {
             OpCode::Neg => {
                 let val = self.pop()?;
                 match val {
-                    IfaValue::Int(n) => self.push(IfaValue::int(-n))?,
+                    IfaValue::Int(n) => {
+                        self.push(n.checked_neg().map(IfaValue::int).ok_or_else(overflow)?)?
+                    }
                     IfaValue::Float(f) => self.push(IfaValue::float(-f))?,
                     _ => return Err(IfaError::Runtime("Invalid type for negation".into())),
                 }
@@ -2364,7 +2602,10 @@ impl IfaVM {
                 let base = self.pop()?;
                 match (base, exp) {
                     (IfaValue::Int(b), IfaValue::Int(e)) => {
-                        self.push(IfaValue::int(b.pow(e as u32)))?
+                        let e = u32::try_from(e).map_err(|_| {
+                            IfaError::Runtime("Integer power needs a non-negative exponent".into())
+                        })?;
+                        self.push(b.checked_pow(e).map(IfaValue::int).ok_or_else(overflow)?)?
                     }
                     (IfaValue::Float(b), IfaValue::Float(e)) => {
                         self.push(IfaValue::float(b.powf(e)))?
@@ -2376,7 +2617,12 @@ impl IfaVM {
                 let b = self.pop()?;
                 let a = self.pop()?;
                 match (a, b) {
-                    (IfaValue::Int(a), IfaValue::Int(b)) => self.push(IfaValue::int(a % b))?,
+                    (IfaValue::Int(_), IfaValue::Int(0)) => {
+                        return Err(IfaError::Runtime("Invalid operands for %".into()));
+                    }
+                    (IfaValue::Int(a), IfaValue::Int(b)) => {
+                        self.push(a.checked_rem(b).map(IfaValue::int).ok_or_else(overflow)?)?
+                    }
                     _ => return Err(IfaError::Runtime("Modulus requires integers".into())),
                 }
             }
@@ -2464,12 +2710,12 @@ impl IfaVM {
             OpCode::Eq => {
                 let b = self.pop()?;
                 let a = self.pop()?;
-                self.push(IfaValue::bool(a == b))?;
+                self.push(IfaValue::bool(a.numeric_eq(&b)))?;
             }
             OpCode::Ne => {
                 let b = self.pop()?;
                 let a = self.pop()?;
-                self.push(IfaValue::bool(a != b))?;
+                self.push(IfaValue::bool(!a.numeric_eq(&b)))?;
             }
 
             OpCode::Push => {
@@ -2535,36 +2781,6 @@ impl IfaVM {
     }
 }
 
-fn collect_exports_vm(program: &crate::ast::Program) -> Vec<String> {
-    let mut out = Vec::new();
-    for stmt in &program.statements {
-        match stmt {
-            crate::ast::Statement::VarDecl {
-                name,
-                visibility: crate::ast::Visibility::Public,
-                ..
-            } => out.push(name.clone()),
-            crate::ast::Statement::Const {
-                name,
-                visibility: crate::ast::Visibility::Public,
-                ..
-            } => out.push(name.clone()),
-            crate::ast::Statement::EseDef {
-                name,
-                visibility: crate::ast::Visibility::Public,
-                ..
-            } => out.push(name.clone()),
-            crate::ast::Statement::OduDef {
-                name,
-                visibility: crate::ast::Visibility::Public,
-                ..
-            } => out.push(name.clone()),
-            _ => {}
-        }
-    }
-    out
-}
-
 impl Default for IfaVM {
     fn default() -> Self {
         Self::new()
@@ -2644,6 +2860,7 @@ fn odu_domain_id(name: &str) -> Option<u8> {
         "ohun" => Some(27),
         "fidio" => Some(28),
         "sys" => Some(29),
+        "opon" => Some(30),
         _ => None,
     }
 }
@@ -152,6 +152,32 @@ fn conformance_vm_errors_are_catchable() {
     assert_eq!(got, expected_value);
 }
 
+#[test]
+fn conformance_vm_integer_overflow_matches_interpreter() {
+    for source in [
+        "pada 9223372036854775807 + 1;",
+        "pada (0 - 9223372036854775807) - 2;",
+        "pada 9223372036854775807 * 2;",
+    ] {
+        let program = parse(source).expect("parse failed");
+        let compiler = Compiler::new("conformance_vm_integer_overflow_matches_interpreter");
+        let bytecode = compiler.compile(&program).expect("compile failed");
+        let mut vm = IfaVM::new();
+        let err = vm.execute(&bytecode).expect_err(source);
+        assert!(
+            err.to_string().contains("integer overflow"),
+            "{source}: {err}"
+        );
+
+        let mut interp = ifa_core::Interpreter::new();
+        let err = interp.execute(&program).expect_err(source);
+        assert!(
+            err.to_string().contains("integer overflow"),
+            "{source}: {err}"
+        );
+    }
+}
+
 #[test]
 fn conformance_vm_catch_preserves_thrown_value() {
     let source = r#"
@@ -246,6 +272,148 @@ fn conformance_vm_closure_captures_outer() {
     assert_eq!(got, IfaValue::Int(8));
 }
 
+#[test]
+fn conformance_vm_byte_string_literal() {
+    let source = r#"
+    pada b"\x01\xFFok";
+    "#;
+
+    let program = parse(source).expect("parse failed");
+    let compiler = Compiler::new("conformance_vm_byte_string_literal");
+    let bytecode = compiler.compile(&program).expect("compile failed");
+    let mut vm = IfaVM::new();
+    let got = vm.execute(&bytecode).expect("vm failed");
+    assert_eq!(got, IfaValue::bytes(vec![0x01, 0xFF, b'o', b'k']));
+}
+
+#[test]
+fn conformance_vm_interpolation_matches_interpreter() {
+    let source = r#"
+    ayanmo n = 3;
+    ayanmo xs = [1, 2];
+    pada $"n={n} f={1.5} b={otito} xs={xs} nil={ofo}";
+    "#;
+
+    let program = parse(source).expect("parse failed");
+    let compiler = Compiler::new("conformance_vm_interpolation_matches_interpreter");
+    let bytecode = compiler.compile(&program).expect("compile failed");
+    let mut vm = IfaVM::new();
+    let got = vm.execute(&bytecode).expect("vm failed");
+
+    let mut interp = ifa_core::Interpreter::new();
+    let expected = interp.execute(&program).expect("interpreter failed");
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn conformance_vm_string_building_loop_is_fast() {
+    let source = r#"
+    ayanmo s = "";
+    ayanmo i = 0;
+    nigba i < 10000 {
+        s += $"{i % 10}";
+        i += 1;
+    }
+    pada s;
+    "#;
+
+    let program = parse(source).expect("parse failed");
+    let compiler = Compiler::new("conformance_vm_string_building_loop_is_fast");
+    let bytecode = compiler.compile(&program).expect("compile failed");
+    let mut vm = IfaVM::new();
+    let start = std::time::Instant::now();
+    let got = vm.execute(&bytecode).expect("vm failed");
+    let elapsed = start.elapsed();
+
+    let expected: String = (0..10_000)
+        .map(|i| char::from(b'0' + (i % 10) as u8))
+        .collect();
+    assert_eq!(got, IfaValue::str(expected));
+    assert!(
+        elapsed < std::time::Duration::from_secs(2),
+        "10k concatenations took {:?}",
+        elapsed
+    );
+}
+
+#[test]
+fn conformance_vm_local_string_append_is_in_place() {
+    let source = r#"
+    ese build(n) {
+        ayanmo s = "";
+        ayanmo i = 0;
+        nigba i < n {
+            s += $"{i % 10}";
+            i += 1;
+        }
+        pada s;
+    }
+    ese snapshots() {
+        ayanmo s = "a";
+        s += "b";
+        ayanmo seen = s;
+        s += "c";
+        pada [seen, s];
+    }
+    pada [build(100000), snapshots()];
+    "#;
+
+    let program = parse(source).expect("parse failed");
+    let compiler = Compiler::new("conformance_vm_local_string_append_is_in_place");
+    let bytecode = compiler.compile(&program).expect("compile failed");
+    assert!(
+        bytecode
+            .code
+            .contains(&(ifa_core::OpCode::AppendLocal as u8)),
+        "expected AppendLocal for `s += ...` on a local"
+    );
+
+    let mut vm = IfaVM::new();
+    let start = std::time::Instant::now();
+    let got = vm.execute(&bytecode).expect("vm failed");
+    let elapsed = start.elapsed();
+
+    let built: String = (0..100_000)
+        .map(|i| char::from(b'0' + (i % 10) as u8))
+        .collect();
+    let expected = IfaValue::list(vec![
+        IfaValue::str(built),
+        IfaValue::list(vec![IfaValue::str("ab"), IfaValue::str("abc")]),
+    ]);
+    assert_eq!(got, expected);
+    assert!(
+        elapsed < std::time::Duration::from_secs(2),
+        "100k local appends took {:?}",
+        elapsed
+    );
+}
+
+#[test]
+fn conformance_vm_local_string_append_does_not_outlive_its_frame() {
+    // The second call puts the same interned "a" in the same slot; it must
+    // not read the buffer the first call's `s += "b"` left behind
+    let source = r#"
+    ese h(flag) {
+        ayanmo s = "a";
+        ti flag {
+            s += "b";
+            pada "";
+        }
+        pada s;
+    }
+    ayanmo x = h(otito);
+    ayanmo y = h(iro);
+    pada y;
+    "#;
+
+    let program = parse(source).expect("parse failed");
+    let compiler = Compiler::new("conformance_vm_local_string_append_does_not_outlive_its_frame");
+    let bytecode = compiler.compile(&program).expect("compile failed");
+    let mut vm = IfaVM::new();
+    let got = vm.execute(&bytecode).expect("vm failed");
+    assert_eq!(got, IfaValue::str("a"));
+}
+
 #[test]
 fn conformance_vm_match_compiles_and_executes() {
     let source = r#"
@@ -271,6 +439,139 @@ fn conformance_vm_match_compiles_and_executes() {
     assert_eq!(got, expected_value);
 }
 
+#[test]
+fn conformance_vm_labeled_break_and_continue() {
+    let source = r#"
+    # expect: 56
+    ayanmo hits = 0;
+    ayanmo i = 0;
+    ami outer: nigba i < 3 {
+        ayanmo j = 0;
+        nigba j < 3 {
+            ayanmo k = j;
+            ti k == 1 {
+                fọ́ outer;
+            }
+            hits = hits + 1;
+            j = j + 1;
+        }
+        i = i + 1;
+    }
+
+    ayanmo pairs = 0;
+    ami rows: fun r ninu [1, 2, 3] {
+        fun c ninu [1, 2, 3] {
+            ti c > r {
+                tesiwaju rows;
+            }
+            pairs = pairs + 1;
+        }
+    }
+
+    ayanmo odd = 0;
+    fun n ninu [1, 2, 3, 4, 5] {
+        ti n % 2 == 0 {
+            tesiwaju;
+        }
+        ti n > 4 {
+            duro;
+        }
+        odd = odd + n;
+    }
+    pada hits * 10 + pairs + odd * 10 + i * 100;
+    "#;
+
+    let expect = parse_expectation(source).unwrap();
+    let expected_value = parse_expected_value(&expect);
+
+    let program = parse(source).expect("parse failed");
+    let compiler = Compiler::new("conformance_vm_labeled_break_and_continue");
+    let bytecode = compiler.compile(&program).expect("compile failed");
+    let mut vm = IfaVM::new();
+    let got = vm.execute(&bytecode).expect("vm failed");
+    assert_eq!(got, expected_value);
+}
+
+#[test]
+fn conformance_vm_break_to_unknown_label_fails_to_compile() {
+    let compile = |source: &str| {
+        Compiler::new("conformance_vm_break_to_unknown_label")
+            .compile(&parse(source).expect("parse failed"))
+            .expect_err("compile should fail")
+            .to_string()
+    };
+
+    let err = compile("ami outer: nigba otito { fọ́ inner; }");
+    assert!(err.contains("unknown loop label 'inner'"), "{err}");
+    let err = compile("ese f() { fọ́ outer; } ami outer: nigba otito { f(); }");
+    assert!(err.contains("unknown loop label 'outer'"), "{err}");
+    let err = compile("tesiwaju;");
+    assert!(err.contains("continue outside of a loop"), "{err}");
+}
+
+#[test]
+fn conformance_vm_match_guard_falls_through() {
+    let source = r#"
+    # expect: 35
+    ayanmo x = 5;
+    ayanmo out = 0;
+    yàn(x) {
+        1..9 ti x > 7 => { out = 10; }
+        5 ti x < 0 => { out = 20; }
+        _ ti x == 5 => { out = 35; }
+        _ => { out = 40; }
+    }
+    pada out;
+    "#;
+
+    let expect = parse_expectation(source).unwrap();
+    let expected_value = parse_expected_value(&expect);
+
+    let program = parse(source).expect("parse failed");
+    let compiler = Compiler::new("conformance_vm_match_guard_falls_through");
+    let bytecode = compiler.compile(&program).expect("compile failed");
+    let mut vm = IfaVM::new();
+    let got = vm.execute(&bytecode).expect("vm failed");
+    assert_eq!(got, expected_value);
+}
+
+/// Hands back the arguments a `CallOdu` received, in the order received.
+struct EchoArgs;
+
+impl ifa_core::native::OduRegistry for EchoArgs {
+    fn call(
+        &self,
+        _domain_id: u8,
+        _method_name: &str,
+        args: Vec<IfaValue>,
+        _ctx: &mut ifa_core::native::VmContext,
+    ) -> Result<IfaValue, IfaError> {
+        Ok(IfaValue::list(args))
+    }
+}
+
+#[test]
+fn conformance_vm_named_args_match_positional() {
+    let source = r#"
+    ayanmo a = Ika.substring(end: 4, text: "abcdef", start: 1);
+    ayanmo b = Ika.substring("abcdef", 1, 4);
+    pada [a, b];
+    "#;
+
+    let program = parse(source).expect("parse failed");
+    let compiler = Compiler::new("conformance_vm_named_args_match_positional");
+    let bytecode = compiler.compile(&program).expect("compile failed");
+    let mut vm = IfaVM::new().with_registry(Box::new(EchoArgs));
+    let got = vm.execute(&bytecode).expect("vm failed");
+
+    let positional = IfaValue::list(vec![
+        IfaValue::str("abcdef"),
+        IfaValue::int(1),
+        IfaValue::int(4),
+    ]);
+    assert_eq!(got, IfaValue::list(vec![positional.clone(), positional]));
+}
+
 #[test]
 fn conformance_vm_return_from_catch_still_runs_finally() {
     let source = r#"
@@ -474,3 +775,117 @@ fn conformance_vm_import_reloads_on_change() {
     let got2 = vm.execute(&bytecode).expect("vm failed");
     assert_eq!(got2, IfaValue::Int(2));
 }
+
+#[test]
+fn conformance_vm_import_uses_module_cache_until_source_changes() {
+    use std::fs;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    let root = std::env::temp_dir().join(format!(
+        "ifa_vm_import_cache_{}",
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis()
+    ));
+    fs::create_dir_all(&root).unwrap();
+
+    let module_path = root.join("a.ifa");
+    let main_path = root.join("main.ifa");
+    fs::write(&module_path, "fi ese foo() { pada \"one\"; }").unwrap();
+
+    let program = parse("iba a;\npada a.foo();").expect("parse failed");
+    let compiler = Compiler::new(main_path.to_string_lossy().as_ref());
+    let bytecode = compiler.compile(&program).expect("compile failed");
+    let run = || {
+        let mut vm = IfaVM::with_file(&main_path);
+        vm.set_module_cache(ifa_core::ModuleCache::new(root.join("cache")));
+        let got = vm.execute(&bytecode).expect("vm failed");
+        (got, vm.module_cache_hits())
+    };
+
+    let first = run();
+    let second = run();
+    fs::write(&module_path, "fi ese foo() { pada \"two\"; }").unwrap();
+    let edited = run();
+    fs::remove_dir_all(&root).ok();
+
+    assert_eq!(first, (IfaValue::str("one"), 0));
+    assert_eq!(second, (IfaValue::str("one"), 1));
+    assert_eq!(edited, (IfaValue::str("two"), 0));
+}
+
+#[test]
+fn conformance_vm_enforces_collection_limit() {
+    let run = |source: &str, limit: Option<usize>| {
+        let program = parse(source).expect("parse failed");
+        let bytecode = Compiler::new("limits")
+            .compile(&program)
+            .expect("compile failed");
+        let mut vm = IfaVM::new();
+        if let Some(limit) = limit {
+            vm.set_collection_limit(limit);
+        }
+        vm.execute(&bytecode)
+    };
+
+    assert_eq!(
+        run("pada [1, 2, 3];", Some(3)).expect("vm failed"),
+        IfaValue::list(vec![IfaValue::Int(1), IfaValue::Int(2), IfaValue::Int(3)])
+    );
+    for source in [
+        "pada [1, 2, 3, 4];",
+        r#"pada {"a": 1, "b": 2, "c": 3, "d": 4};"#,
+        "ayanmo xs = [1, 2, 3]; xs.push(4); pada xs;",
+    ] {
+        let err = run(source, Some(3)).unwrap_err();
+        assert!(
+            err.to_string().contains("collection size limit exceeded"),
+            "{}: {}",
+            source,
+            err
+        );
+    }
+}
+
+#[test]
+fn conformance_vm_match_list_patterns() {
+    let source = r#"
+    ese describe(l) {
+        match (l) {
+            [1, 2] => { pada "one-two"; }
+            [[a, b], ...rest] => { pada [a + b, rest]; }
+            [head, ...tail] ti head > 5 => { pada "big"; }
+            [head, ...tail] => { pada [head, tail]; }
+            _ => { pada "other"; }
+        }
+    }
+    ayanmo r = "none";
+    match ([1, 2, 3]) {
+        [x, y] => { r = "pair"; }
+        [x, y, z] => { r = z; }
+    }
+    pada [describe([1, 2]), describe([9, 1]), describe([[3, 4], 5]), describe([1, 2, 3]), describe([]), describe(7), r];
+    "#;
+
+    let program = parse(source).expect("parse failed");
+    let bytecode = Compiler::new("conformance_vm_match_list_patterns")
+        .compile(&program)
+        .expect("compile failed");
+    let mut vm = IfaVM::new();
+    let got = vm.execute(&bytecode).expect("vm failed");
+
+    let ints = |xs: &[i64]| IfaValue::list(xs.iter().map(|&x| IfaValue::Int(x)).collect());
+    assert_eq!(
+        got,
+        IfaValue::list(vec![
+            IfaValue::str("one-two"),
+            IfaValue::str("big"),
+            IfaValue::list(vec![IfaValue::Int(7), ints(&[5])]),
+            IfaValue::list(vec![IfaValue::Int(1), ints(&[2, 3])]),
+            IfaValue::str("other"),
+            IfaValue::str("other"),
+            IfaValue::Int(3),
+        ])
+    );
+}
@@ -3,7 +3,7 @@
 //! Tests for Odù domain handlers using interpreter-level testing.
 //! Uses ayanmo (variable declaration) statements to capture results.
 
-use ifa_core::interpreter::Environment;
+use ifa_core::interpreter::{Environment, OutputSink};
 use ifa_core::{IfaValue, Interpreter, parser::parse};
 
 /// Helper to run Ifá code and get environment value
@@ -34,6 +34,13 @@ fn test_osa_sum() {
     assert_eq!(result, IfaValue::Int(15));
 }
 
+#[test]
+fn test_osa_sum_mixed_list_matches_general_path() {
+    // Floats truncate and non-numbers count as zero, packed or not
+    let result = run_and_get(r#"ayanmo s = Osa.sum([1, 2.9, "x", 4]);"#, "s").unwrap();
+    assert_eq!(result, IfaValue::Int(7));
+}
+
 #[test]
 fn test_osa_product() {
     let result = run_and_get("ayanmo p = Osa.product([2, 3, 4]);", "p").unwrap();
@@ -72,6 +79,353 @@ fn test_osa_sort() {
     }
 }
 
+// =============================================================================
+// Ọ̀bàrà / Òtúúrúpọ̀n (Math) Handler Tests
+// =============================================================================
+
+#[test]
+fn test_obara_wrapping_add_wraps() {
+    let result = run_and_get(
+        "ayanmo w = Obara.wrapping_add(9223372036854775807, 1);",
+        "w",
+    )
+    .unwrap();
+    assert_eq!(result, IfaValue::Int(i64::MIN));
+}
+
+#[test]
+fn test_obara_saturating_and_checked_add() {
+    let code = r#"
+        ayanmo s = Obara.saturating_add(9223372036854775807, 1);
+        ayanmo c = Obara.checked_add(9223372036854775807, 1);
+    "#;
+    assert_eq!(run_and_get(code, "s").unwrap(), IfaValue::Int(i64::MAX));
+    assert_eq!(run_and_get(code, "c").unwrap(), IfaValue::Null);
+}
+
+#[test]
+fn test_oturupon_wrapping_sub_wraps() {
+    let result = run_and_get(
+        "ayanmo w = Oturupon.wrapping_sub(0 - 9223372036854775807, 2);",
+        "w",
+    )
+    .unwrap();
+    assert_eq!(result, IfaValue::Int(i64::MAX));
+}
+
+#[test]
+fn test_add_overflow_is_an_error() {
+    let err = run_and_get("ayanmo x = 9223372036854775807 + 1;", "x").unwrap_err();
+    assert!(err.contains("integer overflow"), "got: {}", err);
+    let err = run_and_get("ayanmo x = Obara.add(9223372036854775807, 1);", "x").unwrap_err();
+    assert!(err.contains("integer overflow"), "got: {}", err);
+}
+
+#[test]
+fn test_obara_sqrt_and_pow() {
+    let get = |code: &str| run_and_get(&format!("ayanmo r = {};", code), "r");
+    assert_eq!(get("Obara.sqrt(16)").unwrap(), IfaValue::Float(4.0));
+    assert_eq!(get("Oturupon.sqrt(2.25)").unwrap(), IfaValue::Float(1.5));
+    for code in ["Obara.sqrt(0 - 1)", "Oturupon.sqrt(0 - 1)"] {
+        let err = get(code).unwrap_err();
+        assert!(err.contains("sqrt of negative number"), "got: {}", err);
+    }
+
+    assert_eq!(get("Obara.pow(2, 10)").unwrap(), IfaValue::Int(1024));
+    assert_eq!(get("Obara.pow(2, 0 - 1)").unwrap(), IfaValue::Float(0.5));
+    assert_eq!(get("Obara.pow(4, 0.5)").unwrap(), IfaValue::Float(2.0));
+    let err = get("Obara.pow(10, 40)").unwrap_err();
+    assert!(err.contains("integer overflow"), "got: {}", err);
+    let err = get(r#"Obara.pow("2", 3)"#).unwrap_err();
+    assert!(err.contains("number for pow"), "got: {}", err);
+}
+
+#[test]
+fn test_obara_abs_min_max() {
+    let get = |code: &str| run_and_get(&format!("ayanmo r = {};", code), "r");
+    assert_eq!(get("Obara.abs(0 - 7)").unwrap(), IfaValue::Int(7));
+    assert_eq!(get("Obara.abs(0 - 2.5)").unwrap(), IfaValue::Float(2.5));
+
+    assert_eq!(get("Obara.min([4, 2.5, 9])").unwrap(), IfaValue::Float(2.5));
+    assert_eq!(get("Obara.max([4, 2.5, 9])").unwrap(), IfaValue::Int(9));
+    assert_eq!(get("Obara.max(3, 8, 1)").unwrap(), IfaValue::Int(8));
+    let err = get("Obara.min([])").unwrap_err();
+    assert!(err.contains("at least one number"), "got: {}", err);
+    let err = get(r#"Obara.max([1, "x"])"#).unwrap_err();
+    assert!(err.contains("number for max"), "got: {}", err);
+}
+
+#[test]
+fn test_obara_trig() {
+    let get = |code: &str| match run_and_get(&format!("ayanmo r = {};", code), "r").unwrap() {
+        IfaValue::Float(f) => f,
+        other => panic!("expected Float, got {:?}", other),
+    };
+    assert_eq!(get("Obara.sin(0)"), 0.0);
+    assert_eq!(get("Obara.cos(0)"), 1.0);
+    assert!((get("Obara.tan(0.7853981633974483)") - 1.0).abs() < 1e-12);
+    assert!((get("Obara.sin(1.5707963267948966)") - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_int_division_and_explicit_conversions() {
+    let get = |code: &str| run_and_get(&format!("ayanmo r = {};", code), "r");
+    // Int / Int truncates; convert explicitly for a Float result
+    assert_eq!(get("7 / 2").unwrap(), IfaValue::Int(3));
+    assert_eq!(get("Oturupon.div(7, 2)").unwrap(), IfaValue::Int(3));
+    assert_eq!(get("Obara.to_float(7) / 2").unwrap(), IfaValue::Float(3.5));
+    assert_eq!(get("Oturupon.fdiv(7, 2)").unwrap(), IfaValue::Float(3.5));
+    // Like div, a zero divisor is an error rather than inf/NaN
+    let err = get("Oturupon.fdiv(7, 0)").unwrap_err();
+    assert!(err.contains("Division by zero"), "got: {}", err);
+    let err = get("Oturupon.fdiv(0, 0.0)").unwrap_err();
+    assert!(err.contains("Division by zero"), "got: {}", err);
+
+    assert_eq!(get("Obara.to_float(2.5)").unwrap(), IfaValue::Float(2.5));
+    assert_eq!(get("Oturupon.to_int(3.99)").unwrap(), IfaValue::Int(3));
+    assert_eq!(get("Oturupon.to_int(0 - 3.99)").unwrap(), IfaValue::Int(-3));
+    assert_eq!(get("Oturupon.to_int(42)").unwrap(), IfaValue::Int(42));
+
+    let err = get("Oturupon.to_int(Obara.pow(10.0, 300))").unwrap_err();
+    assert!(err.contains("does not fit in an Int"), "got: {}", err);
+    let err = get(r#"Obara.to_float("7")"#).unwrap_err();
+    assert!(err.contains("number for to_float"), "got: {}", err);
+}
+
+#[test]
+fn test_oturupon_try_div_returns_result_values() {
+    let code = r#"
+        ayanmo ok = Oturupon.try_div(10, 2);
+        ayanmo bad = Oturupon.try_div(10, 0);
+        ayanmo after = "still running";
+    "#;
+    let program = parse(code).unwrap();
+    let mut interp = Interpreter::new();
+    interp.execute(&program).unwrap();
+
+    let get = |name: &str| Environment::get(&interp.env, name).unwrap();
+    assert_eq!(get("ok"), IfaValue::ok(IfaValue::Int(5)));
+    assert_eq!(get("bad"), IfaValue::err(IfaValue::str("Division by zero")));
+    assert_eq!(get("after"), IfaValue::str("still running"));
+
+    // div itself still raises
+    let err = run_and_get("ayanmo r = Oturupon.div(10, 0);", "r").unwrap_err();
+    assert!(err.contains("Division by zero"), "got: {}", err);
+}
+
+// =============================================================================
+// Ìká (Strings) Handler Tests
+// =============================================================================
+
+#[test]
+fn test_ika_char_at_counts_scalar_values() {
+    let result = run_and_get(r#"ayanmo c = Ika.char_at("Ifá dá", 2);"#, "c").unwrap();
+    assert_eq!(result, IfaValue::str("á"));
+    let result = run_and_get(r#"ayanmo c = Ika.char_at("Ifá dá", -1);"#, "c").unwrap();
+    assert_eq!(result, IfaValue::str("á"));
+
+    let err = run_and_get(r#"ayanmo c = Ika.char_at("Ifá", 3);"#, "c").unwrap_err();
+    assert!(err.contains("out of range"), "got: {}", err);
+}
+
+#[test]
+fn test_ika_substring_across_multibyte_chars() {
+    // "ẹ" and "à" are multi-byte in UTF-8
+    let result = run_and_get(r#"ayanmo s = Ika.substring("Ẹ̀kọ́ àti", 5, 8);"#, "s").unwrap();
+    assert_eq!(result, IfaValue::str(" àt"));
+    let result = run_and_get(r#"ayanmo s = Ika.substring("Ọrúnmìlà", -3);"#, "s").unwrap();
+    assert_eq!(result, IfaValue::str("ìlà"));
+
+    let err = run_and_get(r#"ayanmo s = Ika.substring("abc", 2, 1);"#, "s").unwrap_err();
+    assert!(err.contains("after end"), "got: {}", err);
+    let err = run_and_get(r#"ayanmo s = Ika.substring("abc", 0, 4);"#, "s").unwrap_err();
+    assert!(err.contains("out of range"), "got: {}", err);
+}
+
+#[test]
+fn test_ika_pad_and_repeat() {
+    let result = run_and_get(r#"ayanmo s = Ika.pad_left("42", 5, "0");"#, "s").unwrap();
+    assert_eq!(result, IfaValue::str("00042"));
+    let result = run_and_get(r#"ayanmo s = Ika.pad_right("ab", 5, ".");"#, "s").unwrap();
+    assert_eq!(result, IfaValue::str("ab..."));
+    // Width counts characters, not bytes
+    let result = run_and_get(r#"ayanmo s = Ika.pad_left("Ifá", 5, "*");"#, "s").unwrap();
+    assert_eq!(result, IfaValue::str("**Ifá"));
+    let result = run_and_get(r#"ayanmo s = Ika.pad_right("toolong", 3, " ");"#, "s").unwrap();
+    assert_eq!(result, IfaValue::str("toolong"));
+
+    let result = run_and_get(r#"ayanmo s = Ika.repeat("ab", 3);"#, "s").unwrap();
+    assert_eq!(result, IfaValue::str("ababab"));
+    let err = run_and_get(r#"ayanmo s = Ika.repeat("ab", -1);"#, "s").unwrap_err();
+    assert!(err.contains("negative"), "got: {}", err);
+}
+
+#[test]
+fn test_ika_repeat_and_pad_check_the_string_limit_before_allocating() {
+    // 2^62 copies of "ab" would need 2^63 bytes; refused, not attempted
+    let err = run_and_get(r#"ayanmo s = Ika.repeat("ab", 4611686018427387904);"#, "s").unwrap_err();
+    assert!(err.contains("string length limit exceeded"), "got: {}", err);
+
+    let mut interp = Interpreter::new();
+    interp.set_string_limit(10);
+    let mut run = |code: &str| {
+        interp
+            .execute(&parse(code).unwrap())
+            .map_err(|e| e.to_string())
+    };
+    run(r#"ayanmo s = Ika.repeat("ab", 5); ayanmo p = Ika.pad_left("é", 9, "*");"#).unwrap();
+    for code in [
+        r#"Ika.repeat("ab", 6);"#,
+        r#"Ika.pad_left("x", 11);"#,
+        r#"Ika.pad_right("x", 6, "é");"#,
+    ] {
+        let err = run(code).unwrap_err();
+        assert!(
+            err.contains("string length limit exceeded"),
+            "{}: {}",
+            code,
+            err
+        );
+    }
+}
+
+#[test]
+fn test_ika_chars_bytes_lines() {
+    // Real CR/LF characters inside the string literal
+    let code = "
+        ayanmo c = Ika.chars(\"Ọ̀ṣun\");
+        ayanmo b = Ika.bytes(\"Ọ̀ṣun\");
+        ayanmo l = Ika.lines(\"one\r\ntwo\nthree\r\n\");
+    ";
+    let len = |var: &str| match run_and_get(code, var).unwrap() {
+        IfaValue::List(items) => items.len(),
+        other => panic!("expected List, got {:?}", other),
+    };
+    // The combining grave accent is its own char; diacritics are multi-byte
+    assert_eq!(len("c"), 5);
+    assert_eq!(len("b"), 10);
+
+    let chars = run_and_get(code, "c").unwrap();
+    assert_eq!(
+        chars,
+        IfaValue::list(
+            ["Ọ", "\u{300}", "ṣ", "u", "n"]
+                .iter()
+                .map(|s| IfaValue::str(*s))
+                .collect()
+        )
+    );
+    let lines = run_and_get(code, "l").unwrap();
+    assert_eq!(
+        lines,
+        IfaValue::list(vec![
+            IfaValue::str("one"),
+            IfaValue::str("two"),
+            IfaValue::str("three")
+        ])
+    );
+}
+
+#[test]
+fn test_ika_slugify_strips_diacritics_and_punctuation() {
+    let code = r#"ayanmo s = Ika.slugify("  Ọ̀ṣun & Ẹ̀kọ́: Ifá's 16 Odù!  ");"#;
+    assert_eq!(
+        run_and_get(code, "s").unwrap(),
+        IfaValue::str("osun-eko-ifa-s-16-odu")
+    );
+    let code = r#"ayanmo s = Ika.slugify("--- ");"#;
+    assert_eq!(run_and_get(code, "s").unwrap(), IfaValue::str(""));
+}
+
+#[test]
+fn test_ika_normalize_spaces() {
+    // Real tab and newline characters inside the string literal
+    let code = "ayanmo s = Ika.normalize_spaces(\"  a\t\tb   c\n d  \");";
+    assert_eq!(run_and_get(code, "s").unwrap(), IfaValue::str("a b c d"));
+}
+
+#[test]
+fn test_ika_levenshtein_counts_chars() {
+    let code = r#"
+        ayanmo d = Ika.levenshtein("kitten", "sitting");
+        ayanmo same = Ika.levenshtein("ọ̀ṣun", "ọ̀ṣun");
+        ayanmo accent = Ika.levenshtein("ọ̀ṣun", "oṣun");
+    "#;
+    assert_eq!(run_and_get(code, "d").unwrap(), IfaValue::int(3));
+    assert_eq!(run_and_get(code, "same").unwrap(), IfaValue::int(0));
+    // `ọ̀` is two chars (ọ + combining grave), both replaced by one `o`
+    assert_eq!(run_and_get(code, "accent").unwrap(), IfaValue::int(2));
+}
+
+#[test]
+fn test_ika_closest_picks_nearest_candidate() {
+    let code = r#"
+        ayanmo c = Ika.closest("lenght", ["width", "length", "height"]);
+        ayanmo none = Ika.closest("x", []);
+    "#;
+    assert_eq!(run_and_get(code, "c").unwrap(), IfaValue::str("length"));
+    assert_eq!(run_and_get(code, "none").unwrap(), IfaValue::Null);
+}
+
+#[test]
+fn test_ika_count_and_split_limit() {
+    let code = r#"
+        ayanmo runs = Ika.count("aaaa", "aa");
+        ayanmo words = Ika.count("ifá ifá ifá", "ifá");
+        ayanmo parts = Ika.split_limit("a:b:c:d", ":", 2);
+        ayanmo all = Ika.split_limit("a:b", ":", 5);
+    "#;
+    // Non-overlapping: "aaaa" holds two "aa", not three
+    assert_eq!(run_and_get(code, "runs").unwrap(), IfaValue::int(2));
+    assert_eq!(run_and_get(code, "words").unwrap(), IfaValue::int(3));
+    assert_eq!(
+        run_and_get(code, "parts").unwrap(),
+        IfaValue::list(vec![IfaValue::str("a"), IfaValue::str("b:c:d")])
+    );
+    assert_eq!(
+        run_and_get(code, "all").unwrap(),
+        IfaValue::list(vec![IfaValue::str("a"), IfaValue::str("b")])
+    );
+
+    let err = run_and_get(r#"ayanmo p = Ika.split_limit("a:b", "", 2);"#, "p").unwrap_err();
+    assert!(err.contains("delimiter cannot be empty"), "got: {}", err);
+    let err = run_and_get(r#"ayanmo p = Ika.split_limit("a:b", ":", 0);"#, "p").unwrap_err();
+    assert!(err.contains("at least 1"), "got: {}", err);
+}
+
+#[test]
+fn test_ika_compiled_template_renders_many_times() {
+    let code = r#"
+        ayanmo t = Ika.compile_template("{} scored {1} ({{{0}}})");
+        ayanmo a = Ika.render(t, ["Ada", 10]);
+        ayanmo b = Ika.render(t, ["Bayo", 7.5]);
+        ayanmo once = Ika.format("{}-{}", [1, 2]);
+    "#;
+    assert_eq!(
+        run_and_get(code, "a").unwrap(),
+        IfaValue::str("Ada scored 10 ({Ada})")
+    );
+    assert_eq!(
+        run_and_get(code, "b").unwrap(),
+        IfaValue::str("Bayo scored 7.5 ({Bayo})")
+    );
+    assert_eq!(run_and_get(code, "once").unwrap(), IfaValue::str("1-2"));
+
+    // Malformed templates are rejected by compile_template itself
+    for bad in ["unclosed {", "stray }", "{name}"] {
+        let code = format!(r#"ayanmo t = Ika.compile_template("{}");"#, bad);
+        let err = run_and_get(&code, "t").unwrap_err();
+        assert!(err.contains("Invalid template"), "{}: {}", bad, err);
+    }
+
+    let err = run_and_get(
+        r#"ayanmo t = Ika.compile_template("{2}"); ayanmo s = Ika.render(t, [1]);"#,
+        "s",
+    )
+    .unwrap_err();
+    assert!(err.contains("needs value 2"), "got: {}", err);
+}
+
 // =============================================================================
 // Ìrẹtẹ̀ (Crypto) Handler Tests
 // =============================================================================
@@ -142,6 +496,1452 @@ fn test_irete_random_bytes() {
     }
 }
 
+#[test]
+fn test_irete_secure_equal_strings_and_bytes() {
+    let code = r#"
+        ayanmo same = Irete.secure_equal("s3cret", "s3cret");
+        ayanmo differ = Irete.secure_equal("s3cret", "s3creT");
+        ayanmo shorter = Irete.secure_equal("s3cret", "s3c");
+        ayanmo raw = Crypto.constant_time_compare(b"\x01\xFF", b"\x01\xFF");
+        ayanmo raw_differ = Crypto.constant_time_compare(b"\x01\xFF", b"\x01\xFE");
+        ayanmo mixed = Irete.secure_equal(b"abc", "abc");
+    "#;
+    for (var, expected) in [
+        ("same", true),
+        ("differ", false),
+        ("shorter", false),
+        ("raw", true),
+        ("raw_differ", false),
+        ("mixed", true),
+    ] {
+        assert_eq!(
+            run_and_get(code, var).unwrap(),
+            IfaValue::Bool(expected),
+            "{}",
+            var
+        );
+    }
+    assert!(run_and_get("ayanmo x = Irete.secure_equal(1, 1);", "x").is_err());
+}
+
+// =============================================================================
+// Ọ̀gbè (System) Handler Tests
+// =============================================================================
+
+#[test]
+fn test_ogbe_path_helpers() {
+    let joined = run_and_get(r#"ayanmo p = Ogbe.path_join("data", "in.txt");"#, "p").unwrap();
+    let expected = std::path::Path::new("data").join("in.txt");
+    assert_eq!(joined, IfaValue::str(expected.to_string_lossy()));
+
+    let base = run_and_get(r#"ayanmo b = Ogbe.basename("logs/app.log");"#, "b").unwrap();
+    assert_eq!(base, IfaValue::str("app.log"));
+    let dir = run_and_get(r#"ayanmo d = Ogbe.dirname("logs/app.log");"#, "d").unwrap();
+    assert_eq!(dir, IfaValue::str("logs"));
+    let dir = run_and_get(r#"ayanmo d = Ogbe.dirname("app.log");"#, "d").unwrap();
+    assert_eq!(dir, IfaValue::str(""));
+}
+
+#[test]
+fn test_ogbe_cwd_chdir_round_trip() {
+    use ifa_core::interpreter::{CapabilitySet, Ofun};
+
+    let original = std::env::current_dir().unwrap();
+    let target = std::env::temp_dir().canonicalize().unwrap();
+
+    let err = run_and_get(
+        &format!(r#"ayanmo ok = Ogbe.chdir("{}");"#, target.display()),
+        "ok",
+    )
+    .unwrap_err();
+    assert!(err.contains("Capability denied"), "got: {}", err);
+
+    let code = format!(
+        r#"
+        ayanmo before = Ogbe.cwd();
+        Ogbe.chdir("{}");
+        ayanmo inside = Ogbe.cwd();
+        Ogbe.chdir(before);
+        ayanmo after = Ogbe.cwd();
+        "#,
+        target.display()
+    );
+    let mut interp = Interpreter::new();
+    let mut caps = CapabilitySet::new();
+    caps.grant(Ofun::ReadFiles {
+        root: target.clone(),
+    });
+    caps.grant(Ofun::ReadFiles {
+        root: original.clone(),
+    });
+    interp.set_capabilities(caps);
+    interp.execute(&parse(&code).unwrap()).unwrap();
+
+    assert_eq!(
+        Environment::get(&interp.env, "inside").unwrap(),
+        IfaValue::str(target.to_string_lossy())
+    );
+    assert_eq!(
+        Environment::get(&interp.env, "after").unwrap(),
+        IfaValue::str(original.to_string_lossy())
+    );
+    assert_eq!(std::env::current_dir().unwrap(), original);
+}
+
+#[test]
+fn test_ogbe_chdir_resolves_relative_paths_without_moving_the_process() {
+    use ifa_core::interpreter::{CapabilitySet, Ofun};
+
+    let original = std::env::current_dir().unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir(dir.path().join("data")).unwrap();
+    std::fs::write(dir.path().join("data").join("in.txt"), "ase").unwrap();
+
+    let code = format!(
+        r#"
+        Ogbe.chdir("{}");
+        Ogbe.chdir("data");
+        ayanmo text = Odi.read("in.txt");
+        "#,
+        dir.path().display()
+    );
+    let mut interp = Interpreter::new();
+    let mut caps = CapabilitySet::new();
+    caps.grant(Ofun::ReadFiles {
+        root: dir.path().to_path_buf(),
+    });
+    interp.set_capabilities(caps);
+    interp.execute(&parse(&code).unwrap()).unwrap();
+
+    assert_eq!(
+        Environment::get(&interp.env, "text").unwrap(),
+        IfaValue::str("ase")
+    );
+    assert_eq!(std::env::current_dir().unwrap(), original);
+}
+
+#[test]
+fn test_ogbe_temp_dir_is_granted_and_cleaned_up() {
+    let code = r#"
+        ayanmo dir = Ogbe.temp_dir();
+        ayanmo same = Ogbe.temp_dir() == dir;
+        ayanmo path = Ogbe.path_join(dir, "scratch.txt");
+        Odi.write(path, "ase");
+        ayanmo text = Odi.read(path);
+    "#;
+    // No file capabilities are granted
+    let mut interp = Interpreter::new();
+    interp.execute(&parse(code).unwrap()).unwrap();
+
+    assert_eq!(
+        Environment::get(&interp.env, "same").unwrap(),
+        IfaValue::bool(true)
+    );
+    assert_eq!(
+        Environment::get(&interp.env, "text").unwrap(),
+        IfaValue::str("ase")
+    );
+    let dir = match Environment::get(&interp.env, "dir").unwrap() {
+        IfaValue::Str(dir) => std::path::PathBuf::from(&*dir),
+        other => panic!("expected a path, got {:?}", other),
+    };
+    assert!(dir.join("scratch.txt").exists());
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(&dir).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o700, "scratch dir mode {:o}", mode);
+    }
+
+    drop(interp);
+    assert!(!dir.exists());
+}
+
+#[test]
+fn test_ogbe_system_info() {
+    let code = r#"
+        ayanmo arch = Ogbe.arch();
+        ayanmo os = Ogbe.platform();
+        ayanmo cpus = Ogbe.cpu_count();
+        ayanmo pid = Ogbe.pid();
+    "#;
+    assert_eq!(
+        run_and_get(code, "arch").unwrap(),
+        IfaValue::str(std::env::consts::ARCH)
+    );
+    assert_eq!(
+        run_and_get(code, "os").unwrap(),
+        IfaValue::str(std::env::consts::OS)
+    );
+    match run_and_get(code, "cpus").unwrap() {
+        IfaValue::Int(n) => assert!(n > 0, "cpu_count should be positive, got {}", n),
+        other => panic!("expected Int, got {:?}", other),
+    }
+    assert_eq!(
+        run_and_get(code, "pid").unwrap(),
+        IfaValue::int(std::process::id() as i64)
+    );
+}
+
+#[cfg(feature = "sysinfo")]
+#[test]
+fn test_ogbe_hostname_needs_environment_capability() {
+    use ifa_core::interpreter::{CapabilitySet, Ofun};
+
+    let err = run_and_get("ayanmo h = Ogbe.hostname();", "h").unwrap_err();
+    assert!(
+        err.contains("Capability denied: Environment"),
+        "got: {}",
+        err
+    );
+
+    let mut interp = Interpreter::new();
+    let mut caps = CapabilitySet::new();
+    caps.grant(Ofun::Environment {
+        keys: vec!["HOSTNAME".into()],
+    });
+    interp.set_capabilities(caps);
+    interp
+        .execute(&parse("ayanmo h = Ogbe.hostname();").unwrap())
+        .unwrap();
+    match Environment::get(&interp.env, "h").unwrap() {
+        IfaValue::Str(name) => assert!(!name.is_empty()),
+        other => panic!("expected a hostname, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_ogbe_env_and_env_all_only_see_granted_keys() {
+    use ifa_core::interpreter::{CapabilitySet, Ofun};
+
+    let err = run_and_get(r#"ayanmo v = Ogbe.env("CARGO_MANIFEST_DIR");"#, "v").unwrap_err();
+    assert!(
+        err.contains("Capability denied: Environment"),
+        "got: {}",
+        err
+    );
+    let none = run_and_get("ayanmo all = Ogbe.env_all();", "all").unwrap();
+    assert_eq!(none, IfaValue::map(Default::default()));
+
+    let code = r#"
+        ayanmo v = Ogbe.env("CARGO_MANIFEST_DIR");
+        ayanmo all = Ogbe.ayika_gbogbo();
+        ayanmo n = Ogbe.len(all);
+    "#;
+    let mut interp = Interpreter::new();
+    let mut caps = CapabilitySet::new();
+    caps.grant(Ofun::Environment {
+        keys: vec!["CARGO_MANIFEST_DIR".into()],
+    });
+    interp.set_capabilities(caps);
+    interp.execute(&parse(code).unwrap()).unwrap();
+
+    let dir = IfaValue::str(env!("CARGO_MANIFEST_DIR"));
+    assert_eq!(Environment::get(&interp.env, "v").unwrap(), dir);
+    assert_eq!(
+        Environment::get(&interp.env, "n").unwrap(),
+        IfaValue::Int(1)
+    );
+}
+
+// =============================================================================
+// Òdí (Files) Handler Tests
+// =============================================================================
+
+/// Write a temp file and return an interpreter allowed to read it.
+fn odi_fixture(name: &str, content: &str) -> (std::path::PathBuf, Interpreter) {
+    use ifa_core::interpreter::{CapabilitySet, Ofun};
+
+    let path = std::env::temp_dir().join(format!("ifa_odi_{}_{}.txt", name, std::process::id()));
+    std::fs::write(&path, content).unwrap();
+    let mut interp = Interpreter::new();
+    let mut caps = CapabilitySet::new();
+    caps.grant(Ofun::ReadFiles { root: path.clone() });
+    interp.set_capabilities(caps);
+    (path, interp)
+}
+
+#[test]
+fn test_odi_read_lines() {
+    let (path, mut interp) = odi_fixture("read_lines", "alpha\nbeta\ngamma\n");
+    let code = format!(r#"ayanmo l = Odi.read_lines("{}");"#, path.display());
+    interp.execute(&parse(&code).unwrap()).unwrap();
+    std::fs::remove_file(&path).ok();
+
+    assert_eq!(
+        Environment::get(&interp.env, "l").unwrap(),
+        IfaValue::list(vec![
+            IfaValue::str("alpha"),
+            IfaValue::str("beta"),
+            IfaValue::str("gamma"),
+        ])
+    );
+}
+
+#[test]
+fn test_odi_streaming_handle_reaches_eof() {
+    let (path, mut interp) = odi_fixture("stream", "one\r\ntwo\n");
+    let code = format!(
+        r#"
+        ayanmo h = Odi.open("{}");
+        ayanmo a = Odi.next_line(h);
+        ayanmo b = Odi.next_line(h);
+        ayanmo c = Odi.next_line(h);
+        "#,
+        path.display()
+    );
+    interp.execute(&parse(&code).unwrap()).unwrap();
+    std::fs::remove_file(&path).ok();
+
+    assert_eq!(
+        Environment::get(&interp.env, "a").unwrap(),
+        IfaValue::str("one")
+    );
+    assert_eq!(
+        Environment::get(&interp.env, "b").unwrap(),
+        IfaValue::str("two")
+    );
+    assert_eq!(Environment::get(&interp.env, "c").unwrap(), IfaValue::Null);
+}
+
+#[test]
+fn test_odi_read_lines_requires_read_capability() {
+    let err = run_and_get(r#"ayanmo l = Odi.read_lines("/etc/hostname");"#, "l").unwrap_err();
+    assert!(err.contains("Capability denied"), "got: {}", err);
+}
+
+#[test]
+fn test_odi_read_refuses_parent_dir_escape_from_a_grant() {
+    use ifa_core::interpreter::{CapabilitySet, Ofun};
+
+    let mut interp = Interpreter::new();
+    let mut caps = CapabilitySet::new();
+    caps.grant(Ofun::ReadFiles {
+        root: "/data".into(),
+    });
+    interp.set_capabilities(caps);
+    let err = interp
+        .execute(&parse(r#"ayanmo t = Odi.read("/data/../etc/passwd");"#).unwrap())
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("may not contain '..'"), "got: {}", err);
+}
+
+#[test]
+fn test_odi_read_is_gated_by_the_registry() {
+    let err = run_and_get(r#"ayanmo t = Odi.read("/etc/hostname");"#, "t").unwrap_err();
+    assert!(err.contains("Capability denied: ReadFiles"), "got: {}", err);
+
+    let (path, mut interp) = odi_fixture("read_gated", "granted");
+    let code = format!(r#"ayanmo t = Odi.read("{}");"#, path.display());
+    interp.execute(&parse(&code).unwrap()).unwrap();
+    std::fs::remove_file(&path).ok();
+    assert_eq!(
+        Environment::get(&interp.env, "t").unwrap(),
+        IfaValue::str("granted")
+    );
+}
+
+#[test]
+fn test_odi_write_through_imported_module_is_gated() {
+    let code = r#"
+        iba std.odi;
+        ayanmo f = odi;
+        f.write("/tmp/ifa_gate_never_written.txt", "x");
+    "#;
+    let err = run_and_get(code, "f").unwrap_err();
+    assert!(
+        err.contains("Capability denied: WriteFiles"),
+        "got: {}",
+        err
+    );
+    assert!(!std::path::Path::new("/tmp/ifa_gate_never_written.txt").exists());
+}
+
+#[test]
+fn test_odi_jsonl_round_trip() {
+    use ifa_core::interpreter::{CapabilitySet, Ofun};
+
+    let path = std::env::temp_dir().join(format!("ifa_odi_jsonl_{}.jsonl", std::process::id()));
+    std::fs::remove_file(&path).ok();
+    let code = format!(
+        r#"
+        Odi.append_jsonl("{0}", {{"id": 1, "tags": ["a", "b"]}});
+        Odi.append_jsonl("{0}", {{"id": 2, "ok": otito}});
+        Odi.append_jsonl("{0}", {{"id": 3, "score": 1.5}});
+        ayanmo rows = Odi.read_jsonl("{0}");
+        "#,
+        path.display()
+    );
+    let mut interp = Interpreter::new();
+    let mut caps = CapabilitySet::new();
+    caps.grant(Ofun::ReadFiles { root: path.clone() });
+    caps.grant(Ofun::WriteFiles { root: path.clone() });
+    interp.set_capabilities(caps);
+    let result = interp.execute(&parse(&code).unwrap());
+    let written = std::fs::read_to_string(&path).unwrap_or_default();
+    std::fs::remove_file(&path).ok();
+    result.unwrap();
+
+    assert_eq!(written.lines().count(), 3);
+    assert!(written.ends_with('\n'));
+    let rows = match Environment::get(&interp.env, "rows").unwrap() {
+        IfaValue::List(rows) => rows,
+        other => panic!("expected list, got {:?}", other),
+    };
+    assert_eq!(rows.len(), 3);
+    let field = |i: usize, key: &str| match &rows[i] {
+        IfaValue::Map(m) => m.get(key).cloned().unwrap(),
+        other => panic!("expected map, got {:?}", other),
+    };
+    assert_eq!(field(0, "id"), IfaValue::Int(1));
+    assert_eq!(
+        field(0, "tags"),
+        IfaValue::list(vec![IfaValue::str("a"), IfaValue::str("b")])
+    );
+    assert_eq!(field(1, "ok"), IfaValue::Bool(true));
+    assert_eq!(field(2, "score"), IfaValue::Float(1.5));
+}
+
+#[test]
+fn test_odi_read_jsonl_reports_malformed_line() {
+    let (path, mut interp) = odi_fixture("jsonl_bad", "{\"a\": 1}\n\n{\"a\": 2}\n{oops\n");
+    let code = format!(r#"ayanmo rows = Odi.read_jsonl("{}");"#, path.display());
+    let err = interp.execute(&parse(&code).unwrap()).unwrap_err();
+    std::fs::remove_file(&path).ok();
+
+    assert!(err.to_string().contains("line 4"), "got: {}", err);
+}
+
+#[cfg(feature = "backend")]
+#[test]
+fn test_odi_sqlite_round_trip() {
+    use ifa_core::interpreter::{CapabilitySet, Ofun};
+
+    let path = std::env::temp_dir().join(format!("ifa_odi_sql_{}.db", std::process::id()));
+    let mut interp = Interpreter::new();
+    let mut caps = CapabilitySet::new();
+    caps.grant(Ofun::ReadFiles { root: path.clone() });
+    caps.grant(Ofun::WriteFiles { root: path.clone() });
+    interp.set_capabilities(caps);
+    let code = format!(
+        r#"
+        ayanmo db = Odi.connect("{}");
+        Odi.execute(db, "CREATE TABLE people (name TEXT, age INTEGER)", []);
+        ayanmo added = Odi.execute(db, "INSERT INTO people VALUES (?1, ?2), (?3, ?4)", ["Ada", 36, "Ọ̀ṣun", 27]);
+        ayanmo rows = Odi.query(db, "SELECT name, age FROM people WHERE age > ?1 ORDER BY age", [20]);
+        "#,
+        path.display()
+    );
+    let result = interp.execute(&parse(&code).unwrap());
+    std::fs::remove_file(&path).ok();
+    result.unwrap();
+
+    assert_eq!(
+        Environment::get(&interp.env, "added").unwrap(),
+        IfaValue::Int(2)
+    );
+    let row = |name: &str, age: i64| {
+        let mut map = std::collections::HashMap::new();
+        map.insert("name".to_string(), IfaValue::str(name));
+        map.insert("age".to_string(), IfaValue::Int(age));
+        IfaValue::map(map)
+    };
+    assert_eq!(
+        Environment::get(&interp.env, "rows").unwrap(),
+        IfaValue::list(vec![row("Ọ̀ṣun", 27), row("Ada", 36)])
+    );
+}
+
+#[cfg(feature = "backend")]
+#[test]
+fn test_odi_connect_requires_file_capabilities() {
+    let err = run_and_get(r#"ayanmo db = Odi.connect("/tmp/ifa_denied.db");"#, "db").unwrap_err();
+    assert!(err.contains("Capability denied"), "got: {}", err);
+
+    // In-memory databases touch no file
+    let rows = run_and_get(
+        r#"
+        ayanmo db = Odi.connect(":memory:");
+        ayanmo rows = Odi.query(db, "SELECT 1 + 1 AS two", ofo);
+        "#,
+        "rows",
+    )
+    .unwrap();
+    let mut map = std::collections::HashMap::new();
+    map.insert("two".to_string(), IfaValue::Int(2));
+    assert_eq!(rows, IfaValue::list(vec![IfaValue::map(map)]));
+}
+
+#[cfg(feature = "backend")]
+#[test]
+fn test_odi_connect_refuses_attach_and_vacuum_into() {
+    let path = std::env::temp_dir().join(format!("ifa_attach_{}.db", std::process::id()));
+    for sql in [
+        format!("ATTACH DATABASE '{}' AS other", path.display()),
+        format!("VACUUM INTO '{}'", path.display()),
+    ] {
+        let code = format!(
+            r#"
+            ayanmo db = Odi.connect(":memory:");
+            ayanmo n = Odi.execute(db, "{}", ofo);
+            "#,
+            sql
+        );
+        let err = run_and_get(&code, "n").unwrap_err();
+        assert!(err.contains("SQL error"), "{}: {}", sql, err);
+        assert!(!path.exists(), "{} created {}", sql, path.display());
+    }
+}
+
+// =============================================================================
+// Òtúrá (Network) Handler Tests
+// =============================================================================
+
+/// Interpreter granted `Network` for 127.0.0.1 and/or `WriteFiles` for `path`.
+fn otura_download_interp(net: bool, write: Option<&std::path::Path>) -> Interpreter {
+    use ifa_core::interpreter::{CapabilitySet, Ofun};
+
+    let mut caps = CapabilitySet::new();
+    if net {
+        caps.grant(Ofun::Network {
+            domains: vec!["127.0.0.1".to_string()],
+        });
+    }
+    if let Some(path) = write {
+        caps.grant(Ofun::WriteFiles {
+            root: path.to_path_buf(),
+        });
+    }
+    let mut interp = Interpreter::new();
+    interp.set_capabilities(caps);
+    interp
+}
+
+#[test]
+fn test_otura_download_requires_network_and_write_capabilities() {
+    let path = std::env::temp_dir().join(format!("ifa_download_denied_{}", std::process::id()));
+    let code = format!(
+        r#"ayanmo n = Otura.download("http://127.0.0.1:9/x", "{}");"#,
+        path.display()
+    );
+
+    for (net, write) in [(false, Some(path.as_path())), (true, None)] {
+        let mut interp = otura_download_interp(net, write);
+        let err = interp.execute(&parse(&code).unwrap()).unwrap_err();
+        assert!(
+            err.to_string().contains("Capability denied"),
+            "got: {}",
+            err
+        );
+    }
+    assert!(!path.exists());
+}
+
+#[cfg(feature = "network")]
+#[test]
+fn test_otura_download_writes_payload() {
+    use std::io::{Read, Write};
+
+    let payload = b"Ifa download payload \x00\xff".to_vec();
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let body = payload.clone();
+    let server = std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut buf = [0u8; 1024];
+        let _ = stream.read(&mut buf);
+        let header = format!(
+            "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            body.len()
+        );
+        stream.write_all(header.as_bytes()).unwrap();
+        stream.write_all(&body).unwrap();
+    });
+
+    let path = std::env::temp_dir().join(format!("ifa_download_{}.bin", std::process::id()));
+    let mut interp = otura_download_interp(true, Some(&path));
+    let code = format!(
+        r#"ayanmo n = Otura.download("http://127.0.0.1:{}/file.bin", "{}");"#,
+        port,
+        path.display()
+    );
+    interp.execute(&parse(&code).unwrap()).unwrap();
+    server.join().unwrap();
+
+    let written = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).ok();
+    assert_eq!(written, payload);
+    assert_eq!(
+        Environment::get(&interp.env, "n").unwrap(),
+        IfaValue::Int(payload.len() as i64)
+    );
+}
+
+// =============================================================================
+// Coop (Polyglot Bridge) Handler Tests
+// =============================================================================
+
+#[test]
+fn test_coop_py_json_requires_bridge_capability() {
+    let err =
+        run_and_get(r#"ayanmo r = Coop.py_json("json", "loads", ["[1]"]);"#, "r").unwrap_err();
+    assert!(err.contains("Capability denied"), "got: {}", err);
+}
+
+#[test]
+fn test_coop_bridges_agree_with_available() {
+    // Probing needs no bridge capability
+    let code = r#"
+        ayanmo bridges = Coop.bridges();
+        ayanmo python = Coop.available("python");
+    "#;
+    let program = parse(code).unwrap();
+    let mut interp = Interpreter::new();
+    interp.execute(&program).unwrap();
+
+    let bridges = Environment::get(&interp.env, "bridges").unwrap();
+    let python = Environment::get(&interp.env, "python").unwrap();
+    let listed = matches!(&bridges, IfaValue::List(l) if l.contains(&IfaValue::str("python")));
+    assert_eq!(python, IfaValue::bool(listed));
+
+    let err = run_and_get(r#"ayanmo r = Coop.available("cobol");"#, "r").unwrap_err();
+    assert!(err.contains("Unknown bridge language"), "got: {}", err);
+}
+
+fn interpreter_with_python_bridge() -> Interpreter {
+    use ifa_core::interpreter::{CapabilitySet, Ofun};
+
+    let mut interp = Interpreter::new();
+    let mut caps = CapabilitySet::new();
+    caps.grant(Ofun::Bridge {
+        language: "python".into(),
+    });
+    caps.grant(Ofun::Execute {
+        programs: vec![python_program().into()],
+    });
+    interp.set_capabilities(caps);
+    interp
+}
+
+fn python_program() -> &'static str {
+    if cfg!(windows) { "python" } else { "python3" }
+}
+
+#[test]
+fn test_coop_bridges_need_execute_for_the_program_they_start() {
+    use ifa_core::interpreter::Ofun;
+
+    let python = Ofun::Bridge {
+        language: "python".into(),
+    };
+    let err = gated_error(r#"Coop.py_json("math", "sqrt", [16]);"#, vec![python]);
+    assert!(err.contains("Capability denied: Execute"), "got: {}", err);
+    assert!(err.contains(python_program()), "got: {}", err);
+
+    let c = Ofun::Bridge {
+        language: "c".into(),
+    };
+    let err = gated_error(
+        r#"Coop.c("int main(void) { return 0; }");"#,
+        vec![c.clone()],
+    );
+    assert!(err.contains("Capability denied: Execute"), "got: {}", err);
+
+    // Granting the default compiler doesn't cover one named in the options
+    let cc = Ofun::Execute {
+        programs: vec!["cc".into()],
+    };
+    let err = gated_error(
+        r#"Coop.c("int main(void) { return 0; }", {"compiler": "/tmp/evil-cc"});"#,
+        vec![c, cc],
+    );
+    assert!(err.contains("Capability denied: Execute"), "got: {}", err);
+    assert!(err.contains("/tmp/evil-cc"), "got: {}", err);
+}
+
+fn python_available() -> bool {
+    let available = std::process::Command::new("python3")
+        .arg("--version")
+        .output()
+        .map(|o| o.status.success())
+        .unwrap_or(false);
+    if !available {
+        eprintln!("skipping: python3 not available");
+    }
+    available
+}
+
+#[test]
+fn test_coop_py_json_round_trips_structured_data() {
+    if !python_available() {
+        return;
+    }
+
+    let code = r#"
+        ayanmo m = Coop.py_json("json", "loads", ['{"a": [1, 2.5, true], "b": null}']);
+        ayanmo l = Coop.py_json("builtins", "sorted", [[3, 1, 2]]);
+    "#;
+    let program = parse(code).unwrap();
+    let mut interp = interpreter_with_python_bridge();
+    interp.set_python_modules(["json", "builtins"]);
+    interp.execute(&program).unwrap();
+
+    match Environment::get(&interp.env, "m").unwrap() {
+        IfaValue::Map(map) => {
+            assert_eq!(
+                map.get("a").cloned(),
+                Some(IfaValue::list(vec![
+                    IfaValue::Int(1),
+                    IfaValue::Float(2.5),
+                    IfaValue::Bool(true),
+                ]))
+            );
+            assert_eq!(map.get("b").cloned(), Some(IfaValue::Null));
+        }
+        other => panic!("Expected Map, got {:?}", other),
+    }
+    assert_eq!(
+        Environment::get(&interp.env, "l").unwrap(),
+        IfaValue::list(vec![IfaValue::Int(1), IfaValue::Int(2), IfaValue::Int(3)])
+    );
+}
+
+#[test]
+fn test_coop_py_json_allows_default_modules() {
+    if !python_available() {
+        return;
+    }
+
+    let program = parse(r#"ayanmo r = Coop.py_json("math", "sqrt", [16]);"#).unwrap();
+    let mut interp = interpreter_with_python_bridge();
+    interp.execute(&program).unwrap();
+    assert_eq!(
+        Environment::get(&interp.env, "r").unwrap(),
+        IfaValue::Float(4.0)
+    );
+}
+
+#[test]
+fn test_coop_py_json_rejects_modules_outside_allowlist() {
+    // Rejected before Python starts, so this holds without python3 too
+    let mut interp = interpreter_with_python_bridge();
+    let program = parse(r#"ayanmo r = Coop.py_json("os", "system", ["echo hi"]);"#).unwrap();
+    let err = interp.execute(&program).unwrap_err().to_string();
+    assert!(err.contains("module 'os' is not allowed"), "got: {}", err);
+
+    let program = parse(r#"ayanmo r = Coop.py_json("math", "__loader__", []);"#).unwrap();
+    let err = interp.execute(&program).unwrap_err().to_string();
+    assert!(err.contains("is private"), "got: {}", err);
+
+    interp.set_python_modules(["statistics"]);
+    let program = parse(r#"ayanmo r = Coop.py_json("json", "dumps", [1]);"#).unwrap();
+    let err = interp.execute(&program).unwrap_err().to_string();
+    assert!(err.contains("allowed: statistics"), "got: {}", err);
+}
+
+fn interpreter_with_wasm_bridge() -> Interpreter {
+    use ifa_core::interpreter::{CapabilitySet, Ofun};
+
+    let mut interp = Interpreter::new();
+    let mut caps = CapabilitySet::new();
+    caps.grant(Ofun::Bridge {
+        language: "wasm".into(),
+    });
+    interp.set_capabilities(caps);
+    interp
+}
+
+#[test]
+fn test_coop_wasm_requires_bridge_capability() {
+    let err = run_and_get(r#"ayanmo r = Coop.wasm("add.wasm", "add", [2, 3]);"#, "r").unwrap_err();
+    assert!(err.contains("Capability denied"), "got: {}", err);
+}
+
+#[test]
+fn test_coop_wasm_rejects_non_numeric_args() {
+    let program = parse(r#"ayanmo r = Coop.wasm("add.wasm", "add", [2, "three"]);"#).unwrap();
+    let err = interpreter_with_wasm_bridge()
+        .execute(&program)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("numeric wasm argument"), "got: {}", err);
+}
+
+#[cfg(feature = "wasm")]
+#[test]
+fn test_coop_wasm_calls_exported_function() {
+    // (module (func (export "add") (param i32 i32) (result i32)
+    //   local.get 0 local.get 1 i32.add))
+    const ADD_WASM: &[u8] = &[
+        0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // header
+        0x01, 0x07, 0x01, 0x60, 0x02, 0x7f, 0x7f, 0x01, 0x7f, // type
+        0x03, 0x02, 0x01, 0x00, // function
+        0x07, 0x07, 0x01, 0x03, b'a', b'd', b'd', 0x00, 0x00, // export
+        0x0a, 0x09, 0x01, 0x07, 0x00, 0x20, 0x00, 0x20, 0x01, 0x6a, 0x0b, // code
+    ];
+    let path = std::env::temp_dir().join(format!("ifa_add_{}.wasm", std::process::id()));
+    std::fs::write(&path, ADD_WASM).unwrap();
+
+    let code = format!(
+        r#"ayanmo r = Coop.wasm("{}", "add", [2, 3]);"#,
+        path.display().to_string().replace('\\', "/")
+    );
+    let mut interp = interpreter_with_wasm_bridge();
+    let result = interp.execute(&parse(&code).unwrap());
+    std::fs::remove_file(&path).ok();
+
+    result.unwrap();
+    assert_eq!(
+        Environment::get(&interp.env, "r").unwrap(),
+        IfaValue::Int(5)
+    );
+}
+
+// =============================================================================
+// Ọpẹlẹ (Divination) Handler Tests
+// =============================================================================
+
+#[test]
+fn test_opele_interpret_compound() {
+    let result = run_and_get(r#"ayanmo w = Opele.interpret("Ogbe_Otura");"#, "w").unwrap();
+    let IfaValue::Map(map) = result else {
+        panic!("Expected Map, got {:?}", result);
+    };
+    assert_eq!(map.get("binary").cloned(), Some(IfaValue::str("11111011")));
+
+    let Some(IfaValue::List(components)) = map.get("components").cloned() else {
+        panic!("Expected components list");
+    };
+    let field = |i: usize, key: &str| match &components[i] {
+        IfaValue::Map(c) => c.get(key).map(|v| v.to_string()).unwrap_or_default(),
+        other => panic!("Expected Map, got {:?}", other),
+    };
+    assert_eq!(components.len(), 2);
+    assert_eq!(field(0, "odu"), "Ogbe");
+    assert_eq!(field(0, "binary"), "1111");
+    assert_eq!(field(1, "odu"), "Otura");
+    assert_eq!(field(1, "binary"), "1011");
+    assert!(!field(0, "proverb").is_empty());
+    assert!(!field(1, "proverb").is_empty());
+    assert_ne!(field(0, "proverb"), field(1, "proverb"));
+
+    let err = run_and_get(r#"ayanmo w = Opele.interpret("Ogbe_Nope");"#, "w").unwrap_err();
+    assert!(err.contains("Unknown Odù"), "got: {}", err);
+}
+
+#[test]
+fn test_opele_journal_records_castings_in_order() {
+    use ifa_core::interpreter::{CapabilitySet, Ofun};
+
+    let code = r#"
+        ayanmo a = Opele.divine("Will it rain?");
+        ayanmo b = Opele.cast();
+        ayanmo c = Opele.divine("Should I travel?");
+        ayanmo journal = Opele.journal();
+        Opele.clear_journal();
+        ayanmo cleared = Opele.journal();
+    "#;
+    let mut interp = Interpreter::new();
+    let mut caps = CapabilitySet::new();
+    caps.grant(Ofun::Time);
+    caps.grant(Ofun::Random);
+    interp.set_capabilities(caps);
+    interp.execute(&parse(code).unwrap()).unwrap();
+    let get = |name: &str| Environment::get(&interp.env, name).unwrap();
+
+    let IfaValue::List(journal) = get("journal") else {
+        panic!("Expected journal list");
+    };
+    assert_eq!(journal.len(), 3);
+    let entry = |i: usize, key: &str| match &journal[i] {
+        IfaValue::Map(m) => m.get(key).cloned(),
+        other => panic!("Expected Map, got {:?}", other),
+    };
+    assert_eq!(entry(0, "question"), Some(IfaValue::str("Will it rain?")));
+    assert_eq!(entry(1, "question"), Some(IfaValue::Null));
+    assert_eq!(
+        entry(2, "question"),
+        Some(IfaValue::str("Should I travel?"))
+    );
+    assert_eq!(entry(0, "result"), Some(get("a")));
+    assert_eq!(entry(1, "result"), Some(get("b")));
+    assert_eq!(entry(2, "result"), Some(get("c")));
+    assert!(matches!(entry(0, "timestamp"), Some(IfaValue::Int(_))));
+
+    assert_eq!(get("cleared"), IfaValue::list(vec![]));
+
+    // Without Time the entries carry no timestamp
+    let mut interp = Interpreter::new();
+    let mut caps = CapabilitySet::new();
+    caps.grant(Ofun::Random);
+    interp.set_capabilities(caps);
+    interp
+        .execute(&parse("Opele.cast(); ayanmo j = Opele.journal();").unwrap())
+        .unwrap();
+    let Some(IfaValue::List(journal)) = Environment::get(&interp.env, "j") else {
+        panic!("Expected journal list");
+    };
+    let IfaValue::Map(entry) = &journal[0] else {
+        panic!("Expected Map");
+    };
+    assert!(entry.get("timestamp").is_none());
+}
+
+#[test]
+fn test_opele_cast_requires_random() {
+    let err = run_and_get("ayanmo r = Opele.cast();", "r").unwrap_err();
+    assert!(err.contains("Random"), "got: {}", err);
+    let err = run_and_get(r#"ayanmo r = Opele.divine("Why?");"#, "r").unwrap_err();
+    assert!(err.contains("Random"), "got: {}", err);
+}
+
+#[test]
+fn test_opele_journal_keeps_only_the_latest_castings() {
+    use ifa_core::interpreter::{CapabilitySet, OPELE_JOURNAL_LIMIT, Ofun};
+
+    let code = format!(
+        r#"
+        fun i ninu Iwori.range(0, {}) {{
+            Opele.divine(Ika.format("q{{}}", [i]));
+        }}
+        ayanmo journal = Opele.journal();
+        "#,
+        OPELE_JOURNAL_LIMIT + 5
+    );
+    let mut interp = Interpreter::new();
+    let mut caps = CapabilitySet::new();
+    caps.grant(Ofun::Random);
+    interp.set_capabilities(caps);
+    interp.execute(&parse(&code).unwrap()).unwrap();
+
+    let Some(IfaValue::List(journal)) = Environment::get(&interp.env, "journal") else {
+        panic!("Expected journal list");
+    };
+    assert_eq!(journal.len(), OPELE_JOURNAL_LIMIT);
+    let question = |entry: &IfaValue| match entry {
+        IfaValue::Map(m) => m.get("question").cloned(),
+        other => panic!("Expected Map, got {:?}", other),
+    };
+    // The five oldest castings were dropped
+    assert_eq!(question(&journal[0]), Some(IfaValue::str("q5")));
+    assert_eq!(
+        question(journal.last().unwrap()),
+        Some(IfaValue::str(format!("q{}", OPELE_JOURNAL_LIMIT + 4)))
+    );
+}
+
+// =============================================================================
+// Ọ̀kànràn (Errors) Handler Tests
+// =============================================================================
+
+#[test]
+fn test_okanran_truthy_matches_conditions() {
+    let cases = [
+        ("0", false),
+        ("7", true),
+        ("0.0", false),
+        (r#""""#, false),
+        (r#""no""#, true),
+        ("[]", false),
+        ("[0]", true),
+        ("{}", false),
+        (r#"{"k": 0}"#, true),
+        ("otito", true),
+        ("iro", false),
+    ];
+    for (literal, expected) in cases {
+        let code = format!(
+            "ayanmo t = Okanran.truthy({0}); ayanmo c = iro; ti {0} {{ c = otito; }}",
+            literal
+        );
+        let truthy = run_and_get(&code, "t").unwrap();
+        assert_eq!(
+            truthy,
+            IfaValue::Bool(expected),
+            "Okanran.truthy({})",
+            literal
+        );
+        let cond = run_and_get(&code, "c").unwrap();
+        assert_eq!(cond, truthy, "condition on {}", literal);
+    }
+
+    let err = run_and_get("ayanmo t = Okanran.truthy();", "t").unwrap_err();
+    assert!(err.contains("requires a value"), "got: {}", err);
+}
+
+#[test]
+fn test_okanran_raise_is_caught() {
+    let code = r#"
+        ayanmo caught = "";
+        gbiyanju {
+            Okanran.raise("bad input");
+        } gba (e) {
+            caught = e["message"];
+        }
+    "#;
+    let caught = run_and_get(code, "caught").unwrap().to_string();
+    assert!(caught.contains("bad input"), "got: {}", caught);
+}
+
+#[test]
+fn test_okanran_panic_skips_catch_but_runs_finally() {
+    let code = r#"
+        ayanmo caught = 0;
+        ayanmo cleaned = 0;
+        ese check() {
+            gbiyanju {
+                Okanran.panic("invariant broken");
+            } gba (e) {
+                caught = 1;
+            } nipari {
+                cleaned = 1;
+                pada 0;
+            }
+        }
+        gbiyanju {
+            check();
+        } gba (e) {
+            caught = 2;
+        }
+    "#;
+    let program = parse(code).unwrap();
+    let mut interp = Interpreter::new();
+    let err = interp.execute(&program).unwrap_err();
+    assert!(err.to_string().contains("invariant broken"), "got: {}", err);
+    assert_eq!(
+        Environment::get(&interp.env, "caught"),
+        Some(IfaValue::Int(0))
+    );
+    assert_eq!(
+        Environment::get(&interp.env, "cleaned"),
+        Some(IfaValue::Int(1))
+    );
+}
+
+// =============================================================================
+// Ìrosù (Console) Handler Tests
+// =============================================================================
+
+#[test]
+fn test_irosu_table_aligns_union_of_keys() {
+    let code = r#"
+        Irosu.table([
+            {"name": "Ada", "age": 36},
+            {"name": "Babatunde", "city": "Ibadan"},
+        ]);
+    "#;
+    let mut interp = Interpreter::new();
+    interp.set_output_sink(OutputSink::Captured);
+    interp.execute(&parse(code).unwrap()).unwrap();
+
+    assert_eq!(
+        interp.get_output(),
+        [
+            "+-----+--------+-----------+",
+            "| age | city   | name      |",
+            "+-----+--------+-----------+",
+            "| 36  |        | Ada       |",
+            "|     | Ibadan | Babatunde |",
+            "+-----+--------+-----------+",
+        ]
+    );
+
+    let err = run_and_get("ayanmo t = Irosu.table([1, 2]);", "t").unwrap_err();
+    assert!(err.contains("map row"), "got: {}", err);
+}
+
+#[test]
+fn test_irosu_progress_prints_lines_when_not_a_tty() {
+    let code = r#"
+        fun i ninu [0, 1, 3] {
+            Irosu.progress(i, 3, "steps");
+        }
+        Irosu.progress_done();
+        Irosu.progress(0.5, 2.0);
+    "#;
+    let mut interp = Interpreter::new();
+    interp.set_output_sink(OutputSink::Captured);
+    interp.execute(&parse(code).unwrap()).unwrap();
+
+    let bar = |filled: usize| format!("[{}{}]", "#".repeat(filled), "-".repeat(30 - filled));
+    assert_eq!(
+        interp.get_output(),
+        [
+            format!("{}   0% steps", bar(0)),
+            format!("{}  33% steps", bar(10)),
+            format!("{} 100% steps", bar(30)),
+            format!("{}  25%", bar(8)),
+        ]
+    );
+    assert!(interp.get_output().iter().all(|line| !line.contains('\r')));
+
+    let err = run_and_get("ayanmo p = Irosu.progress(1, 0);", "p").unwrap_err();
+    assert!(err.contains("total must be positive"), "got: {}", err);
+}
+
+// =============================================================================
+// Ìwòrì (Time) Handler Tests
+// =============================================================================
+
+#[test]
+fn test_iwori_monotonic_and_sleep_until() {
+    use ifa_core::interpreter::{CapabilitySet, Ofun};
+
+    let code = r#"
+        ayanmo a = Iwori.monotonic();
+        ayanmo b = Iwori.monotonic();
+        Iwori.sleep_until(a);
+        ayanmo c = Iwori.monotonic();
+    "#;
+    let mut interp = Interpreter::new();
+    let mut caps = CapabilitySet::new();
+    caps.grant(Ofun::Time);
+    interp.set_capabilities(caps);
+    let started = std::time::Instant::now();
+    interp.execute(&parse(code).unwrap()).unwrap();
+    let get = |name: &str| match Environment::get(&interp.env, name) {
+        Some(IfaValue::Int(n)) => n,
+        other => panic!("Expected Int for {}, got {:?}", name, other),
+    };
+
+    assert!(get("a") <= get("b"));
+    assert!(get("b") <= get("c"));
+    // `a` is already in the past, so sleep_until returns immediately
+    assert!(started.elapsed() < std::time::Duration::from_secs(1));
+
+    let err = run_and_get("ayanmo t = Iwori.monotonic();", "t").unwrap_err();
+    assert!(err.contains("Capability denied"), "got: {}", err);
+}
+
+#[test]
+fn test_iwori_sleep_until_can_be_interrupted() {
+    use ifa_core::interpreter::{CapabilitySet, Ofun};
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    // An hour from now
+    let code = "Iwori.sleep_until(Iwori.monotonic() + 3600000000000);";
+    let mut interp = Interpreter::new();
+    let mut caps = CapabilitySet::new();
+    caps.grant(Ofun::Time);
+    interp.set_capabilities(caps);
+    let flag = Arc::new(AtomicBool::new(false));
+    interp.set_interrupt_flag(flag.clone());
+
+    let raiser = std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        flag.store(true, Ordering::Relaxed);
+    });
+    let started = std::time::Instant::now();
+    let err = interp.execute(&parse(code).unwrap()).unwrap_err();
+    raiser.join().unwrap();
+
+    assert!(err.to_string().contains("interrupted"), "got: {}", err);
+    assert!(started.elapsed() < std::time::Duration::from_secs(5));
+}
+
+#[test]
+fn test_iwori_parse_dates_to_epoch() {
+    let epoch = run_and_get(r#"ayanmo t = Iwori.parse("1970-01-01");"#, "t").unwrap();
+    assert_eq!(epoch, IfaValue::Int(0));
+
+    let code = r#"
+        ayanmo t = Iwori.parse_date("2024-02-29T13:45:30");
+        ayanmo back = Iwori.format(t);
+    "#;
+    assert_eq!(
+        run_and_get(code, "t").unwrap(),
+        IfaValue::Int(1_709_214_330)
+    );
+    assert_eq!(
+        run_and_get(code, "back").unwrap(),
+        IfaValue::str("2024-02-29T13:45:30")
+    );
+
+    let err = run_and_get(r#"ayanmo t = Iwori.parse("2024-02-30");"#, "t").unwrap_err();
+    assert!(err.contains("Invalid date '2024-02-30'"), "got: {}", err);
+}
+
+// =============================================================================
+// Ose Canvas Tests
+// =============================================================================
+
+#[test]
+fn test_ose_canvas_shows_draws_after_present() {
+    let mut interp = Interpreter::new();
+    interp
+        .execute(&parse(r#"Ose.text(0, 0, "hi"); Ose.rect(0, 1, 3, 2, "*");"#).unwrap())
+        .unwrap();
+    assert!(interp.get_canvas().chars().all(|c| c == ' ' || c == '\n'));
+
+    interp.execute(&parse("Ose.present();").unwrap()).unwrap();
+    let canvas = interp.get_canvas();
+    let rows: Vec<&str> = canvas.lines().collect();
+    assert!(rows[0].starts_with("hi "), "got: {:?}", rows[0]);
+    assert!(rows[1].starts_with("*** "), "got: {:?}", rows[1]);
+    assert!(rows[2].starts_with("*** "), "got: {:?}", rows[2]);
+}
+
+#[test]
+fn test_ose_colored_text_survives_ansi_export() {
+    let mut interp = Interpreter::new();
+    interp
+        .execute(&parse(r#"Ose.ko_colored(2, 3, "go", "green"); Ose.present();"#).unwrap())
+        .unwrap();
+
+    let plain = interp.get_canvas();
+    assert!(plain.lines().nth(3).unwrap().starts_with("  go "));
+    let ansi = interp.get_canvas_ansi();
+    let row = ansi.lines().nth(3).unwrap();
+    assert!(row.starts_with("  \x1B[32mgo\x1B[0m "), "got: {:?}", row);
+    assert!(ansi.lines().take(3).all(|line| !line.contains('\x1B')));
+
+    let err = run_and_get(r#"ayanmo r = Ose.ko_colored(0, 0, "x", "mauve");"#, "r").unwrap_err();
+    assert!(err.contains("Unknown color 'mauve'"), "got: {}", err);
+}
+
+#[test]
+fn test_ose_sprite_leaves_background_under_spaces() {
+    let mut interp = Interpreter::new();
+    let code = r#"
+        Ose.clear_canvas(".");
+        Ose.sprite(1, 1, ["o o", " - "], "red");
+        Ose.present();
+    "#;
+    interp.execute(&parse(code).unwrap()).unwrap();
+
+    let canvas = interp.get_canvas();
+    let rows: Vec<&str> = canvas.lines().collect();
+    assert!(rows[0].starts_with("....."), "got: {:?}", rows[0]);
+    assert!(rows[1].starts_with(".o.o."), "got: {:?}", rows[1]);
+    assert!(rows[2].starts_with("..-.."), "got: {:?}", rows[2]);
+    let ansi = interp.get_canvas_ansi();
+    let row = ansi.lines().nth(2).unwrap();
+    assert!(row.starts_with("..\x1B[31m-\x1B[0m.."), "got: {:?}", row);
+}
+
+// =============================================================================
+// Ohùn / Fídíò (Media) Handler Tests
+// =============================================================================
+
+#[test]
+fn test_ohun_play_reaches_handler_once_granted() {
+    use ifa_core::interpreter::{CapabilitySet, Ofun};
+
+    let err = run_and_get(r#"ayanmo p = Ohun.play("a.wav");"#, "p").unwrap_err();
+    assert!(err.contains("Capability denied: ReadFiles"), "got: {}", err);
+
+    let mut interp = Interpreter::new();
+    let mut caps = CapabilitySet::new();
+    caps.grant(Ofun::ReadFiles {
+        root: "a.wav".into(),
+    });
+    interp.set_capabilities(caps);
+    let err = interp
+        .execute(&parse(r#"ayanmo p = Ohun.play("a.wav");"#).unwrap())
+        .unwrap_err()
+        .to_string();
+    // The handler itself answers: no audio backend, or no such file
+    assert!(
+        err.contains("Ohun.play requires the 'audio' build")
+            || err.contains("Failed to open audio file"),
+        "got: {}",
+        err
+    );
+}
+
+#[test]
+fn test_fidio_info_reports_file_facts() {
+    let (path, mut interp) = odi_fixture("clip", "0123456789");
+    let clip = path.with_extension("MP4");
+    std::fs::rename(&path, &clip).unwrap();
+    let mut caps = ifa_core::interpreter::CapabilitySet::new();
+    caps.grant(ifa_core::interpreter::Ofun::ReadFiles { root: clip.clone() });
+    interp.set_capabilities(caps);
+
+    let code = format!(r#"ayanmo i = Fidio.info("{}");"#, clip.display());
+    interp.execute(&parse(&code).unwrap()).unwrap();
+    std::fs::remove_file(&clip).ok();
+
+    let IfaValue::Map(info) = Environment::get(&interp.env, "i").unwrap() else {
+        panic!("Fidio.info should return a map");
+    };
+    assert_eq!(info.get("bytes"), Some(&IfaValue::Int(10)));
+    assert_eq!(info.get("format"), Some(&IfaValue::str("mp4")));
+    assert_eq!(info.get("width"), Some(&IfaValue::Null));
+}
+
+// =============================================================================
+// Feature-Gated Methods (built without the feature)
+// =============================================================================
+
+/// Run `code` with `caps` granted and return the error it fails with
+#[allow(dead_code)]
+fn gated_error(code: &str, caps: Vec<ifa_core::interpreter::Ofun>) -> String {
+    use ifa_core::interpreter::CapabilitySet;
+
+    let mut interp = Interpreter::new();
+    let mut set = CapabilitySet::new();
+    for cap in caps {
+        set.grant(cap);
+    }
+    interp.set_capabilities(set);
+    interp
+        .execute(&parse(code).unwrap())
+        .unwrap_err()
+        .to_string()
+}
+
+#[cfg(not(feature = "audio"))]
+#[test]
+fn test_ohun_beep_needs_audio_feature() {
+    let err = gated_error("Ohun.beep(440, 10);", vec![]);
+    assert!(
+        err.contains("Ohun.beep requires the 'audio' build; rebuild with --features audio"),
+        "got: {}",
+        err
+    );
+}
+
+#[cfg(not(feature = "network"))]
+#[test]
+fn test_otura_get_needs_network_feature() {
+    use ifa_core::interpreter::Ofun;
+
+    let network = Ofun::Network {
+        domains: vec!["example.com".into()],
+    };
+    let err = gated_error(r#"Otura.get("http://example.com/");"#, vec![network]);
+    assert!(
+        err.contains("Otura.get requires the 'network' build; rebuild with --features network"),
+        "got: {}",
+        err
+    );
+}
+
+#[cfg(not(feature = "backend"))]
+#[test]
+fn test_odi_connect_needs_backend_feature() {
+    let err = gated_error(r#"Odi.connect(":memory:");"#, vec![]);
+    assert!(
+        err.contains("Odi.connect requires the 'backend' build; rebuild with --features backend"),
+        "got: {}",
+        err
+    );
+}
+
+#[cfg(not(feature = "game"))]
+#[test]
+fn test_ose_poll_key_needs_game_feature() {
+    let err = gated_error("Ose.poll_key();", vec![]);
+    assert!(
+        err.contains("Ose.poll_key requires the 'game' build; rebuild with --features game"),
+        "got: {}",
+        err
+    );
+}
+
+#[cfg(not(feature = "wasm"))]
+#[test]
+fn test_coop_wasm_needs_wasm_feature() {
+    use ifa_core::interpreter::Ofun;
+
+    let bridge = Ofun::Bridge {
+        language: "wasm".into(),
+    };
+    let err = gated_error(r#"Coop.wasm("m.wasm", "f", []);"#, vec![bridge]);
+    assert!(
+        err.contains("Coop.wasm requires the 'wasm' build; rebuild with --features wasm"),
+        "got: {}",
+        err
+    );
+}
+
+// =============================================================================
+// Òfún (Reflection) Handler Tests
+// =============================================================================
+
+#[test]
+fn test_ofun_type_of_and_is_callable() {
+    let code = r#"
+        ese double(x) { pada x * 2; }
+        ayanmo types = [
+            Ofun.type_of(1), Ofun.type_of(1.5), Ofun.type_of("s"), Ofun.type_of(otito),
+            Ofun.type_of([1]), Ofun.type_of({"a": 1}), Ofun.type_of(ofo), Ofun.type_of(double)
+        ];
+        ayanmo callable = [Ofun.is_callable(double), Ofun.is_callable("double")];
+    "#;
+    let expected = ["int", "float", "str", "bool", "list", "map", "null", "ese"];
+    assert_eq!(
+        run_and_get(code, "types").unwrap(),
+        IfaValue::list(expected.iter().map(|t| IfaValue::str(*t)).collect())
+    );
+    assert_eq!(
+        run_and_get(code, "callable").unwrap(),
+        IfaValue::list(vec![IfaValue::bool(true), IfaValue::bool(false)])
+    );
+}
+
+#[test]
+fn test_ofun_methods_of_reads_the_handler() {
+    let result = run_and_get(r#"ayanmo m = Ofun.methods_of("Ika");"#, "m").unwrap();
+    let IfaValue::List(methods) = result else {
+        panic!("expected List, got {:?}", result);
+    };
+    assert!(methods.contains(&IfaValue::str("upper")));
+    assert!(methods.contains(&IfaValue::str("slugify")));
+
+    let err = run_and_get(r#"ayanmo m = Ofun.methods_of("Nowhere");"#, "m").unwrap_err();
+    assert!(err.contains("Unknown Odù domain"), "got: {}", err);
+}
+
+// =============================================================================
+// Named Argument Tests
+// =============================================================================
+
+#[test]
+fn test_named_args_match_positional_call() {
+    let output_of = |code: &str| {
+        let mut interp = Interpreter::new();
+        interp.execute(&parse(code).unwrap()).unwrap();
+        interp.get_output().to_vec()
+    };
+    let positional = output_of("Ose.box(1, 2, 10, 5);");
+    let named = output_of("Ose.box(h: 5, x: 1, w: 10, y: 2);");
+    assert!(!positional.is_empty());
+    assert_eq!(named, positional);
+
+    let canvas_of = |code: &str| {
+        let mut interp = Interpreter::new();
+        interp.execute(&parse(code).unwrap()).unwrap();
+        interp.get_canvas()
+    };
+    let positional = canvas_of(r#"Ose.rect(1, 1, 6, 3, "*"); Ose.present();"#);
+    let named = canvas_of(r#"Ose.onigun(ch: "*", h: 3, y: 1, w: 6, x: 1); Ose.present();"#);
+    assert!(positional.contains('*'));
+    assert_eq!(named, positional);
+
+    let result = run_and_get(r#"ayanmo s = Ika.pad_left("7", fill: "0", width: 3);"#, "s");
+    assert_eq!(result.unwrap(), IfaValue::str("007"));
+}
+
+#[test]
+fn test_named_args_reject_unknown_and_duplicate_names() {
+    let err = run_and_get(r#"ayanmo s = Ika.repeat("a", times: 3);"#, "s").unwrap_err();
+    assert!(err.contains("unknown argument 'times'"), "got: {}", err);
+
+    let err = run_and_get(r#"ayanmo s = Ika.repeat("a", text: "b");"#, "s").unwrap_err();
+    assert!(err.contains("duplicate argument 'text'"), "got: {}", err);
+
+    let err = run_and_get(r#"ayanmo s = Ika.repeat(count: 2, "a");"#, "s").unwrap_err();
+    assert!(
+        err.contains("Positional argument after named"),
+        "got: {}",
+        err
+    );
+}
+
 // =============================================================================
 // Basic Language Tests
 // =============================================================================
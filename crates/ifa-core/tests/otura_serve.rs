@@ -0,0 +1,62 @@
+#![cfg(feature = "backend")]
+
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::thread;
+use std::time::Duration;
+
+use ifa_core::interpreter::{CapabilitySet, Ofun};
+use ifa_core::{Interpreter, parser::parse};
+
+#[test]
+fn otura_serve_answers_with_ifa_route_handler() {
+    let port = TcpListener::bind("127.0.0.1:0")
+        .and_then(|listener| listener.local_addr())
+        .unwrap()
+        .port();
+    let source = format!(
+        r#"
+        ese greet(req) {{
+            ayanmo params = req["params"];
+            ayanmo name = params["name"];
+            pada {{"status": 201, "body": $"e kaabo {{name}}"}};
+        }}
+        Otura.route("GET", "/hello/:name", greet);
+        Otura.serve({}, {{"max_requests": 1}});
+        "#,
+        port
+    );
+    let server = thread::spawn(move || {
+        let mut caps = CapabilitySet::new();
+        caps.grant(Ofun::Network {
+            domains: vec!["127.0.0.1".to_string()],
+        });
+        let mut interp = Interpreter::new();
+        interp.set_capabilities(caps);
+        interp
+            .execute(&parse(&source).expect("parse failed"))
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    });
+
+    let mut stream = (0..100)
+        .find_map(|_| {
+            TcpStream::connect(("127.0.0.1", port))
+                .inspect_err(|_| thread::sleep(Duration::from_millis(50)))
+                .ok()
+        })
+        .expect("server never started listening");
+    stream
+        .write_all(b"GET /hello/ola HTTP/1.1\r\nHost: localhost\r\n\r\n")
+        .unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+
+    assert!(response.starts_with("HTTP/1.1 201"), "got: {}", response);
+    assert!(
+        response.ends_with("\r\n\r\ne kaabo ola"),
+        "got: {}",
+        response
+    );
+    server.join().unwrap().expect("serve failed");
+}
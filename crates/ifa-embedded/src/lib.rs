@@ -58,6 +58,8 @@ pub enum EmbeddedError {
     InvalidBytecode,
     /// HAL error
     HalError(String),
+    /// Value or opcode needs a feature this runtime tier was built without
+    Unsupported(&'static str),
 }
 
 impl fmt::Display for EmbeddedError {
@@ -70,6 +72,7 @@ impl fmt::Display for EmbeddedError {
             Self::MemoryOutOfBounds => write!(f, "Memory access out of bounds"),
             Self::InvalidBytecode => write!(f, "Invalid bytecode format"),
             Self::HalError(msg) => write!(f, "HAL error: {}", msg),
+            Self::Unsupported(what) => write!(f, "Unsupported on this tier: {}", what),
         }
     }
 }
@@ -77,7 +80,8 @@ impl fmt::Display for EmbeddedError {
 pub type EmbeddedResult<T> = Result<T, EmbeddedError>;
 
 /// VM Exit Status
-#[derive(Debug, Clone, Copy, PartialEq)] // Added PartialEq for tests
+#[derive(Debug, Clone, PartialEq)] // Added PartialEq for tests
+#[cfg_attr(not(feature = "alloc"), derive(Copy))]
 pub enum VmExit {
     /// Program halted normally
     Halted(EmbeddedValue),
@@ -303,6 +307,8 @@ pub enum EmbeddedOpCode {
     PushTrue = 0x04,
     /// Push false
     PushFalse = 0x05,
+    /// Push bytes (followed by u16 length and the raw bytes); needs `alloc`
+    PushBytes = 0x0B,
 
     /// Pop and discard
     Pop = 0x10,
@@ -376,6 +382,7 @@ impl EmbeddedOpCode {
             0x02 => Ok(EmbeddedOpCode::PushFloat),
             0x04 => Ok(EmbeddedOpCode::PushTrue),
             0x05 => Ok(EmbeddedOpCode::PushFalse),
+            0x0B => Ok(EmbeddedOpCode::PushBytes),
             0x10 => Ok(EmbeddedOpCode::Pop),
             0x11 => Ok(EmbeddedOpCode::Dup),
             0x20 => Ok(EmbeddedOpCode::Add),
@@ -598,6 +605,22 @@ impl<'a, const OPON_SIZE: usize, const STACK_SIZE: usize> EmbeddedVm<'a, OPON_SI
                     let value = self.read_f32(code)?;
                     self.push(EmbeddedValue::Float(value as IfaFloat))?;
                 }
+                EmbeddedOpCode::PushBytes => {
+                    let len = self.read_u16(code)? as usize;
+                    if self.ip + len > code.len() {
+                        return Err(EmbeddedError::InvalidBytecode);
+                    }
+                    #[cfg(feature = "alloc")]
+                    {
+                        let bytes = code[self.ip..self.ip + len].to_vec();
+                        self.ip += len;
+                        self.push(EmbeddedValue::Blob(bytes))?;
+                    }
+                    #[cfg(not(feature = "alloc"))]
+                    return Err(EmbeddedError::Unsupported(
+                        "byte strings require the alloc tier",
+                    ));
+                }
                 EmbeddedOpCode::PushTrue => {
                     self.push(EmbeddedValue::Bool(true))?;
                 }
@@ -45,6 +45,13 @@ fn transpile_for_embedded(bc: &Bytecode) -> Vec<u8> {
             OpCode::Div => {
                 out.push(EmbeddedOpCode::Div as u8);
             }
+            OpCode::PushBytes => {
+                // Same layout on both sides: u16 length + raw bytes
+                let len = u16::from_le_bytes([bc.code[ip], bc.code[ip + 1]]) as usize;
+                out.push(EmbeddedOpCode::PushBytes as u8);
+                out.extend_from_slice(&bc.code[ip..ip + 2 + len]);
+                ip += 2 + len;
+            }
             OpCode::Halt => {
                 // Std: Halt (0x55)
                 // Embedded: Halt (0xFF)
@@ -106,3 +113,34 @@ fn test_cross_runtime_arithmetic() {
         Err(e) => panic!("VM Error: {:?}", e),
     }
 }
+
+#[test]
+fn test_cross_runtime_byte_string() {
+    // Program: b"\x01\xFF"
+    let mut bc = Bytecode::new("bytes.ifa");
+    bc.code.push(OpCode::PushBytes as u8);
+    bc.code.extend_from_slice(&2u16.to_le_bytes());
+    bc.code.extend_from_slice(&[0x01, 0xFF]);
+    bc.code.push(OpCode::Halt as u8);
+
+    let embedded_code = transpile_for_embedded(&bc);
+
+    let mut vm = EmbeddedVm::<1024, 256>::new(EmbeddedConfig::standard());
+    let result = vm.start(&embedded_code);
+
+    #[cfg(feature = "alloc")]
+    assert_eq!(
+        result.unwrap(),
+        VmExit::Halted(EmbeddedValue::Blob(vec![0x01, 0xFF]))
+    );
+    #[cfg(not(feature = "alloc"))]
+    match result {
+        Err(e @ ifa_embedded::EmbeddedError::Unsupported(_)) => {
+            assert!(
+                e.to_string()
+                    .contains("byte strings require the alloc tier")
+            )
+        }
+        other => panic!("expected an unsupported-bytes error, got {:?}", other),
+    }
+}
@@ -60,7 +60,11 @@ pub fn format(source: &str, config: FormatterConfig) -> String {
                 formatted.push_str(original_text);
             }
             Token::LBrace => {
-                formatted.push_str(" {");
+                // Keywords already leave a trailing space (`else {`)
+                if !formatted.ends_with(' ') {
+                    formatted.push(' ');
+                }
+                formatted.push('{');
                 indent_level += 1;
                 if let Some(next) = iter.peek() {
                     if !matches!(next.value, Token::Newline) {
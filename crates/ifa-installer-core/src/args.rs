@@ -19,4 +19,8 @@ pub struct InstallerArgs {
     /// Uninstall Ifá-Lang
     #[arg(long)]
     pub uninstall: bool,
+
+    /// Show what would be installed without changing anything
+    #[arg(long)]
+    pub dry_run: bool,
 }
@@ -7,6 +7,8 @@ pub struct InstallConfig {
     pub update_shell: bool,
     pub create_shortcut: bool,
     pub offline_mode: bool,
+    /// Log and return every action without performing any of them
+    pub dry_run: bool,
 }
 
 impl Default for InstallConfig {
@@ -18,6 +20,7 @@ impl Default for InstallConfig {
             update_shell: true,
             create_shortcut: false,
             offline_mode: false,
+            dry_run: false,
         }
     }
 }
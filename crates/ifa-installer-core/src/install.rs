@@ -2,6 +2,8 @@ use crate::check::check_system;
 use crate::config::InstallConfig;
 use crate::net::NetManager;
 use crate::profiles::Component;
+use serde::Serialize;
+use std::fmt;
 use std::fs;
 use std::path::{Path, PathBuf};
 use thiserror::Error;
@@ -68,12 +70,74 @@ pub enum InstallError {
     RequirementsNotMet(String),
 }
 
+/// One side effect of an installation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum PlannedAction {
+    /// Create the installation directory
+    CreateDir { path: PathBuf },
+    /// Download a release asset; `verified` if it is checked against SHA256SUMS
+    Download {
+        url: String,
+        target: PathBuf,
+        verified: bool,
+    },
+    /// Unpack an archive into the installation directory
+    Extract { archive: PathBuf, target: PathBuf },
+    /// Delete a downloaded archive once it is extracted
+    RemoveFile { path: PathBuf },
+    /// Add the installation directory to the user's PATH
+    AddToPath { dir: PathBuf },
+}
+
+impl fmt::Display for PlannedAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlannedAction::CreateDir { path } => write!(f, "create directory {:?}", path),
+            PlannedAction::Download {
+                url,
+                target,
+                verified,
+            } => {
+                let check = if *verified { "verified" } else { "unverified" };
+                write!(f, "download {} to {:?} ({})", url, target, check)
+            }
+            PlannedAction::Extract { archive, target } => {
+                write!(f, "extract {:?} into {:?}", archive, target)
+            }
+            PlannedAction::RemoveFile { path } => write!(f, "remove {:?}", path),
+            PlannedAction::AddToPath { dir } => write!(f, "add {:?} to PATH", dir),
+        }
+    }
+}
+
+/// Actions `install` performed, or for a dry run, would perform
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct InstallPlan {
+    pub dry_run: bool,
+    pub actions: Vec<PlannedAction>,
+}
+
+impl InstallPlan {
+    fn record(&mut self, action: PlannedAction) {
+        if self.dry_run {
+            println!("[Dry run] Would {}", action);
+        }
+        self.actions.push(action);
+    }
+}
+
 /// Minimum available disk space required (1 GB)
 const MIN_DISK_GB: u64 = 1;
 /// Minimum total memory required (2 GB)
 const MIN_MEMORY_GB: u64 = 2;
 
-pub fn install(config: &InstallConfig, components: &[Component]) -> Result<(), InstallError> {
+/// Install `components` as configured. With `config.dry_run` nothing is
+/// written: every action is logged and returned in the plan instead.
+pub fn install(
+    config: &InstallConfig,
+    components: &[Component],
+) -> Result<InstallPlan, InstallError> {
     // 0. Pre-installation Checks
     println!("Performing system checks...");
     let sys = check_system();
@@ -97,13 +161,22 @@ pub fn install(config: &InstallConfig, components: &[Component]) -> Result<(), I
     );
 
     let net = NetManager::new();
+    let mut plan = InstallPlan {
+        dry_run: config.dry_run,
+        actions: Vec::new(),
+    };
 
     // RAII transaction - automatically rolls back on drop if not committed
     let mut txn = InstallTransaction::new();
 
     // 1. Create install directory
     if !config.install_dir.exists() {
-        fs::create_dir_all(&config.install_dir)?;
+        if !config.dry_run {
+            fs::create_dir_all(&config.install_dir)?;
+        }
+        plan.record(PlannedAction::CreateDir {
+            path: config.install_dir.clone(),
+        });
     }
 
     // Wrap installation logic to ensure rollback on error
@@ -166,7 +239,13 @@ pub fn install(config: &InstallConfig, components: &[Component]) -> Result<(), I
                         println!("✓ Checksum verified");
                     }
 
-                    crate::extraction::extract(&local_path, &config.install_dir)?;
+                    if !config.dry_run {
+                        crate::extraction::extract(&local_path, &config.install_dir)?;
+                    }
+                    plan.record(PlannedAction::Extract {
+                        archive: local_path,
+                        target: config.install_dir.clone(),
+                    });
                     continue;
                 }
 
@@ -182,12 +261,29 @@ pub fn install(config: &InstallConfig, components: &[Component]) -> Result<(), I
                     {
                         let target_path = config.install_dir.join(&asset.name);
 
+                        if config.dry_run {
+                            plan.record(PlannedAction::Download {
+                                url: asset.browser_download_url.clone(),
+                                target: target_path.clone(),
+                                verified: checksums
+                                    .as_ref()
+                                    .is_some_and(|c| c.contains_key(&asset.name)),
+                            });
+                            plan.record(PlannedAction::Extract {
+                                archive: target_path.clone(),
+                                target: config.install_dir.clone(),
+                            });
+                            plan.record(PlannedAction::RemoveFile { path: target_path });
+                            continue;
+                        }
+
                         // Register for rollback before download completes
                         txn.track(target_path.clone());
 
                         println!("Downloading {} to {:?}...", asset.name, target_path);
 
                         // Download with verification if checksums available
+                        let mut verified = false;
                         if let Some(ref checksums) = checksums {
                             if let Some(expected_hash) = checksums.get(&asset.name) {
                                 println!("🔐 Download will be verified against SHA256SUMS");
@@ -197,6 +293,7 @@ pub fn install(config: &InstallConfig, components: &[Component]) -> Result<(), I
                                     expected_hash,
                                 )?;
                                 println!("✓ Download verified successfully");
+                                verified = true;
                             } else {
                                 println!(
                                     "⚠ No checksum found for {}, downloading unverified",
@@ -208,10 +305,20 @@ pub fn install(config: &InstallConfig, components: &[Component]) -> Result<(), I
                             // No checksums available, download without verification
                             net.download_asset(&asset.browser_download_url, &target_path)?;
                         }
+                        plan.record(PlannedAction::Download {
+                            url: asset.browser_download_url.clone(),
+                            target: target_path.clone(),
+                            verified,
+                        });
 
                         crate::extraction::extract(&target_path, &config.install_dir)?;
+                        plan.record(PlannedAction::Extract {
+                            archive: target_path.clone(),
+                            target: config.install_dir.clone(),
+                        });
                         // Clean up downloaded archive
                         let _ = fs::remove_file(&target_path);
+                        plan.record(PlannedAction::RemoveFile { path: target_path });
                         txn.untrack_last(); // Archive successfully extracted and removed
                     } else {
                         println!(
@@ -234,7 +341,11 @@ pub fn install(config: &InstallConfig, components: &[Component]) -> Result<(), I
         }
 
         // 4. Update Path (Platform specific)
-        if config.add_to_path {
+        if config.add_to_path && config.dry_run {
+            plan.record(PlannedAction::AddToPath {
+                dir: config.install_dir.clone(),
+            });
+        } else if config.add_to_path {
             #[cfg(target_os = "windows")]
             {
                 use crate::windows::add_to_path;
@@ -248,6 +359,9 @@ pub fn install(config: &InstallConfig, components: &[Component]) -> Result<(), I
                 add_to_path(&config.install_dir)
                     .map_err(|e| InstallError::Platform(e.to_string()))?;
             }
+            plan.record(PlannedAction::AddToPath {
+                dir: config.install_dir.clone(),
+            });
         }
 
         Ok(())
@@ -256,6 +370,48 @@ pub fn install(config: &InstallConfig, components: &[Component]) -> Result<(), I
     // Commit transaction on success (prevents Drop-based rollback)
     result.map(|()| {
         txn.commit();
-        println!("✅ Installation complete.");
+        if config.dry_run {
+            println!(
+                "Dry run complete: {} action(s) planned.",
+                plan.actions.len()
+            );
+        } else {
+            println!("✅ Installation complete.");
+        }
+        plan
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dry_run_plans_without_touching_filesystem() {
+        let temp = tempfile::tempdir().unwrap();
+        let install_dir = temp.path().join("ifa");
+        let config = InstallConfig {
+            install_dir: install_dir.clone(),
+            offline_mode: true,
+            dry_run: true,
+            ..Default::default()
+        };
+
+        let plan = install(&config, &[]).unwrap();
+
+        assert!(plan.dry_run);
+        assert_eq!(
+            plan.actions,
+            vec![
+                PlannedAction::CreateDir {
+                    path: install_dir.clone()
+                },
+                PlannedAction::AddToPath {
+                    dir: install_dir.clone()
+                },
+            ]
+        );
+        assert!(!install_dir.exists());
+        assert_eq!(fs::read_dir(temp.path()).unwrap().count(), 0);
+    }
+}
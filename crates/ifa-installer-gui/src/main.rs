@@ -74,6 +74,7 @@ fn run_headless(args: InstallerArgs) {
         install_dir: args
             .dir
             .unwrap_or_else(|| dirs::home_dir().unwrap().join(".ifa")),
+        dry_run: args.dry_run,
         ..Default::default()
     };
 
@@ -81,6 +82,12 @@ fn run_headless(args: InstallerArgs) {
     let components = profiles::all_components();
 
     match install::install(&config, &components) {
+        Ok(plan) if plan.dry_run => {
+            println!("Nothing was changed. Planned actions:");
+            for action in &plan.actions {
+                println!("  - {}", action);
+            }
+        }
         Ok(_) => println!("Installation complete!"),
         Err(e) => eprintln!("Installation failed: {}", e),
     }
@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Component, Path, PathBuf};
 
 /// Ọ̀fún Capability Definition
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
@@ -12,7 +12,7 @@ pub enum Ofun {
     Network { domains: Vec<String> },
     /// Execute subprocesses
     Execute { programs: Vec<String> },
-    /// Access environment variables
+    /// Access environment variables (`"*"` grants every key)
     Environment { keys: Vec<String> },
     /// High-resolution time
     Time,
@@ -24,6 +24,48 @@ pub enum Ofun {
     Bridge { language: String },
 }
 
+impl Ofun {
+    /// Read access under `root`, canonicalized now: `./src/../..` grants the
+    /// directory it names, not whatever it resolves to from a later cwd.
+    pub fn read_files(root: impl AsRef<Path>) -> Self {
+        Ofun::ReadFiles {
+            root: canonical_root(root.as_ref()),
+        }
+    }
+
+    /// Write access under `root`, canonicalized like [`Ofun::read_files`].
+    pub fn write_files(root: impl AsRef<Path>) -> Self {
+        Ofun::WriteFiles {
+            root: canonical_root(root.as_ref()),
+        }
+    }
+}
+
+/// `path` made absolute against the working directory, with symlinks and
+/// `..` resolved through the part of it that exists. The rest is resolved
+/// lexically, so a root may name a directory that isn't there yet.
+pub fn canonical_root(path: &Path) -> PathBuf {
+    let absolute = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+    let mut resolved = PathBuf::new();
+    for component in absolute.components() {
+        match component {
+            Component::CurDir => {}
+            // `resolved` is already canonical wherever it exists, so its
+            // lexical parent is its real one
+            Component::ParentDir => {
+                resolved.pop();
+            }
+            other => {
+                resolved.push(other);
+                if let Ok(real) = resolved.canonicalize() {
+                    resolved = real;
+                }
+            }
+        }
+    }
+    resolved
+}
+
 /// A set of granted capabilities
 #[derive(Debug, Clone, Default)]
 pub struct CapabilitySet {
@@ -47,19 +89,22 @@ impl CapabilitySet {
         self.capabilities.push(cap);
     }
 
-    /// Check if an operation is allowed
+    /// Check if an operation is allowed. File roots on both sides are
+    /// compared by [`canonical_root`], so a relative path is judged by where
+    /// it really is.
     pub fn check(&self, required: &Ofun) -> bool {
+        let within = |g: &Path, r: &Path| canonical_root(r).starts_with(canonical_root(g));
         self.capabilities
             .iter()
             .any(|granted| match (granted, required) {
-                (Ofun::ReadFiles { root: g }, Ofun::ReadFiles { root: r }) => r.starts_with(g),
-                (Ofun::WriteFiles { root: g }, Ofun::WriteFiles { root: r }) => r.starts_with(g),
+                (Ofun::ReadFiles { root: g }, Ofun::ReadFiles { root: r }) => within(g, r),
+                (Ofun::WriteFiles { root: g }, Ofun::WriteFiles { root: r }) => within(g, r),
                 (Ofun::Network { domains: g }, Ofun::Network { domains: r }) => {
                     // Simple exact match for now, could add globbing
                     r.iter().all(|d| g.contains(d))
                 }
                 (Ofun::Environment { keys: g }, Ofun::Environment { keys: r }) => {
-                    r.iter().all(|k| g.contains(k))
+                    g.iter().any(|k| k == "*") || r.iter().all(|k| g.contains(k))
                 }
                 (Ofun::Execute { programs: g }, Ofun::Execute { programs: r }) => {
                     r.iter().all(|p| g.contains(p))
@@ -23,7 +23,7 @@ pub mod omnibox;
 pub mod runtime;
 pub mod sandbox;
 
-pub use capability::{CapabilitySet, Ofun};
+pub use capability::{CapabilitySet, Ofun, canonical_root};
 pub use config::{SandboxConfig, SecurityProfile};
 pub use monitor::ResourceMonitor;
 pub use omnibox::OmniBox;
@@ -1,5 +1,7 @@
 use crate::{CapabilitySet, Ofun};
 use std::fmt;
+use std::io;
+use std::process::{Command, Output};
 
 /// Error when a capability check fails
 #[derive(Debug)]
@@ -41,4 +43,57 @@ impl NativeRuntime {
             })
         }
     }
+
+    /// Run `program` with `args` and wait for it to finish.
+    ///
+    /// Spawning is denied unless an `Ofun::Execute` grant lists `program`
+    /// exactly; a denied spawn fails with `ErrorKind::PermissionDenied`
+    /// without starting anything.
+    pub fn spawn(&self, program: &str, args: &[&str]) -> io::Result<Output> {
+        let required = Ofun::Execute {
+            programs: vec![program.to_string()],
+        };
+        self.check(required, program)
+            .map_err(|e| io::Error::new(io::ErrorKind::PermissionDenied, e))?;
+        Command::new(program).args(args).output()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn runtime_allowing(programs: &[&str]) -> NativeRuntime {
+        let mut caps = CapabilitySet::new();
+        caps.grant(Ofun::Execute {
+            programs: programs.iter().map(|p| p.to_string()).collect(),
+        });
+        NativeRuntime::new(caps)
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_spawn_allowlisted_program() {
+        let output = runtime_allowing(&["echo"]).spawn("echo", &["ifa"]).unwrap();
+        assert!(output.status.success());
+        assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "ifa");
+    }
+
+    #[test]
+    fn test_spawn_denied_by_default() {
+        let err = NativeRuntime::new(CapabilitySet::new())
+            .spawn("echo", &["ifa"])
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+
+        let err = runtime_allowing(&["echo"])
+            .spawn("sh", &["-c", "echo ifa"])
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+        assert!(
+            err.to_string().contains("Capability denied"),
+            "got: {}",
+            err
+        );
+    }
 }
@@ -171,3 +171,35 @@ fn test_multiple_read_paths() {
         root: PathBuf::from("/etc/passwd")
     }));
 }
+
+#[test]
+fn test_file_grants_are_canonicalized_when_created() {
+    let cwd = std::env::current_dir().unwrap();
+    let parent = cwd.parent().unwrap().canonicalize().unwrap();
+
+    // Stored as the directory it names, not as written
+    assert_eq!(
+        Ofun::read_files("./src/../.."),
+        Ofun::ReadFiles {
+            root: parent.clone()
+        }
+    );
+    assert_eq!(
+        Ofun::write_files("missing/../src"),
+        Ofun::WriteFiles {
+            root: cwd.canonicalize().unwrap().join("src")
+        }
+    );
+
+    let mut caps = CapabilitySet::new();
+    caps.grant(Ofun::read_files("./src/.."));
+    assert!(caps.check(&Ofun::ReadFiles {
+        root: PathBuf::from("Cargo.toml")
+    }));
+    assert!(caps.check(&Ofun::ReadFiles {
+        root: cwd.join("src/lib.rs")
+    }));
+    assert!(!caps.check(&Ofun::ReadFiles {
+        root: parent.join("elsewhere")
+    }));
+}
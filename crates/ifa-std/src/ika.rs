@@ -105,8 +105,15 @@ impl Ika {
     // SERIALIZATION (Native Speed)
     // =========================================================================
 
-    /// Serialize to JSON (yi_si_json)
+    /// Serialize to JSON (yi_si_json). NaN and ±Infinity have no JSON form,
+    /// so a value containing one is an error rather than lossy output.
     pub fn yi_si_json(&self, val: &IfaValue) -> IfaResult<String> {
+        if let Some(f) = val.non_finite_float() {
+            return Err(IfaError::Custom(format!(
+                "JSON error: cannot encode non-finite float {}",
+                f
+            )));
+        }
         serde_json::to_string(val).map_err(|e| IfaError::Custom(format!("JSON error: {}", e)))
     }
 
@@ -351,6 +358,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_json_encode_rejects_non_finite_floats() {
+        let ika = Ika;
+        let mut map = HashMap::new();
+        map.insert("ratio".into(), IfaValue::Float(f64::INFINITY));
+        let err = ika.encode(&IfaValue::Map(map.into())).unwrap_err();
+        assert!(
+            err.to_string().contains("non-finite float inf"),
+            "got: {}",
+            err
+        );
+
+        let nested = IfaValue::list(vec![IfaValue::Int(1), IfaValue::Float(f64::NAN)]);
+        assert!(ika.encode(&nested).is_err());
+    }
+
     #[test]
     fn test_json_serialization() {
         let ika = Ika;
@@ -372,6 +395,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_encode_map_is_order_independent() {
+        let ika = Ika;
+        let keys = ["zeta", "alpha", "mu", "beta", "omega", "kappa"];
+        let build = |order: &[&str]| {
+            let mut map = HashMap::new();
+            for key in order {
+                map.insert(key.to_string(), IfaValue::Int(key.len() as i64));
+            }
+            IfaValue::map(map)
+        };
+        let mut reversed = keys;
+        reversed.reverse();
+
+        let encoded = ika.encode(&build(&keys)).unwrap();
+        assert_eq!(encoded, ika.encode(&build(&reversed)).unwrap());
+        assert!(encoded.find("alpha").unwrap() < encoded.find("zeta").unwrap());
+        assert_eq!(ika.decode(&encoded).unwrap(), build(&keys));
+
+        // Decoding accepts entries in any order
+        let unsorted = r#"{"Map":[["b",{"Int":2}],["a",{"Int":1}]]}"#;
+        let sorted = r#"{"Map":[["a",{"Int":1}],["b",{"Int":2}]]}"#;
+        assert_eq!(ika.decode(unsorted).unwrap(), ika.decode(sorted).unwrap());
+        assert_eq!(ika.encode(&ika.decode(unsorted).unwrap()).unwrap(), sorted);
+    }
+
     #[test]
     fn test_csv_serialization() {
         let ika = Ika;
@@ -467,7 +467,7 @@ where
 // TRAIT IMPLEMENTATION (ifa-types bridge)
 // =============================================================================
 
-use ifa_types::{CpuOps, IfaError, IfaResult, IfaValue};
+use ifa_types::{CpuOps, IfaError, IfaResult, IfaValue, NumericList, overflow};
 
 impl CpuOps for CpuContext {
     fn num_threads() -> usize {
@@ -475,8 +475,17 @@ impl CpuOps for CpuContext {
     }
 
     fn par_sum(data: &[IfaValue]) -> IfaResult<IfaValue> {
-        // Note: Using sequential iteration since IfaValue doesn't implement Sync
-        // For parallel sum of primitives, convert first then use rayon
+        use rayon::prelude::*;
+        // IfaValue isn't Sync: pack all-integer input and sum it with rayon,
+        // anything else is summed sequentially
+        if let Some(NumericList::Int(ints)) = NumericList::from_values(data) {
+            return ints
+                .par_iter()
+                .try_fold(|| 0i64, |acc, &n| acc.checked_add(n))
+                .try_reduce(|| 0, i64::checked_add)
+                .map(IfaValue::Int)
+                .ok_or_else(overflow);
+        }
         let sum: i64 = data
             .iter()
             .filter_map(|v| match v {
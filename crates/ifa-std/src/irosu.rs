@@ -18,6 +18,7 @@ use crossterm::{
 };
 use ifa_core::IfaValue;
 use std::io::{self, BufRead, Write};
+use std::sync::{Arc, Mutex};
 
 use crate::sandbox_shim::{CapabilitySet, Ofun};
 
@@ -25,13 +26,35 @@ use crate::sandbox_shim::{CapabilitySet, Ofun};
 #[derive(Default)]
 pub struct Irosu {
     capabilities: CapabilitySet,
+    /// Receives `fo`/`so` output instead of stdout when set
+    captured: Option<Arc<Mutex<Vec<String>>>>,
 }
 
 impl_odu_domain!(Irosu, "Ìrosù", "1100", "The Voice - Console I/O");
 
 impl Irosu {
     pub fn new(capabilities: CapabilitySet) -> Self {
-        Irosu { capabilities }
+        Irosu {
+            capabilities,
+            captured: None,
+        }
+    }
+
+    /// Record each `fo`/`so` call as one entry of `buffer` instead of
+    /// writing it to stdout.
+    pub fn capture_into(&mut self, buffer: Arc<Mutex<Vec<String>>>) {
+        self.captured = Some(buffer);
+    }
+
+    /// Push `value` to the capture buffer; false when output is not captured.
+    fn capture(&self, value: &IfaValue) -> bool {
+        match &self.captured {
+            Some(buffer) => {
+                buffer.lock().unwrap().push(value.to_string());
+                true
+            }
+            None => false,
+        }
     }
 
     fn check(&self) -> bool {
@@ -40,14 +63,14 @@ impl Irosu {
 
     /// Print with newline (fọ̀)
     pub fn fo(&self, value: &IfaValue) {
-        if self.check() {
+        if self.check() && !self.capture(value) {
             println!("{}", value);
         }
     }
 
     /// Print without newline (sọ)
     pub fn so(&self, value: &IfaValue) {
-        if self.check() {
+        if self.check() && !self.capture(value) {
             print!("{}", value);
             io::stdout().flush().ok();
         }
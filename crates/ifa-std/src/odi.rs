@@ -179,30 +179,63 @@ impl Odi {
         // DB requires both read and write
         self.check_read(&path)?;
         self.check_write(&path)?;
-        Connection::open(&path).map_err(|e| IfaError::Custom(format!("Database error: {}", e)))
+        Connection::open(&path)
+            .map(without_attach)
+            .map_err(|e| IfaError::Custom(format!("Database error: {}", e)))
     }
 
     /// Open in-memory database
     pub fn so_db_iranti(&self) -> IfaResult<Connection> {
         // No perms needed for in-memory
-        Connection::open_in_memory().map_err(|e| IfaError::Custom(format!("Database error: {}", e)))
+        Connection::open_in_memory()
+            .map(without_attach)
+            .map_err(|e| IfaError::Custom(format!("Database error: {}", e)))
     }
 
     // =========================================================================
     // Ergonomic Aliases
     // =========================================================================
-    
-    pub fn read(&self, path: &str) -> IfaResult<String> { self.ka(path) }
-    pub fn write(&self, path: &str, content: &str) -> IfaResult<()> { self.ko(path, content) }
-    pub fn append(&self, path: &str, content: &str) -> IfaResult<()> { self.fi(path, content) }
-    pub fn exists(&self, path: &str) -> bool { self.wa(path) }
-    pub fn remove(&self, path: &str) -> IfaResult<()> { self.pa_faili(path) }
-    pub fn delete(&self, path: &str) -> IfaResult<()> { self.pa_faili(path) }
-    pub fn mkdir(&self, path: &str) -> IfaResult<()> { self.seda_apoti(path) }
-    pub fn ls(&self, path: &str) -> IfaResult<Vec<String>> { self.akojo(path) }
-    pub fn list(&self, path: &str) -> IfaResult<Vec<String>> { self.akojo(path) }
-    pub fn size(&self, path: &str) -> IfaResult<u64> { self.iwon(path) }
-    pub fn open_db(&self, path: &str) -> IfaResult<Connection> { self.so_db(path) }
+
+    pub fn read(&self, path: &str) -> IfaResult<String> {
+        self.ka(path)
+    }
+    pub fn write(&self, path: &str, content: &str) -> IfaResult<()> {
+        self.ko(path, content)
+    }
+    pub fn append(&self, path: &str, content: &str) -> IfaResult<()> {
+        self.fi(path, content)
+    }
+    pub fn exists(&self, path: &str) -> bool {
+        self.wa(path)
+    }
+    pub fn remove(&self, path: &str) -> IfaResult<()> {
+        self.pa_faili(path)
+    }
+    pub fn delete(&self, path: &str) -> IfaResult<()> {
+        self.pa_faili(path)
+    }
+    pub fn mkdir(&self, path: &str) -> IfaResult<()> {
+        self.seda_apoti(path)
+    }
+    pub fn ls(&self, path: &str) -> IfaResult<Vec<String>> {
+        self.akojo(path)
+    }
+    pub fn list(&self, path: &str) -> IfaResult<Vec<String>> {
+        self.akojo(path)
+    }
+    pub fn size(&self, path: &str) -> IfaResult<u64> {
+        self.iwon(path)
+    }
+    pub fn open_db(&self, path: &str) -> IfaResult<Connection> {
+        self.so_db(path)
+    }
+}
+
+/// Stop `conn` from ATTACHing other database files, which would reach paths
+/// the capability check on the opened file never saw.
+fn without_attach(conn: Connection) -> Connection {
+    conn.set_limit(rusqlite::limits::Limit::SQLITE_LIMIT_ATTACHED, 0);
+    conn
 }
 
 #[cfg(test)]
@@ -240,6 +273,18 @@ mod tests {
         assert!(content.contains("More text"));
     }
 
+    #[test]
+    fn test_in_memory_db_cannot_attach_files() {
+        let dir = tempdir().unwrap();
+        let target = dir.path().join("escaped.db");
+        let odi = Odi::new(CapabilitySet::default());
+        let conn = odi.so_db_iranti().unwrap();
+
+        let sql = format!("ATTACH DATABASE '{}' AS other", target.display());
+        assert!(conn.execute(&sql, []).is_err());
+        assert!(!target.exists());
+    }
+
     #[test]
     fn test_sandbox() {
         let dir = tempdir().unwrap();
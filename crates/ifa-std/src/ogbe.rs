@@ -5,6 +5,7 @@
 //! CLI arguments, environment, and program initialization.
 
 use crate::impl_odu_domain;
+use std::collections::BTreeMap;
 use std::env;
 
 use crate::sandbox_shim::{CapabilitySet, Ofun};
@@ -23,7 +24,7 @@ impl Ogbe {
     }
 
     /// Check env capability
-    fn check_env(&self, key: &str) -> bool {
+    pub(crate) fn check_env(&self, key: &str) -> bool {
         self.capabilities.check(&Ofun::Environment {
             keys: vec![key.to_string()],
         })
@@ -60,6 +61,17 @@ impl Ogbe {
         env::var(key).unwrap_or_else(|_| default.to_string())
     }
 
+    /// Get every environment variable whose key was granted (env_all)
+    ///
+    /// Keys outside the `Ofun::Environment` grant are left out, so a
+    /// sandboxed program can't enumerate variables it wasn't given.
+    pub fn ayika_gbogbo(&self) -> BTreeMap<String, String> {
+        env::vars_os()
+            .filter_map(|(key, value)| Some((key.into_string().ok()?, value.into_string().ok()?)))
+            .filter(|(key, _)| self.check_env(key))
+            .collect()
+    }
+
     /// Set environment variable (fí àyíká)
     ///
     /// WARNING: This operation is deprecated and does nothing.
@@ -125,4 +137,29 @@ mod tests {
         assert!(!ogbe.eto().is_empty());
         assert!(!ogbe.apẹrẹ().is_empty());
     }
+
+    #[cfg(feature = "ifa-sandbox")]
+    #[test]
+    fn test_ayika_gbogbo_only_lists_granted_keys() {
+        let all: BTreeMap<String, String> = env::vars_os()
+            .filter_map(|(k, v)| Some((k.into_string().ok()?, v.into_string().ok()?)))
+            .collect();
+        let granted: Vec<String> = all.keys().take(2).cloned().collect();
+
+        let ogbe = Ogbe::new(CapabilitySet::new());
+        assert!(ogbe.ayika_gbogbo().is_empty());
+
+        let mut caps = CapabilitySet::new();
+        caps.grant(Ofun::Environment {
+            keys: granted.clone(),
+        });
+        let listed = Ogbe::new(caps).ayika_gbogbo();
+        assert_eq!(listed.keys().cloned().collect::<Vec<_>>(), granted);
+
+        let mut caps = CapabilitySet::new();
+        caps.grant(Ofun::Environment {
+            keys: vec!["*".into()],
+        });
+        assert_eq!(Ogbe::new(caps).ayika_gbogbo(), all);
+    }
 }
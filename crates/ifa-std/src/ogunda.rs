@@ -5,6 +5,7 @@
 //! Vec with iterators and subprocess spawning via tokio-process.
 
 use crate::impl_odu_domain;
+use crate::sandbox_shim::{CapabilitySet, Ofun};
 use ifa_core::error::{IfaError, IfaResult};
 use ifa_core::value::IfaValue;
 use std::process::{Command, Output, Stdio};
@@ -189,6 +190,40 @@ impl Ogunda {
     pub fn remove(&self, map: &mut IfaValue, key: &str) -> IfaResult<IfaValue> {
         self.yo(map, key)
     }
+
+    /// Get environment variable
+    pub fn ayika(&self, key: &str) -> Option<String> {
+        std::env::var(key).ok()
+    }
+}
+
+/// Ògúndá's process spawning, allowed only for programs an `Execute` grant
+/// lists
+#[derive(Default)]
+pub struct OgundaProcess {
+    capabilities: CapabilitySet,
+}
+
+impl OgundaProcess {
+    /// Create with capabilities
+    pub fn new(capabilities: CapabilitySet) -> Self {
+        OgundaProcess { capabilities }
+    }
+
+    /// Check that an `Execute` grant lists `command`
+    fn check_execute(&self, command: &str) -> IfaResult<()> {
+        if self.capabilities.check(&Ofun::Execute {
+            programs: vec![command.to_string()],
+        }) {
+            Ok(())
+        } else {
+            Err(IfaError::PermissionDenied(format!(
+                "Execute permission denied for: {}",
+                command
+            )))
+        }
+    }
+
     // =========================================================================
 
     /// Dangerous shell metacharacters that could enable injection
@@ -274,6 +309,7 @@ impl Ogunda {
     pub fn sise(&self, command: &str, args: &[&str]) -> IfaResult<Output> {
         Self::validate_command(command)?;
         Self::validate_args(args)?;
+        self.check_execute(command)?;
 
         Command::new(command)
             .args(args)
@@ -292,6 +328,7 @@ impl Ogunda {
     pub fn bere(&self, command: &str, args: &[&str]) -> IfaResult<u32> {
         Self::validate_command(command)?;
         Self::validate_args(args)?;
+        self.check_execute(command)?;
 
         let child = Command::new(command)
             .args(args)
@@ -302,11 +339,6 @@ impl Ogunda {
             .map_err(|e| IfaError::Custom(format!("Spawn error: {}", e)))?;
         Ok(child.id())
     }
-
-    /// Get environment variable
-    pub fn ayika(&self, key: &str) -> Option<String> {
-        std::env::var(key).ok()
-    }
 }
 
 #[cfg(test)]
@@ -370,4 +402,30 @@ mod tests {
         assert_eq!(keys_after.len(), 1);
         assert_eq!(keys_after[0], "b");
     }
+
+    #[cfg(feature = "ifa-sandbox")]
+    #[test]
+    fn test_process_spawning_needs_execute_grant() {
+        let denied = OgundaProcess::default();
+        assert!(matches!(
+            denied.sise("echo", &["ifa"]),
+            Err(IfaError::PermissionDenied(_))
+        ));
+        assert!(matches!(
+            denied.bere("echo", &["ifa"]),
+            Err(IfaError::PermissionDenied(_))
+        ));
+
+        let mut caps = CapabilitySet::default();
+        caps.grant(Ofun::Execute {
+            programs: vec!["echo".to_string()],
+        });
+        let granted = OgundaProcess::new(caps);
+        assert!(matches!(
+            granted.sise("ls", &[]),
+            Err(IfaError::PermissionDenied(_))
+        ));
+        #[cfg(unix)]
+        assert_eq!(granted.sise_ka("echo", &["ifa"]).unwrap().trim(), "ifa");
+    }
 }
@@ -31,6 +31,7 @@ pub mod stub {
         Stdio,
         Random,
         Environment { keys: Vec<String> },
+        Execute { programs: Vec<String> },
     }
 }
 
@@ -3,18 +3,26 @@
 //! Bridges the ifa-std domain structs to the VM's OduRegistry trait,
 //! enabling `CallOdu` opcodes to dispatch to the standard library.
 
+use std::cmp::Ordering;
+use std::sync::{Arc, Mutex};
+
 use ifa_core::IfaValue;
-use ifa_core::error::{IfaError, IfaResult};
+use ifa_core::error::{IfaError, IfaResult, overflow};
+use ifa_core::interpreter::handlers::{ObaraHandler, ogunda_reduce, opon_report};
 use ifa_core::native::{OduRegistry, VmContext};
 
 use crate::irosu::Irosu;
 use crate::odi::Odi;
-use crate::sandbox_shim::CapabilitySet;
+use crate::ogbe::Ogbe;
+use crate::sandbox_shim::{CapabilitySet, Ofun};
 
 /// Standard library registry for the bytecode VM.
 pub struct StdRegistry {
     irosu: Irosu,
+    ogbe: Ogbe,
     odi: Odi,
+    /// Where Ìrosù output goes instead of stdout, if anywhere
+    captured: Option<Arc<Mutex<Vec<String>>>>,
 }
 
 impl StdRegistry {
@@ -22,13 +30,26 @@ impl StdRegistry {
         let caps = CapabilitySet::new();
         Self {
             irosu: Irosu::new(caps.clone()),
+            ogbe: Ogbe::new(caps.clone()),
             odi: Odi::new(caps),
+            captured: None,
         }
     }
 
     pub fn set_capabilities(&mut self, caps: CapabilitySet) {
         self.irosu = Irosu::new(caps.clone());
+        self.ogbe = Ogbe::new(caps.clone());
         self.odi = Odi::new(caps);
+        if let Some(buffer) = &self.captured {
+            self.irosu.capture_into(buffer.clone());
+        }
+    }
+
+    /// Record each Ìrosù `fo`/`so` call in `buffer` instead of printing it,
+    /// the way the interpreter's captured output sink does.
+    pub fn capture_output(&mut self, buffer: Arc<Mutex<Vec<String>>>) {
+        self.irosu.capture_into(buffer.clone());
+        self.captured = Some(buffer);
     }
 }
 
@@ -47,7 +68,7 @@ impl OduRegistry for StdRegistry {
         ctx: &mut VmContext,
     ) -> IfaResult<IfaValue> {
         match domain_id {
-            0 => dispatch_ogbe(method_name, args),
+            0 => self.dispatch_ogbe(method_name, args),
             1 => dispatch_oyeku(method_name, args),
             2 => dispatch_iwori(method_name, args),
             3 => self.dispatch_odi(method_name, args),
@@ -55,12 +76,13 @@ impl OduRegistry for StdRegistry {
             5 => dispatch_owonrin(method_name, args),
             6 => dispatch_obara(method_name, args),
             7 => dispatch_okanran(method_name, args),
-            8 => dispatch_ogunda(method_name, args),
+            8 => dispatch_ogunda(method_name, args, ctx),
             9 => dispatch_osa(method_name, args, ctx),
             10 => dispatch_ika(method_name, args),
             11 => dispatch_oturupon(method_name, args),
             14 => dispatch_ose(method_name, args),
             15 => dispatch_ofun(method_name, args),
+            30 => opon_report(ctx.vm.opon_size, method_name, &ctx.vm.live_values()),
             _ => Err(IfaError::Custom(format!(
                 "Unknown Odù domain ID: {}",
                 domain_id
@@ -106,6 +128,7 @@ impl OduRegistry for StdRegistry {
             "ohun" => 27,
             "fidio" => 28,
             "sys" => 29,
+            "opon" => 30,
             _ => return Err(IfaError::Custom(format!("Unknown std module: {}", name))),
         };
         Ok(IfaValue::str(format!("__odu_mod__:{id}")))
@@ -113,6 +136,37 @@ impl OduRegistry for StdRegistry {
 }
 
 impl StdRegistry {
+    fn dispatch_ogbe(&self, method: &str, args: Vec<IfaValue>) -> IfaResult<IfaValue> {
+        match method {
+            "bere" | "version" => Ok(IfaValue::str("Ifá-Lang v1.2.2")),
+            "ayika" | "env" => match args.first() {
+                Some(IfaValue::Str(key)) if self.ogbe.check_env(key) => Ok(self
+                    .ogbe
+                    .ayika(key)
+                    .map(IfaValue::str)
+                    .unwrap_or(IfaValue::Null)),
+                Some(IfaValue::Str(key)) => Err(IfaError::PermissionDenied(format!(
+                    "Capability denied: {:?}",
+                    Ofun::Environment {
+                        keys: vec![key.to_string()],
+                    }
+                ))),
+                _ => Err(IfaError::ArgumentError("Ogbe.env requires key".into())),
+            },
+            "ayika_gbogbo" | "env_all" => Ok(IfaValue::map(
+                self.ogbe
+                    .ayika_gbogbo()
+                    .into_iter()
+                    .map(|(key, value)| (key.into(), IfaValue::str(value)))
+                    .collect(),
+            )),
+            _ => Err(IfaError::Custom(format!(
+                "Ogbe: unknown method '{}'",
+                method
+            ))),
+        }
+    }
+
     fn dispatch_irosu(&self, method: &str, args: Vec<IfaValue>) -> IfaResult<IfaValue> {
         match method {
             "fo" | "println" => {
@@ -273,7 +327,31 @@ fn dispatch_obara(method: &str, args: Vec<IfaValue>) -> IfaResult<IfaValue> {
         "fikun" | "add" | "plus" => Ok(IfaValue::float(a + b)),
         "isodipupo" | "mul" | "times" => Ok(IfaValue::float(a * b)),
         "agbara" | "pow" => Ok(IfaValue::float(a.powf(b))),
-        "gbongbo" | "sqrt" => Ok(IfaValue::float(a.sqrt())),
+        "gbongbo" | "sqrt" => {
+            let n = ObaraHandler::number_arg(method, args.first())?;
+            if n < 0.0 {
+                return Err(IfaError::Runtime(format!(
+                    "[Obara.sqrt] sqrt of negative number {n}"
+                )));
+            }
+            Ok(IfaValue::float(n.sqrt()))
+        }
+        "abs" => match args.first() {
+            Some(IfaValue::Int(n)) => n.checked_abs().map(IfaValue::int).ok_or_else(overflow),
+            arg => Ok(IfaValue::float(
+                ObaraHandler::number_arg(method, arg)?.abs(),
+            )),
+        },
+        "sin" | "cos" | "tan" => {
+            let n = ObaraHandler::number_arg(method, args.first())?;
+            Ok(IfaValue::float(match method {
+                "sin" => n.sin(),
+                "cos" => n.cos(),
+                _ => n.tan(),
+            }))
+        }
+        "max" => ObaraHandler::extreme(method, &args, Ordering::Greater),
+        "min" => ObaraHandler::extreme(method, &args, Ordering::Less),
         _ => Err(IfaError::Custom(format!(
             "Obara: unknown method '{}'",
             method
@@ -394,8 +472,15 @@ fn dispatch_owonrin(method: &str, args: Vec<IfaValue>) -> IfaResult<IfaValue> {
     }
 }
 
-fn dispatch_ogunda(method: &str, mut args: Vec<IfaValue>) -> IfaResult<IfaValue> {
+fn dispatch_ogunda(
+    method: &str,
+    mut args: Vec<IfaValue>,
+    ctx: &mut VmContext,
+) -> IfaResult<IfaValue> {
     match method {
+        "dinku" | "reduce" => {
+            ogunda_reduce(args, |func, args| ctx.call_function(func.clone(), args))
+        }
         "iwọn" | "len" | "count" | "apapo" => {
             if let Some(IfaValue::List(list)) = args.first() {
                 Ok(IfaValue::int(list.len() as i64))
@@ -405,7 +490,9 @@ fn dispatch_ogunda(method: &str, mut args: Vec<IfaValue>) -> IfaResult<IfaValue>
         }
         "fi" | "push" | "append" => {
             if args.len() < 2 {
-                return Err(IfaError::ArgumentError("push/fi expects (list, item)".into()));
+                return Err(IfaError::ArgumentError(
+                    "push/fi expects (list, item)".into(),
+                ));
             }
             let val = args[1].clone();
             if let IfaValue::List(ref mut list_arc) = args[0] {
@@ -413,7 +500,10 @@ fn dispatch_ogunda(method: &str, mut args: Vec<IfaValue>) -> IfaResult<IfaValue>
                 vec.push(val);
                 Ok(IfaValue::Null)
             } else {
-                Err(IfaError::TypeError { expected: "List".into(), got: args[0].type_name().into() })
+                Err(IfaError::TypeError {
+                    expected: "List".into(),
+                    got: args[0].type_name().into(),
+                })
             }
         }
         "mu" | "pop" => {
@@ -421,7 +511,10 @@ fn dispatch_ogunda(method: &str, mut args: Vec<IfaValue>) -> IfaResult<IfaValue>
                 let vec = std::sync::Arc::make_mut(list_arc);
                 Ok(vec.pop().unwrap_or(IfaValue::Null))
             } else {
-                Err(IfaError::TypeError { expected: "List".into(), got: args[0].type_name().into() })
+                Err(IfaError::TypeError {
+                    expected: "List".into(),
+                    got: args[0].type_name().into(),
+                })
             }
         }
         _ => Err(IfaError::Custom(format!(
@@ -431,16 +524,6 @@ fn dispatch_ogunda(method: &str, mut args: Vec<IfaValue>) -> IfaResult<IfaValue>
     }
 }
 
-fn dispatch_ogbe(method: &str, _args: Vec<IfaValue>) -> IfaResult<IfaValue> {
-    match method {
-        "bere" | "version" => Ok(IfaValue::str("Ifá-Lang v1.2.2")),
-        _ => Err(IfaError::Custom(format!(
-            "Ogbe: unknown method '{}'",
-            method
-        ))),
-    }
-}
-
 fn dispatch_oyeku(method: &str, args: Vec<IfaValue>) -> IfaResult<IfaValue> {
     match method {
         "jade" | "exit" | "quit" | "halt" => {
@@ -477,8 +560,9 @@ fn dispatch_oyeku(method: &str, args: Vec<IfaValue>) -> IfaResult<IfaValue> {
     }
 }
 
-fn dispatch_iwori(method: &str, _args: Vec<IfaValue>) -> IfaResult<IfaValue> {
+fn dispatch_iwori(method: &str, args: Vec<IfaValue>) -> IfaResult<IfaValue> {
     match method {
+        "laarin" | "range" => Ok(IfaValue::range(ifa_types::IntRange::from_args(&args)?)),
         "bayi" | "now" | "current" => {
             let now = std::time::SystemTime::now()
                 .duration_since(std::time::UNIX_EPOCH)
@@ -487,7 +571,7 @@ fn dispatch_iwori(method: &str, _args: Vec<IfaValue>) -> IfaResult<IfaValue> {
             Ok(IfaValue::int(now))
         }
         "akoko" | "timestamp" => {
-             let now = std::time::SystemTime::now()
+            let now = std::time::SystemTime::now()
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap_or_default()
                 .as_secs() as i64;
@@ -508,14 +592,33 @@ fn dispatch_okanran(method: &str, args: Vec<IfaValue>) -> IfaResult<IfaValue> {
             if val.is_truthy() {
                 Ok(IfaValue::bool(true))
             } else {
-                let msg = args.get(1).map(|v| v.to_string()).unwrap_or_else(|| "Assertion failed".into());
+                let msg = args
+                    .get(1)
+                    .map(|v| v.to_string())
+                    .unwrap_or_else(|| "Assertion failed".into());
                 Err(IfaError::Runtime(format!("[Okanran.assert] {msg}")))
             }
         }
-        "kigbe" | "throw" | "panic" | "raise" => {
-            let msg = args.first().map(|v| v.to_string()).unwrap_or_else(|| "Manually triggered error".into());
+        "kigbe" | "throw" | "raise" => {
+            let msg = args
+                .first()
+                .map(|v| v.to_string())
+                .unwrap_or_else(|| "Manually triggered error".into());
             Err(IfaError::Runtime(format!("[Okanran.throw] {msg}")))
         }
+        "jagun" | "panic" => {
+            let msg = args
+                .first()
+                .map(|v| v.to_string())
+                .unwrap_or_else(|| "Panic!".into());
+            Err(IfaError::Panic(msg))
+        }
+        "otitọ" | "truthy" => match args.first() {
+            Some(v) => Ok(IfaValue::bool(v.is_truthy())),
+            None => Err(IfaError::Runtime(
+                "[Okanran.truthy] requires a value".into(),
+            )),
+        },
         _ => Err(IfaError::Custom(format!(
             "Okanran: unknown method '{}'",
             method
@@ -524,7 +627,18 @@ fn dispatch_okanran(method: &str, args: Vec<IfaValue>) -> IfaResult<IfaValue> {
 }
 
 fn dispatch_ose(method: &str, args: Vec<IfaValue>) -> IfaResult<IfaValue> {
-    crate::ose::Ose::dispatch(method, args)
+    #[cfg(feature = "game")]
+    {
+        crate::ose::Ose::dispatch(method, args)
+    }
+    #[cfg(not(feature = "game"))]
+    {
+        let _ = args;
+        Err(IfaError::Runtime(format!(
+            "Ose.{} requires the 'game' build; rebuild with --features game",
+            method
+        )))
+    }
 }
 
 fn dispatch_ofun(method: &str, _args: Vec<IfaValue>) -> IfaResult<IfaValue> {
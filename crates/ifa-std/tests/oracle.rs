@@ -0,0 +1,97 @@
+//! Interpreter/VM differential tests
+//!
+//! Runs each program on the tree-walking interpreter and on the bytecode VM
+//! with the real standard library registry, as `ifa run` and `ifa runb` do.
+
+use std::sync::{Arc, Mutex};
+
+use ifa_core::IfaValue;
+use ifa_core::native::OduRegistry;
+use ifa_core::oracle::{run_cases, run_interpreter, run_vm};
+use ifa_std::sandbox_shim::{CapabilitySet, Ofun};
+use ifa_std::vm_registry::StdRegistry;
+
+fn std_registry(output: Arc<Mutex<Vec<String>>>) -> Box<dyn OduRegistry> {
+    let mut caps = CapabilitySet::new();
+    caps.grant(Ofun::Stdio);
+    let mut registry = StdRegistry::new();
+    registry.set_capabilities(caps);
+    registry.capture_output(output);
+    Box::new(registry)
+}
+
+#[test]
+fn test_interpreter_and_vm_agree() {
+    run_cases(
+        &[
+            ("pada 2 + 3 * 4;", IfaValue::Int(14)),
+            ("pada 7 - 10;", IfaValue::Int(-3)),
+            ("pada 1.5 + 2;", IfaValue::Float(3.5)),
+            (
+                r#"ayanmo s = "Ifá"; s += "-Lang"; pada s;"#,
+                IfaValue::str("Ifá-Lang"),
+            ),
+            (
+                r#"ayanmo name = "Ọ̀rúnmìlà"; Irosu.fo(name); pada Ika.gigun(name);"#,
+                IfaValue::Int(9),
+            ),
+            (
+                "ayanmo i = 0; ayanmo sum = 0; nigba i < 5 { sum = sum + i; i = i + 1; } pada sum;",
+                IfaValue::Int(10),
+            ),
+            (
+                "ese square(x) { pada x * x; } Irosu.fo(square(3)); pada square(4);",
+                IfaValue::Int(16),
+            ),
+            (
+                "ese fact(n) { ti n < 2 { pada 1; } pada n * fact(n - 1); } pada fact(10);",
+                IfaValue::Int(3628800),
+            ),
+        ],
+        std_registry,
+    );
+}
+
+#[test]
+#[should_panic(expected = "INTERPRETER/VM DIVERGENCE")]
+fn test_run_cases_reports_divergence() {
+    // The VM's Ọ̀bàrà always adds as floats, where the interpreter keeps ints
+    run_cases(
+        &[("pada Obara.fikun(1, 2);", IfaValue::Int(3))],
+        std_registry,
+    );
+}
+
+#[test]
+fn test_divergent_output_is_caught() {
+    // The VM's Ìrosù prints only its first argument
+    let source = r#"Irosu.fo("a", "b");"#;
+    let output = Arc::new(Mutex::new(Vec::new()));
+    let vm = run_vm(source, std_registry(output.clone()), &output);
+    let interp = run_interpreter(source);
+    assert_eq!(interp.output, vec!["a b".to_string()]);
+    assert_eq!(vm.output, vec!["a".to_string()]);
+    assert!(!interp.agrees_with(&vm));
+}
+
+#[test]
+fn test_obara_keeps_ints_and_rejects_non_numbers() {
+    run_cases(
+        &[
+            ("pada Obara.abs(-3);", IfaValue::Int(3)),
+            ("pada Obara.max([1, 4, 2]);", IfaValue::Int(4)),
+            ("pada Obara.min(3, 1.5);", IfaValue::Float(1.5)),
+        ],
+        std_registry,
+    );
+    for source in [
+        r#"pada Obara.sqrt("nine");"#,
+        r#"pada Obara.max([1, "x"]);"#,
+        r#"pada Obara.sin(nil);"#,
+    ] {
+        let output = Arc::new(Mutex::new(Vec::new()));
+        let vm = run_vm(source, std_registry(output.clone()), &output);
+        assert!(vm.value.is_err(), "{source} should fail on the VM");
+        assert!(run_interpreter(source).agrees_with(&vm), "{source}");
+    }
+}
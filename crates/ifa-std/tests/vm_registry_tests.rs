@@ -0,0 +1,129 @@
+//! Integration tests for StdRegistry
+//!
+//! Compiles small programs and runs them on the bytecode VM with the
+//! standard library attached, the way `ifa runb` does.
+
+use ifa_core::IfaValue;
+use ifa_core::compiler::Compiler;
+use ifa_core::error::IfaResult;
+use ifa_core::parser::parse;
+use ifa_core::vm::IfaVM;
+use ifa_std::sandbox_shim::{CapabilitySet, Ofun};
+use ifa_std::vm_registry::StdRegistry;
+
+fn run_vm(source: &str) -> IfaResult<IfaValue> {
+    run_vm_with(source, CapabilitySet::new())
+}
+
+fn run_vm_with(source: &str, caps: CapabilitySet) -> IfaResult<IfaValue> {
+    let program = parse(source).expect("parse failed");
+    let bytecode = Compiler::new("vm_registry_tests")
+        .compile(&program)
+        .expect("compile failed");
+    let mut registry = StdRegistry::new();
+    registry.set_capabilities(caps);
+    let mut vm = IfaVM::new().with_registry(Box::new(registry));
+    vm.execute(&bytecode)
+}
+
+#[test]
+fn test_ogunda_reduce_calls_back_into_the_vm() {
+    let got = run_vm(
+        r#"
+        ese add(acc, x) { pada acc + x; }
+        pada Ogunda.reduce([1, 2, 3, 4], add, 10);
+        "#,
+    )
+    .expect("vm failed");
+    assert_eq!(got, IfaValue::Int(20));
+}
+
+#[test]
+fn test_ogunda_reduce_rejects_non_function() {
+    let err = run_vm("pada Ogunda.reduce([1, 2], 5, 0);").unwrap_err();
+    assert!(err.to_string().contains("Function"), "{err}");
+}
+
+#[test]
+fn test_ogbe_env_all_lists_only_granted_keys() {
+    let err = run_vm(r#"pada Ogbe.env("CARGO_MANIFEST_DIR");"#).unwrap_err();
+    assert!(err.to_string().contains("Capability denied"), "{err}");
+
+    let mut caps = CapabilitySet::new();
+    caps.grant(Ofun::Environment {
+        keys: vec!["CARGO_MANIFEST_DIR".into()],
+    });
+    let got = run_vm_with(
+        r#"
+        ayanmo all = Ogbe.env_all();
+        pada [Ogbe.env("CARGO_MANIFEST_DIR"), all["CARGO_MANIFEST_DIR"]];
+        "#,
+        caps,
+    )
+    .expect("vm failed");
+    let dir = IfaValue::str(env!("CARGO_MANIFEST_DIR"));
+    assert_eq!(got, IfaValue::list(vec![dir.clone(), dir]));
+}
+
+#[test]
+fn test_opon_usage_reports_against_the_declared_size() {
+    let got = run_vm(
+        r#"
+        opon kekere;
+        iba { usage } from std.opon;
+        ayanmo lo = usage;
+        ayanmo xs = [1, 2, 3];
+        ayanmo before = lo();
+        ayanmo ys = xs;
+        ayanmo after = Opon.usage();
+        pada [after["total_slots"], after["used_slots"] - before["used_slots"]];
+        "#,
+    )
+    .expect("vm failed");
+    let IfaValue::List(items) = got else {
+        panic!("expected a list, got {got:?}");
+    };
+    assert_eq!(items[0], IfaValue::Int(256));
+    // `ys` shares `xs`'s list, so only the 4 slots of `before` are new
+    assert_eq!(items[1], IfaValue::Int(4));
+}
+
+#[test]
+fn test_iwori_range_stays_lazy_in_variables_and_arguments() {
+    let run = |source: &str| {
+        let program = parse(source).expect("parse failed");
+        let bytecode = Compiler::new("vm_registry_tests")
+            .compile(&program)
+            .expect("compile failed");
+        let mut vm = IfaVM::new().with_registry(Box::new(StdRegistry::new()));
+        vm.set_collection_limit(10);
+        vm.execute(&bytecode)
+    };
+
+    let got = run(r#"
+        ese total(xs) {
+            ayanmo sum = 0;
+            fun i ninu xs { sum = sum + i; }
+            pada sum;
+        }
+        ayanmo big = Iwori.range(0, 100000);
+        pada [total(big), big[99999], total(Iwori.range(3, 0, -1)), Iwori.range(5, 0)];
+        "#)
+    .expect("vm failed");
+    assert_eq!(
+        got,
+        IfaValue::list(vec![
+            IfaValue::Int(4_999_950_000),
+            IfaValue::Int(99_999),
+            IfaValue::Int(6),
+            IfaValue::list(vec![]),
+        ])
+    );
+
+    // Handing a range to the standard library builds it, within the limit
+    let err = run("Irosu.fo(Iwori.range(0, 1000000));").unwrap_err();
+    assert!(
+        err.to_string().contains("collection size limit exceeded"),
+        "{err}"
+    );
+}
@@ -18,6 +18,19 @@ pub struct Span {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Program {
     pub statements: Vec<Statement>,
+    /// Optional `oriki { ... }` manifest directive
+    #[serde(default)]
+    pub metadata: Option<ProgramMetadata>,
+}
+
+/// Program-level metadata declared with `oriki { name: "app", version: "1.0", domains: [otura] }`
+/// Yoruba: oríkì (praise name) - the identity a program declares for itself
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProgramMetadata {
+    pub name: Option<String>,
+    pub version: Option<String>,
+    /// Odù domains the program declares it requires
+    pub domains: Vec<OduDomain>,
 }
 
 /// Visibility level for fields, functions, and classes
@@ -80,6 +93,9 @@ pub enum Statement {
         name: String,
         visibility: Visibility,
         body: Vec<Statement>,
+        /// `///` doc comment directly above the definition
+        #[serde(default)]
+        doc: Option<String>,
         span: Span,
     },
 
@@ -90,6 +106,9 @@ pub enum Statement {
         params: Vec<Param>,
         body: Vec<Statement>,
         is_async: bool,
+        /// `///` doc comment directly above the definition
+        #[serde(default)]
+        doc: Option<String>,
         span: Span,
     },
 
@@ -101,10 +120,12 @@ pub enum Statement {
         span: Span,
     },
 
-    /// While loop
+    /// While loop, optionally labeled: ami outer: nigba x < 3 { }
     While {
         condition: Expression,
         body: Vec<Statement>,
+        #[serde(default)]
+        label: Option<String>,
         span: Span,
     },
 
@@ -113,6 +134,8 @@ pub enum Statement {
         var: String,
         iterable: Expression,
         body: Vec<Statement>,
+        #[serde(default)]
+        label: Option<String>,
         span: Span,
     },
 
@@ -122,6 +145,13 @@ pub enum Statement {
         span: Span,
     },
 
+    /// Break out of the innermost loop, or the one labeled `label`: fọ́ outer;
+    Break { label: Option<String>, span: Span },
+
+    /// Skip to the next iteration of the innermost loop, or the one labeled
+    /// `label`: tesiwaju outer;
+    Continue { label: Option<String>, span: Span },
+
     /// End statement: ase;
     Ase { span: Span },
 
@@ -189,10 +219,45 @@ pub enum Statement {
     },
 }
 
+impl Statement {
+    /// Where the statement starts in the source.
+    pub fn span(&self) -> &Span {
+        match self {
+            Statement::VarDecl { span, .. }
+            | Statement::Assignment { span, .. }
+            | Statement::Import { span, .. }
+            | Statement::Const { span, .. }
+            | Statement::Instruction { span, .. }
+            | Statement::OduDef { span, .. }
+            | Statement::EseDef { span, .. }
+            | Statement::If { span, .. }
+            | Statement::While { span, .. }
+            | Statement::For { span, .. }
+            | Statement::Return { span, .. }
+            | Statement::Break { span, .. }
+            | Statement::Continue { span, .. }
+            | Statement::Ase { span }
+            | Statement::Taboo { span, .. }
+            | Statement::Ewo { span, .. }
+            | Statement::Opon { span, .. }
+            | Statement::Ebo { span, .. }
+            | Statement::Update { span, .. }
+            | Statement::Match { span, .. }
+            | Statement::Expr { span, .. }
+            | Statement::Ailewu { span, .. }
+            | Statement::Yield { span, .. }
+            | Statement::Try { span, .. } => span,
+        }
+    }
+}
+
 /// Match arm: pattern => body
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MatchArm {
     pub pattern: MatchPattern,
+    /// Optional `ti <cond>` guard, checked with the pattern's bindings in scope
+    #[serde(default)]
+    pub guard: Option<Expression>,
     pub body: Vec<Statement>,
 }
 
@@ -208,6 +273,14 @@ pub enum MatchPattern {
     },
     /// Wildcard pattern: _
     Wildcard,
+    /// List pattern: [1, x] or [head, ...tail]
+    List {
+        elements: Vec<MatchPattern>,
+        /// Name bound to the remaining elements (`...tail`)
+        rest: Option<String>,
+    },
+    /// Name bound to the matched element (inside list patterns)
+    Binding(String),
 }
 
 /// Assignment target
@@ -356,6 +429,9 @@ pub enum Expression {
     /// String literal
     String(String),
 
+    /// Byte string literal (`b"\x01\xFF"`)
+    Bytes(Vec<u8>),
+
     /// Boolean literal
     Bool(bool),
 
@@ -419,11 +495,9 @@ pub enum Expression {
     /// §12.3: If `expr` evaluates to an error value, immediately return it from
     /// the enclosing function. Otherwise, unwrap and yield the Ok value.
     Try(Box<Expression>),
-    
+
     /// Interpolated string literal: $"Count: {x}"
-    InterpolatedString {
-        parts: Vec<InterpolatedPart>,
-    },
+    InterpolatedString { parts: Vec<InterpolatedPart> },
 }
 
 /// A part of an interpolated string
@@ -439,10 +513,22 @@ pub struct OduCall {
     pub domain: OduDomain,
     pub method: String,
     pub args: Vec<Expression>,
+    /// `name: value` arguments, after all positional ones
+    #[serde(default)]
+    pub named_args: Vec<(String, Expression)>,
     pub is_optional: bool,
     pub span: Span,
 }
 
+impl OduCall {
+    /// Positional then named argument expressions, in source order.
+    pub fn all_args(&self) -> impl Iterator<Item = &Expression> {
+        self.args
+            .iter()
+            .chain(self.named_args.iter().map(|(_, a)| a))
+    }
+}
+
 /// Binary operators
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum BinaryOperator {
@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 
 /// The 16 Odù domains + Infrastructure + Stacks
 ///
-/// Includes the 16 principal Odù, pseudo-domains (Coop, Opele),
+/// Includes the 16 principal Odù, pseudo-domains (Coop, Opele, Opon),
 /// infrastructure layer (Cpu, Gpu, Storage), and application stacks.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum OduDomain {
@@ -49,6 +49,8 @@ pub enum OduDomain {
     Coop,
     /// Ọpẹlẹ - Divination/Compound Odù
     Opele,
+    /// Ọpọ́n - Calabash (memory budget) introspection
+    Opon,
 
     // =========================================================================
     // Infrastructure Layer (Hardware/OS)
@@ -128,6 +130,7 @@ impl OduDomain {
             OduDomain::Ofun => "Òfún",
             OduDomain::Coop => "Àjọṣe",
             OduDomain::Opele => "Ọpẹlẹ",
+            OduDomain::Opon => "Ọpọ́n",
             OduDomain::Cpu => "Ẹrọ-ìṣirò",
             OduDomain::Gpu => "Ẹrọ-àwòrán",
             OduDomain::Storage => "Àkójọpọ̀",
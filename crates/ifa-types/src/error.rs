@@ -130,6 +130,11 @@ pub enum IfaError {
     #[error("User error: {0}")]
     UserError(Box<crate::IfaValue>),
 
+    /// Unrecoverable error via `Ọ̀kànràn.panic`. Never bound by a catch arm:
+    /// it unwinds to the top, running only `finally` blocks on the way.
+    #[error("Panic: {0}")]
+    Panic(String),
+
     // =========================================================================
     // PARSER/INTERPRETER ERRORS
     // =========================================================================
@@ -150,6 +155,13 @@ impl From<std::io::Error> for IfaError {
     }
 }
 
+/// Integer arithmetic is always checked: overflow is an error, never a silent
+/// wrap or a switch to Float. Use `Obara.wrapping_add` and friends when
+/// wrapping is wanted. Shared by the interpreter, the VM and `IfaValue`.
+pub fn overflow() -> IfaError {
+    IfaError::Runtime("integer overflow".into())
+}
+
 impl IfaError {
     /// Get the standard numeric error code for this error.
     ///
@@ -190,6 +202,7 @@ impl IfaError {
             | IfaError::NotImplemented(_)
             | IfaError::Custom(_)
             | IfaError::UserError(_)
+            | IfaError::Panic(_)
             | IfaError::Yielded
             | IfaError::RegistryNotAttached(_)
             | IfaError::Runtime(_) => ErrorCode::VmError,
@@ -222,6 +235,11 @@ impl IfaError {
         }
     }
 
+    /// Whether a try/catch may handle this error. Panics only run `finally`.
+    pub fn is_catchable(&self) -> bool {
+        !matches!(self, IfaError::Panic(_))
+    }
+
     /// If this is a `UserError`, extract the inner `IfaValue`.
     /// Used by the VM's catch handler to bind the error variable.
     pub fn user_value(&self) -> Option<&crate::IfaValue> {
@@ -21,11 +21,14 @@ extern crate alloc;
 
 pub mod domain;
 pub mod error;
+pub mod numeric;
+pub mod range;
 pub mod shared;
 pub mod token;
 pub mod traits;
 pub mod value;
 pub mod value_union; // Unified Type System (Internal)
+pub mod wisdom;
 
 #[cfg(feature = "vm")]
 pub mod ast;
@@ -35,7 +38,9 @@ pub mod bytecode;
 
 // Re-exports for convenience
 pub use domain::OduDomain;
-pub use error::{IfaError, IfaResult, SpannedError, format_error};
+pub use error::{IfaError, IfaResult, SpannedError, format_error, overflow};
+pub use numeric::NumericList;
+pub use range::IntRange;
 pub use shared::IfaShared;
 pub use token::ResourceToken;
 pub use traits::*;
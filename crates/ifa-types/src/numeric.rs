@@ -0,0 +1,182 @@
+//! # Numeric Lists
+//!
+//! Contiguous storage for homogeneous numeric lists. `IfaValue::List` boxes
+//! every element, so reductions over large numeric lists (Ọ̀sá, Cpu, Ml) first
+//! pack them into a `NumericList` and work on the plain `i64`/`f64` slice.
+//!
+//! The packed form is internal: anything handed back to a program is
+//! converted to an ordinary `IfaValue::List` with identical elements.
+
+use crate::IfaValue;
+use crate::error::{IfaResult, overflow};
+
+/// A list whose elements are all `Int` or all numeric with at least one `Float`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NumericList {
+    Int(Vec<i64>),
+    Float(Vec<f64>),
+}
+
+impl NumericList {
+    /// Pack `values`, or `None` if any element is not a number.
+    ///
+    /// Mixed `Int`/`Float` lists are packed as `Float`.
+    pub fn from_values(values: &[IfaValue]) -> Option<Self> {
+        let mut ints = Vec::with_capacity(values.len());
+        for (i, value) in values.iter().enumerate() {
+            match value {
+                IfaValue::Int(n) => ints.push(*n),
+                IfaValue::Float(_) => {
+                    let mut floats: Vec<f64> = ints.iter().map(|n| *n as f64).collect();
+                    floats.reserve(values.len() - i);
+                    for value in &values[i..] {
+                        match value {
+                            IfaValue::Int(n) => floats.push(*n as f64),
+                            IfaValue::Float(f) => floats.push(*f),
+                            _ => return None,
+                        }
+                    }
+                    return Some(NumericList::Float(floats));
+                }
+                _ => return None,
+            }
+        }
+        Some(NumericList::Int(ints))
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            NumericList::Int(v) => v.len(),
+            NumericList::Float(v) => v.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Element at `index`, as the `IfaValue` the general list would hold.
+    pub fn get(&self, index: usize) -> Option<IfaValue> {
+        match self {
+            NumericList::Int(v) => v.get(index).map(|n| IfaValue::Int(*n)),
+            NumericList::Float(v) => v.get(index).map(|f| IfaValue::Float(*f)),
+        }
+    }
+
+    /// Append `value`, widening `Int` to `Float` as needed.
+    ///
+    /// A non-numeric value demotes the list: `Err` carries the general
+    /// list with `value` already appended.
+    pub fn push(self, value: IfaValue) -> Result<Self, Vec<IfaValue>> {
+        match (self, value) {
+            (NumericList::Int(mut v), IfaValue::Int(n)) => {
+                v.push(n);
+                Ok(NumericList::Int(v))
+            }
+            (NumericList::Int(v), IfaValue::Float(f)) => {
+                let mut floats: Vec<f64> = v.into_iter().map(|n| n as f64).collect();
+                floats.push(f);
+                Ok(NumericList::Float(floats))
+            }
+            (NumericList::Float(mut v), IfaValue::Int(n)) => {
+                v.push(n as f64);
+                Ok(NumericList::Float(v))
+            }
+            (NumericList::Float(mut v), IfaValue::Float(f)) => {
+                v.push(f);
+                Ok(NumericList::Float(v))
+            }
+            (list, other) => {
+                let mut values = list.to_values();
+                values.push(other);
+                Err(values)
+            }
+        }
+    }
+
+    /// The packed integers, if this is an `Int` list.
+    pub fn as_ints(&self) -> Option<&[i64]> {
+        match self {
+            NumericList::Int(v) => Some(v),
+            NumericList::Float(_) => None,
+        }
+    }
+
+    /// Sum of all elements: `Int` for integer lists, `Float` otherwise.
+    /// An integer sum that overflows `i64` is an error.
+    pub fn sum(&self) -> IfaResult<IfaValue> {
+        match self {
+            NumericList::Int(v) => v
+                .iter()
+                .try_fold(0i64, |acc, &n| acc.checked_add(n))
+                .map(IfaValue::Int)
+                .ok_or_else(overflow),
+            NumericList::Float(v) => Ok(IfaValue::Float(v.iter().sum())),
+        }
+    }
+
+    /// Unpack into the general representation.
+    pub fn to_values(&self) -> Vec<IfaValue> {
+        match self {
+            NumericList::Int(v) => v.iter().map(|n| IfaValue::Int(*n)).collect(),
+            NumericList::Float(v) => v.iter().map(|f| IfaValue::Float(*f)).collect(),
+        }
+    }
+}
+
+impl From<NumericList> for IfaValue {
+    fn from(list: NumericList) -> Self {
+        IfaValue::list(list.to_values())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numeric_list_indexes_like_general_list() {
+        let values = vec![IfaValue::Int(3), IfaValue::Int(-1), IfaValue::Int(7)];
+        let packed = NumericList::from_values(&values).unwrap();
+
+        assert_eq!(packed.len(), 3);
+        for (i, value) in values.iter().enumerate() {
+            assert_eq!(packed.get(i).as_ref(), Some(value));
+        }
+        assert_eq!(packed.get(3), None);
+        assert_eq!(IfaValue::from(packed), IfaValue::list(values));
+    }
+
+    #[test]
+    fn test_mixed_numbers_pack_as_float() {
+        let packed = NumericList::from_values(&[IfaValue::Int(1), IfaValue::Float(2.5)]).unwrap();
+        assert_eq!(packed, NumericList::Float(vec![1.0, 2.5]));
+        assert_eq!(packed.sum().unwrap(), IfaValue::Float(3.5));
+
+        let widened = NumericList::Int(vec![1])
+            .push(IfaValue::Float(0.5))
+            .unwrap();
+        assert_eq!(widened, NumericList::Float(vec![1.0, 0.5]));
+    }
+
+    #[test]
+    fn test_int_sum_overflow_is_an_error() {
+        assert_eq!(
+            NumericList::Int(vec![2, 3]).sum().unwrap(),
+            IfaValue::Int(5)
+        );
+        let err = NumericList::Int(vec![i64::MAX, 1]).sum().unwrap_err();
+        assert_eq!(err.to_string(), overflow().to_string());
+    }
+
+    #[test]
+    fn test_push_string_demotes_to_general_list() {
+        let packed = NumericList::from_values(&[IfaValue::Int(1), IfaValue::Int(2)]).unwrap();
+        let general = packed.push(IfaValue::str("three")).unwrap_err();
+        assert_eq!(
+            general,
+            vec![IfaValue::Int(1), IfaValue::Int(2), IfaValue::str("three")]
+        );
+        assert!(NumericList::from_values(&general).is_none());
+    }
+}
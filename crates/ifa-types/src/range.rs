@@ -0,0 +1,144 @@
+//! # Integer Ranges
+//!
+//! The lazy value behind `Iwori.range(start, end[, step])`. A range is
+//! stored as its bounds, so holding `range(0, 1000000)` in a variable or
+//! passing it to a function costs nothing; its elements are produced as a
+//! `fun` loop, an index or `len` asks for them.
+
+use crate::error::{IfaError, IfaResult};
+use crate::value_union::IfaValue;
+
+/// Half-open integer range `start..end` stepping by `step`.
+///
+/// Iterating advances `start`, so a clone is iterated when the range itself
+/// must stay intact.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IntRange {
+    start: i64,
+    end: i64,
+    step: i64,
+}
+
+impl IntRange {
+    /// Build from `(start, end[, step])`. The step defaults to 1, so
+    /// `range(5, 0)` is empty; counting down takes an explicit negative step.
+    pub fn from_args(args: &[IfaValue]) -> IfaResult<Self> {
+        let (start, end) = match (args.first(), args.get(1)) {
+            (Some(IfaValue::Int(s)), Some(IfaValue::Int(e))) => (*s, *e),
+            _ => {
+                return Err(IfaError::Runtime(
+                    "range requires start and end integers".into(),
+                ));
+            }
+        };
+        let step = match args.get(2) {
+            None => 1,
+            Some(IfaValue::Int(0)) => {
+                return Err(IfaError::Runtime("range step cannot be 0".into()));
+            }
+            Some(IfaValue::Int(step)) => *step,
+            Some(other) => {
+                return Err(IfaError::Runtime(format!(
+                    "range step must be an integer, got {}",
+                    other.type_name()
+                )));
+            }
+        };
+        Ok(Self { start, end, step })
+    }
+
+    /// Number of values still to be produced, saturating at `usize::MAX`.
+    pub fn len(&self) -> usize {
+        let (start, end, step) = (self.start as i128, self.end as i128, self.step as i128);
+        let remaining = if step > 0 && start < end {
+            (end - start - 1) / step + 1
+        } else if step < 0 && start > end {
+            (start - end - 1) / -step + 1
+        } else {
+            0
+        };
+        usize::try_from(remaining).unwrap_or(usize::MAX)
+    }
+
+    /// Whether no values remain.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The value at `index`, without producing the ones before it.
+    pub fn get(&self, index: usize) -> Option<i64> {
+        if index >= self.len() {
+            return None;
+        }
+        // In bounds, so the offset lands between start and end
+        Some((self.start as i128 + index as i128 * self.step as i128) as i64)
+    }
+
+    /// The elements as a list.
+    pub fn to_list(&self) -> Vec<IfaValue> {
+        self.clone().map(IfaValue::Int).collect()
+    }
+}
+
+impl Iterator for IntRange {
+    type Item = i64;
+
+    fn next(&mut self) -> Option<i64> {
+        let in_range = if self.step > 0 {
+            self.start < self.end
+        } else {
+            self.start > self.end
+        };
+        if !in_range {
+            return None;
+        }
+        let current = self.start;
+        // On overflow the range is exhausted
+        self.start = current.checked_add(self.step).unwrap_or(self.end);
+        Some(current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(args: &[i64]) -> IntRange {
+        let args: Vec<IfaValue> = args.iter().copied().map(IfaValue::Int).collect();
+        IntRange::from_args(&args).unwrap()
+    }
+
+    fn collect(args: &[i64]) -> Vec<i64> {
+        range(args).collect()
+    }
+
+    #[test]
+    fn test_int_range_directions() {
+        assert_eq!(collect(&[0, 5]), vec![0, 1, 2, 3, 4]);
+        assert_eq!(collect(&[5, 0]), Vec::<i64>::new());
+        assert_eq!(collect(&[5, 0, -1]), vec![5, 4, 3, 2, 1]);
+        assert_eq!(collect(&[0, 10, 3]), vec![0, 3, 6, 9]);
+        assert_eq!(collect(&[0, 5, -1]), Vec::<i64>::new());
+        assert_eq!(collect(&[i64::MAX - 1, i64::MAX, 5]), vec![i64::MAX - 1]);
+    }
+
+    #[test]
+    fn test_int_range_len_and_get_match_values() {
+        for args in [[0, 5, 1], [5, 0, -1], [0, 10, 3], [0, 5, -1], [-3, 3, 4]] {
+            let values = collect(&args);
+            let range = range(&args);
+            assert_eq!(range.len(), values.len());
+            for (i, v) in values.iter().enumerate() {
+                assert_eq!(range.get(i), Some(*v));
+            }
+            assert_eq!(range.get(values.len()), None);
+        }
+        assert_eq!(range(&[i64::MIN, i64::MAX]).len(), usize::MAX);
+    }
+
+    #[test]
+    fn test_int_range_rejects_zero_step() {
+        let args = [IfaValue::Int(0), IfaValue::Int(5), IfaValue::Int(0)];
+        assert!(IntRange::from_args(&args).is_err());
+    }
+}
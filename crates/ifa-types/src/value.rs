@@ -17,7 +17,7 @@ use crate::shared::IfaShared;
 // use crate::domain::OduDomain;
 #[cfg(feature = "vm")]
 use crate::ast::Statement;
-use crate::error::{IfaError, IfaResult};
+use crate::error::{IfaError, IfaResult, overflow};
 use crate::token::ResourceToken;
 
 /// Function signature for lambdas: takes arguments, returns a value
@@ -186,15 +186,47 @@ impl fmt::Display for IfaValue {
 // MATH OPERATIONS (Ọ̀bàrà & Òtúúrúpọ̀n)
 // =============================================================================
 
+// The operators cannot fail, so anything the checked_* methods below reject
+// (integer overflow included) comes out as Null.
+
 impl Add for IfaValue {
     type Output = IfaValue;
 
     fn add(self, other: IfaValue) -> IfaValue {
-        match (&self, &other) {
-            (IfaValue::Int(a), IfaValue::Int(b)) => a
-                .checked_add(*b)
-                .map(IfaValue::Int)
-                .unwrap_or_else(|| IfaValue::Float(*a as f64 + *b as f64)),
+        self.checked_add(&other).unwrap_or(IfaValue::Null)
+    }
+}
+
+impl Sub for IfaValue {
+    type Output = IfaValue;
+
+    fn sub(self, other: IfaValue) -> IfaValue {
+        self.checked_sub(&other).unwrap_or(IfaValue::Null)
+    }
+}
+
+impl Mul for IfaValue {
+    type Output = IfaValue;
+
+    fn mul(self, other: IfaValue) -> IfaValue {
+        self.checked_mul(&other).unwrap_or(IfaValue::Null)
+    }
+}
+
+impl IfaValue {
+    fn operand_error(&self, op: &str, other: &IfaValue) -> IfaError {
+        IfaError::TypeError {
+            expected: format!("operands for {}", op),
+            got: format!("{} {} {}", self.type_name(), op, other.type_name()),
+        }
+    }
+
+    /// Checked addition; integer overflow is an error, as in the interpreter and VM.
+    pub fn checked_add(&self, other: &IfaValue) -> IfaResult<IfaValue> {
+        Ok(match (self, other) {
+            (IfaValue::Int(a), IfaValue::Int(b)) => {
+                a.checked_add(*b).map(IfaValue::Int).ok_or_else(overflow)?
+            }
             (IfaValue::Float(a), IfaValue::Float(b)) => IfaValue::Float(a + b),
             (IfaValue::Int(a), IfaValue::Float(b)) => IfaValue::Float(*a as f64 + b),
             (IfaValue::Float(a), IfaValue::Int(b)) => IfaValue::Float(a + *b as f64),
@@ -206,37 +238,29 @@ impl Add for IfaValue {
                 result.extend(b.clone());
                 IfaValue::List(result)
             }
-            _ => IfaValue::Null,
-        }
+            _ => return Err(self.operand_error("+", other)),
+        })
     }
-}
-
-impl Sub for IfaValue {
-    type Output = IfaValue;
 
-    fn sub(self, other: IfaValue) -> IfaValue {
-        match (&self, &other) {
-            (IfaValue::Int(a), IfaValue::Int(b)) => a
-                .checked_sub(*b)
-                .map(IfaValue::Int)
-                .unwrap_or_else(|| IfaValue::Float(*a as f64 - *b as f64)),
+    /// Checked subtraction; integer overflow is an error.
+    pub fn checked_sub(&self, other: &IfaValue) -> IfaResult<IfaValue> {
+        Ok(match (self, other) {
+            (IfaValue::Int(a), IfaValue::Int(b)) => {
+                a.checked_sub(*b).map(IfaValue::Int).ok_or_else(overflow)?
+            }
             (IfaValue::Float(a), IfaValue::Float(b)) => IfaValue::Float(a - b),
             (IfaValue::Int(a), IfaValue::Float(b)) => IfaValue::Float(*a as f64 - b),
             (IfaValue::Float(a), IfaValue::Int(b)) => IfaValue::Float(a - *b as f64),
-            _ => IfaValue::Null,
-        }
+            _ => return Err(self.operand_error("-", other)),
+        })
     }
-}
-
-impl Mul for IfaValue {
-    type Output = IfaValue;
 
-    fn mul(self, other: IfaValue) -> IfaValue {
-        match (&self, &other) {
-            (IfaValue::Int(a), IfaValue::Int(b)) => a
-                .checked_mul(*b)
-                .map(IfaValue::Int)
-                .unwrap_or_else(|| IfaValue::Float(*a as f64 * *b as f64)),
+    /// Checked multiplication; integer overflow is an error.
+    pub fn checked_mul(&self, other: &IfaValue) -> IfaResult<IfaValue> {
+        Ok(match (self, other) {
+            (IfaValue::Int(a), IfaValue::Int(b)) => {
+                a.checked_mul(*b).map(IfaValue::Int).ok_or_else(overflow)?
+            }
             (IfaValue::Float(a), IfaValue::Float(b)) => IfaValue::Float(a * b),
             (IfaValue::Int(a), IfaValue::Float(b)) => IfaValue::Float(*a as f64 * b),
             (IfaValue::Float(a), IfaValue::Int(b)) => IfaValue::Float(a * *b as f64),
@@ -246,12 +270,10 @@ impl Mul for IfaValue {
             (IfaValue::Int(n), IfaValue::Str(s)) if *n >= 0 => {
                 IfaValue::Str(s.repeat(*n as usize).into())
             }
-            _ => IfaValue::Null,
-        }
+            _ => return Err(self.operand_error("*", other)),
+        })
     }
-}
 
-impl IfaValue {
     /// Checked division that returns proper errors.
     pub fn checked_div(&self, other: &IfaValue) -> IfaResult<IfaValue> {
         match (self, other) {
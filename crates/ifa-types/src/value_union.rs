@@ -8,6 +8,7 @@ use serde::de::Error as DeError;
 #[cfg(feature = "serde")]
 use serde::ser::Error as SerError;
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::fmt;
 use std::sync::Arc;
@@ -16,6 +17,7 @@ use std::{cell::RefCell, rc::Rc, sync::Mutex};
 
 #[cfg(feature = "vm")]
 use crate::ast::Statement;
+use crate::range::IntRange;
 use crate::token::ResourceToken;
 
 // ============================================================================
@@ -40,6 +42,10 @@ pub enum IfaValue {
     Str(Arc<str>),
     List(Arc<Vec<IfaValue>>),
     Map(Arc<HashMap<Arc<str>, IfaValue>>),
+    /// Raw byte string (`b"\x01\xFF"`), used by the IoT/embedded path.
+    Bytes(Arc<Vec<u8>>),
+    /// Lazy integer range from `Iwori.range`, equal to the list it produces.
+    Range(Arc<IntRange>),
 
     // 3. Special / VM Objects
     Fn(Arc<BytecodeFnData>),
@@ -48,6 +54,10 @@ pub enum IfaValue {
     #[cfg(feature = "vm")]
     AstFn(Arc<AstFnData>),
 
+    /// Host function bound by the embedder (`Interpreter::define_native`).
+    #[cfg(feature = "vm")]
+    NativeFn(Rc<NativeFnData>),
+
     /// Boxed/captured binding cell (closure upvalue).
     #[cfg(feature = "vm")]
     Upvalue(UpvalueCell),
@@ -142,6 +152,14 @@ impl IfaValue {
         IfaValue::List(Arc::new(items))
     }
 
+    pub fn bytes(b: Vec<u8>) -> Self {
+        IfaValue::Bytes(Arc::new(b))
+    }
+
+    pub fn range(r: IntRange) -> Self {
+        IfaValue::Range(Arc::new(r))
+    }
+
     pub fn map(m: HashMap<String, IfaValue>) -> Self {
         let mut internal = HashMap::with_capacity(m.len());
         for (k, v) in m {
@@ -208,9 +226,13 @@ impl IfaValue {
             IfaValue::Str(_) => "Str",
             IfaValue::List(_) => "List",
             IfaValue::Map(_) => "Map",
+            IfaValue::Bytes(_) => "Bytes",
+            IfaValue::Range(_) => "Range",
             IfaValue::Fn(_) => "Fn",
             #[cfg(feature = "vm")]
             IfaValue::AstFn(_) => "Fn",
+            #[cfg(feature = "vm")]
+            IfaValue::NativeFn(_) => "Fn",
             IfaValue::Result(_) => "Result",
             #[cfg(feature = "vm")]
             IfaValue::Upvalue(_) => "Upvalue",
@@ -222,6 +244,12 @@ impl IfaValue {
         }
     }
 
+    /// Truthiness used by conditions, `!` and `Okanran.truthy`.
+    ///
+    /// Falsy: `Null`, `false`, `0`, `0.0`, `NaN`, and an empty `Str`,
+    /// `List`, `Map` or `Bytes`. Everything else is truthy, including
+    /// functions and `Result`s. Matches `EmbeddedValue::is_truthy` for the
+    /// variants both share (`Bytes` corresponds to `Blob`).
     pub fn is_truthy(&self) -> bool {
         match self {
             IfaValue::Null => false,
@@ -231,10 +259,14 @@ impl IfaValue {
             IfaValue::Str(s) => !s.is_empty(),
             IfaValue::List(l) => !l.is_empty(),
             IfaValue::Map(m) => !m.is_empty(),
+            IfaValue::Bytes(b) => !b.is_empty(),
+            IfaValue::Range(r) => !r.is_empty(),
             IfaValue::Fn(_) => true,
             #[cfg(feature = "vm")]
             IfaValue::AstFn(_) => true,
             #[cfg(feature = "vm")]
+            IfaValue::NativeFn(_) => true,
+            #[cfg(feature = "vm")]
             IfaValue::Closure(_) => true,
             #[cfg(feature = "vm")]
             IfaValue::Return(v) => v.is_truthy(),
@@ -256,13 +288,34 @@ impl IfaValue {
         matches!(self, IfaValue::Null)
     }
 
+    /// Structural equality, behind `PartialEq`/`Eq`.
+    ///
+    /// Floats within `f64::EPSILON` are equal, infinities equal themselves,
+    /// and NaN equals NaN, so equality stays reflexive and a list holding NaN
+    /// equals a copy of itself. (The language's `==` operator keeps IEEE
+    /// semantics, where NaN equals nothing.) Map keys are always strings, so
+    /// a float — NaN or otherwise — can never be a key.
     pub fn is_equal(&self, other: &Self) -> bool {
         match (self, other) {
             (IfaValue::Null, IfaValue::Null) => true,
             (IfaValue::Bool(a), IfaValue::Bool(b)) => a == b,
             (IfaValue::Int(a), IfaValue::Int(b)) => a == b,
-            (IfaValue::Float(a), IfaValue::Float(b)) => (a - b).abs() < f64::EPSILON,
+            (IfaValue::Float(a), IfaValue::Float(b)) => {
+                a == b || (a.is_nan() && b.is_nan()) || (a - b).abs() < f64::EPSILON
+            }
             (IfaValue::Str(a), IfaValue::Str(b)) => a == b,
+            (IfaValue::Bytes(a), IfaValue::Bytes(b)) => a == b,
+            // Ranges are arithmetic, so the first two values and the length
+            // settle it
+            (IfaValue::Range(a), IfaValue::Range(b)) => {
+                a.len() == b.len() && a.get(0) == b.get(0) && a.get(1) == b.get(1)
+            }
+            (IfaValue::Range(r), IfaValue::List(l)) | (IfaValue::List(l), IfaValue::Range(r)) => {
+                r.len() == l.len()
+                    && l.iter()
+                        .zip(r.as_ref().clone())
+                        .all(|(v, i)| matches!(v, IfaValue::Int(x) if *x == i))
+            }
             (IfaValue::List(a), IfaValue::List(b)) => {
                 if Arc::ptr_eq(a, b) {
                     return true;
@@ -290,6 +343,65 @@ impl IfaValue {
             _ => false,
         }
     }
+
+    /// The language's `==`: an Int equals a Float of the same value and
+    /// NaN equals nothing (IEEE), at any depth inside lists, maps and
+    /// results. Everything else compares as `is_equal`.
+    pub fn numeric_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (IfaValue::Float(a), IfaValue::Float(b)) => a == b || (a - b).abs() < f64::EPSILON,
+            (IfaValue::Int(i), IfaValue::Float(f)) | (IfaValue::Float(f), IfaValue::Int(i)) => {
+                *i as f64 == *f
+            }
+            (IfaValue::List(a), IfaValue::List(b)) => {
+                a.len() == b.len() && a.iter().zip(b.iter()).all(|(x, y)| x.numeric_eq(y))
+            }
+            (IfaValue::Map(a), IfaValue::Map(b)) => {
+                a.len() == b.len()
+                    && a.iter()
+                        .all(|(k, v)| b.get(k).is_some_and(|bv| v.numeric_eq(bv)))
+            }
+            (IfaValue::Result(a), IfaValue::Result(b)) => match (a.as_ref(), b.as_ref()) {
+                (ResultPayload::Ok(av), ResultPayload::Ok(bv))
+                | (ResultPayload::Err(av), ResultPayload::Err(bv)) => av.numeric_eq(bv),
+                _ => false,
+            },
+            _ => self.is_equal(other),
+        }
+    }
+
+    /// Total ordering for sorting: numbers by value (Int promoted to Float),
+    /// strings lexicographically. Every NaN sorts after +Infinity and equal to
+    /// other NaNs. `None` when the two values are not comparable.
+    pub fn total_cmp(&self, other: &Self) -> Option<Ordering> {
+        fn floats(a: f64, b: f64) -> Ordering {
+            match (a.is_nan(), b.is_nan()) {
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Greater,
+                (false, true) => Ordering::Less,
+                (false, false) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+            }
+        }
+        match (self, other) {
+            (IfaValue::Int(a), IfaValue::Int(b)) => Some(a.cmp(b)),
+            (IfaValue::Float(a), IfaValue::Float(b)) => Some(floats(*a, *b)),
+            (IfaValue::Int(a), IfaValue::Float(b)) => Some(floats(*a as f64, *b)),
+            (IfaValue::Float(a), IfaValue::Int(b)) => Some(floats(*a, *b as f64)),
+            (IfaValue::Str(a), IfaValue::Str(b)) => Some(a.cmp(b)),
+            _ => None,
+        }
+    }
+
+    /// The first NaN or infinite float in `self`, looking inside lists and
+    /// maps, for encoders (such as JSON) that cannot represent one.
+    pub fn non_finite_float(&self) -> Option<f64> {
+        match self {
+            IfaValue::Float(f) if !f.is_finite() => Some(*f),
+            IfaValue::List(items) => items.iter().find_map(IfaValue::non_finite_float),
+            IfaValue::Map(map) => map.values().find_map(IfaValue::non_finite_float),
+            _ => None,
+        }
+    }
 }
 
 // ============================================================================
@@ -313,10 +425,20 @@ impl fmt::Display for IfaValue {
             IfaValue::Float(fl) => write!(f, "{}", fl),
             IfaValue::Str(s) => write!(f, "{}", s),
             IfaValue::List(_) => write!(f, "[List]"),
+            IfaValue::Range(_) => write!(f, "[Range]"),
             IfaValue::Map(_) => write!(f, "{{Map}}"),
+            IfaValue::Bytes(bytes) => {
+                write!(f, "b\"")?;
+                for b in bytes.iter() {
+                    write!(f, "\\x{:02X}", b)?;
+                }
+                write!(f, "\"")
+            }
             IfaValue::Fn(_) => write!(f, "<fn>"),
             #[cfg(feature = "vm")]
             IfaValue::AstFn(data) => write!(f, "<fn {}>", data.name),
+            #[cfg(feature = "vm")]
+            IfaValue::NativeFn(data) => write!(f, "<native fn {}>", data.name),
             IfaValue::Result(payload) => match payload.as_ref() {
                 ResultPayload::Ok(val) => write!(f, "Ok({})", val),
                 ResultPayload::Err(val) => write!(f, "Err({})", val),
@@ -354,6 +476,10 @@ enum IfaValueSurrogate {
     List(Vec<IfaValue>),
     /// Placeholder for non-serializable variants (Fn, Closure, Class, etc.)
     Unsupported,
+    // Appended so existing variant indices stay stable
+    Bytes(Vec<u8>),
+    /// Entries sorted by key so encoded output is stable across runs
+    Map(Vec<(String, IfaValue)>),
 }
 
 #[cfg(feature = "serde")]
@@ -372,6 +498,14 @@ impl Serialize for IfaValue {
                 let inner = l.iter().cloned().collect();
                 IfaValueSurrogate::List(inner)
             }
+            IfaValue::Bytes(b) => IfaValueSurrogate::Bytes(b.to_vec()),
+            IfaValue::Range(r) => IfaValueSurrogate::List(r.to_list()),
+            IfaValue::Map(m) => {
+                let mut entries: Vec<(String, IfaValue)> =
+                    m.iter().map(|(k, v)| (k.to_string(), v.clone())).collect();
+                entries.sort_by(|a, b| a.0.cmp(&b.0));
+                IfaValueSurrogate::Map(entries)
+            }
             other => {
                 return Err(S::Error::custom(format!(
                     "IfaValue variant '{}' is not serializable",
@@ -397,6 +531,8 @@ impl<'de> Deserialize<'de> for IfaValue {
             IfaValueSurrogate::Float(f) => IfaValue::Float(f),
             IfaValueSurrogate::Str(s) => IfaValue::str(s),
             IfaValueSurrogate::List(l) => IfaValue::list(l),
+            IfaValueSurrogate::Bytes(b) => IfaValue::bytes(b),
+            IfaValueSurrogate::Map(entries) => IfaValue::map(entries.into_iter().collect()),
             IfaValueSurrogate::Unsupported => {
                 return Err(D::Error::custom(
                     "unsupported IfaValue surrogate in serialized data",
@@ -425,6 +561,129 @@ mod tests {
     }
 }
 
+#[cfg(test)]
+mod float_tests {
+    use super::*;
+
+    #[test]
+    fn nan_and_infinity_equality_is_reflexive() {
+        let nan = IfaValue::Float(f64::NAN);
+        assert_eq!(nan, nan.clone());
+        assert_eq!(
+            IfaValue::list(vec![IfaValue::Int(1), nan.clone()]),
+            IfaValue::list(vec![IfaValue::Int(1), nan.clone()])
+        );
+        assert_eq!(
+            IfaValue::Float(f64::INFINITY),
+            IfaValue::Float(f64::INFINITY)
+        );
+        assert_ne!(
+            IfaValue::Float(f64::INFINITY),
+            IfaValue::Float(f64::NEG_INFINITY)
+        );
+        assert_ne!(nan, IfaValue::Float(0.0));
+    }
+
+    #[test]
+    fn numeric_eq_promotes_ints_but_partial_eq_stays_structural() {
+        let nan = IfaValue::Float(f64::NAN);
+        assert!(IfaValue::Int(1).numeric_eq(&IfaValue::Float(1.0)));
+        assert!(
+            IfaValue::list(vec![IfaValue::Int(3)])
+                .numeric_eq(&IfaValue::list(vec![IfaValue::Float(3.0)]))
+        );
+        assert!(!nan.numeric_eq(&nan));
+        assert!(!IfaValue::Int(1).numeric_eq(&IfaValue::str("1")));
+
+        assert_ne!(IfaValue::Int(1), IfaValue::Float(1.0));
+        assert_eq!(nan, nan.clone());
+    }
+
+    #[test]
+    fn total_cmp_sorts_nan_last() {
+        let mut values = [
+            IfaValue::Float(f64::NAN),
+            IfaValue::Int(2),
+            IfaValue::Float(f64::INFINITY),
+            IfaValue::Float(-0.5),
+        ];
+        values.sort_by(|a, b| a.total_cmp(b).unwrap());
+        assert_eq!(values[0], IfaValue::Float(-0.5));
+        assert_eq!(values[1], IfaValue::Int(2));
+        assert_eq!(values[2], IfaValue::Float(f64::INFINITY));
+        assert!(matches!(values[3], IfaValue::Float(f) if f.is_nan()));
+        assert_eq!(IfaValue::Int(1).total_cmp(&IfaValue::str("1")), None);
+    }
+
+    #[test]
+    fn non_finite_float_searches_collections() {
+        let mut map = HashMap::new();
+        map.insert(
+            "x".into(),
+            IfaValue::list(vec![IfaValue::Float(f64::NEG_INFINITY)]),
+        );
+        assert_eq!(
+            IfaValue::map(map).non_finite_float(),
+            Some(f64::NEG_INFINITY)
+        );
+        assert_eq!(IfaValue::Float(1.5).non_finite_float(), None);
+    }
+}
+
+#[cfg(test)]
+mod truthiness_tests {
+    use super::*;
+
+    #[test]
+    fn is_truthy_covers_every_variant() {
+        assert!(!IfaValue::Null.is_truthy());
+        assert!(!IfaValue::Bool(false).is_truthy());
+        assert!(IfaValue::Bool(true).is_truthy());
+        assert!(!IfaValue::Int(0).is_truthy());
+        assert!(IfaValue::Int(-1).is_truthy());
+        assert!(!IfaValue::Float(0.0).is_truthy());
+        assert!(!IfaValue::Float(f64::NAN).is_truthy());
+        assert!(IfaValue::Float(0.5).is_truthy());
+        assert!(!IfaValue::str("").is_truthy());
+        assert!(IfaValue::str("0").is_truthy());
+        assert!(!IfaValue::list(vec![]).is_truthy());
+        assert!(IfaValue::list(vec![IfaValue::Null]).is_truthy());
+        assert!(!IfaValue::map(HashMap::new()).is_truthy());
+        assert!(IfaValue::map(HashMap::from([("k".to_string(), IfaValue::Null)])).is_truthy());
+        assert!(!IfaValue::bytes(vec![]).is_truthy());
+        assert!(IfaValue::bytes(vec![0]).is_truthy());
+        let range = |start, end| {
+            IfaValue::range(
+                IntRange::from_args(&[IfaValue::Int(start), IfaValue::Int(end)]).unwrap(),
+            )
+        };
+        assert!(!range(5, 0).is_truthy());
+        assert!(range(0, 1).is_truthy());
+
+        let func = IfaValue::Fn(Arc::new(BytecodeFnData {
+            name: "f".to_string(),
+            start_ip: 0,
+            arity: 0,
+            is_async: false,
+        }));
+        assert!(func.is_truthy());
+        assert!(IfaValue::Resource(Arc::new(ResourceToken::new(0))).is_truthy());
+        assert!(IfaValue::ok(IfaValue::Null).is_truthy());
+        assert!(IfaValue::err(IfaValue::Null).is_truthy());
+    }
+
+    #[cfg(feature = "vm")]
+    #[test]
+    fn wrapper_variants_use_inner_truthiness() {
+        assert!(!IfaValue::return_value(IfaValue::Int(0)).is_truthy());
+        assert!(IfaValue::return_value(IfaValue::Int(1)).is_truthy());
+        let cell: UpvalueCell = Rc::new(RefCell::new(IfaValue::str("")));
+        assert!(!IfaValue::Upvalue(cell.clone()).is_truthy());
+        *cell.borrow_mut() = IfaValue::str("x");
+        assert!(IfaValue::Upvalue(cell).is_truthy());
+    }
+}
+
 #[cfg(test)]
 mod layout_tests {
     use super::*;
@@ -456,3 +715,22 @@ pub struct AstFnData {
     pub closure_id: u64,
     pub is_async: bool,
 }
+
+/// Signature of a host function callable from Ifá code.
+#[cfg(feature = "vm")]
+pub type NativeFnBody = Box<dyn Fn(Vec<IfaValue>) -> crate::IfaResult<IfaValue>>;
+
+#[cfg(feature = "vm")]
+pub struct NativeFnData {
+    pub name: String,
+    pub func: NativeFnBody,
+}
+
+#[cfg(feature = "vm")]
+impl fmt::Debug for NativeFnData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NativeFnData")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}